@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_life::{
+    BoundaryCondition, Cell1d, Elementary1dPlugin, GenerationScroll, MapSize, Rule30CellState,
+    SimulationSpeed,
+};
+
+const WIDTH: i32 = 201;
+const SPRITE_SIZE: f32 = 5.;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Rule 30".to_string(),
+                resolution: [1000.0, 1000.0].into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(Elementary1dPlugin::<30>::new())
+        .insert_resource(SimulationSpeed::new(20.0))
+        .insert_resource(BoundaryCondition::Wrap)
+        .insert_resource(MapSize::new(WIDTH as u32, 1))
+        .insert_resource(GenerationScroll::new(SPRITE_SIZE, 200))
+        .add_systems(Startup, (setup_camera, setup_map))
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    // Camera
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn setup_map(mut commands: Commands) {
+    spawn_map(&mut commands);
+}
+
+fn spawn_map(commands: &mut Commands) {
+    let color = Color::rgba(0., 0., 0., 0.);
+
+    commands
+        .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+            -(WIDTH as f32 * SPRITE_SIZE) / 2.,
+            450.,
+            0.,
+        )))
+        .with_children(|builder| {
+            for x in 0..WIDTH {
+                builder.spawn((
+                    SpriteBundle {
+                        sprite: Sprite {
+                            custom_size: Some(Vec2::splat(SPRITE_SIZE)),
+                            color,
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(SPRITE_SIZE * x as f32, 0., 0.),
+                        ..default()
+                    },
+                    Cell1d::new(x),
+                    // A single live cell in the middle of the row
+                    Rule30CellState::from(x == WIDTH / 2),
+                ));
+            }
+        });
+    println!("map generated");
+}
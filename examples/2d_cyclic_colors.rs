@@ -1,5 +1,7 @@
 use bevy::prelude::*;
-use bevy_life::{CyclicColorCellState, CyclicColors2dPlugin, MooreCell2d, SimulationBatch};
+use bevy_life::{
+    CyclicColorCellState, CyclicColors2dPlugin, MooreCell2d, SimulationBatch, SimulationSpeed,
+};
 use rand::Rng;
 
 fn main() {
@@ -12,8 +14,9 @@ fn main() {
             }),
             ..default()
         }))
-        .add_plugins(CyclicColors2dPlugin::new().with_time_step(0.05))
-        .insert_resource(SimulationBatch)
+        .add_plugins(CyclicColors2dPlugin::new())
+        .insert_resource(SimulationSpeed::new(20.0))
+        .insert_resource(SimulationBatch::default())
         .add_systems(Startup, (setup_camera, setup_map))
         .run();
 }
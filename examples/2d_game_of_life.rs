@@ -12,8 +12,8 @@ fn main() {
             }),
             ..default()
         }))
-        .add_plugins(GameOfLife2dPlugin::default())
-        .insert_resource(SimulationBatch)
+        .add_plugins(GameOfLife2dPlugin::new().with_cached_neighbors())
+        .insert_resource(SimulationBatch::default())
         .add_systems(Startup, (setup_camera, setup_map))
         .run();
 }
@@ -13,7 +13,7 @@ fn main() {
             ..default()
         }))
         .add_plugins(ImmigrationGame2dPlugin::default())
-        .insert_resource(SimulationBatch)
+        .insert_resource(SimulationBatch::default())
         .add_systems(Startup, (setup_camera, setup_map))
         .run();
 }
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_life::{
+    Ant, AntCellState, BoundaryCondition, Heading, LangtonsAntPlugin, MapSize, MooreCell2d,
+    SimulationSpeed,
+};
+
+const SIZE: u32 = 200;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Langton's Ant".to_string(),
+                resolution: [1000.0, 1000.0].into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(LangtonsAntPlugin::<MooreCell2d>::new())
+        .insert_resource(SimulationSpeed::new(1000.0))
+        .insert_resource(BoundaryCondition::Wrap)
+        .insert_resource(MapSize::new(SIZE, SIZE))
+        .add_systems(Startup, (setup_camera, setup_map))
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    // Camera
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn setup_map(mut commands: Commands) {
+    spawn_map(&mut commands);
+}
+
+fn spawn_map(commands: &mut Commands) {
+    let size = SIZE as i32;
+    let sprite_size = 5.;
+    let color = Color::rgba(0., 0., 0., 0.);
+
+    commands
+        .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+            -(size as f32 * sprite_size) / 2.,
+            -(size as f32 * sprite_size) / 2.,
+            0.,
+        )))
+        .with_children(|builder| {
+            for y in 0..size {
+                for x in 0..size {
+                    builder.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::splat(sprite_size)),
+                                color,
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(
+                                sprite_size * x as f32,
+                                sprite_size * y as f32,
+                                0.,
+                            ),
+                            ..default()
+                        },
+                        MooreCell2d::new(IVec2::new(x, y)),
+                        AntCellState(0),
+                    ));
+                }
+            }
+        });
+    commands.spawn(Ant::new(IVec2::splat(size / 2), Heading::North));
+    println!("map generated");
+}
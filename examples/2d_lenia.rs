@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use bevy_life::{
+    BoundaryCondition, Kernel2d, KernelCell2d, Lenia2dPlugin, LeniaGrowth, LeniaState, MapSize,
+    SimulationBatch,
+};
+use rand::Rng;
+
+const SIZE: u32 = 128;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Lenia".to_string(),
+                resolution: [1000.0, 1000.0].into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(Lenia2dPlugin::new().with_dense_map(MapSize::new(SIZE, SIZE)))
+        .insert_resource(SimulationBatch::default())
+        .insert_resource(BoundaryCondition::Wrap)
+        .add_systems(Startup, (setup_camera, setup_map))
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    // Camera
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn setup_map(mut commands: Commands) {
+    spawn_map(&mut commands);
+}
+
+fn spawn_map(commands: &mut Commands) {
+    let mut rng = rand::thread_rng();
+    let size = SIZE as i32;
+    let sprite_size = 7.;
+    let color = Color::rgba(0., 0., 0., 0.);
+    let kernel = Kernel2d::lenia(13);
+    let growth = LeniaGrowth::default();
+    // Random soup in the center of the map
+    let soup = (size / 2 - 20)..(size / 2 + 20);
+
+    commands
+        .spawn(SpatialBundle::from_transform(Transform::from_xyz(
+            -(size as f32 * sprite_size) / 2.,
+            -(size as f32 * sprite_size) / 2.,
+            0.,
+        )))
+        .with_children(|builder| {
+            for y in 0..size {
+                for x in 0..size {
+                    let value = if soup.contains(&x) && soup.contains(&y) {
+                        rng.gen_range(0.0..1.0)
+                    } else {
+                        0.0
+                    };
+                    builder.spawn((
+                        SpriteBundle {
+                            sprite: Sprite {
+                                custom_size: Some(Vec2::splat(sprite_size)),
+                                color,
+                                ..default()
+                            },
+                            transform: Transform::from_xyz(
+                                sprite_size * x as f32,
+                                sprite_size * y as f32,
+                                0.,
+                            ),
+                            ..default()
+                        },
+                        KernelCell2d::new(IVec2::new(x, y), &kernel),
+                        LeniaState::new(value, growth),
+                    ));
+                }
+            }
+        });
+    println!("map generated");
+}
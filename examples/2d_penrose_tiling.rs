@@ -0,0 +1,136 @@
+use bevy::{prelude::*, utils::HashMap};
+use bevy_life::{
+    AdjacencyTable, CellularAutomatonPlugin, ConwayCellState, SimulationSpeed, TableCell,
+};
+use rand::Rng;
+use std::f32::consts::PI;
+
+const GOLDEN_RATIO: f32 = 1.618_034;
+const SUBDIVISIONS: u32 = 7;
+const RADIUS: f32 = 380.;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Penrose Game Of Life".to_string(),
+                resolution: [800.0, 800.0].into(),
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(CellularAutomatonPlugin::<TableCell, ConwayCellState>::new())
+        .insert_resource(SimulationSpeed::new(10.0))
+        .add_systems(Startup, (setup_camera, setup_map))
+        .run();
+}
+
+fn setup_camera(mut commands: Commands) {
+    // Camera
+    commands.spawn(Camera2dBundle::default());
+}
+
+fn setup_map(mut commands: Commands) {
+    let (centers, table) = penrose_patch(SUBDIVISIONS);
+    let mut rng = rand::thread_rng();
+    let sprite_size = RADIUS / GOLDEN_RATIO.powi(SUBDIVISIONS as i32) / 2.;
+    for (id, center) in centers.into_iter().enumerate() {
+        let state = ConwayCellState(rng.gen_bool(1. / 3.));
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    custom_size: Some(Vec2::splat(sprite_size)),
+                    ..default()
+                },
+                transform: Transform::from_translation((center * RADIUS).extend(0.)),
+                ..default()
+            },
+            TableCell::new(id as u32, &table),
+            state,
+        ));
+    }
+    println!("map generated: {} tiles", table.len());
+}
+
+/// Robinson triangle: `true` for the thin (36°) triangles, `false` for the
+/// thick (108°) ones
+type Triangle = (bool, Vec2, Vec2, Vec2);
+
+/// Builds a Penrose rhombus (P3) tiling patch by deflating a wheel of Robinson
+/// triangles `subdivisions` times.
+///
+/// Returns the tile centers and the adjacency table, tiles being neighbors
+/// when they share a vertex.
+fn penrose_patch(subdivisions: u32) -> (Vec<Vec2>, AdjacencyTable) {
+    let mut triangles: Vec<Triangle> = (0..10)
+        .map(|i: u32| {
+            let angle = i as f32 * PI / 5.;
+            let b = Vec2::from_angle(angle - PI / 10.);
+            let c = Vec2::from_angle(angle + PI / 10.);
+            if i.is_multiple_of(2) {
+                (true, Vec2::ZERO, c, b)
+            } else {
+                (true, Vec2::ZERO, b, c)
+            }
+        })
+        .collect();
+    for _ in 0..subdivisions {
+        triangles = triangles
+            .into_iter()
+            .flat_map(|(thin, a, b, c)| {
+                if thin {
+                    let p = a + (b - a) / GOLDEN_RATIO;
+                    vec![(true, c, p, b), (false, p, c, a)]
+                } else {
+                    let q = b + (a - b) / GOLDEN_RATIO;
+                    let r = b + (c - b) / GOLDEN_RATIO;
+                    vec![(false, r, c, a), (false, q, r, b), (true, r, q, a)]
+                }
+            })
+            .collect();
+    }
+    // Quantized points, to merge the floating point duplicates
+    let key = |p: Vec2| (p * 1e4).round().as_ivec2();
+    // Every rhombus is made of two triangles sharing their `b, c` edge
+    let mut tiles: HashMap<(IVec2, IVec2), Vec<Vec2>> = HashMap::new();
+    for (_, a, b, c) in triangles {
+        let (kb, kc) = (key(b), key(c));
+        let edge = if (kb.x, kb.y) < (kc.x, kc.y) {
+            (kb, kc)
+        } else {
+            (kc, kb)
+        };
+        let vertices = tiles.entry(edge).or_insert_with(|| vec![b, c]);
+        vertices.push(a);
+    }
+    let tiles: Vec<Vec<Vec2>> = tiles.into_values().collect();
+    let centers = tiles
+        .iter()
+        .map(|vertices| vertices.iter().sum::<Vec2>() / vertices.len() as f32)
+        .collect();
+    let mut vertex_tiles: HashMap<IVec2, Vec<u32>> = HashMap::new();
+    for (id, vertices) in tiles.iter().enumerate() {
+        for vertex in vertices {
+            vertex_tiles
+                .entry(key(*vertex))
+                .or_default()
+                .push(id as u32);
+        }
+    }
+    let table = tiles
+        .iter()
+        .enumerate()
+        .map(|(id, vertices)| {
+            let id = id as u32;
+            let mut neighbors: Vec<u32> = vertices
+                .iter()
+                .flat_map(|v| vertex_tiles[&key(*v)].iter().copied())
+                .filter(|n| *n != id)
+                .collect();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            (id, neighbors)
+        })
+        .collect();
+    (centers, table)
+}
@@ -42,7 +42,7 @@ fn main() {
             ..default()
         }))
         .add_plugins(CellularAutomatonPlugin::<MooreCell2d, RockPaperScissor>::default())
-        .insert_resource(SimulationBatch)
+        .insert_resource(SimulationBatch::default())
         .add_systems(Startup, (setup_camera, setup_map))
         .add_systems(Update, color_sprites)
         .run();
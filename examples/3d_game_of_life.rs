@@ -17,7 +17,7 @@ fn main() {
             ..default()
         }))
         .add_plugins(GameOfLife3dPlugin::default())
-        .insert_resource(SimulationBatch)
+        .insert_resource(SimulationBatch::default())
         .add_systems(Startup, (setup_camera, setup_map))
         .add_systems(Update, color)
         .run();
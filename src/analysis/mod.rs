@@ -0,0 +1,3 @@
+pub use spatial_autocorrelation::*;
+
+mod spatial_autocorrelation;
@@ -0,0 +1,153 @@
+use crate::{
+    components::{Cell, CellCoordinates},
+    resources::{BoundaryCondition, MapSize},
+};
+use bevy::utils::HashMap;
+
+/// Spatial autocorrelation of a scalar value of every cell, quantifying the
+/// spatial clustering of the value, like the infected individuals of an
+/// epidemic.
+///
+/// The statistics use binary contiguity weights: every cell is linked to each
+/// of its neighbors. They are computed on demand, for example in a system
+/// iterating over the cells:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn measure(cells: Query<(&MooreCell2d, &SeirCellState)>) {
+///     let autocorrelation = SpatialAutocorrelation::compute(&cells, None, |state| {
+///         f64::from(u8::from(state.is_infectious()))
+///     });
+///     if let Some(morans_i) = autocorrelation.morans_i {
+///         info!("Moran's I: {morans_i}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SpatialAutocorrelation {
+    /// Amount of cells
+    pub cells: usize,
+    /// Sum of the weights, the amount of links between neighbor cells in both
+    /// directions
+    pub links: usize,
+    /// [Moran's I], from `-1` for dispersed values to `1` for clustered
+    /// values. It is close to `-1 / (cells - 1)` for random values.
+    ///
+    /// `None` if the cells have no links or if the values are all equal.
+    ///
+    /// [Moran's I]: https://en.wikipedia.org/wiki/Moran%27s_I
+    pub morans_i: Option<f64>,
+    /// [Geary's C], below `1` for clustered values and above `1` for dispersed
+    /// values.
+    ///
+    /// `None` if the cells have no links or if the values are all equal.
+    ///
+    /// [Geary's C]: https://en.wikipedia.org/wiki/Geary%27s_C
+    pub gearys_c: Option<f64>,
+}
+
+impl SpatialAutocorrelation {
+    /// Computes the spatial autocorrelation of the `value` of every cell
+    /// state. The `boundary` condition, if any, is applied to the neighbors of
+    /// bounded grids.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compute<'a, C, S>(
+        cells: impl IntoIterator<Item = (&'a C, &'a S)>,
+        boundary: Option<(BoundaryCondition, MapSize)>,
+        value: impl Fn(&S) -> f64,
+    ) -> Self
+    where
+        C: Cell + 'a,
+        S: 'a,
+    {
+        let cells: Vec<(&C, f64)> = cells
+            .into_iter()
+            .map(|(cell, state)| (cell, value(state)))
+            .collect();
+        let values: HashMap<&C::Coordinates, f64> =
+            cells.iter().map(|(cell, x)| (cell.coords(), *x)).collect();
+        let n = cells.len() as f64;
+        let mean = cells.iter().map(|(_, x)| x).sum::<f64>() / n;
+        let variance: f64 = cells.iter().map(|(_, x)| (x - mean).powi(2)).sum();
+        let mut links = 0;
+        let (mut covariance, mut squared_differences) = (0.0, 0.0);
+        for (cell, x) in &cells {
+            for neighbor in cell.neighbor_coordinates() {
+                let neighbor = match boundary {
+                    Some((condition, size)) => neighbor.apply_boundary(condition, size),
+                    None => Some(neighbor),
+                };
+                let Some(y) = neighbor.and_then(|n| values.get(&n)) else {
+                    continue;
+                };
+                links += 1;
+                covariance += (x - mean) * (y - mean);
+                squared_differences += (x - y).powi(2);
+            }
+        }
+        let defined = links > 0 && variance > 0.0;
+        let weights = links as f64;
+        Self {
+            cells: cells.len(),
+            links,
+            morans_i: defined.then(|| n / weights * covariance / variance),
+            gearys_c: defined.then(|| (n - 1.0) / (2.0 * weights) * squared_differences / variance),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::NeumannCell2d;
+    use bevy::prelude::IVec2;
+
+    fn grid(value: fn(IVec2) -> bool) -> Vec<(NeumannCell2d, bool)> {
+        (0..4)
+            .flat_map(|x| (0..4).map(move |y| IVec2::new(x, y)))
+            .map(|coords| (NeumannCell2d::new(coords), value(coords)))
+            .collect()
+    }
+
+    fn autocorrelation(
+        cells: &[(NeumannCell2d, bool)],
+        boundary: Option<(BoundaryCondition, MapSize)>,
+    ) -> SpatialAutocorrelation {
+        SpatialAutocorrelation::compute(cells.iter().map(|(c, s)| (c, s)), boundary, |s| {
+            f64::from(u8::from(*s))
+        })
+    }
+
+    #[test]
+    fn checkerboard() {
+        let cells = grid(|c| (c.x + c.y) % 2 == 0);
+        let result = autocorrelation(&cells, None);
+        assert_eq!(result.cells, 16);
+        assert_eq!(result.links, 48);
+        assert!((result.morans_i.unwrap() + 1.0).abs() < 1e-9);
+        assert!((result.gearys_c.unwrap() - 1.875).abs() < 1e-9);
+    }
+
+    #[test]
+    fn halves() {
+        let cells = grid(|c| c.x < 2);
+        let result = autocorrelation(&cells, None);
+        assert!((result.morans_i.unwrap() - 2.0 / 3.0).abs() < 1e-9);
+        assert!((result.gearys_c.unwrap() - 0.3125).abs() < 1e-9);
+        // Wrapping links the halves on both sides
+        let size = MapSize::new(4, 4);
+        let result = autocorrelation(&cells, Some((BoundaryCondition::Wrap, size)));
+        assert_eq!(result.links, 64);
+        assert!((result.morans_i.unwrap() - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn undefined() {
+        let cells = grid(|_| true);
+        let result = autocorrelation(&cells, None);
+        assert_eq!(result.morans_i, None);
+        assert_eq!(result.gearys_c, None);
+    }
+}
@@ -0,0 +1,289 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, IVec2, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::ops::Deref;
+
+/// Relative turn of an [`Ant`], in the [turmite] notation order
+///
+/// [turmite]: https://en.wikipedia.org/wiki/Turmite
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum Turn {
+    /// Keep going forward
+    #[default]
+    None,
+    /// Quarter turn clockwise
+    Right,
+    /// Half turn
+    UTurn,
+    /// Quarter turn counter-clockwise
+    Left,
+}
+
+/// Cardinal heading of an [`Ant`] on a square grid, `North` being the `+Y`
+/// direction.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum Heading {
+    /// Towards `+Y`
+    #[default]
+    North,
+    /// Towards `+X`
+    East,
+    /// Towards `-Y`
+    South,
+    /// Towards `-X`
+    West,
+}
+
+impl Heading {
+    /// Heading after a quarter turn clockwise
+    #[must_use]
+    #[inline]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// Heading after a quarter turn counter-clockwise
+    #[must_use]
+    #[inline]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Heading after a `turn`
+    #[must_use]
+    #[inline]
+    pub const fn turn(self, turn: Turn) -> Self {
+        match turn {
+            Turn::None => self,
+            Turn::Right => self.turn_right(),
+            Turn::UTurn => self.turn_right().turn_right(),
+            Turn::Left => self.turn_left(),
+        }
+    }
+
+    /// Coordinates offset of a step forward
+    #[must_use]
+    #[inline]
+    pub const fn offset(self) -> IVec2 {
+        match self {
+            Self::North => IVec2::Y,
+            Self::East => IVec2::X,
+            Self::South => IVec2::NEG_Y,
+            Self::West => IVec2::NEG_X,
+        }
+    }
+}
+
+/// Agent moving on a square grid of `IVec2` coordinates cells, like
+/// [Langton's ant] or any [turmite].
+///
+/// Unlike cells, ants are not updated synchronously from their neighborhood:
+/// every tick, each ant reads and modifies the state of the cell at its
+/// `coords`, then turns and moves following the [`TurmiteRules`]. See the
+/// [`LangtonsAntPlugin`].
+///
+/// [Langton's ant]: https://en.wikipedia.org/wiki/Langton%27s_ant
+/// [turmite]: https://en.wikipedia.org/wiki/Turmite
+/// [`TurmiteRules`]: crate::TurmiteRules
+/// [`LangtonsAntPlugin`]: crate::LangtonsAntPlugin
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Component, Reflect)]
+pub struct Ant {
+    /// Coordinates of the cell under the ant
+    pub coords: IVec2,
+    /// Direction of the next step
+    pub heading: Heading,
+    /// Internal turmite state of the ant, always `0` for Langton's ant
+    pub state: u8,
+}
+
+impl Ant {
+    /// Instantiates a new ant at `coords` facing `heading`, in the `0` state
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2, heading: Heading) -> Self {
+        Self {
+            coords,
+            heading,
+            state: 0,
+        }
+    }
+
+    /// Sets the internal turmite `state` of the ant
+    #[must_use]
+    #[inline]
+    pub const fn with_state(mut self, state: u8) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Moves the ant one step forward
+    #[inline]
+    pub fn step(&mut self) {
+        self.coords += self.heading.offset();
+    }
+}
+
+#[cfg(feature = "auto-coloring")]
+const ANT_COLORS: [Color; 8] = [
+    Color::WHITE,
+    Color::ORANGE,
+    Color::CYAN,
+    Color::LIME_GREEN,
+    Color::RED,
+    Color::YELLOW,
+    Color::PURPLE,
+    Color::BLUE,
+];
+
+/// Cell state of [Langton's ant] and [turmite] grids: the color index of the
+/// cell, `0` being the background color.
+///
+/// The state has no rules of its own, it is only modified by the [`Ant`]
+/// agents. For Langton's ant, an ant on a `0` cell turns right, an ant on a
+/// `1` cell turns left, and both flip the cell color before moving forward.
+///
+/// [Langton's ant]: https://en.wikipedia.org/wiki/Langton%27s_ant
+/// [turmite]: https://en.wikipedia.org/wiki/Turmite
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Component, Reflect)]
+pub struct AntCellState(pub u8);
+
+impl CellState for AntCellState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        *self
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self.0 {
+            0 => None,
+            c => Some(ANT_COLORS[usize::from(c - 1) % ANT_COLORS.len()]),
+        }
+    }
+}
+
+impl Deref for AntCellState {
+    type Target = u8;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<u8> for AntCellState {
+    fn from(val: u8) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LangtonsAntPlugin, MooreCell2d, TurmiteRules, TurmiteTransition};
+    use bevy::{prelude::*, utils::HashSet};
+
+    fn spawn_grid(app: &mut App) {
+        for y in -5..=5 {
+            for x in -5..=5 {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, y)), AntCellState(0)));
+            }
+        }
+    }
+
+    fn black_cells(app: &mut App) -> HashSet<IVec2> {
+        app.world
+            .query::<(&MooreCell2d, &AntCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| state.0 != 0)
+            .map(|(cell, _)| cell.coords)
+            .collect()
+    }
+
+    #[test]
+    fn headings() {
+        let mut heading = Heading::North;
+        for _ in 0..4 {
+            assert_eq!(heading.turn_right().turn_left(), heading);
+            assert_eq!(
+                heading.turn_right().turn_right().offset(),
+                -heading.offset()
+            );
+            heading = heading.turn_right();
+        }
+        assert_eq!(heading, Heading::North);
+        assert_eq!(Heading::East.turn(Turn::UTurn), Heading::West);
+        assert_eq!(Heading::East.turn(Turn::None), Heading::East);
+    }
+
+    #[test]
+    fn langtons_ant() {
+        let mut app = App::new();
+        app.add_plugins(LangtonsAntPlugin::<MooreCell2d>::new());
+        spawn_grid(&mut app);
+        let ant = app.world.spawn(Ant::new(IVec2::ZERO, Heading::North)).id();
+        for _ in 0..4 {
+            app.update();
+        }
+        // The ant walked a clockwise square
+        let square: HashSet<_> = [(0, 0), (1, 0), (1, -1), (0, -1)]
+            .into_iter()
+            .map(IVec2::from)
+            .collect();
+        assert_eq!(black_cells(&mut app), square);
+        assert_eq!(
+            *app.world.get::<Ant>(ant).unwrap(),
+            Ant::new(IVec2::ZERO, Heading::North)
+        );
+        // Back on a black cell, it turns left
+        app.update();
+        let expected: HashSet<_> = square.into_iter().filter(|c| *c != IVec2::ZERO).collect();
+        assert_eq!(black_cells(&mut app), expected);
+        assert_eq!(
+            *app.world.get::<Ant>(ant).unwrap(),
+            Ant::new(IVec2::NEG_X, Heading::West)
+        );
+    }
+
+    #[test]
+    fn turmite() {
+        // Alternates between going straight and turning right, with two colors
+        let rules = TurmiteRules::new(vec![
+            vec![TurmiteTransition::new(1, Turn::None, 1)],
+            vec![TurmiteTransition::new(2, Turn::Right, 0)],
+        ]);
+        let mut app = App::new();
+        app.add_plugins(LangtonsAntPlugin::<MooreCell2d>::new().with_rules(rules));
+        spawn_grid(&mut app);
+        let ant = app.world.spawn(Ant::new(IVec2::ZERO, Heading::North)).id();
+        for _ in 0..3 {
+            app.update();
+        }
+        let colors: HashSet<_> = app
+            .world
+            .query::<(&MooreCell2d, &AntCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| state.0 != 0)
+            .map(|(cell, state)| (cell.coords, state.0))
+            .collect();
+        let expected: HashSet<_> = [((0, 0), 1), ((0, 1), 2), ((1, 1), 1)]
+            .into_iter()
+            .map(|(c, color)| (IVec2::from(c), color))
+            .collect();
+        assert_eq!(colors, expected);
+        assert_eq!(
+            *app.world.get::<Ant>(ant).unwrap(),
+            Ant::new(IVec2::new(2, 1), Heading::East).with_state(1)
+        );
+    }
+}
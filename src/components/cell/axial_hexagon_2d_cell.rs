@@ -0,0 +1,199 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, IVec2, IVec3, Reflect, Vec2};
+use std::ops::Deref;
+
+const NEIGHBOR_COORDINATES: [IVec2; 6] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(1, 0),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, 0),
+];
+
+const SQRT_3: f32 = 1.732_050_8;
+
+/// Hexagon orientation, used for world space conversions
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Reflect)]
+pub enum HexOrientation {
+    /// Hexagons with a vertex on top, rows are horizontal
+    #[default]
+    Pointy,
+    /// Hexagons with an edge on top, columns are vertical
+    Flat,
+}
+
+/// Hexagonal 2D cell. It has 6 neighbors and uses `IVec2` coordinates (Axial
+/// coordinates).
+///
+/// Axial coordinates `(q, r)` are the `x` and `z` components of the cubic
+/// coordinates used by [`HexagonCell2d`], the `y` component being `-q - r`.
+///
+/// See [`hex_to_world`] and [`world_to_hex`] for world space conversions.
+///
+/// [`HexagonCell2d`]: crate::HexagonCell2d
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct AxialHexagonCell2d {
+    /// The 2D cell axial coordinates
+    pub coords: IVec2,
+}
+
+impl Deref for AxialHexagonCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for AxialHexagonCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        NEIGHBOR_COORDINATES.map(|c| c + *self.coords())
+    }
+}
+
+impl AxialHexagonCell2d {
+    /// Instantiates a new cell with `coords` axial values
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2) -> Self {
+        Self { coords }
+    }
+
+    /// Instantiates a new cell from `cubic` coordinates
+    #[must_use]
+    #[inline]
+    pub const fn from_cubic(cubic: IVec3) -> Self {
+        Self::new(IVec2::new(cubic.x, cubic.z))
+    }
+
+    /// Retrieves the cubic coordinates of the cell
+    #[must_use]
+    #[inline]
+    pub const fn to_cubic(&self) -> IVec3 {
+        IVec3::new(self.coords.x, -self.coords.x - self.coords.y, self.coords.y)
+    }
+
+    /// Retrieves the world position of the hexagon center, see
+    /// [`hex_to_world`]
+    #[must_use]
+    #[inline]
+    pub fn world_position(&self, orientation: HexOrientation, size: f32) -> Vec2 {
+        hex_to_world(self.coords, orientation, size)
+    }
+}
+
+/// Converts hexagon axial `coords` to the world position of the hexagon
+/// center, `size` being the distance between the center and any vertex.
+#[must_use]
+pub fn hex_to_world(coords: IVec2, orientation: HexOrientation, size: f32) -> Vec2 {
+    let [q, r] = coords.as_vec2().to_array();
+    let position = match orientation {
+        HexOrientation::Pointy => Vec2::new(SQRT_3.mul_add(q, SQRT_3 / 2.0 * r), 1.5 * r),
+        HexOrientation::Flat => Vec2::new(1.5 * q, (SQRT_3 / 2.0).mul_add(q, SQRT_3 * r)),
+    };
+    position * size
+}
+
+/// Converts a world `position` to the axial coordinates of the hexagon
+/// containing it, `size` being the distance between the center and any vertex.
+#[must_use]
+pub fn world_to_hex(position: Vec2, orientation: HexOrientation, size: f32) -> IVec2 {
+    let Vec2 { x, y } = position / size;
+    let (q, r) = match orientation {
+        HexOrientation::Pointy => ((SQRT_3 / 3.0).mul_add(x, -y / 3.0), 2.0 / 3.0 * y),
+        HexOrientation::Flat => (2.0 / 3.0 * x, (SQRT_3 / 3.0).mul_add(y, -x / 3.0)),
+    };
+    axial_round(q, r)
+}
+
+/// Rounds fractional axial coordinates to the closest hexagon, using cubic
+/// rounding
+#[allow(clippy::cast_possible_truncation)]
+fn axial_round(q: f32, r: f32) -> IVec2 {
+    let s = -q - r;
+    let (mut rq, mut rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+    if dq > dr && dq > ds {
+        rq = -rr - rs;
+    } else if dr > ds {
+        rr = -rq - rs;
+    }
+    IVec2::new(rq as i32, rr as i32)
+}
+
+impl From<IVec2> for AxialHexagonCell2d {
+    #[inline]
+    fn from(coords: IVec2) -> Self {
+        Self::new(coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HexagonCell2d;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = AxialHexagonCell2d::new(IVec2::new(-10, 5));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                IVec2::new(-10, 4),
+                IVec2::new(-9, 4),
+                IVec2::new(-9, 5),
+                IVec2::new(-10, 6),
+                IVec2::new(-11, 6),
+                IVec2::new(-11, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_cubic_neighbors() {
+        let cubic = HexagonCell2d::new(IVec3::new(3, -1, -2));
+        let axial = AxialHexagonCell2d::from_cubic(cubic.coords);
+        assert_eq!(axial.to_cubic(), cubic.coords);
+        let neighbors = axial
+            .neighbor_coordinates()
+            .into_iter()
+            .map(|c| AxialHexagonCell2d::new(c).to_cubic())
+            .collect::<Vec<_>>();
+        let expected = cubic.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, expected);
+    }
+
+    #[test]
+    fn world_round_trip() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            for q in -5..=5 {
+                for r in -5..=5 {
+                    let coords = IVec2::new(q, r);
+                    let position = hex_to_world(coords, orientation, 3.0);
+                    assert_eq!(world_to_hex(position, orientation, 3.0), coords);
+                    // Slightly off center positions
+                    let offset = position + Vec2::new(1.2, -0.9);
+                    assert_eq!(world_to_hex(offset, orientation, 3.0), coords);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn world_positions() {
+        let position = hex_to_world(IVec2::new(1, 0), HexOrientation::Pointy, 1.0);
+        assert!((position - Vec2::new(SQRT_3, 0.0)).length() < 1e-5);
+        let position = hex_to_world(IVec2::new(0, 1), HexOrientation::Flat, 1.0);
+        assert!((position - Vec2::new(0.0, SQRT_3)).length() < 1e-5);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, Reflect};
+use std::ops::Deref;
+
+/// 1D cell. It has 2 neighbors and uses `i32` coordinates.
+///
+/// ```ascii
+/// +-------+-------+-------+
+/// |       |       |       |
+/// |  -1   |   0   |   1   |
+/// |       |       |       |
+/// +-------+-------+-------+
+/// ```
+///
+/// Used for [elementary cellular automata] like *Rule 30* or *Rule 110*.
+///
+/// [elementary cellular automata]: https://en.wikipedia.org/wiki/Elementary_cellular_automaton
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct Cell1d {
+    /// The 1D cell coordinates
+    pub coords: i32,
+}
+
+impl Deref for Cell1d {
+    type Target = i32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for Cell1d {
+    type Coordinates = i32;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        // Left, Right
+        [self.coords - 1, self.coords + 1]
+    }
+}
+
+impl Cell1d {
+    /// Instantiates a new cell with `coords` values
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: i32) -> Self {
+        Self { coords }
+    }
+}
+
+impl From<i32> for Cell1d {
+    #[inline]
+    fn from(coords: i32) -> Self {
+        Self::new(coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = Cell1d::new(10);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![9, 11]);
+    }
+
+    #[test]
+    fn correct_coordinates_negative() {
+        let cell = Cell1d::new(-10);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![-11, -9]);
+    }
+
+    #[test]
+    fn correct_coordinates_origin() {
+        let cell = Cell1d::new(0);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![-1, 1]);
+    }
+}
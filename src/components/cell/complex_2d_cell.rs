@@ -0,0 +1,230 @@
+use crate::components::{Cell, DEFAULT_LONG_RANGE_RADIUS};
+use bevy::prelude::{Component, IVec2, Reflect};
+use std::ops::Deref;
+
+const NEIGHBOR_COORDINATES: [IVec2; 8] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Top Left
+    IVec2::new(-1, 1),
+    // Top
+    IVec2::new(0, 1),
+    // Top Right
+    IVec2::new(1, 1),
+    // Right
+    IVec2::new(1, 0),
+    // Bottom Right
+    IVec2::new(1, -1),
+    // Bottom
+    IVec2::new(0, -1),
+    // Bottom Left
+    IVec2::new(-1, -1),
+];
+
+const LONG_RANGE_DIRECTIONS: [IVec2; 4] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Top
+    IVec2::new(0, 1),
+    // Right
+    IVec2::new(1, 0),
+    // Bottom
+    IVec2::new(0, -1),
+];
+
+/// [Moore] 2D cell with additional long-range links. It uses `IVec2`
+/// coordinates.
+///
+/// Every cell has the 8 [Moore] neighbors, and cells lying on the long-range
+/// lattice (both coordinates being multiples of `long_range_radius`) are also
+/// connected to the 4 closest lattice cells, `long_range_radius` cells away
+/// on each axis.
+///
+/// For a realistic mobility structure, prefer [`SmallWorldCell2d`] cells with
+/// long-range links drawn by a [`GravityModel`].
+///
+/// [`SmallWorldCell2d`]: crate::SmallWorldCell2d
+/// [`GravityModel`]: crate::GravityModel
+/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct ComplexCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The long-range lattice spacing. A non-positive spacing disables the
+    /// long-range links
+    pub long_range_radius: i32,
+}
+
+impl Deref for ComplexCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for ComplexCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let coords = *self.coords();
+        let radius = self.long_range_radius;
+        let long_range = self
+            .is_long_range_hub()
+            .then(|| LONG_RANGE_DIRECTIONS.map(|c| c * radius + coords));
+        NEIGHBOR_COORDINATES
+            .map(|c| c + coords)
+            .into_iter()
+            .chain(long_range.into_iter().flatten())
+    }
+}
+
+impl ComplexCell2d {
+    /// Instantiates a new cell with `coords` values and the
+    /// [`DEFAULT_LONG_RANGE_RADIUS`] lattice spacing
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2) -> Self {
+        Self::new_with_radius(coords, DEFAULT_LONG_RANGE_RADIUS)
+    }
+
+    /// Instantiates a new cell with `coords` values and a custom
+    /// `long_range_radius` lattice spacing
+    ///
+    /// # Panics
+    ///
+    /// Panics if `long_range_radius` is not strictly positive
+    #[must_use]
+    #[inline]
+    pub const fn new_with_radius(coords: IVec2, long_range_radius: i32) -> Self {
+        assert!(long_range_radius > 0, "long range radius must be positive");
+        Self {
+            coords,
+            long_range_radius,
+        }
+    }
+
+    /// Is the cell on the long-range lattice, with 4 additional long-range
+    /// neighbors. Always `false` for a non-positive `long_range_radius`
+    #[must_use]
+    #[inline]
+    pub const fn is_long_range_hub(&self) -> bool {
+        self.long_range_radius > 0
+            && self.coords.x.rem_euclid(self.long_range_radius) == 0
+            && self.coords.y.rem_euclid(self.long_range_radius) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = ComplexCell2d::new(IVec2::new(10, 10));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(9, 10),
+                // Top Left
+                IVec2::new(9, 11),
+                // Top
+                IVec2::new(10, 11),
+                // Top Right
+                IVec2::new(11, 11),
+                // Right
+                IVec2::new(11, 10),
+                // Bottom Right
+                IVec2::new(11, 9),
+                // Bottom
+                IVec2::new(10, 9),
+                // Bottom Left
+                IVec2::new(9, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_coordinates_custom_radius() {
+        let cell = ComplexCell2d::new_with_radius(IVec2::new(10, -10), 5);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(9, -10),
+                // Top Left
+                IVec2::new(9, -9),
+                // Top
+                IVec2::new(10, -9),
+                // Top Right
+                IVec2::new(11, -9),
+                // Right
+                IVec2::new(11, -10),
+                // Bottom Right
+                IVec2::new(11, -11),
+                // Bottom
+                IVec2::new(10, -11),
+                // Bottom Left
+                IVec2::new(9, -11),
+                // Long range Left
+                IVec2::new(5, -10),
+                // Long range Top
+                IVec2::new(10, -5),
+                // Long range Right
+                IVec2::new(15, -10),
+                // Long range Bottom
+                IVec2::new(10, -15),
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_coordinates_origin() {
+        let cell = ComplexCell2d::new(IVec2::ZERO);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 12);
+        assert_eq!(
+            neighbors[8..],
+            [
+                IVec2::new(-50, 0),
+                IVec2::new(0, 50),
+                IVec2::new(50, 0),
+                IVec2::new(0, -50),
+            ]
+        );
+    }
+
+    #[test]
+    fn variable_neighbor_count() {
+        for x in -12..=12 {
+            for y in -12..=12 {
+                let cell = ComplexCell2d::new_with_radius(IVec2::new(x, y), 6);
+                let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+                let expected = if x % 6 == 0 && y % 6 == 0 { 12 } else { 8 };
+                assert_eq!(neighbors.len(), expected);
+                assert!(neighbors
+                    .iter()
+                    .all(|n| (*n - cell.coords).abs().max_element() <= 6));
+            }
+        }
+    }
+
+    #[test]
+    fn zero_radius() {
+        let cell = ComplexCell2d {
+            coords: IVec2::ZERO,
+            long_range_radius: 0,
+        };
+        assert!(!cell.is_long_range_hub());
+        assert_eq!(cell.neighbor_coordinates().into_iter().count(), 8);
+    }
+}
@@ -0,0 +1,155 @@
+use super::moore_3d_cell::NEIGHBOR_COORDINATES;
+use crate::components::{Cell, DEFAULT_LONG_RANGE_RADIUS};
+use bevy::prelude::{Component, IVec3, Reflect};
+use std::ops::Deref;
+
+const LONG_RANGE_DIRECTIONS: [IVec3; 6] = [
+    // Back
+    IVec3::new(0, 0, -1),
+    // Left
+    IVec3::new(-1, 0, 0),
+    // Top
+    IVec3::new(0, 1, 0),
+    // Right
+    IVec3::new(1, 0, 0),
+    // Bottom
+    IVec3::new(0, -1, 0),
+    // Front
+    IVec3::new(0, 0, 1),
+];
+
+/// [Moore] 3D cell with additional long-range links. It uses `IVec3`
+/// coordinates.
+///
+/// Every cell has the 26 [Moore] neighbors, and cells lying on the long-range
+/// lattice (all coordinates being multiples of `long_range_radius`) are also
+/// connected to the 6 closest lattice cells, `long_range_radius` cells away
+/// on each axis.
+///
+/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct ComplexCell3d {
+    /// The 3D cell coordinates
+    pub coords: IVec3,
+    /// The long-range lattice spacing. A non-positive spacing disables the
+    /// long-range links
+    pub long_range_radius: i32,
+}
+
+impl Deref for ComplexCell3d {
+    type Target = IVec3;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for ComplexCell3d {
+    type Coordinates = IVec3;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let coords = *self.coords();
+        let radius = self.long_range_radius;
+        let long_range = self
+            .is_long_range_hub()
+            .then(|| LONG_RANGE_DIRECTIONS.map(|c| c * radius + coords));
+        NEIGHBOR_COORDINATES
+            .map(|c| c + coords)
+            .into_iter()
+            .chain(long_range.into_iter().flatten())
+    }
+}
+
+impl ComplexCell3d {
+    /// Instantiates a new cell with `coords` values and the
+    /// [`DEFAULT_LONG_RANGE_RADIUS`] lattice spacing
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec3) -> Self {
+        Self::new_with_radius(coords, DEFAULT_LONG_RANGE_RADIUS)
+    }
+
+    /// Instantiates a new cell with `coords` values and a custom
+    /// `long_range_radius` lattice spacing
+    ///
+    /// # Panics
+    ///
+    /// Panics if `long_range_radius` is not strictly positive
+    #[must_use]
+    #[inline]
+    pub const fn new_with_radius(coords: IVec3, long_range_radius: i32) -> Self {
+        assert!(long_range_radius > 0, "long range radius must be positive");
+        Self {
+            coords,
+            long_range_radius,
+        }
+    }
+
+    /// Is the cell on the long-range lattice, with 6 additional long-range
+    /// neighbors. Always `false` for a non-positive `long_range_radius`
+    #[must_use]
+    #[inline]
+    pub const fn is_long_range_hub(&self) -> bool {
+        self.long_range_radius > 0
+            && self.coords.x.rem_euclid(self.long_range_radius) == 0
+            && self.coords.y.rem_euclid(self.long_range_radius) == 0
+            && self.coords.z.rem_euclid(self.long_range_radius) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = ComplexCell3d::new(IVec3::new(10, -10, 3));
+        assert!(!cell.is_long_range_hub());
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 26);
+        assert!(neighbors
+            .iter()
+            .all(|n| (*n - cell.coords).abs().max_element() == 1));
+    }
+
+    #[test]
+    fn correct_coordinates_custom_radius() {
+        let cell = ComplexCell3d::new_with_radius(IVec3::new(-4, 8, 0), 4);
+        assert!(cell.is_long_range_hub());
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 32);
+        assert_eq!(
+            neighbors[26..],
+            [
+                // Back
+                IVec3::new(-4, 8, -4),
+                // Left
+                IVec3::new(-8, 8, 0),
+                // Top
+                IVec3::new(-4, 12, 0),
+                // Right
+                IVec3::new(0, 8, 0),
+                // Bottom
+                IVec3::new(-4, 4, 0),
+                // Front
+                IVec3::new(-4, 8, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_radius() {
+        let cell = ComplexCell3d {
+            coords: IVec3::ZERO,
+            long_range_radius: 0,
+        };
+        assert!(!cell.is_long_range_hub());
+        assert_eq!(cell.neighbor_coordinates().into_iter().count(), 26);
+    }
+}
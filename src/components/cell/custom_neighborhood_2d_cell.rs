@@ -0,0 +1,99 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, IVec2, Reflect};
+use std::{ops::Deref, sync::Arc};
+
+/// 2D cell with a runtime defined neighborhood. It uses `IVec2` coordinates.
+///
+/// The neighborhood is defined by relative `offsets`, allowing arbitrary
+/// shapes (knight moves, plus shapes, kernels loaded from configuration,
+/// etc). The offsets are shared between cells, so cloning the `Arc` for every
+/// spawned cell is cheap.
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(from_reflect = false)]
+pub struct CustomNeighborhoodCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The relative coordinates of the neighbors
+    #[reflect(ignore)]
+    pub offsets: Arc<[IVec2]>,
+}
+
+impl Deref for CustomNeighborhoodCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for CustomNeighborhoodCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let coords = *self.coords();
+        self.offsets.iter().map(move |c| *c + coords)
+    }
+}
+
+impl CustomNeighborhoodCell2d {
+    /// Instantiates a new cell with `coords` values and neighbor `offsets`
+    #[must_use]
+    #[inline]
+    pub fn new(coords: IVec2, offsets: impl Into<Arc<[IVec2]>>) -> Self {
+        Self {
+            coords,
+            offsets: offsets.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KNIGHT_MOVES: [IVec2; 8] = [
+        IVec2::new(-2, 1),
+        IVec2::new(-1, 2),
+        IVec2::new(1, 2),
+        IVec2::new(2, 1),
+        IVec2::new(2, -1),
+        IVec2::new(1, -2),
+        IVec2::new(-1, -2),
+        IVec2::new(-2, -1),
+    ];
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = CustomNeighborhoodCell2d::new(IVec2::new(-10, 10), KNIGHT_MOVES);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                IVec2::new(-12, 11),
+                IVec2::new(-11, 12),
+                IVec2::new(-9, 12),
+                IVec2::new(-8, 11),
+                IVec2::new(-8, 9),
+                IVec2::new(-9, 8),
+                IVec2::new(-11, 8),
+                IVec2::new(-12, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_offsets() {
+        let offsets: Arc<[IVec2]> = Arc::from(vec![IVec2::X, IVec2::NEG_X]);
+        let a = CustomNeighborhoodCell2d::new(IVec2::ZERO, offsets.clone());
+        let b = CustomNeighborhoodCell2d::new(IVec2::ONE, offsets);
+        assert!(Arc::ptr_eq(&a.offsets, &b.offsets));
+        let neighbors = b.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![IVec2::new(2, 1), IVec2::new(0, 1)]);
+    }
+}
@@ -0,0 +1,129 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, Entity, Reflect};
+
+/// Graph cell, whose neighbors are an explicit list of entities instead of
+/// being derived from coordinates. It uses its own `Entity` as coordinates.
+///
+/// Allows to simulate arbitrary contact networks (schools, workplaces,
+/// airports, etc) with the same [`CellularAutomatonPlugin`] machinery.
+///
+/// # Usage
+///
+/// Spawn every node entity first, with its state, and then insert the
+/// `GraphCell` components once the entities are known:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::{ConwayCellState, GraphCell};
+/// fn spawn_graph(mut commands: Commands) {
+///     let a = commands.spawn(ConwayCellState(true)).id();
+///     let b = commands.spawn(ConwayCellState(false)).id();
+///     commands.entity(a).insert(GraphCell::new(a, vec![b]));
+///     commands.entity(b).insert(GraphCell::new(b, vec![a]));
+/// }
+/// ```
+///
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct GraphCell {
+    /// The entity holding this cell
+    pub entity: Entity,
+    /// The neighbor cell entities
+    pub neighbors: Vec<Entity>,
+}
+
+impl Cell for GraphCell {
+    type Coordinates = Entity;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.entity
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        self.neighbors.iter().copied()
+    }
+}
+
+impl GraphCell {
+    /// Instantiates a new cell for `entity` with `neighbors` entities
+    #[must_use]
+    #[inline]
+    pub const fn new(entity: Entity, neighbors: Vec<Entity>) -> Self {
+        Self { entity, neighbors }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CellularAutomatonPlugin, ConwayCellState};
+    use bevy::prelude::*;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = GraphCell::new(
+            Entity::from_raw(0),
+            vec![Entity::from_raw(3), Entity::from_raw(1)],
+        );
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![Entity::from_raw(3), Entity::from_raw(1)]);
+    }
+
+    #[test]
+    fn star_graph_simulation() {
+        let mut app = App::new();
+        app.add_plugins(CellularAutomatonPlugin::<GraphCell, ConwayCellState>::new());
+        let center = app.world.spawn(ConwayCellState(false)).id();
+        let leaves: Vec<_> = (0..3)
+            .map(|_| app.world.spawn(ConwayCellState(true)).id())
+            .collect();
+        app.world
+            .entity_mut(center)
+            .insert(GraphCell::new(center, leaves.clone()));
+        for leaf in &leaves {
+            app.world
+                .entity_mut(*leaf)
+                .insert(GraphCell::new(*leaf, vec![center]));
+        }
+        app.update();
+        // The center has 3 live neighbors and is born, the leaves have a single
+        // dead neighbor and die
+        assert!(app.world.get::<ConwayCellState>(center).unwrap().0);
+        for leaf in leaves {
+            assert!(!app.world.get::<ConwayCellState>(leaf).unwrap().0);
+        }
+    }
+
+    #[test]
+    fn directed_graph_simulation() {
+        let mut app = App::new();
+        app.add_plugins(CellularAutomatonPlugin::<GraphCell, ConwayCellState>::new());
+        let target = app.world.spawn(ConwayCellState(false)).id();
+        let sources: Vec<_> = (0..3)
+            .map(|_| app.world.spawn(ConwayCellState(true)).id())
+            .collect();
+        // The target listens to the sources but the sources don't listen to the
+        // target
+        app.world
+            .entity_mut(target)
+            .insert(GraphCell::new(target, sources.clone()));
+        for source in &sources {
+            app.world
+                .entity_mut(*source)
+                .insert(GraphCell::new(*source, vec![]));
+        }
+        app.update();
+        assert!(app.world.get::<ConwayCellState>(target).unwrap().0);
+        for source in &sources {
+            assert!(!app.world.get::<ConwayCellState>(*source).unwrap().0);
+        }
+        // The live target doesn't revive the sources
+        app.update();
+        assert!(!app.world.get::<ConwayCellState>(target).unwrap().0);
+        for source in &sources {
+            assert!(!app.world.get::<ConwayCellState>(*source).unwrap().0);
+        }
+    }
+}
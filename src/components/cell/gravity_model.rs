@@ -0,0 +1,156 @@
+use crate::components::SmallWorldCell2d;
+use bevy::{prelude::IVec2, utils::HashMap};
+use rand::Rng;
+
+/// [Gravity model] long-range links generator, for [`SmallWorldCell2d`] maps
+/// with a realistic mobility structure.
+///
+/// Every pair of cells `i, j` is linked with a probability proportional to
+/// `pop_i * pop_j / dist^k`, `k` being the `distance_exponent`. Probabilities
+/// are scaled so that every cell has `mean_links` long-range links on average.
+///
+/// Links are undirected: both cells of a linked pair list each other. Pairs
+/// within the [Moore] neighborhood are never linked.
+///
+/// # Note
+///
+/// Every pair of cells is evaluated, so generation is quadratic in the cell
+/// count. This is meant for map construction, not per-frame use.
+///
+/// [Gravity model]: https://en.wikipedia.org/wiki/Gravity_model_of_migration
+/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GravityModel {
+    /// The distance decay exponent `k`
+    pub distance_exponent: f32,
+    /// The average amount of long-range links per cell
+    pub mean_links: f32,
+}
+
+impl Default for GravityModel {
+    fn default() -> Self {
+        Self::new(2.0, 1.0)
+    }
+}
+
+impl GravityModel {
+    /// Instantiates a new gravity model with a `distance_exponent` decay and
+    /// `mean_links` long-range links per cell on average
+    #[must_use]
+    #[inline]
+    pub const fn new(distance_exponent: f32, mean_links: f32) -> Self {
+        Self {
+            distance_exponent,
+            mean_links,
+        }
+    }
+
+    /// Draws the long-range links between the cells of `populations`, made of
+    /// cell coordinates and population sizes.
+    ///
+    /// Returns the linked coordinates of every cell having at least one link.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn links(
+        &self,
+        populations: &[(IVec2, f32)],
+        rng: &mut impl Rng,
+    ) -> HashMap<IVec2, Vec<IVec2>> {
+        let pairs = || {
+            populations.iter().enumerate().flat_map(move |(i, a)| {
+                populations[i + 1..]
+                    .iter()
+                    .filter(move |b| (b.0 - a.0).abs().max_element() > 1)
+                    .map(move |b| (a, b, self.attraction(*a, *b)))
+            })
+        };
+        let total: f32 = pairs().map(|(_, _, w)| w).sum();
+        let mut links: HashMap<IVec2, Vec<IVec2>> = HashMap::new();
+        if total <= 0.0 {
+            return links;
+        }
+        let scale = self.mean_links * populations.len() as f32 / (2.0 * total);
+        for ((a, _), (b, _), weight) in pairs() {
+            if rng.gen::<f32>() < weight * scale {
+                links.entry(*a).or_default().push(*b);
+                links.entry(*b).or_default().push(*a);
+            }
+        }
+        links
+    }
+
+    /// Builds a [`SmallWorldCell2d`] for every cell of `populations`, with
+    /// long-range links drawn with [`Self::links`]
+    #[must_use]
+    pub fn cells(&self, populations: &[(IVec2, f32)], rng: &mut impl Rng) -> Vec<SmallWorldCell2d> {
+        let mut links = self.links(populations, rng);
+        populations
+            .iter()
+            .map(|(coords, _)| {
+                SmallWorldCell2d::new(*coords, links.remove(coords).unwrap_or_default())
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn attraction(self, (a, pop_a): (IVec2, f32), (b, pop_b): (IVec2, f32)) -> f32 {
+        let distance = (b - a).as_vec2().length();
+        pop_a * pop_b / distance.powf(self.distance_exponent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Cell;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn grid(size: i32, population: impl Fn(IVec2) -> f32) -> Vec<(IVec2, f32)> {
+        (0..size)
+            .flat_map(|y| (0..size).map(move |x| IVec2::new(x, y)))
+            .map(|c| (c, population(c)))
+            .collect()
+    }
+
+    #[test]
+    fn symmetric_long_range_links() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let populations = grid(20, |_| 100.0);
+        let links = GravityModel::new(1.0, 2.0).links(&populations, &mut rng);
+        let count: usize = links.values().map(Vec::len).sum();
+        // 400 cells with 2 links on average
+        assert!((600..=1000).contains(&count), "{count}");
+        for (coords, linked) in &links {
+            for other in linked {
+                assert!((*other - *coords).abs().max_element() > 1);
+                assert!(links[other].contains(coords));
+            }
+        }
+    }
+
+    #[test]
+    fn populated_cells_attract_links() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let city = IVec2::new(10, 10);
+        let populations = grid(20, |c| if c == city { 10_000.0 } else { 10.0 });
+        let cells = GravityModel::new(2.0, 1.0).cells(&populations, &mut rng);
+        assert_eq!(cells.len(), 400);
+        let city_cell = cells.iter().find(|c| *c.coords() == city).unwrap();
+        let max_other = cells
+            .iter()
+            .filter(|c| *c.coords() != city)
+            .map(|c| c.long_range_links.len())
+            .max()
+            .unwrap();
+        assert!(city_cell.long_range_links.len() > max_other);
+    }
+
+    #[test]
+    fn no_population() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let populations = grid(5, |_| 0.0);
+        assert!(GravityModel::default()
+            .links(&populations, &mut rng)
+            .is_empty());
+    }
+}
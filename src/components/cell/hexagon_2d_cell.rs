@@ -2,7 +2,7 @@ use crate::components::Cell;
 use bevy::prelude::{Component, IVec3, Reflect};
 use std::ops::Deref;
 
-const NEIGHBOR_COORDINATES: [IVec3; 6] = [
+const DIRECTIONS: [IVec3; 6] = [
     IVec3::new(0, 1, -1),
     IVec3::new(1, 0, -1),
     IVec3::new(1, -1, 0),
@@ -14,7 +14,7 @@ const NEIGHBOR_COORDINATES: [IVec3; 6] = [
 /// Hexagonal 2D cell. It has 6 neighbors and uses `IVec3` coordinates (Cubic
 /// coordinates).
 ///
-/// ````ascii
+/// ```ascii
 ///               X
 ///             _____
 ///            /     \
@@ -33,14 +33,19 @@ const NEIGHBOR_COORDINATES: [IVec3; 6] = [
 ///           \       /
 ///            \_____/
 ///
-/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+/// ```
+///
+/// The `RADIUS` const argument extends the neighborhood to every hexagon
+/// within `RADIUS` steps, giving `3 * RADIUS * (RADIUS + 1)` neighbors (18 for
+/// the range-2 neighborhood). Neighbors are yielded ring by ring, each ring
+/// going clockwise from its top cell.
 #[derive(Debug, Clone, Component, Reflect)]
-pub struct HexagonCell2d {
+pub struct HexagonCell2d<const RADIUS: u32 = 1> {
     /// The 2D cell coordinates
     pub coords: IVec3,
 }
 
-impl Deref for HexagonCell2d {
+impl<const RADIUS: u32> Deref for HexagonCell2d<RADIUS> {
     type Target = IVec3;
 
     fn deref(&self) -> &Self::Target {
@@ -48,7 +53,7 @@ impl Deref for HexagonCell2d {
     }
 }
 
-impl Cell for HexagonCell2d {
+impl<const RADIUS: u32> Cell for HexagonCell2d<RADIUS> {
     type Coordinates = IVec3;
 
     #[inline]
@@ -58,7 +63,8 @@ impl Cell for HexagonCell2d {
 
     #[inline]
     fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
-        NEIGHBOR_COORDINATES.map(|c| c + *self.coords())
+        let coords = *self.coords();
+        Self::neighbor_offsets().map(move |c| c + coords)
     }
 }
 
@@ -71,13 +77,45 @@ impl HexagonCell2d {
     }
 }
 
+impl<const RADIUS: u32> HexagonCell2d<RADIUS> {
+    /// Amount of neighbors of the cell
+    #[allow(clippy::cast_possible_truncation)]
+    pub const NEIGHBOR_COUNT: usize = 3 * RADIUS as usize * (RADIUS as usize + 1);
+
+    /// Instantiates a new cell with `coords` values and a custom `RADIUS`,
+    /// for example `HexagonCell2d::<2>::from_coords(coords)`
+    #[must_use]
+    #[inline]
+    pub const fn from_coords(coords: IVec3) -> Self {
+        Self { coords }
+    }
+
+    /// Relative coordinates of the neighbors
+    #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    fn neighbor_offsets() -> impl Iterator<Item = IVec3> {
+        (1..=RADIUS as i32).flat_map(|d| {
+            (0..6 * d).map(move |k| {
+                let (side, step) = ((k / d) as usize, k % d);
+                DIRECTIONS[side] * d + DIRECTIONS[(side + 2) % 6] * step
+            })
+        })
+    }
+}
+
+impl<const RADIUS: u32> From<IVec3> for HexagonCell2d<RADIUS> {
+    #[inline]
+    fn from(coords: IVec3) -> Self {
+        Self::from_coords(coords)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn correct_coordinates() {
-        let cell = HexagonCell2d {
+        let cell: HexagonCell2d = HexagonCell2d {
             coords: IVec3::new(10, 10, 10),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -96,7 +134,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_negative() {
-        let cell = HexagonCell2d {
+        let cell: HexagonCell2d = HexagonCell2d {
             coords: IVec3::new(-10, 8, 5),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -115,7 +153,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_origin() {
-        let cell = HexagonCell2d {
+        let cell: HexagonCell2d = HexagonCell2d {
             coords: IVec3::new(0, 0, 0),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -131,4 +169,43 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn correct_coordinates_radius_2() {
+        let cell = HexagonCell2d::<2>::from_coords(IVec3::new(-10, 8, 2));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), HexagonCell2d::<2>::NEIGHBOR_COUNT);
+        assert_eq!(
+            neighbors[6..],
+            [
+                IVec3::new(-10, 10, 0),
+                IVec3::new(-9, 9, 0),
+                IVec3::new(-8, 8, 0),
+                IVec3::new(-8, 7, 1),
+                IVec3::new(-8, 6, 2),
+                IVec3::new(-9, 6, 3),
+                IVec3::new(-10, 6, 4),
+                IVec3::new(-11, 7, 4),
+                IVec3::new(-12, 8, 4),
+                IVec3::new(-12, 9, 3),
+                IVec3::new(-12, 10, 2),
+                IVec3::new(-11, 10, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn range_neighborhood() {
+        let cell = HexagonCell2d::<3>::from_coords(IVec3::new(1, 2, -3));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 36);
+        for neighbor in &neighbors {
+            let offset = *neighbor - cell.coords;
+            assert_eq!(offset.x + offset.y + offset.z, 0);
+            assert!((1..=3).contains(&offset.abs().max_element()));
+        }
+        for (i, a) in neighbors.iter().enumerate() {
+            assert!(!neighbors[i + 1..].contains(a));
+        }
+    }
 }
@@ -0,0 +1,287 @@
+use crate::components::{Cell, WeightedCell};
+use bevy::prelude::{Component, IVec2, Reflect};
+use std::{ops::Deref, sync::Arc};
+
+/// Precomputed 2D neighborhood kernel: every relative coordinates within a
+/// radius along with its weight.
+///
+/// The kernel is shared between cells, so cloning it for every spawned
+/// [`KernelCell2d`] is cheap.
+#[derive(Debug, Clone, Default)]
+pub struct Kernel2d(Arc<[(IVec2, f32)]>);
+
+impl Kernel2d {
+    /// Instantiates a new kernel with explicit relative coordinates and
+    /// weights
+    #[must_use]
+    pub fn new(weights: impl IntoIterator<Item = (IVec2, f32)>) -> Self {
+        Self(weights.into_iter().collect())
+    }
+
+    /// Instantiates a kernel with every coordinates within `radius` (euclidean
+    /// distance), excluding the center, weighted by `weight(distance)`.
+    ///
+    /// Weights are normalized to sum up to 1.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn from_distance(radius: u32, weight: impl Fn(f32) -> f32) -> Self {
+        let r = radius as i32;
+        let mut weights: Vec<_> = (-r..=r)
+            .flat_map(|y| (-r..=r).map(move |x| IVec2::new(x, y)))
+            .filter(|c| *c != IVec2::ZERO && c.length_squared() <= r * r)
+            .map(|c| (c, weight(c.as_vec2().length())))
+            .collect();
+        let total: f32 = weights.iter().map(|(_, w)| w).sum();
+        if total > 0.0 {
+            for (_, w) in &mut weights {
+                *w /= total;
+            }
+        }
+        Self::new(weights)
+    }
+
+    /// Instantiates a normalized Gaussian kernel of `sigma` standard deviation
+    /// truncated at `radius`
+    #[must_use]
+    pub fn gaussian(radius: u32, sigma: f32) -> Self {
+        let factor = -1.0 / (2.0 * sigma * sigma);
+        Self::from_distance(radius, |d| (d * d * factor).exp())
+    }
+
+    /// Instantiates a normalized exponential kernel of `scale` mean distance
+    /// truncated at `radius`
+    #[must_use]
+    pub fn exponential(radius: u32, scale: f32) -> Self {
+        Self::from_distance(radius, |d| (-d / scale).exp())
+    }
+
+    /// Instantiates a normalized [Lenia] ring kernel of `radius`, peaking at
+    /// half the radius
+    ///
+    /// [Lenia]: https://en.wikipedia.org/wiki/Lenia
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn lenia(radius: u32) -> Self {
+        let scale = radius as f32;
+        Self::from_distance(radius, |d| {
+            let r = d / scale;
+            if r > 0.0 && r < 1.0 {
+                (4.0 - 1.0 / (r * (1.0 - r))).exp()
+            } else {
+                0.0
+            }
+        })
+    }
+
+    /// Instantiates a [SmoothLife] kernel: the inner disc of `inner_radius`,
+    /// center included, with positive weights and the outer ring up to
+    /// `outer_radius` with negative weights.
+    ///
+    /// The weights of each part sum up to 1 in absolute value, so the weighted
+    /// sums of the positive and negative parts are the inner and outer
+    /// fillings.
+    ///
+    /// [SmoothLife]: https://arxiv.org/abs/1111.1567
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn smooth_life(inner_radius: f32, outer_radius: f32) -> Self {
+        let r = outer_radius.ceil() as i32;
+        let coords: Vec<_> = (-r..=r)
+            .flat_map(|y| (-r..=r).map(move |x| IVec2::new(x, y)))
+            .map(|c| (c, c.as_vec2().length()))
+            .filter(|(_, d)| *d <= outer_radius)
+            .collect();
+        let inner_count = coords.iter().filter(|(_, d)| *d <= inner_radius).count() as f32;
+        let outer_count = coords.len() as f32 - inner_count;
+        Self::new(coords.into_iter().map(|(c, d)| {
+            if d <= inner_radius {
+                (c, 1.0 / inner_count)
+            } else {
+                (c, -1.0 / outer_count)
+            }
+        }))
+    }
+
+    /// Retrieves the relative coordinates and weights of the kernel
+    #[must_use]
+    #[inline]
+    pub fn weights(&self) -> &[(IVec2, f32)] {
+        &self.0
+    }
+}
+
+/// 2D cell with a weighted dispersal kernel neighborhood. It uses `IVec2`
+/// coordinates.
+///
+/// Every coordinates of its [`Kernel2d`] is a neighbor, its weight being
+/// available to [`WeightedCellState`] rules through the
+/// [`WeightedCellularAutomatonPlugin`].
+///
+/// [`WeightedCellState`]: crate::WeightedCellState
+/// [`WeightedCellularAutomatonPlugin`]: crate::WeightedCellularAutomatonPlugin
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct KernelCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The shared neighborhood kernel
+    #[reflect(ignore)]
+    pub kernel: Kernel2d,
+}
+
+impl Deref for KernelCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for KernelCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let coords = *self.coords();
+        self.kernel.weights().iter().map(move |(c, _)| *c + coords)
+    }
+}
+
+impl WeightedCell for KernelCell2d {
+    #[inline]
+    fn weighted_neighbor_coordinates(&self) -> impl IntoIterator<Item = (Self::Coordinates, f32)> {
+        let coords = *self.coords();
+        self.kernel
+            .weights()
+            .iter()
+            .map(move |(c, w)| (*c + coords, *w))
+    }
+}
+
+impl KernelCell2d {
+    /// Instantiates a new cell with `coords` values and a shared `kernel`
+    #[must_use]
+    #[inline]
+    pub fn new(coords: IVec2, kernel: &Kernel2d) -> Self {
+        Self {
+            coords,
+            kernel: kernel.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CellState, WeightedCellState, WeightedCellularAutomatonPlugin};
+    use bevy::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, Component)]
+    struct Density(f32);
+
+    impl CellState for Density {
+        fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+            self.new_cell_state_weighted(neighbor_cells.map(|c| (c, 1.0)))
+        }
+
+        #[cfg(feature = "auto-coloring")]
+        fn color(&self) -> Option<Color> {
+            None
+        }
+    }
+
+    impl WeightedCellState for Density {
+        fn new_cell_state_weighted<'a>(
+            &self,
+            neighbor_cells: impl Iterator<Item = (&'a Self, f32)>,
+        ) -> Self {
+            Self(neighbor_cells.map(|(c, w)| c.0 * w).sum())
+        }
+    }
+
+    #[test]
+    fn correct_coordinates() {
+        let kernel = Kernel2d::new([(IVec2::new(-1, 0), 0.25), (IVec2::new(0, 2), 0.75)]);
+        let cell = KernelCell2d::new(IVec2::new(-10, 10), &kernel);
+        let neighbors = cell
+            .weighted_neighbor_coordinates()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![(IVec2::new(-11, 10), 0.25), (IVec2::new(-10, 12), 0.75)]
+        );
+    }
+
+    #[test]
+    fn gaussian_kernel() {
+        let kernel = Kernel2d::gaussian(3, 1.5);
+        // 29 cells within a 3 radius disk, minus the center
+        assert_eq!(kernel.weights().len(), 28);
+        let total: f32 = kernel.weights().iter().map(|(_, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        let weight = |c: IVec2| kernel.weights().iter().find(|(o, _)| *o == c).unwrap().1;
+        assert!(weight(IVec2::new(1, 0)) > weight(IVec2::new(2, 0)));
+        assert!(weight(IVec2::new(2, 0)) > weight(IVec2::new(3, 0)));
+        assert!((weight(IVec2::new(0, -2)) - weight(IVec2::new(2, 0))).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn exponential_kernel() {
+        let kernel = Kernel2d::exponential(2, 1.0);
+        assert_eq!(kernel.weights().len(), 12);
+        let total: f32 = kernel.weights().iter().map(|(_, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn lenia_kernel() {
+        let kernel = Kernel2d::lenia(10);
+        let total: f32 = kernel.weights().iter().map(|(_, w)| w).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+        let weight = |c: IVec2| kernel.weights().iter().find(|(o, _)| *o == c).unwrap().1;
+        assert!(weight(IVec2::new(5, 0)) > weight(IVec2::new(2, 0)));
+        assert!(weight(IVec2::new(5, 0)) > weight(IVec2::new(8, 0)));
+        assert!(weight(IVec2::new(0, 10)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn smooth_life_kernel() {
+        let kernel = Kernel2d::smooth_life(1.0, 2.0);
+        let (inner, outer): (Vec<_>, Vec<_>) = kernel.weights().iter().partition(|(_, w)| *w > 0.0);
+        // Center and its 4 direct neighbors in the inner disc
+        assert_eq!(inner.len(), 5);
+        assert_eq!(outer.len(), 8);
+        let total = |part: &[&(IVec2, f32)]| part.iter().map(|(_, w)| w).sum::<f32>();
+        assert!((total(&inner) - 1.0).abs() < 1e-5);
+        assert!((total(&outer) + 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn weighted_simulation() {
+        let kernel = Kernel2d::new([(IVec2::new(-1, 0), 0.25), (IVec2::new(1, 0), 0.75)]);
+        let mut app = App::new();
+        app.add_plugins(WeightedCellularAutomatonPlugin::<KernelCell2d, Density>::new());
+        let cells: Vec<_> = [(0, 4.0), (1, 0.0), (2, 8.0)]
+            .into_iter()
+            .map(|(x, density)| {
+                app.world
+                    .spawn((
+                        KernelCell2d::new(IVec2::new(x, 0), &kernel),
+                        Density(density),
+                    ))
+                    .id()
+            })
+            .collect();
+        app.update();
+        let densities: Vec<_> = cells
+            .iter()
+            .map(|e| app.world.get::<Density>(*e).unwrap().0)
+            .collect();
+        assert_eq!(densities, vec![0.0, 7.0, 0.0]);
+    }
+}
@@ -1,13 +1,37 @@
-use bevy::prelude::Component;
+use crate::resources::{BoundaryCondition, MapSize};
+use bevy::prelude::{Component, Entity, IVec2, IVec3};
+#[cfg(feature = "1D")]
+pub use cell_1d::*;
+pub use graph_cell::*;
 use std::{fmt::Debug, hash::Hash};
+pub use table_cell::*;
 #[cfg(feature = "2D")]
-pub use {hexagon_2d_cell::*, moore_2d_cell::*, neumann_2d_cell::*};
+pub use {
+    axial_hexagon_2d_cell::*, complex_2d_cell::*, custom_neighborhood_2d_cell::*, gravity_model::*,
+    hexagon_2d_cell::*, kernel_2d_cell::*, moore_2d_cell::*, neumann_2d_cell::*,
+    small_world_2d_cell::*, triangle_2d_cell::*, wrapped_moore_2d_cell::*,
+};
 #[cfg(feature = "3D")]
-pub use {moore_3d_cell::*, neumann_3d_cell::*};
+pub use {complex_3d_cell::*, moore_3d_cell::*, neumann_3d_cell::*, sphere_cell::*};
 
+#[cfg(feature = "2D")]
+mod axial_hexagon_2d_cell;
+#[cfg(feature = "1D")]
+mod cell_1d;
+#[cfg(feature = "2D")]
+mod complex_2d_cell;
+#[cfg(feature = "3D")]
+mod complex_3d_cell;
+#[cfg(feature = "2D")]
+mod custom_neighborhood_2d_cell;
+mod graph_cell;
+#[cfg(feature = "2D")]
+mod gravity_model;
 #[cfg(feature = "2D")]
 mod hexagon_2d_cell;
 #[cfg(feature = "2D")]
+mod kernel_2d_cell;
+#[cfg(feature = "2D")]
 mod moore_2d_cell;
 #[cfg(feature = "3D")]
 mod moore_3d_cell;
@@ -15,18 +39,226 @@ mod moore_3d_cell;
 mod neumann_2d_cell;
 #[cfg(feature = "3D")]
 mod neumann_3d_cell;
+#[cfg(feature = "2D")]
+mod small_world_2d_cell;
+#[cfg(feature = "3D")]
+mod sphere_cell;
+mod table_cell;
+#[cfg(feature = "2D")]
+mod triangle_2d_cell;
+#[cfg(feature = "2D")]
+mod wrapped_moore_2d_cell;
+
+/// Default long-range lattice spacing of complex cells like `ComplexCell2d`
+pub const DEFAULT_LONG_RANGE_RADIUS: i32 = 50;
 
-/// Trait defining a Cell, every cell type (2d, 3d, hexagonal, etc) must
+/// Trait defining a Cell, every cell type (1d, 2d, 3d, hexagonal, etc) must
 /// implement this trait and define an associated `Coordinates` type
 pub trait Cell: Clone + Component {
     /// Associated coordinates type
-    type Coordinates: Clone + Debug + Send + Sync + Eq + Hash;
+    type Coordinates: CellCoordinates;
 
     /// Retrieves the cell coordinates
     #[must_use]
     fn coords(&self) -> &Self::Coordinates;
 
     /// Retrieves the coordinates of the neighbor cells
+    ///
+    /// The neighbor count doesn't need to be constant, cells with
+    /// heterogeneous connectivity should only yield their actual neighbors
+    /// instead of padding with placeholder coordinates.
+    ///
+    /// Neighbors are *in-neighbors*: their states are used to compute the
+    /// state of `self`. The relation doesn't need to be symmetric, a cell may
+    /// list a neighbor which doesn't list it back, allowing directed
+    /// neighborhoods (one-way corridors, wind-driven spread, etc).
     #[must_use]
     fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates>;
 }
+
+/// Trait defining a Cell whose neighbors have individual weights, used by the
+/// [`WeightedCellularAutomatonPlugin`] with a [`WeightedCellState`].
+///
+/// [`WeightedCellularAutomatonPlugin`]: crate::WeightedCellularAutomatonPlugin
+/// [`WeightedCellState`]: crate::WeightedCellState
+pub trait WeightedCell: Cell {
+    /// Retrieves the coordinates of the neighbor cells along with their weight
+    #[must_use]
+    fn weighted_neighbor_coordinates(&self) -> impl IntoIterator<Item = (Self::Coordinates, f32)>;
+}
+
+/// Trait defining a cell coordinates type, with optional support for bounded
+/// grids through [`BoundaryCondition`].
+///
+/// Custom coordinate types may use the default implementation, ignoring
+/// boundary conditions and dense maps.
+pub trait CellCoordinates: Clone + Debug + Send + Sync + Eq + Hash {
+    /// Applies the boundary `condition` of a grid of `size` to neighbor
+    /// coordinates, returning `None` if the neighbor should be ignored.
+    #[must_use]
+    #[inline]
+    fn apply_boundary(self, condition: BoundaryCondition, size: MapSize) -> Option<Self> {
+        let _ = (condition, size);
+        Some(self)
+    }
+
+    /// Retrieves the index of the coordinates in a dense map of `size`, or
+    /// `None` if they can't be stored in it.
+    ///
+    /// Cells whose coordinates have no index are ignored by dense maps.
+    #[must_use]
+    #[inline]
+    fn dense_index(&self, size: MapSize) -> Option<usize> {
+        let _ = size;
+        None
+    }
+
+    /// Retrieves the row of the coordinates in a bottom-up sequential sweep,
+    /// used by the sweep update mode of the `MovingCellularAutomatonPlugin`.
+    ///
+    /// Coordinates without rows are all swept in a random order.
+    #[must_use]
+    #[inline]
+    fn sweep_row(&self) -> i32 {
+        0
+    }
+
+    /// Retrieves the coordinates along every axis, exported as the `x`, `y` and
+    /// `z` columns of cell snapshots.
+    ///
+    /// Coordinates without axes export no position.
+    #[must_use]
+    #[inline]
+    fn axes(&self) -> Vec<i64> {
+        Vec::new()
+    }
+
+    /// Retrieves the chunk of `size` cells per axis containing the
+    /// coordinates, used by the [`DirtyChunks`] tracking.
+    ///
+    /// Coordinates without chunk are always updated.
+    ///
+    /// [`DirtyChunks`]: crate::DirtyChunks
+    #[must_use]
+    #[inline]
+    fn chunk(&self, size: u32) -> Option<IVec3> {
+        let _ = size;
+        None
+    }
+}
+
+/// Divides `coord` by the chunk `size`, rounding down
+#[inline]
+fn chunk_axis(coord: i32, size: u32) -> i32 {
+    coord.div_euclid(i32::try_from(size.max(1)).unwrap_or(i32::MAX))
+}
+
+impl CellCoordinates for IVec2 {
+    #[inline]
+    fn apply_boundary(self, condition: BoundaryCondition, size: MapSize) -> Option<Self> {
+        if size.is_empty() {
+            return None;
+        }
+        match condition {
+            BoundaryCondition::Wrap => Some(size.wrap(self)),
+            BoundaryCondition::FixedDead => size.contains(self).then_some(self),
+            BoundaryCondition::Mirror => Some(size.mirror(self)),
+        }
+    }
+
+    #[inline]
+    fn dense_index(&self, size: MapSize) -> Option<usize> {
+        size.index(*self)
+    }
+
+    #[inline]
+    fn sweep_row(&self) -> i32 {
+        self.y
+    }
+
+    #[inline]
+    fn axes(&self) -> Vec<i64> {
+        vec![i64::from(self.x), i64::from(self.y)]
+    }
+
+    #[inline]
+    fn chunk(&self, size: u32) -> Option<IVec3> {
+        Some(IVec3::new(
+            chunk_axis(self.x, size),
+            chunk_axis(self.y, size),
+            0,
+        ))
+    }
+}
+
+/// 1D coordinates use the `size` width as bounds
+impl CellCoordinates for i32 {
+    #[inline]
+    fn apply_boundary(self, condition: BoundaryCondition, size: MapSize) -> Option<Self> {
+        IVec2::new(self, 0)
+            .apply_boundary(condition, MapSize::new(size.width(), 1))
+            .map(|c| c.x)
+    }
+
+    #[inline]
+    fn dense_index(&self, size: MapSize) -> Option<usize> {
+        MapSize::new(size.width(), 1).index(IVec2::new(*self, 0))
+    }
+
+    #[inline]
+    fn axes(&self) -> Vec<i64> {
+        vec![i64::from(*self)]
+    }
+
+    #[inline]
+    fn chunk(&self, size: u32) -> Option<IVec3> {
+        Some(IVec3::new(chunk_axis(*self, size), 0, 0))
+    }
+}
+
+impl CellCoordinates for IVec3 {
+    #[inline]
+    fn apply_boundary(self, condition: BoundaryCondition, size: MapSize) -> Option<Self> {
+        if size.is_empty() {
+            return None;
+        }
+        match condition {
+            BoundaryCondition::Wrap => Some(size.wrap_3d(self)),
+            BoundaryCondition::FixedDead => size.contains_3d(self).then_some(self),
+            BoundaryCondition::Mirror => Some(size.mirror_3d(self)),
+        }
+    }
+
+    #[inline]
+    fn dense_index(&self, size: MapSize) -> Option<usize> {
+        size.index_3d(*self)
+    }
+
+    #[inline]
+    fn sweep_row(&self) -> i32 {
+        self.y
+    }
+
+    #[inline]
+    fn axes(&self) -> Vec<i64> {
+        vec![i64::from(self.x), i64::from(self.y), i64::from(self.z)]
+    }
+
+    #[inline]
+    fn chunk(&self, size: u32) -> Option<IVec3> {
+        Some(Self::new(
+            chunk_axis(self.x, size),
+            chunk_axis(self.y, size),
+            chunk_axis(self.z, size),
+        ))
+    }
+}
+
+impl CellCoordinates for u32 {
+    #[inline]
+    fn axes(&self) -> Vec<i64> {
+        vec![i64::from(*self)]
+    }
+}
+
+impl CellCoordinates for Entity {}
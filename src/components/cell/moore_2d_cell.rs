@@ -2,25 +2,6 @@ use crate::components::Cell;
 use bevy::prelude::{Component, IVec2, Reflect};
 use std::ops::Deref;
 
-const NEIGHBOR_COORDINATES: [IVec2; 8] = [
-    // Left
-    IVec2::new(-1, 0),
-    // Top Left
-    IVec2::new(-1, 1),
-    // Top
-    IVec2::new(0, 1),
-    // Top Right
-    IVec2::new(1, 1),
-    // Right
-    IVec2::new(1, 0),
-    // Bottom Right
-    IVec2::new(1, -1),
-    // Bottom
-    IVec2::new(0, -1),
-    // Bottom Left
-    IVec2::new(-1, -1),
-];
-
 /// [Moore] 2D cell. It has 8 neighbors and uses `IVec2` coordinates.
 ///
 /// ```ascii
@@ -39,14 +20,20 @@ const NEIGHBOR_COORDINATES: [IVec2; 8] = [
 /// +-------+-------+-------+
 /// ```
 ///
+/// The `RADIUS` const argument extends the neighborhood to the square of every
+/// cell within `RADIUS` [Chebyshev distance], giving `4 * RADIUS * (RADIUS +
+/// 1)` neighbors. Neighbors are yielded ring by ring, each ring going
+/// clockwise from its left-most middle cell.
+///
 /// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+/// [Chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
 #[derive(Debug, Clone, Component, Reflect)]
-pub struct MooreCell2d {
+pub struct MooreCell2d<const RADIUS: u32 = 1> {
     /// The 2D cell coordinates
     pub coords: IVec2,
 }
 
-impl Deref for MooreCell2d {
+impl<const RADIUS: u32> Deref for MooreCell2d<RADIUS> {
     type Target = IVec2;
 
     fn deref(&self) -> &Self::Target {
@@ -54,7 +41,7 @@ impl Deref for MooreCell2d {
     }
 }
 
-impl Cell for MooreCell2d {
+impl<const RADIUS: u32> Cell for MooreCell2d<RADIUS> {
     type Coordinates = IVec2;
 
     #[inline]
@@ -64,7 +51,8 @@ impl Cell for MooreCell2d {
 
     #[inline]
     fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
-        NEIGHBOR_COORDINATES.map(|c| c + *self.coords())
+        let coords = *self.coords();
+        Self::neighbor_offsets().map(move |c| c + coords)
     }
 }
 
@@ -77,13 +65,53 @@ impl MooreCell2d {
     }
 }
 
+impl<const RADIUS: u32> MooreCell2d<RADIUS> {
+    /// Amount of neighbors of the cell
+    #[allow(clippy::cast_possible_truncation)]
+    pub const NEIGHBOR_COUNT: usize = 4 * RADIUS as usize * (RADIUS as usize + 1);
+
+    /// Instantiates a new cell with `coords` values and a custom `RADIUS`,
+    /// for example `MooreCell2d::<2>::from_coords(coords)`
+    #[must_use]
+    #[inline]
+    pub const fn from_coords(coords: IVec2) -> Self {
+        Self { coords }
+    }
+
+    /// Relative coordinates of the neighbors
+    #[allow(clippy::cast_possible_wrap)]
+    fn neighbor_offsets() -> impl Iterator<Item = IVec2> {
+        (1..=RADIUS as i32).flat_map(|d| {
+            (0..8 * d).map(move |k| match k {
+                // Left to Top Left
+                k if k < d => IVec2::new(-d, k),
+                // Top Left to Top Right
+                k if k < 3 * d => IVec2::new(k - 2 * d, d),
+                // Top Right to Bottom Right
+                k if k < 5 * d => IVec2::new(d, 4 * d - k),
+                // Bottom Right to Bottom Left
+                k if k < 7 * d => IVec2::new(6 * d - k, -d),
+                // Bottom Left to Left
+                k => IVec2::new(-d, k - 8 * d),
+            })
+        })
+    }
+}
+
+impl<const RADIUS: u32> From<IVec2> for MooreCell2d<RADIUS> {
+    #[inline]
+    fn from(coords: IVec2) -> Self {
+        Self::from_coords(coords)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn correct_coordinates() {
-        let cell = MooreCell2d {
+        let cell: MooreCell2d = MooreCell2d {
             coords: IVec2::new(10, 10),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -112,7 +140,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_negative() {
-        let cell = MooreCell2d {
+        let cell: MooreCell2d = MooreCell2d {
             coords: IVec2::new(-10, 10),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -141,7 +169,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_origin() {
-        let cell = MooreCell2d {
+        let cell: MooreCell2d = MooreCell2d {
             coords: IVec2::new(0, 0),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -167,4 +195,55 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn correct_coordinates_radius_2_negative() {
+        let cell = MooreCell2d::<2>::from_coords(IVec2::new(-10, -10));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), MooreCell2d::<2>::NEIGHBOR_COUNT);
+        assert_eq!(
+            neighbors,
+            vec![
+                // Radius 1
+                IVec2::new(-11, -10),
+                IVec2::new(-11, -9),
+                IVec2::new(-10, -9),
+                IVec2::new(-9, -9),
+                IVec2::new(-9, -10),
+                IVec2::new(-9, -11),
+                IVec2::new(-10, -11),
+                IVec2::new(-11, -11),
+                // Radius 2
+                IVec2::new(-12, -10),
+                IVec2::new(-12, -9),
+                IVec2::new(-12, -8),
+                IVec2::new(-11, -8),
+                IVec2::new(-10, -8),
+                IVec2::new(-9, -8),
+                IVec2::new(-8, -8),
+                IVec2::new(-8, -9),
+                IVec2::new(-8, -10),
+                IVec2::new(-8, -11),
+                IVec2::new(-8, -12),
+                IVec2::new(-9, -12),
+                IVec2::new(-10, -12),
+                IVec2::new(-11, -12),
+                IVec2::new(-12, -12),
+                IVec2::new(-12, -11),
+            ]
+        );
+    }
+
+    #[test]
+    fn square_neighborhood() {
+        let cell = MooreCell2d::<4>::from_coords(IVec2::new(-3, 7));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 80);
+        for x in -4..=4 {
+            for y in -4..=4 {
+                let coord = IVec2::new(x - 3, y + 7);
+                assert_eq!(neighbors.contains(&coord), (x, y) != (0, 0), "{coord:?}");
+            }
+        }
+    }
 }
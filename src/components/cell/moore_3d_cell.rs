@@ -2,7 +2,7 @@ use crate::components::Cell;
 use bevy::prelude::{Component, IVec3, Reflect};
 use std::ops::Deref;
 
-const NEIGHBOR_COORDINATES: [IVec3; 26] = [
+pub(super) const NEIGHBOR_COORDINATES: [IVec3; 26] = [
     // Z - 1
 
     // Center
@@ -102,3 +102,10 @@ impl MooreCell3d {
         Self { coords }
     }
 }
+
+impl From<IVec3> for MooreCell3d {
+    #[inline]
+    fn from(coords: IVec3) -> Self {
+        Self::new(coords)
+    }
+}
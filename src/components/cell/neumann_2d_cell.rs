@@ -2,17 +2,6 @@ use crate::components::Cell;
 use bevy::prelude::{Component, IVec2, Reflect};
 use std::ops::Deref;
 
-const NEIGHBOR_COORDINATES: [IVec2; 4] = [
-    // Left
-    IVec2::new(-1, 0),
-    // Top
-    IVec2::new(0, 1),
-    // Right
-    IVec2::new(1, 0),
-    // Bottom
-    IVec2::new(0, -1),
-];
-
 /// [Neumann] 2D cell. It has 4 neighbors and uses `IVec2` coordinates.
 ///
 /// ```ascii
@@ -30,14 +19,21 @@ const NEIGHBOR_COORDINATES: [IVec2; 4] = [
 ///         |       |
 ///         +-------+
 /// ```
+///
+/// The `RADIUS` const argument extends the neighborhood to the diamond of
+/// every cell within `RADIUS` [Manhattan distance], giving `2 * RADIUS *
+/// (RADIUS + 1)` neighbors. Neighbors are yielded ring by ring, each ring
+/// going clockwise from its left-most cell.
+///
 /// [Neumann]: https://en.wikipedia.org/wiki/Von_Neumann_neighborhood
+/// [Manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
 #[derive(Debug, Clone, Component, Reflect)]
-pub struct NeumannCell2d {
+pub struct NeumannCell2d<const RADIUS: u32 = 1> {
     /// The 2D cell coordinates
     pub coords: IVec2,
 }
 
-impl Deref for NeumannCell2d {
+impl<const RADIUS: u32> Deref for NeumannCell2d<RADIUS> {
     type Target = IVec2;
 
     fn deref(&self) -> &Self::Target {
@@ -45,7 +41,7 @@ impl Deref for NeumannCell2d {
     }
 }
 
-impl Cell for NeumannCell2d {
+impl<const RADIUS: u32> Cell for NeumannCell2d<RADIUS> {
     type Coordinates = IVec2;
 
     #[inline]
@@ -55,7 +51,8 @@ impl Cell for NeumannCell2d {
 
     #[inline]
     fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
-        NEIGHBOR_COORDINATES.map(|c| c + *self.coords())
+        let coords = *self.coords();
+        Self::neighbor_offsets().map(move |c| c + coords)
     }
 }
 
@@ -68,13 +65,54 @@ impl NeumannCell2d {
     }
 }
 
+impl<const RADIUS: u32> NeumannCell2d<RADIUS> {
+    /// Amount of neighbors of the cell
+    #[allow(clippy::cast_possible_truncation)]
+    pub const NEIGHBOR_COUNT: usize = 2 * RADIUS as usize * (RADIUS as usize + 1);
+
+    /// Instantiates a new cell with `coords` values and a custom `RADIUS`,
+    /// for example `NeumannCell2d::<2>::from_coords(coords)`
+    #[must_use]
+    #[inline]
+    pub const fn from_coords(coords: IVec2) -> Self {
+        Self { coords }
+    }
+
+    /// Relative coordinates of the neighbors
+    #[allow(clippy::cast_possible_wrap)]
+    fn neighbor_offsets() -> impl Iterator<Item = IVec2> {
+        (1..=RADIUS as i32).flat_map(|d| {
+            (0..4 * d).map(move |k| {
+                let i = k % d;
+                match k / d {
+                    // Left to Top
+                    0 => IVec2::new(-d + i, i),
+                    // Top to Right
+                    1 => IVec2::new(i, d - i),
+                    // Right to Bottom
+                    2 => IVec2::new(d - i, -i),
+                    // Bottom to Left
+                    _ => IVec2::new(-i, -d + i),
+                }
+            })
+        })
+    }
+}
+
+impl<const RADIUS: u32> From<IVec2> for NeumannCell2d<RADIUS> {
+    #[inline]
+    fn from(coords: IVec2) -> Self {
+        Self::from_coords(coords)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn correct_coordinates() {
-        let cell = NeumannCell2d {
+        let cell: NeumannCell2d = NeumannCell2d {
             coords: IVec2::new(10, 10),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -95,7 +133,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_negative() {
-        let cell = NeumannCell2d {
+        let cell: NeumannCell2d = NeumannCell2d {
             coords: IVec2::new(-10, 10),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -116,7 +154,7 @@ mod tests {
 
     #[test]
     fn correct_coordinates_origin() {
-        let cell = NeumannCell2d {
+        let cell: NeumannCell2d = NeumannCell2d {
             coords: IVec2::new(0, 0),
         };
         let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
@@ -134,4 +172,49 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn correct_coordinates_radius_2() {
+        let cell = NeumannCell2d::<2>::from_coords(IVec2::new(-10, 10));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), NeumannCell2d::<2>::NEIGHBOR_COUNT);
+        assert_eq!(
+            neighbors,
+            vec![
+                // Radius 1
+                IVec2::new(-11, 10),
+                IVec2::new(-10, 11),
+                IVec2::new(-9, 10),
+                IVec2::new(-10, 9),
+                // Radius 2
+                IVec2::new(-12, 10),
+                IVec2::new(-11, 11),
+                IVec2::new(-10, 12),
+                IVec2::new(-9, 11),
+                IVec2::new(-8, 10),
+                IVec2::new(-9, 9),
+                IVec2::new(-10, 8),
+                IVec2::new(-11, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn diamond_neighborhood() {
+        let cell = NeumannCell2d::<5>::from_coords(IVec2::new(3, -7));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors.len(), 60);
+        let mut expected = Vec::new();
+        for x in -5_i32..=5 {
+            for y in -5_i32..=5 {
+                if (1..=5).contains(&(x.abs() + y.abs())) {
+                    expected.push(IVec2::new(x + 3, y - 7));
+                }
+            }
+        }
+        for coord in &expected {
+            assert!(neighbors.contains(coord), "{coord:?}");
+        }
+        assert_eq!(expected.len(), neighbors.len());
+    }
 }
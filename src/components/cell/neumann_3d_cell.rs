@@ -63,3 +63,10 @@ impl NeumannCell3d {
         Self { coords }
     }
 }
+
+impl From<IVec3> for NeumannCell3d {
+    #[inline]
+    fn from(coords: IVec3) -> Self {
+        Self::new(coords)
+    }
+}
@@ -0,0 +1,161 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, IVec2, Reflect, Vec2};
+use rand::{distributions::Distribution, Rng};
+use std::{f32::consts::TAU, ops::Deref};
+
+const NEIGHBOR_COORDINATES: [IVec2; 8] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Top Left
+    IVec2::new(-1, 1),
+    // Top
+    IVec2::new(0, 1),
+    // Top Right
+    IVec2::new(1, 1),
+    // Right
+    IVec2::new(1, 0),
+    // Bottom Right
+    IVec2::new(1, -1),
+    // Bottom
+    IVec2::new(0, -1),
+    // Bottom Left
+    IVec2::new(-1, -1),
+];
+
+/// [Small world] 2D cell. It has the 8 [Moore] neighbors and a list of
+/// per-cell long-range links, and uses `IVec2` coordinates.
+///
+/// The long-range links are usually drawn randomly at spawn time with
+/// [`Self::with_random_links`] (Watts–Strogatz style), allowing true
+/// small-world topologies.
+///
+/// # Note
+///
+/// Links are directed: a cell linking to another one isn't automatically
+/// linked back.
+///
+/// [Small world]: https://en.wikipedia.org/wiki/Small-world_network
+/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct SmallWorldCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The coordinates of the long-range linked cells
+    pub long_range_links: Vec<IVec2>,
+}
+
+impl Deref for SmallWorldCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for SmallWorldCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        NEIGHBOR_COORDINATES
+            .map(|c| c + *self.coords())
+            .into_iter()
+            .chain(self.long_range_links.iter().copied())
+    }
+}
+
+impl SmallWorldCell2d {
+    /// Instantiates a new cell with `coords` values and explicit
+    /// `long_range_links` coordinates
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2, long_range_links: Vec<IVec2>) -> Self {
+        Self {
+            coords,
+            long_range_links,
+        }
+    }
+
+    /// Instantiates a new cell with `coords` values and `k` random long-range
+    /// links.
+    ///
+    /// Each link distance is sampled from `distance` and its direction is
+    /// uniformly random. Sampled links falling inside the [Moore]
+    /// neighborhood are pushed outside of it, so every link is a real
+    /// long-range connection.
+    ///
+    /// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+    #[must_use]
+    pub fn with_random_links(
+        coords: IVec2,
+        k: usize,
+        distance: &impl Distribution<f32>,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let long_range_links = (0..k)
+            .map(|_| {
+                let angle = rng.gen_range(0.0..TAU);
+                let direction = Vec2::from_angle(angle);
+                let length = distance.sample(rng).abs().max(2.0);
+                coords + (direction * length).round().as_ivec2()
+            })
+            .collect();
+        Self::new(coords, long_range_links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{distributions::Uniform, rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = SmallWorldCell2d::new(
+            IVec2::new(-10, 10),
+            vec![IVec2::new(40, 10), IVec2::new(-10, -35)],
+        );
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(-11, 10),
+                // Top Left
+                IVec2::new(-11, 11),
+                // Top
+                IVec2::new(-10, 11),
+                // Top Right
+                IVec2::new(-9, 11),
+                // Right
+                IVec2::new(-9, 10),
+                // Bottom Right
+                IVec2::new(-9, 9),
+                // Bottom
+                IVec2::new(-10, 9),
+                // Bottom Left
+                IVec2::new(-11, 9),
+                // Long range links
+                IVec2::new(40, 10),
+                IVec2::new(-10, -35),
+            ]
+        );
+    }
+
+    #[test]
+    fn random_links() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let distance = Uniform::new(10.0, 20.0);
+        let cell = SmallWorldCell2d::with_random_links(IVec2::new(5, 5), 16, &distance, &mut rng);
+        assert_eq!(cell.long_range_links.len(), 16);
+        for link in &cell.long_range_links {
+            let length = (*link - cell.coords).as_vec2().length();
+            assert!((9.0..=21.0).contains(&length), "{length}");
+        }
+    }
+}
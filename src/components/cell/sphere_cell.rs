@@ -0,0 +1,214 @@
+use crate::components::Cell;
+use bevy::{
+    prelude::{Component, Reflect, Vec3},
+    utils::HashMap,
+};
+
+const GOLDEN_RATIO: f32 = 1.618_034;
+
+/// Geodesic sphere cell. It has 5 or 6 neighbors and uses `u32` coordinates
+/// (the index of the cell in its [`Icosphere`]).
+///
+/// Cells are the vertices of a subdivided icosahedron: the 12 original
+/// vertices have 5 neighbors, every other one has 6. This allows global
+/// simulations without map edge artifacts.
+///
+/// Use [`Icosphere::cells`] to build every cell of a sphere.
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct SphereCell {
+    /// The cell index in its [`Icosphere`]
+    pub index: u32,
+    /// The neighbor cell indexes
+    pub neighbors: Vec<u32>,
+    /// The cell position on the unit sphere
+    pub position: Vec3,
+}
+
+impl Cell for SphereCell {
+    type Coordinates = u32;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.index
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        self.neighbors.iter().copied()
+    }
+}
+
+impl SphereCell {
+    /// Retrieves the world position of the cell on a sphere of `radius`
+    /// centered on the origin
+    #[must_use]
+    #[inline]
+    pub fn world_position(&self, radius: f32) -> Vec3 {
+        self.position * radius
+    }
+}
+
+/// Geodesic sphere, built by subdividing an icosahedron and projecting the
+/// vertices on the unit sphere. Each vertex is a [`SphereCell`].
+#[derive(Debug, Clone)]
+pub struct Icosphere {
+    positions: Vec<Vec3>,
+    neighbors: Vec<Vec<u32>>,
+}
+
+impl Icosphere {
+    /// Builds an icosphere with `subdivisions` iterations, giving
+    /// `10 * 4^subdivisions + 2` cells.
+    #[must_use]
+    pub fn new(subdivisions: u32) -> Self {
+        let t = GOLDEN_RATIO;
+        let mut positions: Vec<Vec3> = [
+            [-1.0, t, 0.0],
+            [1.0, t, 0.0],
+            [-1.0, -t, 0.0],
+            [1.0, -t, 0.0],
+            [0.0, -1.0, t],
+            [0.0, 1.0, t],
+            [0.0, -1.0, -t],
+            [0.0, 1.0, -t],
+            [t, 0.0, -1.0],
+            [t, 0.0, 1.0],
+            [-t, 0.0, -1.0],
+            [-t, 0.0, 1.0],
+        ]
+        .into_iter()
+        .map(|p| Vec3::from_array(p).normalize())
+        .collect();
+        let mut faces: Vec<[u32; 3]> = vec![
+            [0, 11, 5],
+            [0, 5, 1],
+            [0, 1, 7],
+            [0, 7, 10],
+            [0, 10, 11],
+            [1, 5, 9],
+            [5, 11, 4],
+            [11, 10, 2],
+            [10, 7, 6],
+            [7, 1, 8],
+            [3, 9, 4],
+            [3, 4, 2],
+            [3, 2, 6],
+            [3, 6, 8],
+            [3, 8, 9],
+            [4, 9, 5],
+            [2, 4, 11],
+            [6, 2, 10],
+            [8, 6, 7],
+            [9, 8, 1],
+        ];
+        for _ in 0..subdivisions {
+            let mut midpoints = HashMap::new();
+            let mut midpoint = |a: u32, b: u32| -> u32 {
+                *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+                    let p = (positions[a as usize] + positions[b as usize]).normalize();
+                    positions.push(p);
+                    Self::index(positions.len() - 1)
+                })
+            };
+            faces = faces
+                .into_iter()
+                .flat_map(|[a, b, c]| {
+                    let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+        let mut neighbors = vec![Vec::with_capacity(6); positions.len()];
+        for [a, b, c] in faces {
+            for (from, to) in [(a, b), (b, c), (c, a), (b, a), (c, b), (a, c)] {
+                let list: &mut Vec<u32> = &mut neighbors[from as usize];
+                if !list.contains(&to) {
+                    list.push(to);
+                }
+            }
+        }
+        for list in &mut neighbors {
+            list.sort_unstable();
+        }
+        Self {
+            positions,
+            neighbors,
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    const fn index(i: usize) -> u32 {
+        i as u32
+    }
+
+    /// Amount of cells of the sphere
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Is the sphere empty, always `false`
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Retrieves the position on the unit sphere of the cell at `index`
+    #[must_use]
+    #[inline]
+    pub fn position(&self, index: u32) -> Option<Vec3> {
+        self.positions.get(index as usize).copied()
+    }
+
+    /// Builds every cell of the sphere
+    pub fn cells(&self) -> impl Iterator<Item = SphereCell> + '_ {
+        self.positions
+            .iter()
+            .zip(&self.neighbors)
+            .enumerate()
+            .map(|(i, (position, neighbors))| SphereCell {
+                index: Self::index(i),
+                neighbors: neighbors.clone(),
+                position: *position,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cell_count() {
+        for (subdivisions, count) in [(0, 12), (1, 42), (2, 162), (3, 642)] {
+            let sphere = Icosphere::new(subdivisions);
+            assert_eq!(sphere.len(), count);
+            assert_eq!(sphere.cells().count(), count);
+        }
+    }
+
+    #[test]
+    fn neighbor_counts() {
+        let sphere = Icosphere::new(3);
+        let cells: Vec<_> = sphere.cells().collect();
+        let pentagons = cells.iter().filter(|c| c.neighbors.len() == 5).count();
+        let hexagons = cells.iter().filter(|c| c.neighbors.len() == 6).count();
+        assert_eq!(pentagons, 12);
+        assert_eq!(hexagons, cells.len() - 12);
+    }
+
+    #[test]
+    fn symmetric_neighbors() {
+        let sphere = Icosphere::new(2);
+        let cells: Vec<_> = sphere.cells().collect();
+        for cell in &cells {
+            assert!((cell.position.length() - 1.0).abs() < 1e-5);
+            assert!((cell.world_position(3.0).length() - 3.0).abs() < 1e-4);
+            for neighbor in cell.neighbor_coordinates() {
+                assert!(cells[neighbor as usize].neighbors.contains(&cell.index));
+            }
+        }
+    }
+}
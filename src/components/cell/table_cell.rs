@@ -0,0 +1,93 @@
+use crate::{components::Cell, resources::AdjacencyTable};
+use bevy::prelude::{Component, Reflect};
+
+/// Cell whose neighborhood is read from an [`AdjacencyTable`]. It uses `u32`
+/// coordinates (the cell id in the table).
+///
+/// Allows cells on aperiodic tilings (Penrose, hat tile, etc) or any other
+/// tiling without a regular coordinate system: generate the tiling, fill an
+/// [`AdjacencyTable`] with the tile adjacencies and spawn a `TableCell` for
+/// every tile id.
+///
+/// See the `2d_penrose_tiling` example for a Penrose patch generator.
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct TableCell {
+    /// The cell id in the adjacency table
+    pub id: u32,
+    /// The shared adjacency table
+    #[reflect(ignore)]
+    pub table: AdjacencyTable,
+}
+
+impl Cell for TableCell {
+    type Coordinates = u32;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.id
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        self.table.neighbors(self.id).iter().copied()
+    }
+}
+
+impl TableCell {
+    /// Instantiates a new cell with `id` using the neighbors defined in
+    /// `table`
+    #[must_use]
+    #[inline]
+    pub fn new(id: u32, table: &AdjacencyTable) -> Self {
+        Self {
+            id,
+            table: table.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CellularAutomatonPlugin, ConwayCellState};
+    use bevy::prelude::*;
+
+    fn ring(size: u32) -> AdjacencyTable {
+        (0..size)
+            .map(|id| (id, vec![(id + size - 1) % size, (id + 1) % size]))
+            .collect()
+    }
+
+    #[test]
+    fn correct_coordinates() {
+        let table = ring(5);
+        let cell = TableCell::new(0, &table);
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(neighbors, vec![4, 1]);
+        let cell = TableCell::new(10, &table);
+        assert_eq!(cell.neighbor_coordinates().into_iter().count(), 0);
+    }
+
+    #[test]
+    fn table_simulation() {
+        // Complete graph: every cell has the 3 others as neighbors
+        let table: AdjacencyTable = (0..4)
+            .map(|id| (id, (0..4).filter(|n| *n != id).collect()))
+            .collect();
+        let mut app = App::new();
+        app.add_plugins(CellularAutomatonPlugin::<TableCell, ConwayCellState>::new());
+        let cells: Vec<_> = (0..4)
+            .map(|id| {
+                app.world
+                    .spawn((TableCell::new(id, &table), ConwayCellState(id != 0)))
+                    .id()
+            })
+            .collect();
+        app.update();
+        // The dead cell has 3 live neighbors and is born, the live cells have 2
+        // live neighbors and survive
+        for cell in cells {
+            assert!(app.world.get::<ConwayCellState>(cell).unwrap().0);
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use crate::components::Cell;
+use bevy::prelude::{Component, IVec2, Quat, Reflect, Transform, Vec2};
+use std::{f32::consts::PI, ops::Deref};
+
+const UP_EDGE_NEIGHBOR_COORDINATES: [IVec2; 3] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Right
+    IVec2::new(1, 0),
+    // Bottom
+    IVec2::new(0, -1),
+];
+
+const DOWN_EDGE_NEIGHBOR_COORDINATES: [IVec2; 3] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Top
+    IVec2::new(0, 1),
+    // Right
+    IVec2::new(1, 0),
+];
+
+const UP_VERTEX_NEIGHBOR_COORDINATES: [IVec2; 12] = [
+    // Top row
+    IVec2::new(-1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(1, 1),
+    // Center row
+    IVec2::new(-2, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(1, 0),
+    IVec2::new(2, 0),
+    // Bottom row
+    IVec2::new(-2, -1),
+    IVec2::new(-1, -1),
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(2, -1),
+];
+
+const DOWN_VERTEX_NEIGHBOR_COORDINATES: [IVec2; 12] = [
+    // Top row
+    IVec2::new(-2, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(1, 1),
+    IVec2::new(2, 1),
+    // Center row
+    IVec2::new(-2, 0),
+    IVec2::new(-1, 0),
+    IVec2::new(1, 0),
+    IVec2::new(2, 0),
+    // Bottom row
+    IVec2::new(-1, -1),
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+];
+
+/// Neighborhood variants of a [`TriangleCell2d`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Reflect)]
+pub enum TriangleNeighborhood {
+    /// The 3 triangles sharing an edge with the cell
+    #[default]
+    Edges,
+    /// The 12 triangles sharing at least a vertex with the cell
+    Vertices,
+}
+
+/// Triangular 2D cell. It has 3 or 12 neighbors depending on its
+/// `neighborhood` and uses `IVec2` coordinates.
+///
+/// Triangles alternate between pointing up (when `x + y` is even) and
+/// pointing down (when `x + y` is odd), every row being shifted by half a
+/// triangle width.
+///
+/// ```ascii
+///  ________________________
+///  \      /\      /\      /
+///   \ 0,1/  \ 2,1/  \ 4,1/
+///    \  / 1,1\  / 3,1\  /
+///  ___\/______\/______\/___
+///     /\      /\      /\
+///    /  \ 1,0/  \ 3,0/  \
+///   / 0,0\  / 2,0\  / 4,0\
+///  /______\/______\/______\
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct TriangleCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The neighborhood variant
+    pub neighborhood: TriangleNeighborhood,
+}
+
+impl Deref for TriangleCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for TriangleCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let offsets: &[IVec2] = match (self.neighborhood, self.is_pointing_up()) {
+            (TriangleNeighborhood::Edges, true) => &UP_EDGE_NEIGHBOR_COORDINATES,
+            (TriangleNeighborhood::Edges, false) => &DOWN_EDGE_NEIGHBOR_COORDINATES,
+            (TriangleNeighborhood::Vertices, true) => &UP_VERTEX_NEIGHBOR_COORDINATES,
+            (TriangleNeighborhood::Vertices, false) => &DOWN_VERTEX_NEIGHBOR_COORDINATES,
+        };
+        let coords = *self.coords();
+        offsets.iter().map(move |c| *c + coords)
+    }
+}
+
+impl TriangleCell2d {
+    /// Instantiates a new cell with `coords` values and the 3 edge neighbors
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2) -> Self {
+        Self::new_with_neighborhood(coords, TriangleNeighborhood::Edges)
+    }
+
+    /// Instantiates a new cell with `coords` values and a custom
+    /// `neighborhood`
+    #[must_use]
+    #[inline]
+    pub const fn new_with_neighborhood(coords: IVec2, neighborhood: TriangleNeighborhood) -> Self {
+        Self {
+            coords,
+            neighborhood,
+        }
+    }
+
+    /// Is the triangle pointing up, otherwise it's pointing down
+    #[must_use]
+    #[inline]
+    pub const fn is_pointing_up(&self) -> bool {
+        (self.coords.x + self.coords.y).rem_euclid(2) == 0
+    }
+
+    /// Retrieves the world position of the triangle centroid, for triangles
+    /// of `size` side length
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn world_position(&self, size: f32) -> Vec2 {
+        let height = size * 3.0_f32.sqrt() / 2.0;
+        let centroid_offset = if self.is_pointing_up() {
+            height / 3.0
+        } else {
+            height * 2.0 / 3.0
+        };
+        Vec2::new(
+            self.coords.x as f32 * size / 2.0,
+            (self.coords.y as f32).mul_add(height, centroid_offset),
+        )
+    }
+
+    /// Retrieves the world transform of the triangle, for triangles of `size`
+    /// side length. Triangles pointing down are rotated by half a turn, so an
+    /// up-pointing triangle mesh can be used for every cell.
+    #[must_use]
+    pub fn world_transform(&self, size: f32) -> Transform {
+        let rotation = if self.is_pointing_up() {
+            Quat::IDENTITY
+        } else {
+            Quat::from_rotation_z(PI)
+        };
+        Transform::from_translation(self.world_position(size).extend(0.0)).with_rotation(rotation)
+    }
+}
+
+impl From<IVec2> for TriangleCell2d {
+    #[inline]
+    fn from(coords: IVec2) -> Self {
+        Self::new(coords)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_coordinates_up() {
+        let cell = TriangleCell2d::new(IVec2::new(-10, 10));
+        assert!(cell.is_pointing_up());
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(-11, 10),
+                // Right
+                IVec2::new(-9, 10),
+                // Bottom
+                IVec2::new(-10, 9),
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_coordinates_down() {
+        let cell = TriangleCell2d::new(IVec2::new(1, 0));
+        assert!(!cell.is_pointing_up());
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(0, 0),
+                // Top
+                IVec2::new(1, 1),
+                // Right
+                IVec2::new(2, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn symmetric_neighborhoods() {
+        for neighborhood in [TriangleNeighborhood::Edges, TriangleNeighborhood::Vertices] {
+            for x in -3..=3 {
+                for y in -3..=3 {
+                    let cell =
+                        TriangleCell2d::new_with_neighborhood(IVec2::new(x, y), neighborhood);
+                    for coords in cell.neighbor_coordinates() {
+                        let neighbor = TriangleCell2d::new_with_neighborhood(coords, neighborhood);
+                        assert!(neighbor
+                            .neighbor_coordinates()
+                            .into_iter()
+                            .any(|c| c == cell.coords));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn world_positions() {
+        let size = 2.0;
+        let height = 3.0_f32.sqrt();
+        let up = TriangleCell2d::new(IVec2::ZERO);
+        let down = TriangleCell2d::new(IVec2::new(1, 0));
+        assert!((up.world_position(size) - Vec2::new(0.0, height / 3.0)).length() < 1e-5);
+        assert!((down.world_position(size) - Vec2::new(1.0, height * 2.0 / 3.0)).length() < 1e-5);
+        assert_eq!(up.world_transform(size).rotation, Quat::IDENTITY);
+        assert_eq!(
+            down.world_transform(size).rotation,
+            Quat::from_rotation_z(PI)
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use crate::{components::Cell, resources::MapSize};
+use bevy::prelude::{Component, IVec2, Reflect};
+use std::ops::Deref;
+
+const NEIGHBOR_COORDINATES: [IVec2; 8] = [
+    // Left
+    IVec2::new(-1, 0),
+    // Top Left
+    IVec2::new(-1, 1),
+    // Top
+    IVec2::new(0, 1),
+    // Top Right
+    IVec2::new(1, 1),
+    // Right
+    IVec2::new(1, 0),
+    // Bottom Right
+    IVec2::new(1, -1),
+    // Bottom
+    IVec2::new(0, -1),
+    // Bottom Left
+    IVec2::new(-1, -1),
+];
+
+/// Toroidal [Moore] 2D cell. It has 8 neighbors and uses `IVec2` coordinates.
+///
+/// Neighbor coordinates wrap around the edges of the grid defined by
+/// `map_size`, so finite grids have no boundary artifacts: the left neighbor
+/// of a cell on the first column is on the last column. Cells of an empty
+/// `map_size` have no neighbors.
+///
+/// [Moore]: https://en.wikipedia.org/wiki/Moore_neighborhood
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct WrappedMooreCell2d {
+    /// The 2D cell coordinates
+    pub coords: IVec2,
+    /// The size of the wrapping grid
+    pub map_size: MapSize,
+}
+
+impl Deref for WrappedMooreCell2d {
+    type Target = IVec2;
+
+    fn deref(&self) -> &Self::Target {
+        &self.coords
+    }
+}
+
+impl Cell for WrappedMooreCell2d {
+    type Coordinates = IVec2;
+
+    #[inline]
+    fn coords(&self) -> &Self::Coordinates {
+        &self.coords
+    }
+
+    #[inline]
+    fn neighbor_coordinates(&self) -> impl IntoIterator<Item = Self::Coordinates> {
+        let coords = *self.coords();
+        // An empty grid has no cell to wrap around
+        (!self.map_size.is_empty())
+            .then(|| NEIGHBOR_COORDINATES.map(|c| self.map_size.wrap(c + coords)))
+            .into_iter()
+            .flatten()
+    }
+}
+
+impl WrappedMooreCell2d {
+    /// Instantiates a new cell with `coords` values in a grid of `map_size`
+    #[must_use]
+    #[inline]
+    pub const fn new(coords: IVec2, map_size: MapSize) -> Self {
+        Self { coords, map_size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_coordinates() {
+        let cell = WrappedMooreCell2d::new(IVec2::new(5, 5), MapSize::new(10, 10));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(4, 5),
+                // Top Left
+                IVec2::new(4, 6),
+                // Top
+                IVec2::new(5, 6),
+                // Top Right
+                IVec2::new(6, 6),
+                // Right
+                IVec2::new(6, 5),
+                // Bottom Right
+                IVec2::new(6, 4),
+                // Bottom
+                IVec2::new(5, 4),
+                // Bottom Left
+                IVec2::new(4, 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_coordinates_origin() {
+        let cell = WrappedMooreCell2d::new(IVec2::ZERO, MapSize::new(10, 8));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(9, 0),
+                // Top Left
+                IVec2::new(9, 1),
+                // Top
+                IVec2::new(0, 1),
+                // Top Right
+                IVec2::new(1, 1),
+                // Right
+                IVec2::new(1, 0),
+                // Bottom Right
+                IVec2::new(1, 7),
+                // Bottom
+                IVec2::new(0, 7),
+                // Bottom Left
+                IVec2::new(9, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn correct_coordinates_corner() {
+        let cell = WrappedMooreCell2d::new(IVec2::new(9, 7), MapSize::new(10, 8));
+        let neighbors = cell.neighbor_coordinates().into_iter().collect::<Vec<_>>();
+        assert_eq!(
+            neighbors,
+            vec![
+                // Left
+                IVec2::new(8, 7),
+                // Top Left
+                IVec2::new(8, 0),
+                // Top
+                IVec2::new(9, 0),
+                // Top Right
+                IVec2::new(0, 0),
+                // Right
+                IVec2::new(0, 7),
+                // Bottom Right
+                IVec2::new(0, 6),
+                // Bottom
+                IVec2::new(9, 6),
+                // Bottom Left
+                IVec2::new(8, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_map_size() {
+        for map_size in [MapSize::new(0, 8), MapSize::new(10, 0)] {
+            let cell = WrappedMooreCell2d::new(IVec2::new(3, 2), map_size);
+            assert_eq!(cell.neighbor_coordinates().into_iter().count(), 0);
+        }
+    }
+}
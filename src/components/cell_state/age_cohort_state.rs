@@ -0,0 +1,224 @@
+use crate::{
+    components::{
+        CellState, CohortState, InfectableCellState, ParametricCellState, VaccinableCellState,
+    },
+    resources::{AgeMixingParams, CellRng},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// Age structured [`CohortState`], holding a population cohort for each of
+/// the `G` age groups of the cell.
+///
+/// Every generation, the infection pressure on each age group sums the
+/// pressure `transmission_probability * I / N` of every age group, scaled by
+/// the contact matrix of the [`AgeMixingParams`]. As for cohorts, the
+/// infectious individuals `I` and population `N` of an age group include the
+/// ones of the neighbor cells weighted by `neighbor_coupling`. The other
+/// events follow the rules of the [`CohortState`].
+///
+/// Individuals don't age: births join the susceptible individuals of their
+/// age group.
+///
+/// The parameters are read through the [`ParametricCellularAutomatonPlugin`].
+/// With a regular plugin, the default parameters are used.
+///
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct AgeCohortState<const G: usize> {
+    /// Population cohort of every age group
+    pub groups: [CohortState; G],
+}
+
+impl<const G: usize> Default for AgeCohortState<G> {
+    fn default() -> Self {
+        Self::new([CohortState::default(); G])
+    }
+}
+
+impl<const G: usize> AgeCohortState<G> {
+    /// Instantiates a new state with the cohort of every age group
+    #[must_use]
+    #[inline]
+    pub const fn new(groups: [CohortState; G]) -> Self {
+        Self { groups }
+    }
+
+    /// Total population of the cell
+    #[must_use]
+    pub fn population(&self) -> u64 {
+        self.groups.iter().map(CohortState::population).sum()
+    }
+
+    /// Total cohort of the cell, summing every age group
+    #[must_use]
+    pub fn total(&self) -> CohortState {
+        self.groups
+            .iter()
+            .fold(CohortState::default(), |t, g| t + *g)
+    }
+
+    /// Vaccinates a `coverage` fraction of the susceptible individuals of the
+    /// age `group` only, for age targeted interventions
+    #[must_use]
+    pub fn vaccinate_group(&self, group: usize, coverage: f64) -> Self {
+        let mut state = *self;
+        if let Some(cohort) = state.groups.get_mut(group) {
+            *cohort = cohort.vaccinate(coverage);
+        }
+        state
+    }
+}
+
+impl<const G: usize> CellState for AgeCohortState<G> {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &AgeMixingParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        self.total().color()
+    }
+}
+
+impl<const G: usize> ParametricCellState for AgeCohortState<G> {
+    type Params = AgeMixingParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let epidemic = &params.epidemic;
+        let coupling = epidemic.neighbor_coupling.clamp(0.0, 1.0);
+        let mut infectious = self.groups.map(|c| f64::from(c.infectious));
+        let mut population = self.groups.map(|c| c.population() as f64);
+        for cell in neighbor_cells {
+            for (group, cohort) in cell.groups.iter().enumerate() {
+                infectious[group] =
+                    coupling.mul_add(f64::from(cohort.infectious), infectious[group]);
+                population[group] = coupling.mul_add(cohort.population() as f64, population[group]);
+            }
+        }
+        // Infection pressure of every age group, before contacts
+        let pressures: [f64; G] = std::array::from_fn(|group| {
+            if population[group] > 0.0 {
+                epidemic.transmission() * infectious[group] / population[group]
+            } else {
+                0.0
+            }
+        });
+        let mut groups = self.groups;
+        for (a, cohort) in groups.iter_mut().enumerate() {
+            let pressure = pressures
+                .iter()
+                .enumerate()
+                .map(|(b, pressure)| params.contact(a, b) * pressure)
+                .sum();
+            *cohort = cohort.advance_with_rng(pressure, epidemic, rng);
+        }
+        Self { groups }
+    }
+}
+
+impl<const G: usize> VaccinableCellState for AgeCohortState<G> {
+    fn is_infected(&self) -> bool {
+        self.groups.iter().any(VaccinableCellState::is_infected)
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        Self {
+            groups: self.groups.map(|c| c.vaccinate(coverage)),
+        }
+    }
+}
+
+impl<const G: usize> InfectableCellState for AgeCohortState<G> {
+    /// Every case infects a random susceptible individual
+    fn import_infection(&self, cases: u32) -> Self {
+        let mut rng = CellRng;
+        let mut state = *self;
+        for _ in 0..cases {
+            let total = state.total().susceptible;
+            if total == 0 {
+                break;
+            }
+            let mut draw = rng.gen_range(0..total);
+            for cohort in &mut state.groups {
+                if draw < cohort.susceptible {
+                    *cohort = cohort.import_infection(1);
+                    break;
+                }
+                draw -= cohort.susceptible;
+            }
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EpidemicParams;
+
+    #[test]
+    fn mixing_matrix() {
+        // Children infect each other and adults, adults only infect adults
+        let params = AgeMixingParams::new(
+            EpidemicParams::new(1.0, 1, u32::MAX).with_neighbor_coupling(0.0),
+            vec![vec![10.0, 0.0], vec![10.0, 10.0]],
+        );
+        let state = AgeCohortState::new([
+            CohortState::new(1000, 0, 0),
+            CohortState::new(1000, 1000, 0),
+        ]);
+        let state = state.new_cell_state_with_params(std::iter::empty(), &params);
+        assert_eq!(state.groups[0], CohortState::new(1000, 0, 0));
+        assert!(state.groups[1].infectious > 1000);
+        let state = AgeCohortState::new([
+            CohortState::new(1000, 1000, 0),
+            CohortState::new(1000, 0, 0),
+        ]);
+        let state = state.new_cell_state_with_params(std::iter::empty(), &params);
+        assert!(state.groups[0].infectious > 1000);
+        assert!(state.groups[1].infectious > 0);
+        assert_eq!(state.population(), 3000);
+    }
+
+    #[test]
+    fn homogeneous_mixing() {
+        // A single age group with homogeneous mixing is a regular cohort
+        let params = AgeMixingParams::new(EpidemicParams::new(0.0, 1, u32::MAX), Vec::new());
+        let cohort = CohortState::new(100, 10, 5);
+        let neighbors = [AgeCohortState::new([CohortState::new(3, 2, 1)])];
+        assert_eq!(
+            AgeCohortState::new([cohort]).new_cell_state_with_params(neighbors.iter(), &params),
+            AgeCohortState::new([cohort])
+        );
+    }
+
+    #[test]
+    fn targeted_vaccination() {
+        let state = AgeCohortState::new([CohortState::new(100, 0, 0), CohortState::new(100, 1, 0)]);
+        let vaccinated = state.vaccinate_group(1, 1.0);
+        assert_eq!(
+            vaccinated.groups,
+            [CohortState::new(100, 0, 0), CohortState::new(0, 1, 100)]
+        );
+        assert!(vaccinated.is_infected());
+        assert_eq!(state.vaccinate_group(2, 1.0), state);
+        assert_eq!(state.vaccinate(1.0).total(), CohortState::new(0, 1, 200));
+    }
+}
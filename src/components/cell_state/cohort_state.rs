@@ -0,0 +1,386 @@
+use crate::{
+    components::{
+        CellState, CoarseCellState, InfectableCellState, ParametricCellState, VaccinableCellState,
+    },
+    resources::{CellRng, EpidemicParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+use rand_distr::{Binomial, Distribution};
+use std::ops::Add;
+
+/// Stochastic [SIR] epidemic state of a whole population: the amount of
+/// susceptible, infectious and recovered individuals of the cell.
+///
+/// Every generation, with `N` the population and `I` the infectious
+/// individuals of the cell and of its neighbors weighted by
+/// `neighbor_coupling`:
+///
+/// - every susceptible individual is infected with probability
+///   `1 - exp(-transmission_probability * I / N)`
+/// - every infectious individual dies with probability
+///   `mortality_probability`, or else recovers with probability
+///   `1 / infectious_period`
+///
+/// With vital dynamics, every individual also gives birth to a susceptible
+/// individual with probability `birth_rate` and dies of other causes with
+/// probability `death_rate`.
+///
+/// Every event is drawn from binomial distributions, and
+/// invalid parameters are clamped so that the compartments never underflow.
+///
+/// The parameters are read from the [`EpidemicParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [SIR]: https://en.wikipedia.org/wiki/Compartmental_models_in_epidemiology#The_SIR_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct CohortState {
+    /// Susceptible individuals
+    pub susceptible: u32,
+    /// Infectious individuals
+    pub infectious: u32,
+    /// Recovered and immune individuals
+    pub recovered: u32,
+}
+
+impl CohortState {
+    /// Instantiates a new cohort with its `susceptible`, `infectious` and
+    /// `recovered` individuals
+    #[must_use]
+    #[inline]
+    pub const fn new(susceptible: u32, infectious: u32, recovered: u32) -> Self {
+        Self {
+            susceptible,
+            infectious,
+            recovered,
+        }
+    }
+
+    /// Total population of the cohort
+    #[must_use]
+    #[inline]
+    pub const fn population(&self) -> u64 {
+        self.susceptible as u64 + self.infectious as u64 + self.recovered as u64
+    }
+
+    /// Advances the cohort one generation under the infection `pressure`:
+    /// every susceptible individual is infected with probability
+    /// `1 - exp(-pressure)`, then the other events follow the `params`.
+    #[must_use]
+    pub fn advance(&self, pressure: f64, params: &EpidemicParams) -> Self {
+        self.advance_with_rng(pressure, params, &mut CellRng)
+    }
+
+    /// Advances the cohort like [`CohortState::advance`], drawing the events
+    /// from the `rng`
+    #[must_use]
+    pub fn advance_with_rng(
+        &self,
+        pressure: f64,
+        params: &EpidemicParams,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let recovery = 1.0 / f64::from(params.infectious_period.max(1));
+        let compartments = [self.susceptible, self.infectious, self.recovered];
+        let births = compartments.iter().fold(0_u32, |births, c| {
+            births.saturating_add(binomial(rng, *c, params.birth_rate))
+        });
+        let [susceptible, infectious, recovered] =
+            compartments.map(|c| c.saturating_sub(binomial(rng, c, params.death_rate)));
+        let new_infections = binomial(rng, susceptible, 1.0 - (-pressure).exp());
+        let deaths = binomial(rng, infectious, params.mortality_probability);
+        let survivors = infectious.saturating_sub(deaths);
+        let recoveries = binomial(rng, survivors, recovery);
+        Self {
+            susceptible: susceptible
+                .saturating_sub(new_infections)
+                .saturating_add(births),
+            infectious: survivors
+                .saturating_sub(recoveries)
+                .saturating_add(new_infections),
+            recovered: recovered.saturating_add(recoveries),
+        }
+    }
+}
+
+impl Add for CohortState {
+    type Output = Self;
+
+    /// Merges the individuals of both cohorts, saturating on overflow
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            susceptible: self.susceptible.saturating_add(rhs.susceptible),
+            infectious: self.infectious.saturating_add(rhs.infectious),
+            recovered: self.recovered.saturating_add(rhs.recovered),
+        }
+    }
+}
+
+/// Draws the amount of successes among `trials` with probability `p`, with no
+/// success if `p` is `NaN`
+fn binomial(rng: &mut impl Rng, trials: u32, p: f64) -> u32 {
+    Binomial::new(u64::from(trials), p.clamp(0.0, 1.0))
+        .map_or(0, |b| b.sample(rng))
+        .try_into()
+        .map_or(trials, |successes: u32| successes.min(trials))
+}
+
+impl CellState for CohortState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &EpidemicParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn color(&self) -> Option<Color> {
+        let population = self.population();
+        if population == 0 {
+            return None;
+        }
+        let share = |count: u32| (f64::from(count) / population as f64) as f32;
+        Some(Color::rgb(
+            share(self.infectious),
+            share(self.recovered),
+            share(self.susceptible) * 0.5,
+        ))
+    }
+}
+
+impl ParametricCellState for CohortState {
+    type Params = EpidemicParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let coupling = params.neighbor_coupling.clamp(0.0, 1.0);
+        let (pressure_infectious, pressure_population) = neighbor_cells.fold(
+            (f64::from(self.infectious), self.population() as f64),
+            |(i, n), c| {
+                (
+                    coupling.mul_add(f64::from(c.infectious), i),
+                    coupling.mul_add(c.population() as f64, n),
+                )
+            },
+        );
+        let pressure = if pressure_population > 0.0 {
+            params.transmission() * pressure_infectious / pressure_population
+        } else {
+            0.0
+        };
+        self.advance_with_rng(pressure, params, rng)
+    }
+}
+
+impl VaccinableCellState for CohortState {
+    fn is_infected(&self) -> bool {
+        self.infectious > 0
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        let vaccinated = binomial(&mut CellRng, self.susceptible, coverage);
+        Self {
+            susceptible: self.susceptible.saturating_sub(vaccinated),
+            recovered: self.recovered.saturating_add(vaccinated),
+            ..*self
+        }
+    }
+}
+
+impl InfectableCellState for CohortState {
+    fn import_infection(&self, cases: u32) -> Self {
+        let cases = cases.min(self.susceptible);
+        Self {
+            susceptible: self.susceptible - cases,
+            infectious: self.infectious.saturating_add(cases),
+            ..*self
+        }
+    }
+}
+
+impl CoarseCellState for CohortState {
+    /// Merges the individuals of every cohort
+    fn coarsen(states: &[&Self]) -> Self {
+        states
+            .iter()
+            .fold(Self::default(), |sum, state| sum + **state)
+    }
+
+    /// Spreads the individuals of every compartment evenly, the remainders
+    /// going to the first cohorts
+    fn refine(&self, cells: usize) -> Vec<Self> {
+        let cells_u32 = u32::try_from(cells).unwrap_or(u32::MAX).max(1);
+        let share = |count: u32, i: u32| count / cells_u32 + u32::from(i < count % cells_u32);
+        (0..cells)
+            .map(|i| {
+                let i = u32::try_from(i).unwrap_or(u32::MAX);
+                Self {
+                    susceptible: share(self.susceptible, i),
+                    infectious: share(self.infectious, i),
+                    recovered: share(self.recovered, i),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extreme_params() {
+        let cohort = CohortState::new(100, 10, 0);
+        let neighbors = [CohortState::new(u32::MAX, u32::MAX, u32::MAX)];
+        // Nothing happens
+        let params = EpidemicParams::new(0.0, 1, u32::MAX).with_neighbor_coupling(0.0);
+        assert_eq!(
+            cohort.new_cell_state_with_params(neighbors.iter(), &params),
+            cohort
+        );
+        // Every infectious individual recovers
+        let params = EpidemicParams::new(0.0, 1, 0);
+        assert_eq!(
+            cohort.new_cell_state_with_params(neighbors.iter(), &params),
+            CohortState::new(100, 0, 10)
+        );
+        // Invalid probabilities don't panic
+        for p in [f64::NAN, -1.0, 2.0, f64::INFINITY] {
+            let params = EpidemicParams::new(p, 1, 1).with_neighbor_coupling(p);
+            let state = cohort.new_cell_state_with_params(neighbors.iter(), &params);
+            assert_eq!(state.population(), cohort.population());
+        }
+        // Empty cohorts stay empty
+        let empty = CohortState::default();
+        assert_eq!(
+            empty.new_cell_state_with_params(neighbors.iter(), &EpidemicParams::default()),
+            empty
+        );
+    }
+
+    #[test]
+    fn coarse_graining() {
+        let cohorts = [CohortState::new(10, 3, 1), CohortState::new(5, 0, 2)];
+        let coarse = CohortState::coarsen(&[&cohorts[0], &cohorts[1]]);
+        assert_eq!(coarse, CohortState::new(15, 3, 3));
+        let fine = coarse.refine(4);
+        assert_eq!(
+            fine,
+            [
+                CohortState::new(4, 1, 1),
+                CohortState::new(4, 1, 1),
+                CohortState::new(4, 1, 1),
+                CohortState::new(3, 0, 0),
+            ]
+        );
+        assert_eq!(
+            CohortState::coarsen(&fine.iter().collect::<Vec<_>>()),
+            coarse
+        );
+    }
+
+    #[test]
+    fn population_conservation() {
+        let params = EpidemicParams::new(0.8, 1, 5);
+        let mut cohort = CohortState::new(1000, 5, 0);
+        for _ in 0..50 {
+            let next = cohort.new_cell_state_with_params(std::iter::empty(), &params);
+            assert_eq!(next.population(), 1005);
+            assert!(next.susceptible <= cohort.susceptible);
+            assert!(next.recovered >= cohort.recovered);
+            cohort = next;
+        }
+        assert!(cohort.recovered > 0);
+    }
+
+    #[test]
+    fn mortality() {
+        let params = EpidemicParams::new(0.0, 1, u32::MAX).with_mortality(1.0);
+        let cohort = CohortState::new(100, 10, 5);
+        assert_eq!(
+            cohort.new_cell_state_with_params(std::iter::empty(), &params),
+            CohortState::new(100, 0, 5)
+        );
+    }
+
+    #[test]
+    fn vital_dynamics() {
+        let params = EpidemicParams::new(0.0, 1, u32::MAX).with_vital_dynamics(1.0, 0.0);
+        let cohort = CohortState::new(100, 10, 5);
+        // Everyone gives birth
+        assert_eq!(
+            cohort.new_cell_state_with_params(std::iter::empty(), &params),
+            CohortState::new(215, 10, 5)
+        );
+        // Everyone dies, but newborns
+        let params = params.with_vital_dynamics(1.0, 1.0);
+        assert_eq!(
+            cohort.new_cell_state_with_params(std::iter::empty(), &params),
+            CohortState::new(115, 0, 0)
+        );
+        // Stable population on average
+        let params = EpidemicParams::new(0.5, 1, 10).with_vital_dynamics(0.01, 0.01);
+        let mut cohort = CohortState::new(100_000, 100, 0);
+        for _ in 0..100 {
+            cohort = cohort.new_cell_state_with_params(std::iter::empty(), &params);
+        }
+        assert!((90_000..110_000).contains(&cohort.population()));
+    }
+
+    #[test]
+    fn vaccination() {
+        let cohort = CohortState::new(100, 10, 5);
+        assert!(cohort.is_infected());
+        assert_eq!(cohort.vaccinate(1.0), CohortState::new(0, 10, 105));
+        assert_eq!(cohort.vaccinate(0.0), cohort);
+        let vaccinated = CohortState::new(100_000, 0, 0).vaccinate(0.3);
+        assert!(!vaccinated.is_infected());
+        assert!((28_000..32_000).contains(&vaccinated.recovered));
+        assert_eq!(vaccinated.population(), 100_000);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn coupled_cohorts() {
+        use crate::{Cohort2dPlugin, MooreCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Cohort2dPlugin::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, u32::MAX).with_neighbor_coupling(1.0));
+        let infected = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), CohortState::new(0, 500, 0)))
+            .id();
+        let healthy = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::X), CohortState::new(500, 0, 0)))
+            .id();
+        for _ in 0..5 {
+            app.update();
+        }
+        // The epidemic reached the neighbor population
+        let state = app.world.get::<CohortState>(healthy).unwrap();
+        assert!(state.infectious > 0);
+        assert_eq!(state.population(), 500);
+        assert_eq!(
+            app.world.get::<CohortState>(infected),
+            Some(&CohortState::new(0, 500, 0))
+        );
+    }
+}
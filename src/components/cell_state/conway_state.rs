@@ -17,7 +17,7 @@ use std::ops::{Deref, DerefMut};
 ///   if by reproduction.
 ///
 /// A dead cell is `false`, a live cell is `true`
-#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Component, Reflect)]
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
 pub struct ConwayCellState(pub bool);
 
 impl CellState for ConwayCellState {
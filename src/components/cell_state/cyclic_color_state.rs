@@ -26,7 +26,7 @@ const CYCLIC_COLORS: [Color; 9] = [
 /// > (Note that `0` is the successor of `n − 1`.
 ///
 /// For this type we use `9` for `n` and arbitrary colors.
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Component, Reflect, Default)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Component, Reflect, Default)]
 pub struct CyclicColorCellState(pub usize);
 
 impl CellState for CyclicColorCellState {
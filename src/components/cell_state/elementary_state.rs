@@ -0,0 +1,143 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::ops::{Deref, DerefMut};
+
+/// [Elementary cellular automaton] state, following the Wolfram rule number
+/// `RULE`.
+///
+/// The bit of `RULE` at index `4 * left + 2 * center + right` defines the new
+/// state of a cell from its left neighbor, itself and its right neighbor.
+///
+/// Meant to be used with a `Cell1d`, whose neighbors are the left and right
+/// cells. At the edges of a bounded row the single remaining neighbor is
+/// considered on both sides, use a `BoundaryCondition::Wrap` to avoid edge
+/// effects.
+///
+/// A dead cell is `false`, a live cell is `true`
+///
+/// [Elementary cellular automaton]: https://en.wikipedia.org/wiki/Elementary_cellular_automaton
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct ElementaryCellState<const RULE: u8>(pub bool);
+
+/// Elementary cellular automaton state following the chaotic *Rule 30*
+pub type Rule30CellState = ElementaryCellState<30>;
+
+/// Elementary cellular automaton state following *Rule 90*, drawing a
+/// Sierpiński triangle from a single live cell
+pub type Rule90CellState = ElementaryCellState<90>;
+
+/// Elementary cellular automaton state following the Turing complete
+/// *Rule 110*
+pub type Rule110CellState = ElementaryCellState<110>;
+
+impl<const RULE: u8> CellState for ElementaryCellState<RULE> {
+    fn new_cell_state<'a>(&self, mut neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let left = neighbor_cells.next().is_some_and(|c| c.0);
+        let right = neighbor_cells.next().map_or(left, |c| c.0);
+        let index = u8::from(left) << 2 | u8::from(self.0) << 1 | u8::from(right);
+        Self(RULE >> index & 1 == 1)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        if self.0 {
+            Some(Color::WHITE)
+        } else {
+            None
+        }
+    }
+}
+
+impl<const RULE: u8> Deref for ElementaryCellState<RULE> {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const RULE: u8> DerefMut for ElementaryCellState<RULE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<const RULE: u8> From<bool> for ElementaryCellState<RULE> {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next<const RULE: u8>(left: bool, center: bool, right: bool) -> bool {
+        let neighbors = [
+            ElementaryCellState::<RULE>(left),
+            ElementaryCellState(right),
+        ];
+        ElementaryCellState::<RULE>(center)
+            .new_cell_state(neighbors.iter())
+            .0
+    }
+
+    #[test]
+    fn rule_numbers() {
+        // Rule 30: 00011110
+        let rule_30: Vec<_> = (0..8_u8)
+            .rev()
+            .map(|i| next::<30>(i & 4 != 0, i & 2 != 0, i & 1 != 0))
+            .collect();
+        assert_eq!(
+            rule_30,
+            vec![false, false, false, true, true, true, true, false]
+        );
+        // Rule 90: left XOR right
+        assert!(next::<90>(true, true, false));
+        assert!(!next::<90>(true, false, true));
+        // Rule 110
+        assert!(!next::<110>(true, true, true));
+        assert!(next::<110>(false, true, true));
+        assert!(!next::<0>(true, true, true));
+        assert!(next::<255>(false, false, false));
+    }
+
+    #[cfg(feature = "1D")]
+    #[test]
+    fn sierpinski_triangle() {
+        use crate::{BoundaryCondition, Cell1d, CellularAutomatonPlugin, MapSize};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(CellularAutomatonPlugin::<Cell1d, Rule90CellState>::new())
+            .insert_resource(BoundaryCondition::Wrap)
+            .insert_resource(MapSize::new(9, 1));
+        let row: Vec<_> = (0..9)
+            .map(|x| {
+                app.world
+                    .spawn((Cell1d::new(x), Rule90CellState::from(x == 4)))
+                    .id()
+            })
+            .collect();
+        let pattern = |app: &App| -> String {
+            row.iter()
+                .map(|e| {
+                    if app.world.get::<Rule90CellState>(*e).unwrap().0 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        };
+        app.update();
+        assert_eq!(pattern(&app), "...#.#...");
+        app.update();
+        assert_eq!(pattern(&app), "..#...#..");
+        app.update();
+        assert_eq!(pattern(&app), ".#.#.#.#.");
+    }
+}
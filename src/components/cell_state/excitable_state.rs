@@ -0,0 +1,133 @@
+use crate::{
+    components::{CellState, ParametricCellState},
+    resources::ExcitableParams,
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// [Greenberg–Hastings] excitable media state and rules. The rules are the
+/// following:
+///
+/// - A resting cell becomes excited if at least `threshold` neighbors are
+///   excited.
+/// - An excited cell becomes refractory.
+/// - A refractory cell stays refractory for `refractory_length` generations,
+///   then rests again.
+///
+/// Like the cyclic colors automaton, excitation waves travel through resting
+/// cells and form spirals around refractory ones, which makes it a simple
+/// model of cardiac tissue.
+///
+/// The `threshold` and `refractory_length` are read from the
+/// [`ExcitableParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [Greenberg–Hastings]: https://en.wikipedia.org/wiki/Excitable_medium
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum ExcitableState {
+    /// Resting cell, which may be excited
+    #[default]
+    Resting,
+    /// Excited cell
+    Excited,
+    /// Refractory cell with the amount of generations spent since its
+    /// excitation
+    Refractory(u8),
+}
+
+impl CellState for ExcitableState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &ExcitableParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Resting => None,
+            Self::Excited => Some(Color::YELLOW),
+            Self::Refractory(step) => {
+                // Fades from red to black through the refractory period
+                let fade = 1.0 / f32::from(*step);
+                Some(Color::rgb(0.9 * fade, 0.2 * fade, 0.1 * fade))
+            }
+        }
+    }
+}
+
+impl ParametricCellState for ExcitableState {
+    type Params = ExcitableParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        let refractory = |step: u8| {
+            if step > params.refractory_length {
+                Self::Resting
+            } else {
+                Self::Refractory(step)
+            }
+        };
+        match self {
+            Self::Resting => {
+                let excited = neighbor_cells.filter(|c| **c == Self::Excited).count();
+                if excited >= params.threshold {
+                    Self::Excited
+                } else {
+                    Self::Resting
+                }
+            }
+            Self::Excited => refractory(1),
+            Self::Refractory(step) => refractory(step.saturating_add(1)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excitation() {
+        let params = ExcitableParams::new(2, 2);
+        let neighbors = [
+            ExcitableState::Excited,
+            ExcitableState::Excited,
+            ExcitableState::Refractory(1),
+        ];
+        assert_eq!(
+            ExcitableState::Resting.new_cell_state_with_params(neighbors.iter(), &params),
+            ExcitableState::Excited
+        );
+        assert_eq!(
+            ExcitableState::Resting.new_cell_state_with_params(neighbors[1..].iter(), &params),
+            ExcitableState::Resting
+        );
+        // Excited and refractory cells ignore their neighbors
+        assert_eq!(
+            ExcitableState::Excited.new_cell_state_with_params(neighbors.iter(), &params),
+            ExcitableState::Refractory(1)
+        );
+        assert_eq!(
+            ExcitableState::Refractory(1).new_cell_state_with_params(neighbors.iter(), &params),
+            ExcitableState::Refractory(2)
+        );
+        assert_eq!(
+            ExcitableState::Refractory(2).new_cell_state_with_params(neighbors.iter(), &params),
+            ExcitableState::Resting
+        );
+    }
+
+    #[test]
+    fn no_refractory_period() {
+        let params = ExcitableParams::new(1, 0);
+        assert_eq!(
+            ExcitableState::Excited.new_cell_state_with_params(std::iter::empty(), &params),
+            ExcitableState::Resting
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    components::{CellMove, CellState, MovingCellState},
+    resources::CellRng,
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::{seq::IteratorRandom, Rng};
+
+/// Falling sand state and rules. The rules are the following:
+///
+/// - Sand falls down, or diagonally down if the cell below is blocked, and
+///   sinks through water.
+/// - Water falls like sand, or flows left or right if it can't fall.
+/// - Walls never move.
+///
+/// The rules rely on the neighbor positions of a radius 1 `MooreCell2d`.
+/// Cells move by claiming a neighbor cell, so this state must be used with the
+/// [`MovingCellularAutomatonPlugin`], preferably in sweep mode so whole
+/// columns fall in a single tick.
+///
+/// [`MovingCellularAutomatonPlugin`]: crate::MovingCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum FallingSandState {
+    /// Empty cell
+    #[default]
+    Empty,
+    /// Sand grain
+    Sand,
+    /// Water drop
+    Water,
+    /// Static wall
+    Wall,
+}
+
+impl FallingSandState {
+    /// Index of the left neighbor of a `MooreCell2d`
+    const LEFT: usize = 0;
+    /// Index of the right neighbor of a `MooreCell2d`
+    const RIGHT: usize = 4;
+    /// Index of the bottom right neighbor of a `MooreCell2d`
+    const BOTTOM_RIGHT: usize = 5;
+    /// Index of the bottom neighbor of a `MooreCell2d`
+    const BOTTOM: usize = 6;
+    /// Index of the bottom left neighbor of a `MooreCell2d`
+    const BOTTOM_LEFT: usize = 7;
+
+    /// Can the cell move into a cell of the `other` state
+    const fn displaces(self, other: Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::Sand | Self::Water, Self::Empty) | (Self::Sand, Self::Water)
+        )
+    }
+}
+
+impl CellState for FallingSandState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        *self
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            Self::Sand => Some(Color::rgb(0.9, 0.8, 0.5)),
+            Self::Water => Some(Color::rgb(0.2, 0.4, 0.9)),
+            Self::Wall => Some(Color::GRAY),
+        }
+    }
+}
+
+impl MovingCellState for FallingSandState {
+    fn move_cell(&self, neighbor_cells: &[Option<&Self>]) -> CellMove<Self> {
+        self.move_cell_rng(neighbor_cells, &mut CellRng)
+    }
+
+    fn move_cell_rng(
+        &self,
+        neighbor_cells: &[Option<&Self>],
+        rng: &mut impl Rng,
+    ) -> CellMove<Self> {
+        let directions: &[&[usize]] = match self {
+            Self::Sand => &[&[Self::BOTTOM], &[Self::BOTTOM_LEFT, Self::BOTTOM_RIGHT]],
+            Self::Water => &[
+                &[Self::BOTTOM],
+                &[Self::BOTTOM_LEFT, Self::BOTTOM_RIGHT],
+                &[Self::LEFT, Self::RIGHT],
+            ],
+            Self::Empty | Self::Wall => return CellMove::stay(*self),
+        };
+        for indices in directions {
+            let target = indices
+                .iter()
+                .filter_map(|i| {
+                    let neighbor = (*neighbor_cells.get(*i)?)?;
+                    self.displaces(*neighbor).then_some((*i, *neighbor))
+                })
+                .choose(rng);
+            if let Some((index, neighbor)) = target {
+                return CellMove::claim(neighbor, index, *self);
+            }
+        }
+        CellMove::stay(*self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use FallingSandState::{Empty, Sand, Wall, Water};
+
+    #[test]
+    fn sand_rules() {
+        // Falling
+        let neighbors = [
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(&Empty),
+            Some(&Empty),
+            None,
+        ];
+        assert_eq!(Sand.move_cell(&neighbors), CellMove::claim(Empty, 6, Sand));
+        // Sinking
+        let neighbors = [None, None, None, None, None, None, Some(&Water), None];
+        assert_eq!(Sand.move_cell(&neighbors), CellMove::claim(Water, 6, Sand));
+        // Sliding
+        let neighbors = [
+            Some(&Empty),
+            None,
+            None,
+            None,
+            None,
+            Some(&Sand),
+            Some(&Wall),
+            Some(&Empty),
+        ];
+        assert_eq!(Sand.move_cell(&neighbors), CellMove::claim(Empty, 7, Sand));
+        // Piling
+        let neighbors = [
+            Some(&Empty),
+            None,
+            None,
+            None,
+            Some(&Empty),
+            Some(&Sand),
+            Some(&Wall),
+            None,
+        ];
+        assert_eq!(Sand.move_cell(&neighbors), CellMove::stay(Sand));
+    }
+
+    #[test]
+    fn water_rules() {
+        // Flowing
+        let neighbors = [
+            Some(&Sand),
+            None,
+            None,
+            None,
+            Some(&Empty),
+            Some(&Water),
+            Some(&Wall),
+            None,
+        ];
+        assert_eq!(
+            Water.move_cell(&neighbors),
+            CellMove::claim(Empty, 4, Water)
+        );
+        // Water doesn't sink through sand or water
+        let neighbors = [
+            Some(&Water),
+            None,
+            None,
+            None,
+            None,
+            Some(&Sand),
+            Some(&Water),
+            None,
+        ];
+        assert_eq!(Water.move_cell(&neighbors), CellMove::stay(Water));
+        assert_eq!(Wall.move_cell(&[Some(&Empty); 8]), CellMove::stay(Wall));
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn sweep() {
+        use crate::{FallingSand2dPlugin, MooreCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(FallingSand2dPlugin::new().with_sweep());
+        let column: Vec<_> = [Empty, Empty, Sand, Sand]
+            .into_iter()
+            .zip(0..)
+            .map(|(state, y)| {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(0, y)), state))
+                    .id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<FallingSandState> {
+            column
+                .iter()
+                .map(|e| *app.world.get::<FallingSandState>(*e).unwrap())
+                .collect()
+        };
+        // The whole column falls at once
+        app.update();
+        assert_eq!(states(&app), vec![Empty, Sand, Sand, Empty]);
+        app.update();
+        assert_eq!(states(&app), vec![Sand, Sand, Empty, Empty]);
+        app.update();
+        assert_eq!(states(&app), vec![Sand, Sand, Empty, Empty]);
+    }
+}
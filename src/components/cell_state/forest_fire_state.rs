@@ -0,0 +1,174 @@
+use crate::{
+    components::{CellState, ParametricCellState},
+    resources::{CellRng, ForestFireParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// [Forest-fire model] cellular automaton state and rules. The rules are the
+/// following:
+///
+/// - A burning cell turns into an empty cell.
+/// - A tree will burn if at least one neighbor is burning.
+/// - A tree ignites with probability `f` even if no neighbor is burning.
+/// - An empty space fills with a tree with probability `p`.
+///
+/// The `p` and `f` probabilities are read from the [`ForestFireParams`]
+/// resource through the [`ParametricCellularAutomatonPlugin`]. With a regular
+/// plugin, the default parameters are used.
+///
+/// [Forest-fire model]: https://en.wikipedia.org/wiki/Forest-fire_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum ForestFireState {
+    /// Empty cell state
+    #[default]
+    Empty,
+    /// Tree cell state
+    Tree,
+    /// Burning tree cell state
+    Burning,
+}
+
+impl CellState for ForestFireState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &ForestFireParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            Self::Tree => Some(Color::DARK_GREEN),
+            Self::Burning => Some(Color::ORANGE_RED),
+        }
+    }
+}
+
+impl ParametricCellState for ForestFireState {
+    type Params = ForestFireParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        mut neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        match self {
+            Self::Burning => Self::Empty,
+            Self::Tree => {
+                if neighbor_cells.any(|c| *c == Self::Burning)
+                    || rng.gen_bool(params.lightning_probability)
+                {
+                    Self::Burning
+                } else {
+                    Self::Tree
+                }
+            }
+            Self::Empty => {
+                if rng.gen_bool(params.growth_probability) {
+                    Self::Tree
+                } else {
+                    Self::Empty
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fire_rules() {
+        let params = ForestFireParams::new(0.0, 0.0);
+        let neighbors = [ForestFireState::Tree, ForestFireState::Burning];
+        let state = ForestFireState::Tree;
+        assert_eq!(
+            state.new_cell_state_with_params(neighbors.iter(), &params),
+            ForestFireState::Burning
+        );
+        assert_eq!(
+            state.new_cell_state_with_params(neighbors[..1].iter(), &params),
+            ForestFireState::Tree
+        );
+        let state = ForestFireState::Burning;
+        assert_eq!(
+            state.new_cell_state_with_params(neighbors.iter(), &params),
+            ForestFireState::Empty
+        );
+    }
+
+    #[test]
+    fn probabilities() {
+        let params = ForestFireParams::new(1.0, 1.0);
+        let neighbors = [ForestFireState::Tree];
+        assert_eq!(
+            ForestFireState::Empty.new_cell_state_with_params(neighbors.iter(), &params),
+            ForestFireState::Tree
+        );
+        assert_eq!(
+            ForestFireState::Tree.new_cell_state_with_params(neighbors.iter(), &params),
+            ForestFireState::Burning
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn tweaked_params() {
+        use crate::{ForestFire2dPlugin, NeumannCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(ForestFire2dPlugin::new())
+            .insert_resource(ForestFireParams::new(0.0, 0.0));
+        let cells: Vec<_> = (0..5)
+            .map(|x| {
+                let state = if x == 0 {
+                    ForestFireState::Burning
+                } else {
+                    ForestFireState::Tree
+                };
+                app.world
+                    .spawn((NeumannCell2d::new(IVec2::new(x, 0)), state))
+                    .id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<ForestFireState> {
+            cells
+                .iter()
+                .map(|e| *app.world.get::<ForestFireState>(*e).unwrap())
+                .collect()
+        };
+        app.update();
+        app.update();
+        // The fire spreads along the tree line
+        assert_eq!(
+            states(&app),
+            vec![
+                ForestFireState::Empty,
+                ForestFireState::Empty,
+                ForestFireState::Burning,
+                ForestFireState::Tree,
+                ForestFireState::Tree
+            ]
+        );
+        // Trees always grow back
+        app.world
+            .resource_mut::<ForestFireParams>()
+            .growth_probability = 1.0;
+        app.update();
+        assert_eq!(states(&app)[..2], [ForestFireState::Tree; 2]);
+    }
+}
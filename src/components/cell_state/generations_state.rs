@@ -0,0 +1,182 @@
+use crate::components::{CellState, LifeLikeRule};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// [Brian's Brain] state: `B2/S/C3`
+///
+/// [Brian's Brain]: https://conwaylife.com/wiki/OCA:Brian%27s_Brain
+pub type BriansBrainCellState = GenerationsCellState<{ LifeLikeRule::new(&[2], &[]).birth }, 0, 3>;
+
+/// [Star Wars] state: `B2/S345/C4`
+///
+/// [Star Wars]: https://conwaylife.com/wiki/OCA:Star_Wars
+pub type StarWarsCellState = GenerationsCellState<
+    { LifeLikeRule::new(&[2], &[]).birth },
+    { LifeLikeRule::new(&[], &[3, 4, 5]).survival },
+    4,
+>;
+
+/// [Generations] cellular automaton state and rules.
+///
+/// Generations rules extend [Life-like] rules with decay states: a live cell
+/// which doesn't survive doesn't die immediately but decays through
+/// `STATES - 2` dying states before being dead. Dying cells are not counted as
+/// live neighbors and can't be born again until they are dead.
+///
+/// The state value is:
+/// * `0` for a dead cell
+/// * `1` for a live cell
+/// * `2..STATES` for the decay states
+///
+/// The `BIRTH` and `SURVIVAL` live neighbor counts are bitmasks, like the
+/// [`LifeLikeRule`] fields, so any rule may be defined:
+///
+/// ```rust,no_run
+/// # use bevy_life::{GenerationsCellState, LifeLikeRule};
+/// // B3/S23/C8
+/// type DecayingLife = GenerationsCellState<
+///     { LifeLikeRule::CONWAY.birth },
+///     { LifeLikeRule::CONWAY.survival },
+///     8,
+/// >;
+/// ```
+///
+/// [Generations]: https://conwaylife.com/wiki/Generations
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Component, Reflect)]
+pub struct GenerationsCellState<const BIRTH: u32, const SURVIVAL: u32, const STATES: u8>(pub u8);
+
+impl<const BIRTH: u32, const SURVIVAL: u32, const STATES: u8>
+    GenerationsCellState<BIRTH, SURVIVAL, STATES>
+{
+    /// Dead cell state
+    pub const DEAD: Self = Self(0);
+    /// Live cell state
+    pub const ALIVE: Self = Self(1);
+
+    /// Instantiates a new state with the `state` value
+    #[must_use]
+    #[inline]
+    pub const fn new(state: u8) -> Self {
+        Self(state)
+    }
+
+    /// The birth and survival rule of the state
+    #[must_use]
+    #[inline]
+    pub const fn rule() -> LifeLikeRule {
+        LifeLikeRule {
+            birth: BIRTH,
+            survival: SURVIVAL,
+        }
+    }
+
+    /// Is the cell alive
+    #[must_use]
+    #[inline]
+    pub const fn is_alive(self) -> bool {
+        self.0 == 1
+    }
+
+    /// Is the cell in a decay state
+    #[must_use]
+    #[inline]
+    pub const fn is_dying(self) -> bool {
+        self.0 > 1
+    }
+}
+
+impl<const BIRTH: u32, const SURVIVAL: u32, const STATES: u8> CellState
+    for GenerationsCellState<BIRTH, SURVIVAL, STATES>
+{
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let rule = Self::rule();
+        match self.0 {
+            0 => {
+                let alive_cells_count = neighbor_cells.filter(|c| c.is_alive()).count();
+                if rule.is_born(alive_cells_count) {
+                    Self::ALIVE
+                } else {
+                    Self::DEAD
+                }
+            }
+            1 => {
+                let alive_cells_count = neighbor_cells.filter(|c| c.is_alive()).count();
+                if rule.survives(alive_cells_count) {
+                    Self::ALIVE
+                } else {
+                    Self(2 % STATES.max(2))
+                }
+            }
+            state => Self((state + 1) % STATES),
+        }
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self.0 {
+            0 => None,
+            1 => Some(Color::WHITE),
+            state => {
+                // Fades from blue to black through the decay states
+                let fade = 1.0 - f32::from(state - 2) / f32::from(STATES - 2);
+                Some(Color::rgb(0.2 * fade, 0.4 * fade, fade))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn brians_brain() {
+        type State = BriansBrainCellState;
+        let neighbors = [State::ALIVE, State::ALIVE, State::ALIVE, State::new(2)];
+        // Birth with exactly 2 live neighbors, dying cells don't count
+        assert_eq!(
+            State::DEAD.new_cell_state(neighbors[..2].iter()),
+            State::ALIVE
+        );
+        assert_eq!(
+            State::DEAD.new_cell_state(neighbors[1..].iter()),
+            State::ALIVE
+        );
+        assert_eq!(State::DEAD.new_cell_state(neighbors.iter()), State::DEAD);
+        // Live cells never survive, dying cells die
+        assert_eq!(
+            State::ALIVE.new_cell_state(neighbors[..2].iter()),
+            State::new(2)
+        );
+        assert_eq!(
+            State::new(2).new_cell_state(neighbors[..2].iter()),
+            State::DEAD
+        );
+    }
+
+    #[test]
+    fn star_wars() {
+        type State = StarWarsCellState;
+        let neighbors = [State::ALIVE; 5];
+        assert_eq!(
+            State::ALIVE.new_cell_state(neighbors[..3].iter()),
+            State::ALIVE
+        );
+        assert_eq!(
+            State::ALIVE.new_cell_state(neighbors[..2].iter()),
+            State::new(2)
+        );
+        // Decay lasts `STATES - 2` generations and ignores neighbors
+        assert_eq!(
+            State::new(2).new_cell_state(neighbors[..2].iter()),
+            State::new(3)
+        );
+        assert_eq!(
+            State::new(3).new_cell_state(neighbors[..2].iter()),
+            State::DEAD
+        );
+        assert_eq!(State::rule().to_string(), "B2/S345");
+    }
+}
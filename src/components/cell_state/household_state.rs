@@ -0,0 +1,183 @@
+use crate::{
+    components::{
+        CellState, CohortState, InfectableCellState, ParametricCellState, VaccinableCellState,
+    },
+    resources::{CellRng, HouseholdParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// Population of `N` households, each household being a [`CohortState`].
+///
+/// Every generation, the infection pressure on a household adds up:
+///
+/// - the `household_transmission` of every infectious individual of the
+///   household
+/// - the community pressure `transmission_probability * I / N` of the
+///   [`EpidemicParams`], with `I` and `N` the infectious individuals and the
+///   population of every household of the cell and of its neighbors, weighted
+///   by `neighbor_coupling`
+///
+/// The other events follow the rules of the [`CohortState`]. The parameters
+/// are read from the [`HouseholdParams`] through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [`EpidemicParams`]: crate::EpidemicParams
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct HouseholdState<const N: usize> {
+    /// Population cohort of every household
+    pub households: [CohortState; N],
+}
+
+impl<const N: usize> Default for HouseholdState<N> {
+    fn default() -> Self {
+        Self::new([CohortState::default(); N])
+    }
+}
+
+impl<const N: usize> HouseholdState<N> {
+    /// Instantiates a new state with the cohort of every household
+    #[must_use]
+    #[inline]
+    pub const fn new(households: [CohortState; N]) -> Self {
+        Self { households }
+    }
+
+    /// Total cohort of the cell, summing every household
+    #[must_use]
+    pub fn total(&self) -> CohortState {
+        self.households
+            .iter()
+            .fold(CohortState::default(), |t, h| t + *h)
+    }
+}
+
+impl<const N: usize> CellState for HouseholdState<N> {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &HouseholdParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        self.total().color()
+    }
+}
+
+impl<const N: usize> ParametricCellState for HouseholdState<N> {
+    type Params = HouseholdParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let epidemic = &params.epidemic;
+        let coupling = epidemic.neighbor_coupling.clamp(0.0, 1.0);
+        let total = self.total();
+        let (infectious, population) = neighbor_cells.map(Self::total).fold(
+            (f64::from(total.infectious), total.population() as f64),
+            |(i, n), c| {
+                (
+                    coupling.mul_add(f64::from(c.infectious), i),
+                    coupling.mul_add(c.population() as f64, n),
+                )
+            },
+        );
+        let community = if population > 0.0 {
+            epidemic.transmission() * infectious / population
+        } else {
+            0.0
+        };
+        let household_transmission = params.household_transmission.max(0.0);
+        Self {
+            households: self.households.map(|h| {
+                let pressure = household_transmission.mul_add(f64::from(h.infectious), community);
+                h.advance_with_rng(pressure, epidemic, rng)
+            }),
+        }
+    }
+}
+
+impl<const N: usize> VaccinableCellState for HouseholdState<N> {
+    fn is_infected(&self) -> bool {
+        self.households.iter().any(VaccinableCellState::is_infected)
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        Self {
+            households: self.households.map(|h| h.vaccinate(coverage)),
+        }
+    }
+}
+
+impl<const N: usize> InfectableCellState for HouseholdState<N> {
+    /// Every case infects a random susceptible individual
+    fn import_infection(&self, cases: u32) -> Self {
+        let mut rng = CellRng;
+        let mut state = *self;
+        for _ in 0..cases {
+            let total = state.total().susceptible;
+            if total == 0 {
+                break;
+            }
+            let mut draw = rng.gen_range(0..total);
+            for cohort in &mut state.households {
+                if draw < cohort.susceptible {
+                    *cohort = cohort.import_infection(1);
+                    break;
+                }
+                draw -= cohort.susceptible;
+            }
+        }
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EpidemicParams;
+
+    #[test]
+    fn household_transmission() {
+        // The disease only spreads within households
+        let params = HouseholdParams::new(EpidemicParams::new(0.0, 1, u32::MAX), 50.0);
+        let state = HouseholdState::new([CohortState::new(4, 1, 0), CohortState::new(5, 0, 0)]);
+        let neighbors = [HouseholdState::new([CohortState::new(0, 100, 0); 2])];
+        let state = state.new_cell_state_with_params(neighbors.iter(), &params);
+        assert_eq!(
+            state.households,
+            [CohortState::new(0, 5, 0), CohortState::new(5, 0, 0)]
+        );
+        assert_eq!(state.total(), CohortState::new(5, 5, 0));
+    }
+
+    #[test]
+    fn community_transmission() {
+        // Households infect each other through the community
+        let params = HouseholdParams::new(
+            EpidemicParams::new(1.0, 1, u32::MAX).with_neighbor_coupling(0.0),
+            0.0,
+        );
+        let state =
+            HouseholdState::new([CohortState::new(0, 1000, 0), CohortState::new(1000, 0, 0)]);
+        let state = state.new_cell_state_with_params(std::iter::empty(), &params);
+        assert!(state.households[1].infectious > 0);
+        assert!(state.is_infected());
+        assert_eq!(state.vaccinate(1.0).households[1].susceptible, 0);
+    }
+}
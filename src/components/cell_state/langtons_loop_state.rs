@@ -0,0 +1,214 @@
+use crate::{
+    components::CellState,
+    resources::{RuleTable, TableSymmetry},
+};
+use bevy::math::IVec2;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::sync::OnceLock;
+
+/// Langton's transitions in the `CNESWC'` format: the cell, its north, east,
+/// south and west neighbors and the new cell state
+const LANGTONS_LOOPS_TABLE: &str = "
+    000000 000012 000020 000030 000050 000063 000071 000112 000122 000132
+    000212 000220 000230 000262 000272 000320 000525 000622 000722 001022
+    001120 002020 002030 002050 002125 002220 002322 005222 012321 012421
+    012525 012621 012721 012751 014221 014321 014421 014721 016251 017221
+    017255 017521 017621 017721 025271 100011 100061 100077 100111 100121
+    100211 100244 100277 100511 101011 101111 101244 101277 102026 102121
+    102211 102244 102263 102277 102327 102424 102626 102644 102677 102710
+    102727 105427 111121 111221 111244 111251 111261 111277 111522 112121
+    112221 112244 112251 112277 112321 112424 112621 112727 113221 122244
+    122277 122434 122547 123244 123277 124255 124267 125275 200012 200022
+    200042 200071 200122 200152 200212 200222 200232 200242 200250 200262
+    200272 200326 200423 200517 200522 200575 200722 201022 201122 201222
+    201422 201722 202022 202032 202052 202073 202122 202152 202212 202222
+    202272 202321 202422 202452 202520 202552 202622 202722 203122 203216
+    203226 203422 204222 205122 205212 205222 205521 205725 206222 206722
+    207122 207222 207422 207722 211222 211261 212222 212242 212262 212272
+    214222 215222 216222 217222 222272 222442 222462 222762 222772 300013
+    300022 300041 300076 300123 300421 300622 301021 301220 302511 401120
+    401220 401250 402120 402221 402326 402520 403221 500022 500215 500225
+    500232 500272 500520 502022 502122 502152 502220 502244 502722 512122
+    512220 512422 512722 600011 600021 602120 612125 612131 612225 700077
+    701120 701220 701250 702120 702221 702251 702321 702525 702720
+";
+
+#[cfg(feature = "auto-coloring")]
+const LANGTONS_LOOP_COLORS: [Color; 7] = [
+    Color::BLUE,
+    Color::RED,
+    Color::GREEN,
+    Color::YELLOW,
+    Color::FUCHSIA,
+    Color::WHITE,
+    Color::CYAN,
+];
+
+/// [Langton's loops] cellular automaton state: one of 8 states following a
+/// transition table on a von Neumann neighborhood.
+///
+/// The canonical [`Self::SEED`] is a loop of sheath (`2`) cells carrying
+/// genetic signals around a core (`1`) which extends into a construction arm,
+/// building a daughter loop every 151 generations.
+///
+/// The transitions are the original table of Langton's 1984 paper with
+/// rotational symmetry, undefined transitions keeping the cell state. The
+/// state is meant to be used with `NeumannCell2d`, through the
+/// `LangtonsLoops2dPlugin`. Neighbors missing at the edge of the map are
+/// considered in state `0`, but any edge neighbor shifts the other ones so
+/// the loops should grow away from the map borders.
+///
+/// [Langton's loops]: https://en.wikipedia.org/wiki/Langton%27s_loops
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct LangtonsLoopCellState(pub u8);
+
+impl LangtonsLoopCellState {
+    /// Canonical Langton's loop, top row first, with spaces as `0` cells
+    pub const SEED: &'static str = concat!(
+        " 22222222\n",
+        "2170140142\n",
+        "2022222202\n",
+        "272    212\n",
+        "212    212\n",
+        "202    212\n",
+        "272    212\n",
+        "21222222122222\n",
+        "207107107111112\n",
+        " 2222222222222",
+    );
+
+    /// Langton's loops transition table, on clockwise ordered neighbors
+    #[must_use]
+    pub fn rule_table() -> &'static RuleTable {
+        static TABLE: OnceLock<RuleTable> = OnceLock::new();
+        TABLE.get_or_init(parse_table)
+    }
+
+    /// Loads the non `0` cells of a `pattern` like [`Self::SEED`], made of
+    /// rows of digits or spaces, top row first. The bottom left cell is at
+    /// the origin and `y` goes up.
+    ///
+    /// Returns `None` if the pattern has another character.
+    #[must_use]
+    pub fn load_pattern(pattern: &str) -> Option<Vec<(IVec2, Self)>> {
+        let rows: Vec<_> = pattern.lines().collect();
+        rows.iter()
+            .rev()
+            .zip(0..)
+            .flat_map(|(row, y)| {
+                row.chars().zip(0..).filter_map(move |(c, x)| match c {
+                    ' ' | '0' => None,
+                    c => Some(
+                        c.to_digit(8)
+                            .and_then(|d| u8::try_from(d).ok())
+                            .map(|d| (IVec2::new(x, y), Self(d))),
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    /// Loads the canonical [`Self::SEED`] loop
+    #[must_use]
+    pub fn seed() -> Vec<(IVec2, Self)> {
+        Self::load_pattern(Self::SEED).unwrap_or_default()
+    }
+}
+
+fn parse_table() -> RuleTable {
+    RuleTable::from_digits(4, TableSymmetry::Rotate(4), LANGTONS_LOOPS_TABLE)
+        .expect("invalid Langton's loops table")
+}
+
+impl CellState for LangtonsLoopCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let neighbors = neighbor_cells
+            .map(|c| c.0)
+            .chain(std::iter::repeat(0))
+            .take(4);
+        Self::rule_table()
+            .transition(self.0, neighbors)
+            .map_or(*self, Self)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self.0 {
+            0 => None,
+            s => LANGTONS_LOOP_COLORS.get(usize::from(s) - 1).copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transitions() {
+        assert_eq!(LangtonsLoopCellState::rule_table().len(), 857);
+        let state = LangtonsLoopCellState(0);
+        let neighbors = [0, 0, 1, 0].map(LangtonsLoopCellState);
+        assert_eq!(
+            state.new_cell_state(neighbors.iter()),
+            LangtonsLoopCellState(2)
+        );
+        // Undefined transition
+        let neighbors = [7, 7, 7, 7].map(LangtonsLoopCellState);
+        assert_eq!(state.new_cell_state(neighbors.iter()), state);
+    }
+
+    #[test]
+    fn seed() {
+        let seed = LangtonsLoopCellState::seed();
+        assert_eq!(seed.len(), 86);
+        assert!(seed.contains(&(IVec2::new(1, 9), LangtonsLoopCellState(2))));
+        assert!(seed.contains(&(IVec2::new(2, 1), LangtonsLoopCellState(7))));
+        assert_eq!(LangtonsLoopCellState::load_pattern("19"), None);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn replication() {
+        use crate::{LangtonsLoops2dPlugin, NeumannCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(LangtonsLoops2dPlugin::new());
+        let seed = LangtonsLoopCellState::seed();
+        let cells: Vec<_> = (-2..30)
+            .flat_map(|x| (-2..18).map(move |y| IVec2::new(x, y)))
+            .map(|coords| {
+                let state = seed
+                    .iter()
+                    .find(|(c, _)| *c == coords)
+                    .map_or_else(LangtonsLoopCellState::default, |(_, s)| *s);
+                let entity = app.world.spawn((NeumannCell2d::new(coords), state)).id();
+                (coords, entity)
+            })
+            .collect();
+        for _ in 0..151 {
+            app.update();
+        }
+        // The daughter loop is a copy of the initial loop
+        let state = |coords: IVec2| {
+            cells
+                .iter()
+                .find(|(c, _)| *c == coords)
+                .and_then(|(_, e)| app.world.get::<LangtonsLoopCellState>(*e))
+                .copied()
+                .unwrap()
+        };
+        for x in 0..10 {
+            for y in 0..10 {
+                let expected = seed
+                    .iter()
+                    .find(|(c, _)| *c == IVec2::new(x, y))
+                    .map_or_else(LangtonsLoopCellState::default, |(_, s)| *s);
+                assert_eq!(state(IVec2::new(x + 11, y)), expected, "({x}, {y})");
+            }
+        }
+    }
+}
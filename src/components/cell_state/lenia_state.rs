@@ -0,0 +1,143 @@
+use crate::components::{CellState, WeightedCellState};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// [Lenia] growth mapping: a Gaussian bump turning the convolution `potential`
+/// of a cell into a growth rate in `[-1, 1]`.
+///
+/// The default parameters are the ones of the *Orbium* glider, to use with a
+/// `Kernel2d::lenia(13)` kernel.
+///
+/// [Lenia]: https://en.wikipedia.org/wiki/Lenia
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct LeniaGrowth {
+    /// Potential of maximal growth
+    pub mu: f32,
+    /// Width of the growth bump
+    pub sigma: f32,
+    /// Integration time step, the fraction of the growth applied every tick
+    pub time_step: f32,
+}
+
+impl Default for LeniaGrowth {
+    fn default() -> Self {
+        Self::new(0.15, 0.015, 0.1)
+    }
+}
+
+impl LeniaGrowth {
+    /// Instantiates a new growth mapping
+    #[must_use]
+    #[inline]
+    pub const fn new(mu: f32, sigma: f32, time_step: f32) -> Self {
+        Self {
+            mu,
+            sigma,
+            time_step,
+        }
+    }
+
+    /// Growth rate for a `potential`, in `[-1, 1]`
+    #[must_use]
+    #[inline]
+    pub fn growth(&self, potential: f32) -> f32 {
+        let distance = (potential - self.mu) / self.sigma;
+        2.0f32.mul_add((-distance * distance / 2.0).exp(), -1.0)
+    }
+}
+
+/// [Lenia] continuous cellular automaton state.
+///
+/// Every cell has a `value` in `[0, 1]`. Each tick, the cell *potential* is
+/// the convolution of its neighbor values by its kernel, and the value grows
+/// by `time_step * growth(potential)`.
+///
+/// The kernel being the cell neighborhood, this state is meant to be used with
+/// a `KernelCell2d` and a `Kernel2d::lenia` kernel in the
+/// [`WeightedCellularAutomatonPlugin`]. With a regular plugin, every neighbor
+/// has the same weight.
+///
+/// [Lenia]: https://en.wikipedia.org/wiki/Lenia
+/// [`WeightedCellularAutomatonPlugin`]: crate::WeightedCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, PartialEq, Component, Reflect)]
+pub struct LeniaState {
+    /// The cell value, in `[0, 1]`
+    pub value: f32,
+    /// The growth mapping followed by the cell
+    pub growth: LeniaGrowth,
+}
+
+impl LeniaState {
+    /// Instantiates a new state with `value` following `growth`
+    #[must_use]
+    #[inline]
+    pub const fn new(value: f32, growth: LeniaGrowth) -> Self {
+        Self { value, growth }
+    }
+
+    #[inline]
+    fn grow(self, potential: f32) -> Self {
+        let value = self
+            .growth
+            .time_step
+            .mul_add(self.growth.growth(potential), self.value);
+        Self::new(value.clamp(0.0, 1.0), self.growth)
+    }
+}
+
+impl CellState for LeniaState {
+    #[allow(clippy::cast_precision_loss)]
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let (sum, count) =
+            neighbor_cells.fold((0.0, 0), |(sum, count), c| (sum + c.value, count + 1));
+        let potential = if count > 0 { sum / count as f32 } else { 0.0 };
+        self.grow(potential)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        (self.value > 0.01)
+            .then(|| Color::rgb(self.value, self.value * self.value, 1.0 - self.value))
+    }
+}
+
+impl WeightedCellState for LeniaState {
+    fn new_cell_state_weighted<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = (&'a Self, f32)>,
+    ) -> Self {
+        let potential = neighbor_cells.map(|(c, w)| c.value * w).sum();
+        self.grow(potential)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn growth() {
+        let growth = LeniaGrowth::default();
+        assert!((growth.growth(0.15) - 1.0).abs() < f32::EPSILON);
+        assert!((growth.growth(0.0) + 1.0).abs() < 1e-5);
+        assert!(growth.growth(0.14) > 0.0);
+        assert!(growth.growth(0.2) < 0.0);
+    }
+
+    #[test]
+    fn weighted_rules() {
+        let growth = LeniaGrowth::default();
+        let state = LeniaState::new(0.5, growth);
+        let full = LeniaState::new(1.0, growth);
+        // Optimal potential
+        let new_state = state.new_cell_state_weighted(std::iter::once((&full, 0.15)));
+        assert!((new_state.value - 0.6).abs() < 1e-5);
+        // Empty neighborhood
+        let new_state = state.new_cell_state_weighted(std::iter::empty());
+        assert!((new_state.value - 0.4).abs() < 1e-5);
+        // Values stay in `[0, 1]`
+        let new_state = full.new_cell_state_weighted(std::iter::once((&full, 0.15)));
+        assert!((new_state.value - 1.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::{fmt, str::FromStr};
+
+/// [Life-like] rule, in `B/S` notation: a dead cell is born if its live
+/// neighbor count is in the `birth` set, a live cell survives if its live
+/// neighbor count is in the `survival` set.
+///
+/// Sets are stored as bitmasks, bit `n` being set if a count of `n` neighbors
+/// is in the set.
+///
+/// Rules may be parsed from rule strings like `"B3/S23"` or `"B36/S23"`:
+///
+/// ```rust,no_run
+/// # use bevy_life::LifeLikeRule;
+/// let rule: LifeLikeRule = "B36/S23".parse().unwrap();
+/// assert_eq!(rule, LifeLikeRule::HIGH_LIFE);
+/// ```
+///
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Reflect)]
+pub struct LifeLikeRule {
+    /// Bitmask of the live neighbor counts giving birth to a dead cell
+    pub birth: u32,
+    /// Bitmask of the live neighbor counts keeping a live cell alive
+    pub survival: u32,
+}
+
+impl LifeLikeRule {
+    /// Conway's Game of Life: `B3/S23`
+    pub const CONWAY: Self = Self::new(&[3], &[2, 3]);
+    /// High Life: `B36/S23`
+    pub const HIGH_LIFE: Self = Self::new(&[3, 6], &[2, 3]);
+    /// Day & Night: `B3678/S34678`
+    pub const DAY_AND_NIGHT: Self = Self::new(&[3, 6, 7, 8], &[3, 4, 6, 7, 8]);
+    /// Maze, growing maze-like corridors: `B3/S12345`
+    pub const MAZE: Self = Self::new(&[3], &[1, 2, 3, 4, 5]);
+    /// Mazectric, growing longer maze corridors: `B3/S1234`
+    pub const MAZECTRIC: Self = Self::new(&[3], &[1, 2, 3, 4]);
+
+    /// Instantiates a new rule from the `birth` and `survival` neighbor counts
+    ///
+    /// # Panics
+    ///
+    /// Panics if a count is greater than 31
+    #[must_use]
+    pub const fn new(birth: &[u32], survival: &[u32]) -> Self {
+        Self {
+            birth: mask(birth),
+            survival: mask(survival),
+        }
+    }
+
+    /// Is a dead cell with `count` live neighbors born
+    #[must_use]
+    #[inline]
+    pub const fn is_born(&self, count: usize) -> bool {
+        count < 32 && self.birth & (1 << count) != 0
+    }
+
+    /// Does a live cell with `count` live neighbors survive
+    #[must_use]
+    #[inline]
+    pub const fn survives(&self, count: usize) -> bool {
+        count < 32 && self.survival & (1 << count) != 0
+    }
+}
+
+impl Default for LifeLikeRule {
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+const fn mask(counts: &[u32]) -> u32 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        assert!(counts[i] < 32, "neighbor counts must be lower than 32");
+        mask |= 1 << counts[i];
+        i += 1;
+    }
+    mask
+}
+
+/// Error returned when parsing an invalid [`LifeLikeRule`] string
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    /// The rule doesn't have a `B` and a `S` part separated by `/`
+    InvalidFormat,
+    /// A neighbor count isn't a digit
+    InvalidCount(char),
+}
+
+impl fmt::Display for RuleParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected a `B<counts>/S<counts>` rule"),
+            Self::InvalidCount(c) => write!(f, "invalid neighbor count `{c}`"),
+        }
+    }
+}
+
+impl std::error::Error for RuleParseError {}
+
+impl FromStr for LifeLikeRule {
+    type Err = RuleParseError;
+
+    /// Parses `B<counts>/S<counts>` rule strings, case insensitive and in any
+    /// order
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mut birth, mut survival) = (None, None);
+        for part in s.trim().split('/') {
+            let mut chars = part.chars();
+            let target = match chars.next().map(|c| c.to_ascii_uppercase()) {
+                Some('B') => &mut birth,
+                Some('S') => &mut survival,
+                _ => return Err(RuleParseError::InvalidFormat),
+            };
+            if target.is_some() {
+                return Err(RuleParseError::InvalidFormat);
+            }
+            let mask = chars.try_fold(0_u32, |mask, c| {
+                c.to_digit(10)
+                    .map(|count| mask | 1 << count)
+                    .ok_or(RuleParseError::InvalidCount(c))
+            })?;
+            *target = Some(mask);
+        }
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Self { birth, survival }),
+            _ => Err(RuleParseError::InvalidFormat),
+        }
+    }
+}
+
+impl fmt::Display for LifeLikeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let counts = |mask: u32| -> String {
+            (0..10)
+                .filter(|n| mask & (1 << n) != 0)
+                .map(|n| char::from(b'0' + n))
+                .collect()
+        };
+        write!(f, "B{}/S{}", counts(self.birth), counts(self.survival))
+    }
+}
+
+/// [Life-like] cellular automaton state, following any [`LifeLikeRule`] stored
+/// in the state itself.
+///
+/// Allows to explore any life-like rule without writing a new [`CellState`]
+/// implementation:
+///
+/// ```rust,no_run
+/// # use bevy_life::{LifeLikeCellState, LifeLikeRule};
+/// let rule: LifeLikeRule = "B36/S23".parse().unwrap();
+/// let state = LifeLikeCellState::new(true, rule);
+/// ```
+///
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Component, Reflect)]
+pub struct LifeLikeCellState {
+    /// Is the cell alive
+    pub alive: bool,
+    /// The rule followed by the cell
+    pub rule: LifeLikeRule,
+}
+
+impl CellState for LifeLikeCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let alive_cells_count = neighbor_cells.filter(|c| c.alive).count();
+        let alive = if self.alive {
+            self.rule.survives(alive_cells_count)
+        } else {
+            self.rule.is_born(alive_cells_count)
+        };
+        Self::new(alive, self.rule)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        if self.alive {
+            Some(Color::WHITE)
+        } else {
+            None
+        }
+    }
+}
+
+impl LifeLikeCellState {
+    /// Instantiates a new state following `rule`
+    #[must_use]
+    #[inline]
+    pub const fn new(alive: bool, rule: LifeLikeRule) -> Self {
+        Self { alive, rule }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        assert_eq!("B3/S23".parse(), Ok(LifeLikeRule::CONWAY));
+        assert_eq!("s23/b3".parse(), Ok(LifeLikeRule::CONWAY));
+        assert_eq!("B36/S23".parse(), Ok(LifeLikeRule::HIGH_LIFE));
+        assert_eq!("B3678/S34678".parse(), Ok(LifeLikeRule::DAY_AND_NIGHT));
+        assert_eq!("B3/S12345".parse(), Ok(LifeLikeRule::MAZE));
+        assert_eq!("B3/S1234".parse(), Ok(LifeLikeRule::MAZECTRIC));
+        assert_eq!("B2/S".parse(), Ok(LifeLikeRule::new(&[2], &[])));
+        assert_eq!(
+            "B3/S2x".parse::<LifeLikeRule>(),
+            Err(RuleParseError::InvalidCount('x'))
+        );
+        for invalid in ["", "B3", "23/3", "B3/S23/S1", "B3/B23"] {
+            assert_eq!(
+                invalid.parse::<LifeLikeRule>(),
+                Err(RuleParseError::InvalidFormat),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(LifeLikeRule::HIGH_LIFE.to_string(), "B36/S23");
+        assert_eq!(LifeLikeRule::new(&[2], &[]).to_string(), "B2/S");
+    }
+
+    #[test]
+    fn rules() {
+        let states = |alive: &[bool]| -> Vec<LifeLikeCellState> {
+            alive
+                .iter()
+                .map(|a| LifeLikeCellState::new(*a, LifeLikeRule::HIGH_LIFE))
+                .collect()
+        };
+        let neighbors = states(&[true, true, true, true, true, true, false, false]);
+        let dead = LifeLikeCellState::new(false, LifeLikeRule::HIGH_LIFE);
+        assert!(dead.new_cell_state(neighbors.iter()).alive);
+        assert!(!dead.new_cell_state(neighbors[..5].iter()).alive);
+        assert!(dead.new_cell_state(neighbors[..3].iter()).alive);
+        let alive = LifeLikeCellState::new(true, LifeLikeRule::HIGH_LIFE);
+        assert!(alive.new_cell_state(neighbors[..2].iter()).alive);
+        assert!(!alive.new_cell_state(neighbors[..4].iter()).alive);
+    }
+}
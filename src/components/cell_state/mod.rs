@@ -1,16 +1,71 @@
-use bevy::prelude::Component;
+use crate::resources::CellRng;
+pub use age_cohort_state::*;
+use bevy::prelude::{Component, Resource};
+use bevy::reflect::GetTypeRegistration;
+pub use cohort_state::*;
 pub use conway_state::*;
 pub use conway_state_3d::*;
 pub use cyclic_color_state::*;
+pub use elementary_state::*;
+pub use excitable_state::*;
+pub use falling_sand_state::*;
+pub use forest_fire_state::*;
+pub use generations_state::*;
+pub use household_state::*;
 pub use immigration_state::*;
+pub use langtons_loop_state::*;
+pub use lenia_state::*;
+pub use life_like_state::*;
+pub use multi_strain_state::*;
+pub use parity_state::*;
+pub use potts_state::*;
 pub use rainbow_state::*;
+use rand::{seq::SliceRandom, Rng};
+pub use reiter_state::*;
+pub use sandpile_state::*;
+pub use schelling_state::*;
+pub use seeds_state::*;
+pub use seir_state::*;
+pub use severity_state::*;
+pub use sirs_state::*;
+pub use smooth_life_state::*;
+pub use table_state::*;
+#[cfg(feature = "1D")]
+pub use traffic_state::*;
+pub use wa_tor_state::*;
 pub use wire_world_cell_state::*;
 
+mod age_cohort_state;
+mod cohort_state;
 mod conway_state;
 mod conway_state_3d;
 mod cyclic_color_state;
+mod elementary_state;
+mod excitable_state;
+mod falling_sand_state;
+mod forest_fire_state;
+mod generations_state;
+mod household_state;
 mod immigration_state;
+mod langtons_loop_state;
+mod lenia_state;
+mod life_like_state;
+mod multi_strain_state;
+mod parity_state;
+mod potts_state;
 mod rainbow_state;
+mod reiter_state;
+mod sandpile_state;
+mod schelling_state;
+mod seeds_state;
+mod seir_state;
+mod severity_state;
+mod sirs_state;
+mod smooth_life_state;
+mod table_state;
+#[cfg(feature = "1D")]
+mod traffic_state;
+mod wa_tor_state;
 mod wire_world_cell_state;
 
 /// This trait defines the state of any given `Cell`. The trait implementation
@@ -26,8 +81,272 @@ pub trait CellState: Component + Sized + Clone + PartialEq {
     #[must_use]
     fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self;
 
+    /// Defines the new state for a cell like [`CellState::new_cell_state`],
+    /// drawing the randomness of stochastic rules from the injected `rng`.
+    ///
+    /// The built-in systems inject the [`CellRng`], following the
+    /// [`SimulationRng`] if any. Defaults to [`CellState::new_cell_state`],
+    /// stochastic states should override it.
+    ///
+    /// [`SimulationRng`]: crate::SimulationRng
+    #[must_use]
+    fn new_cell_state_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let _ = rng;
+        self.new_cell_state(neighbor_cells)
+    }
+
     #[cfg(feature = "auto-coloring")]
     /// Color of the state, to use with `auto-coloring` feature
     #[must_use]
     fn color(&self) -> Option<bevy::render::color::Color>;
 }
+
+/// Weighted variant of [`CellState`], whose rules receive the weight of every
+/// neighbor along with its state.
+///
+/// Used by the [`WeightedCellularAutomatonPlugin`] with a [`WeightedCell`] for
+/// dispersal kernels in epidemic and ecology models.
+///
+/// [`WeightedCellularAutomatonPlugin`]: crate::WeightedCellularAutomatonPlugin
+/// [`WeightedCell`]: crate::WeightedCell
+pub trait WeightedCellState: CellState {
+    /// Defines the new state for a cell given the `neighbor_cells` states with
+    /// their weight and `self`.
+    #[must_use]
+    fn new_cell_state_weighted<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = (&'a Self, f32)>,
+    ) -> Self;
+}
+
+/// Variant of [`CellState`] whose rules read global parameters, stored in the
+/// `Params` resource.
+///
+/// Used by the [`ParametricCellularAutomatonPlugin`], the parameters may be
+/// tweaked at runtime by modifying the resource. The plugin registers the
+/// resource type, which may then be edited through reflection, for instance
+/// with an inspector.
+///
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+pub trait ParametricCellState: CellState {
+    /// Global parameters resource of the rules
+    type Params: Resource + Clone + Default + GetTypeRegistration;
+
+    /// Defines the new state for a cell given the `neighbor_cells` states, the
+    /// global `params` and `self`.
+    #[must_use]
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self;
+
+    /// Defines the new state for a cell like
+    /// [`ParametricCellState::new_cell_state_with_params`], drawing the
+    /// randomness of stochastic rules from the injected `rng`. Defaults to
+    /// [`ParametricCellState::new_cell_state_with_params`], stochastic states
+    /// should override it.
+    #[must_use]
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let _ = rng;
+        self.new_cell_state_with_params(neighbor_cells, params)
+    }
+}
+
+/// Variant of [`CellState`] coupled to a second layer of `O` states on the same
+/// grid, like a host population coupled to its disease vectors.
+///
+/// Used by the [`CoupledCellularAutomatonPlugin`]: the rules read the state of
+/// the other layer at the same coordinates. Coupling both layers to each other
+/// with two plugins updates them synchronously, from the states of the
+/// previous generation.
+///
+/// [`CoupledCellularAutomatonPlugin`]: crate::CoupledCellularAutomatonPlugin
+pub trait CoupledCellState<O: CellState>: CellState {
+    /// Defines the new state for a cell given the `neighbor_cells` states,
+    /// the `coupled` state of the other layer at the same coordinates, if any,
+    /// and `self`.
+    #[must_use]
+    fn new_cell_state_coupled<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        coupled: Option<&O>,
+    ) -> Self;
+}
+
+/// Result of a [`MovingCellState`] rule: the new state of the cell and an
+/// optional claimed neighbor with its new state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellMove<S> {
+    /// New state of the cell
+    pub origin: S,
+    /// Index of the claimed neighbor, in the neighbor slice given to the rule,
+    /// and its new state
+    pub target: Option<(usize, S)>,
+}
+
+impl<S> CellMove<S> {
+    /// The cell state changes to `origin` without claiming any neighbor
+    #[must_use]
+    #[inline]
+    pub const fn stay(origin: S) -> Self {
+        Self {
+            origin,
+            target: None,
+        }
+    }
+
+    /// The cell state changes to `origin` and the neighbor at `index` changes
+    /// to `target`
+    #[must_use]
+    #[inline]
+    pub const fn claim(origin: S, index: usize, target: S) -> Self {
+        Self {
+            origin,
+            target: Some((index, target)),
+        }
+    }
+}
+
+/// Variant of [`CellState`] whose rules may move the cell content to a
+/// neighbor cell, for agent based models like predator-prey models.
+///
+/// Used by the [`MovingCellularAutomatonPlugin`]: unlike the synchronous
+/// update of other states, cells are updated one after the other in a random
+/// order, every rule seeing the moves already applied during the tick. A cell
+/// claimed by a move is not updated again in the same tick.
+///
+/// [`MovingCellularAutomatonPlugin`]: crate::MovingCellularAutomatonPlugin
+pub trait MovingCellState: CellState {
+    /// Defines the new state of the cell and of an optional claimed neighbor
+    /// given the current `neighbor_cells` states and `self`.
+    ///
+    /// The `neighbor_cells` follow the order of the cell neighbor
+    /// coordinates, missing neighbors being `None`, so a rule may rely on the
+    /// position of a neighbor.
+    #[must_use]
+    fn move_cell(&self, neighbor_cells: &[Option<&Self>]) -> CellMove<Self>;
+
+    /// Defines the new state of the cell and of an optional claimed neighbor
+    /// like [`MovingCellState::move_cell`], drawing the randomness of
+    /// stochastic rules from the injected `rng`. Defaults to
+    /// [`MovingCellState::move_cell`], stochastic states should override it.
+    #[must_use]
+    fn move_cell_rng(
+        &self,
+        neighbor_cells: &[Option<&Self>],
+        rng: &mut impl Rng,
+    ) -> CellMove<Self> {
+        let _ = rng;
+        self.move_cell(neighbor_cells)
+    }
+}
+
+/// Variant of [`ParametricCellState`] whose cells may relocate anywhere on the
+/// map, for agent based models like the Schelling segregation model.
+///
+/// Used by the [`RelocatingCellularAutomatonPlugin`]: after the regular
+/// update, the content of every unsatisfied cell is swapped with a random
+/// vacant cell of the map.
+///
+/// [`RelocatingCellularAutomatonPlugin`]: crate::RelocatingCellularAutomatonPlugin
+pub trait RelocatingCellState: ParametricCellState {
+    /// Can the cell receive a relocated cell content ?
+    #[must_use]
+    fn is_vacant(&self) -> bool;
+
+    /// Is the cell satisfied with its current `neighbor_cells`? Unsatisfied
+    /// cells relocate to a vacant cell.
+    #[must_use]
+    fn is_satisfied<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> bool;
+}
+
+/// Epidemic [`CellState`] whose susceptible individuals may be vaccinated.
+///
+/// Used by the [`VaccinationPlugin`], which vaccinates the cells targeted by
+/// a [`Vaccination`] campaign.
+///
+/// [`VaccinationPlugin`]: crate::VaccinationPlugin
+/// [`Vaccination`]: crate::Vaccination
+pub trait VaccinableCellState: CellState {
+    /// Does the cell hold infected individuals ? Ring vaccination targets the
+    /// neighbors of infected cells.
+    #[must_use]
+    fn is_infected(&self) -> bool;
+
+    /// Vaccinates a `coverage` fraction of the susceptible individuals of the
+    /// cell, making them immune.
+    #[must_use]
+    fn vaccinate(&self, coverage: f64) -> Self;
+}
+
+/// Epidemic [`CellState`] which may receive infections imported from outside
+/// of the simulation.
+///
+/// Used by the [`ImportationPlugin`], which seeds the infections of an
+/// [`Importation`] resource.
+///
+/// [`ImportationPlugin`]: crate::ImportationPlugin
+/// [`Importation`]: crate::Importation
+pub trait InfectableCellState: CellState {
+    /// Infects up to `cases` susceptible individuals of the cell. Single host
+    /// states are infected by any case.
+    #[must_use]
+    fn import_infection(&self, cases: u32) -> Self;
+}
+
+/// [`CellState`] of a quantity, like a population, which may be aggregated
+/// over a region of cells and split back.
+///
+/// Used by the [`LevelOfDetailPlugin`], which coarse-grains the regions far
+/// from the camera into super-cells.
+///
+/// [`LevelOfDetailPlugin`]: crate::LevelOfDetailPlugin
+pub trait CoarseCellState: CellState {
+    /// Aggregates the `states` of the cells of a region into the state of a
+    /// super-cell
+    #[must_use]
+    fn coarsen(states: &[&Self]) -> Self;
+
+    /// Splits the state of a super-cell back into the states of its `cells`
+    /// cells
+    #[must_use]
+    fn refine(&self, cells: usize) -> Vec<Self>;
+}
+
+/// Epidemic [`ParametricCellState`] whose infections may be attributed to a
+/// neighbor.
+///
+/// Used by the [`TracedCellularAutomatonPlugin`], which sends a
+/// [`TransmissionEvent`] for every attributed infection.
+///
+/// [`TracedCellularAutomatonPlugin`]: crate::TracedCellularAutomatonPlugin
+/// [`TransmissionEvent`]: crate::TransmissionEvent
+pub trait TraceableCellState: ParametricCellState {
+    /// If changing from `self` to `new_state` is an infection, returns the
+    /// index in `neighbor_cells` of the neighbor which infected the cell.
+    #[must_use]
+    fn infection_source(&self, new_state: &Self, neighbor_cells: &[&Self]) -> Option<usize>;
+}
+
+/// Index of a random neighbor among the `neighbor_cells` matching
+/// `is_source`, every potential source being as likely to have transmitted
+fn random_source<S>(neighbor_cells: &[&S], is_source: impl Fn(&S) -> bool) -> Option<usize> {
+    let sources: Vec<_> = (0..neighbor_cells.len())
+        .filter(|i| is_source(neighbor_cells[*i]))
+        .collect();
+    sources.choose(&mut CellRng).copied()
+}
@@ -0,0 +1,258 @@
+use super::random_source;
+use crate::{
+    components::{CellState, ParametricCellState, TraceableCellState},
+    resources::{CellRng, MultiStrainParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// Maximum amount of strains, the size of the immunity bit mask
+const MAX_STRAINS: usize = 32;
+
+/// Multi-strain [SIR] epidemic state and rules, with cross-immunity between
+/// the strains. The rules are the following:
+///
+/// - A cell infected by a strain recovers after `infectious_period`
+///   generations, and becomes immune to that strain.
+/// - A cell which isn't infected may be infected by each of its neighbors
+///   infectious with a strain, with the `transmission_probabilities` of that
+///   strain. Each past infection protects it against the strain following the
+///   `cross_immunity` matrix.
+/// - A cell loses the immunity of all its past infections with probability
+///   `waning_probability` every generation.
+///
+/// Strains escaping the immunity of the others may then replace them. The
+/// parameters are read from the [`MultiStrainParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [SIR]: https://en.wikipedia.org/wiki/Compartmental_models_in_epidemiology#The_SIR_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct MultiStrainState {
+    /// Strain infecting the cell, with the amount of generations spent
+    /// infectious
+    pub infection: Option<(u8, u32)>,
+    /// Bit mask of the strains the cell recovered from
+    pub immunity: u32,
+}
+
+impl MultiStrainState {
+    /// Instantiates a naive cell infected by `strain`
+    #[must_use]
+    #[inline]
+    pub const fn infected(strain: u8) -> Self {
+        Self {
+            infection: Some((strain, 0)),
+            immunity: 0,
+        }
+    }
+
+    /// Instantiates a cell recovered from the strains of the `immunity` bit
+    /// mask
+    #[must_use]
+    #[inline]
+    pub const fn recovered(immunity: u32) -> Self {
+        Self {
+            infection: None,
+            immunity,
+        }
+    }
+
+    /// Strain infecting the cell, if any
+    #[must_use]
+    #[inline]
+    pub fn strain(&self) -> Option<u8> {
+        self.infection.map(|(strain, _)| strain)
+    }
+
+    /// Did the cell recover from `strain` ?
+    #[must_use]
+    #[inline]
+    pub fn recovered_from(&self, strain: u8) -> bool {
+        1_u32
+            .checked_shl(u32::from(strain))
+            .is_some_and(|bit| self.immunity & bit != 0)
+    }
+}
+
+#[cfg(feature = "auto-coloring")]
+fn strain_hue(strain: u8) -> f32 {
+    (f32::from(strain) * 137.5) % 360.0
+}
+
+impl CellState for MultiStrainState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &MultiStrainParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    fn color(&self) -> Option<Color> {
+        if let Some(strain) = self.strain() {
+            return Some(Color::hsl(strain_hue(strain), 0.9, 0.5));
+        }
+        // Recovered cells blend the pale hues of their past strains
+        let (count, rgb) = (0..MAX_STRAINS as u8)
+            .filter(|strain| self.recovered_from(*strain))
+            .map(|strain| Color::hsl(strain_hue(strain), 0.6, 0.8).as_rgba_f32())
+            .fold((0_u32, [0.0; 3]), |(count, [r, g, b]), c| {
+                (count + 1, [r + c[0], g + c[1], b + c[2]])
+            });
+        (count > 0).then(|| {
+            let [r, g, b] = rgb.map(|c| c / count as f32);
+            Color::rgb(r, g, b)
+        })
+    }
+}
+
+impl ParametricCellState for MultiStrainState {
+    type Params = MultiStrainParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        if let Some((strain, ticks)) = self.infection {
+            return if ticks.saturating_add(1) >= params.infectious_period {
+                Self::recovered(self.immunity | 1_u32.checked_shl(u32::from(strain)).unwrap_or(0))
+            } else {
+                Self {
+                    infection: Some((strain, ticks + 1)),
+                    ..*self
+                }
+            };
+        }
+        let immunity = if self.immunity != 0 && rng.gen::<f64>() < params.waning_probability {
+            0
+        } else {
+            self.immunity
+        };
+        let strains = params.strains();
+        let mut infectious = [0_i32; MAX_STRAINS];
+        for strain in neighbor_cells.filter_map(Self::strain) {
+            if let Some(count) = infectious.get_mut(usize::from(strain)) {
+                *count += 1;
+            }
+        }
+        // Infection probability by every strain, reduced by the immunity
+        let mut probabilities = [0.0; MAX_STRAINS];
+        for strain in (0..strains).filter(|s| infectious[*s] > 0) {
+            let transmission = params.transmission_probabilities[strain].clamp(0.0, 1.0);
+            let susceptibility: f64 = (0..strains)
+                .filter(|past| immunity & (1 << past) != 0)
+                .map(|past| 1.0 - params.protection(past, strain))
+                .product();
+            probabilities[strain] =
+                (1.0 - (1.0 - transmission).powi(infectious[strain])) * susceptibility;
+        }
+        let escape: f64 = probabilities.iter().map(|p| 1.0 - p).product();
+        let total: f64 = probabilities.iter().sum();
+        if total <= 0.0 || rng.gen::<f64>() >= 1.0 - escape {
+            return Self::recovered(immunity);
+        }
+        // The infecting strain is drawn proportionally to its probability
+        let mut draw = rng.gen::<f64>() * total;
+        let strain = probabilities
+            .iter()
+            .position(|p| {
+                draw -= p;
+                *p > 0.0 && draw < 0.0
+            })
+            .or_else(|| probabilities.iter().rposition(|p| *p > 0.0))
+            .unwrap_or_default();
+        Self {
+            infection: Some((u8::try_from(strain).unwrap_or_default(), 0)),
+            immunity,
+        }
+    }
+}
+
+impl TraceableCellState for MultiStrainState {
+    fn infection_source(&self, new_state: &Self, neighbor_cells: &[&Self]) -> Option<usize> {
+        if self.infection.is_some() {
+            return None;
+        }
+        let strain = new_state.strain()?;
+        random_source(neighbor_cells, |c| c.strain() == Some(strain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recovery() {
+        let params = MultiStrainParams::default()
+            .with_infectious_period(2)
+            .with_waning(0.0);
+        let mut state = MultiStrainState::infected(1);
+        state = state.new_cell_state_with_params(std::iter::empty(), &params);
+        assert_eq!(state.infection, Some((1, 1)));
+        state = state.new_cell_state_with_params(std::iter::empty(), &params);
+        assert_eq!(state, MultiStrainState::recovered(0b10));
+        assert!(state.recovered_from(1));
+        assert!(!state.recovered_from(0));
+        // Immunity wanes
+        let params = params.with_waning(1.0);
+        assert_eq!(
+            state.new_cell_state_with_params(std::iter::empty(), &params),
+            MultiStrainState::default()
+        );
+    }
+
+    #[test]
+    fn cross_immunity() {
+        // The first strain protects against the second one, not the other
+        // way around
+        let params = MultiStrainParams::new(vec![1.0, 1.0], vec![vec![1.0, 1.0], vec![0.0, 1.0]])
+            .with_waning(0.0);
+        let first = [MultiStrainState::infected(0)];
+        let second = [MultiStrainState::infected(1)];
+        let recovered_first = MultiStrainState::recovered(0b01);
+        let recovered_second = MultiStrainState::recovered(0b10);
+        assert_eq!(
+            recovered_first.new_cell_state_with_params(second.iter(), &params),
+            recovered_first
+        );
+        assert_eq!(
+            recovered_second
+                .new_cell_state_with_params(first.iter(), &params)
+                .infection,
+            Some((0, 0))
+        );
+        assert_eq!(
+            recovered_second.new_cell_state_with_params(second.iter(), &params),
+            recovered_second
+        );
+    }
+
+    #[test]
+    fn competing_strains() {
+        let params = MultiStrainParams::new(vec![0.0, 1.0, 1.0], vec![]).with_waning(0.0);
+        let neighbors = [
+            MultiStrainState::infected(0),
+            MultiStrainState::infected(2),
+            // Unknown strains are ignored
+            MultiStrainState::infected(7),
+        ];
+        for _ in 0..20 {
+            let state =
+                MultiStrainState::default().new_cell_state_with_params(neighbors.iter(), &params);
+            assert_eq!(state.strain(), Some(2));
+        }
+    }
+}
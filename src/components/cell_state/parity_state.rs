@@ -0,0 +1,186 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::ops::Deref;
+
+/// [Replicator] cellular automaton state, following the **B1357/S1357** rule:
+/// a cell is alive if an odd number of its neighbors are alive, whatever its
+/// own state.
+///
+/// The rule is linear: the new state is the XOR of the neighbor states, which
+/// is computed directly instead of counting live neighbors. As a consequence,
+/// on a Moore neighborhood any pattern is replaced by 8 copies of itself after
+/// a power of two generations.
+///
+/// A dead cell is `false`, a live cell is `true`
+///
+/// [Replicator]: https://conwaylife.com/wiki/OCA:Replicator
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct ReplicatorCellState(pub bool);
+
+/// [Fredkin] parity cellular automaton state, following the **B1357/S02468**
+/// rule: a cell is alive if an odd number of cells among itself and its
+/// neighbors are alive.
+///
+/// Like the [`ReplicatorCellState`], the new state is a XOR of the neighbor
+/// states and of the cell state. On a Moore neighborhood any pattern is
+/// replaced by 9 copies of itself after a power of two generations.
+///
+/// A dead cell is `false`, a live cell is `true`
+///
+/// [Fredkin]: https://conwaylife.com/wiki/OCA:Fredkin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct FredkinCellState(pub bool);
+
+impl CellState for ReplicatorCellState {
+    #[inline]
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        Self(neighbor_cells.fold(false, |parity, c| parity ^ c.0))
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        self.0.then_some(Color::WHITE)
+    }
+}
+
+impl CellState for FredkinCellState {
+    #[inline]
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        Self(neighbor_cells.fold(self.0, |parity, c| parity ^ c.0))
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        self.0.then_some(Color::WHITE)
+    }
+}
+
+impl Deref for ReplicatorCellState {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Deref for FredkinCellState {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<bool> for ReplicatorCellState {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+impl From<bool> for FredkinCellState {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CellularAutomatonPlugin, LifeLikeCellState, LifeLikeRule, MooreCell2d};
+    use bevy::{prelude::*, utils::HashSet};
+
+    const SIZE: i32 = 24;
+
+    /// Runs `generations` of a `SIZE` grid starting with `pattern` alive
+    fn run<S>(pattern: &HashSet<IVec2>, generations: usize) -> HashSet<IVec2>
+    where
+        S: CellState + From<bool> + Deref<Target = bool>,
+    {
+        let mut app = App::new();
+        app.add_plugins(CellularAutomatonPlugin::<MooreCell2d, S>::new());
+        for y in 0..SIZE {
+            for x in 0..SIZE {
+                let coords = IVec2::new(x, y);
+                app.world
+                    .spawn((MooreCell2d::new(coords), S::from(pattern.contains(&coords))));
+            }
+        }
+        for _ in 0..generations {
+            app.update();
+        }
+        app.world
+            .query::<(&MooreCell2d, &S)>()
+            .iter(&app.world)
+            .filter(|(_, state)| ***state)
+            .map(|(cell, _)| cell.coords)
+            .collect()
+    }
+
+    fn copies(pattern: &HashSet<IVec2>, offsets: impl Iterator<Item = IVec2>) -> HashSet<IVec2> {
+        offsets
+            .flat_map(|offset| pattern.iter().map(move |c| *c + offset))
+            .collect()
+    }
+
+    fn r_pentomino() -> HashSet<IVec2> {
+        [(11, 10), (12, 10), (10, 11), (11, 11), (11, 12)]
+            .into_iter()
+            .map(IVec2::from)
+            .collect()
+    }
+
+    fn moore_offsets(distance: i32) -> impl Iterator<Item = IVec2> {
+        (-1..=1)
+            .flat_map(move |y| (-1..=1).map(move |x| IVec2::new(x, y) * distance))
+            .filter(|c| *c != IVec2::ZERO)
+    }
+
+    #[test]
+    fn replicator_self_replication() {
+        let pattern = r_pentomino();
+        let expected = copies(&pattern, moore_offsets(4));
+        assert_eq!(expected.len(), 8 * pattern.len());
+        assert_eq!(run::<ReplicatorCellState>(&pattern, 4), expected);
+    }
+
+    #[test]
+    fn fredkin_self_replication() {
+        let pattern = r_pentomino();
+        let expected = copies(
+            &pattern,
+            moore_offsets(4).chain(std::iter::once(IVec2::ZERO)),
+        );
+        assert_eq!(expected.len(), 9 * pattern.len());
+        assert_eq!(run::<FredkinCellState>(&pattern, 4), expected);
+    }
+
+    #[test]
+    fn matches_life_like_rules() {
+        let replicator: LifeLikeRule = "B1357/S1357".parse().unwrap();
+        let fredkin: LifeLikeRule = "B1357/S02468".parse().unwrap();
+        for count in 0..=8 {
+            let neighbors = vec![ReplicatorCellState(true); count];
+            for alive in [false, true] {
+                let life_like = LifeLikeCellState::new(alive, replicator);
+                let life_like_neighbors = vec![LifeLikeCellState::new(true, replicator); count];
+                assert_eq!(
+                    ReplicatorCellState(alive)
+                        .new_cell_state(neighbors.iter())
+                        .0,
+                    life_like.new_cell_state(life_like_neighbors.iter()).alive
+                );
+                let life_like = LifeLikeCellState::new(alive, fredkin);
+                let life_like_neighbors = vec![LifeLikeCellState::new(true, fredkin); count];
+                let fredkin_neighbors = vec![FredkinCellState(true); count];
+                assert_eq!(
+                    FredkinCellState(alive)
+                        .new_cell_state(fredkin_neighbors.iter())
+                        .0,
+                    life_like.new_cell_state(life_like_neighbors.iter()).alive
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    components::{CellState, ParametricCellState},
+    resources::{CellRng, PottsParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// `Q`-state [Potts model] state and rules, the value being the spin of the
+/// cell in `0..Q`.
+///
+/// Each generation, every cell attempts a spin flip with the
+/// `update_probability` of the [`PottsParams`] resource: a random other spin
+/// is picked and accepted following the Metropolis algorithm, depending on
+/// the energy change and the `temperature`. The energy of a cell is the
+/// opposite of the amount of neighbors sharing its spin.
+///
+/// The parameters are read through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [Potts model]: https://en.wikipedia.org/wiki/Potts_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct PottsState<const Q: u8>(pub u8);
+
+/// [Ising model] state, the 2-state Potts model
+///
+/// [Ising model]: https://en.wikipedia.org/wiki/Ising_model
+pub type IsingState = PottsState<2>;
+
+impl<const Q: u8> PottsState<Q> {
+    /// Instantiates a state with a random spin
+    #[must_use]
+    pub fn random() -> Self {
+        Self(CellRng.gen_range(0..Q.max(1)))
+    }
+}
+
+impl<const Q: u8> CellState for PottsState<Q> {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &PottsParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        let hue = 360.0 * f32::from(self.0) / f32::from(Q.max(1));
+        Some(Color::hsl(hue, 0.8, 0.5))
+    }
+}
+
+impl<const Q: u8> ParametricCellState for PottsState<Q> {
+    type Params = PottsParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        if Q < 2 || !rng.gen_bool(params.update_probability) {
+            return *self;
+        }
+        let spin = (self.0 + rng.gen_range(1..Q)) % Q;
+        let (current, flipped) = neighbor_cells.fold((0_i32, 0_i32), |(current, flipped), c| {
+            (
+                current + i32::from(c.0 == self.0),
+                flipped + i32::from(c.0 == spin),
+            )
+        });
+        let energy_change = f64::from(current - flipped);
+        if energy_change <= 0.0
+            || (params.temperature > 0.0
+                && rng.gen_bool((-energy_change / params.temperature).exp()))
+        {
+            Self(spin)
+        } else {
+            *self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_temperature() {
+        let params = PottsParams::new(0.0, 1.0);
+        let neighbors = [IsingState::default(), PottsState(1), PottsState(1)];
+        // Aligns with the majority
+        assert_eq!(
+            IsingState::default().new_cell_state_with_params(neighbors.iter(), &params),
+            PottsState(1)
+        );
+        assert_eq!(
+            PottsState::<2>(1).new_cell_state_with_params(neighbors.iter(), &params),
+            PottsState(1)
+        );
+        // Never flips without updates
+        let params = PottsParams::new(100.0, 0.0);
+        assert_eq!(
+            IsingState::default().new_cell_state_with_params(neighbors.iter(), &params),
+            IsingState::default()
+        );
+    }
+
+    #[test]
+    fn potts_spins() {
+        let params = PottsParams::new(0.0, 1.0);
+        // Flipping to any other spin keeps the energy
+        let neighbors = [PottsState::<5>(3)];
+        for _ in 0..20 {
+            let state = PottsState::<5>(1).new_cell_state_with_params(neighbors.iter(), &params);
+            assert_ne!(state, PottsState(1));
+            assert!(state.0 < 5);
+        }
+        assert!((0..20).all(|_| PottsState::<5>::random().0 < 5));
+        // A single spin never flips
+        assert_eq!(
+            PottsState::<1>(0).new_cell_state_with_params(std::iter::empty(), &params),
+            PottsState(0)
+        );
+    }
+}
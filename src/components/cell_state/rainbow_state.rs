@@ -4,7 +4,7 @@ use bevy::prelude::{Component, Reflect};
 use bevy::render::color::Color;
 use std::fmt::Debug;
 
-#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect)]
+#[derive(Debug, Copy, Clone, PartialEq, Component, Reflect, Default)]
 /// Classic cellular automation state and rules following Conway's game of life
 /// variation: The immigration game.
 ///
@@ -15,10 +15,11 @@ use std::fmt::Debug;
 /// - Any live cell with more than three live neighbours dies, as if by
 ///   overpopulation.
 /// - Any dead cell with exactly three live neighbours becomes a live cell,
-/// as if by reproduction and takes the arithmetic mean state of the majority of
-/// the live neighbors.
+///   as if by reproduction and takes the arithmetic mean state of the
+///   majority of the live neighbors.
 pub enum RainbowCellState {
     /// A dead cell
+    #[default]
     Dead,
     /// Alive cell with a `f32` sub-state
     Alive(f32),
@@ -60,9 +61,3 @@ impl RainbowCellState {
         matches!(self, Self::Alive(_))
     }
 }
-
-impl Default for RainbowCellState {
-    fn default() -> Self {
-        Self::Dead
-    }
-}
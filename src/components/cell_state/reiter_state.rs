@@ -0,0 +1,89 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// Snowflake growth state of the Reiter model, from *A local cellular model
+/// for snow crystal growth* (C. A. Reiter, 2005): the water level of the
+/// cell, frozen from `1.0`.
+///
+/// A cell is *receptive* if it is frozen or has a frozen neighbor. Every
+/// generation:
+///
+/// - receptive cells keep their water and receive `gamma` vapor
+/// - vapor of the other cells diffuses with the `alpha` coefficient,
+///   receptive cells absorbing the vapor diffused towards them
+///
+/// Non frozen cells should start at the `beta` background level. The
+/// parameters are read from the [`ReiterParams`] resource.
+///
+/// The receptivity of neighbors depends on their own neighbors, so this
+/// state must be used with the [`ReiterPlugin`], on hexagonal cells like
+/// `HexagonCell2d`. With a regular plugin the cells never change.
+///
+/// [`ReiterParams`]: crate::ReiterParams
+/// [`ReiterPlugin`]: crate::ReiterPlugin
+#[derive(Debug, Copy, Clone, Default, PartialEq, Component, Reflect)]
+pub struct ReiterState(pub f32);
+
+impl ReiterState {
+    /// Is the cell part of the snowflake
+    #[must_use]
+    #[inline]
+    pub fn is_frozen(self) -> bool {
+        self.0 >= 1.0
+    }
+}
+
+impl CellState for ReiterState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        *self
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        if self.is_frozen() {
+            Some(Color::rgb(0.8, 0.9, 1.0))
+        } else {
+            Some(Color::rgb(0.0, 0.1, 0.3 * self.0.clamp(0.0, 1.0)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn snowflake_growth() {
+        use crate::{HexagonCell2d, Reiter2dPlugin, ReiterParams};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Reiter2dPlugin::new())
+            .insert_resource(ReiterParams::new(1.0, 0.4, 0.1));
+        let mut spawn = |coords: IVec3| {
+            let state = ReiterState(if coords == IVec3::ZERO { 1.0 } else { 0.4 });
+            app.world.spawn((HexagonCell2d::new(coords), state)).id()
+        };
+        // Hexagonal map of radius 2
+        let cells: Vec<_> = (-2..=2)
+            .flat_map(|x| (-2..=2).map(move |y| IVec3::new(x, y, -x - y)))
+            .filter(|c| c.z.abs() <= 2)
+            .map(|c| (c, spawn(c)))
+            .collect();
+        app.update();
+        let state = |coords: IVec3| {
+            let (_, entity) = cells.iter().find(|(c, _)| *c == coords).unwrap();
+            app.world.get::<ReiterState>(*entity).unwrap().0
+        };
+        // The frozen center receives vapor
+        assert!((state(IVec3::ZERO) - 1.1).abs() < 1e-5);
+        // Receptive neighbors receive vapor and absorb the diffused vapor
+        assert!((state(IVec3::new(0, 1, -1)) - 0.6).abs() < 1e-5);
+        // Non receptive cells diffuse their vapor: 0.4 + (0.4 * 5 / 6 - 0.4) / 2
+        assert!((state(IVec3::new(0, 2, -2)) - 0.366_666_7).abs() < 1e-5);
+        assert!(ReiterState(1.0).is_frozen());
+    }
+}
@@ -0,0 +1,166 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+#[cfg(feature = "auto-coloring")]
+const SANDPILE_COLORS: [Color; 4] = [
+    Color::rgb(0.1, 0.1, 0.3),
+    Color::rgb(0.3, 0.4, 0.9),
+    Color::rgb(0.9, 0.8, 0.3),
+    Color::rgb(0.8, 0.2, 0.1),
+];
+
+/// [Abelian sandpile] state: the amount of sand grains of the cell.
+///
+/// A cell with at least [`Self::THRESHOLD`] grains is unstable and *topples*,
+/// giving one grain to each of its 4 neighbors, which may make them topple in
+/// turn. Grains falling out of the grid are lost. The state is meant to be
+/// used with a von Neumann neighborhood like `NeumannCell2d`.
+///
+/// The rule topples every unstable cell once per generation. The
+/// [`SandpilePlugin`] may also resolve whole toppling cascades within a single
+/// tick, and drop grains to drive the pile with a [`SandpileDrop`] resource.
+///
+/// [Abelian sandpile]: https://en.wikipedia.org/wiki/Abelian_sandpile_model
+/// [`SandpilePlugin`]: crate::SandpilePlugin
+/// [`SandpileDrop`]: crate::SandpileDrop
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct SandpileState(pub u32);
+
+impl SandpileState {
+    /// Amount of grains making a cell topple
+    pub const THRESHOLD: u32 = 4;
+
+    /// Is the cell stable
+    #[must_use]
+    #[inline]
+    pub const fn is_stable(self) -> bool {
+        self.0 < Self::THRESHOLD
+    }
+}
+
+impl CellState for SandpileState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let received = neighbor_cells.filter(|c| !c.is_stable()).count();
+        let kept = if self.is_stable() {
+            self.0
+        } else {
+            self.0 - Self::THRESHOLD
+        };
+        Self(kept + u32::try_from(received).unwrap_or(u32::MAX))
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        SANDPILE_COLORS
+            .get(self.0 as usize)
+            .copied()
+            .or(Some(Color::WHITE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toppling_rule() {
+        let neighbors = [SandpileState(4), SandpileState(5), SandpileState(2)];
+        // Receives a grain from each unstable neighbor
+        assert_eq!(
+            SandpileState(1).new_cell_state(neighbors.iter()),
+            SandpileState(3)
+        );
+        // Topples
+        assert_eq!(
+            SandpileState(6).new_cell_state(neighbors[2..].iter()),
+            SandpileState(2)
+        );
+        assert_eq!(
+            SandpileState(4).new_cell_state(neighbors.iter()),
+            SandpileState(2)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    fn spawn_pile(app: &mut bevy::prelude::App, center: u32) -> Vec<bevy::prelude::Entity> {
+        use crate::NeumannCell2d;
+        use bevy::prelude::*;
+
+        (0..49)
+            .map(|i| {
+                let coords = IVec2::new(i % 7, i / 7);
+                let grains = if coords == IVec2::splat(3) { center } else { 0 };
+                app.world
+                    .spawn((NeumannCell2d::new(coords), SandpileState(grains)))
+                    .id()
+            })
+            .collect()
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn cascade() {
+        use crate::Sandpile2dPlugin;
+        use bevy::prelude::*;
+
+        let states = |app: &App, cells: &[Entity]| -> Vec<SandpileState> {
+            cells
+                .iter()
+                .map(|e| *app.world.get::<SandpileState>(*e).unwrap())
+                .collect()
+        };
+        let mut cascade_app = App::new();
+        cascade_app.add_plugins(Sandpile2dPlugin::new().with_cascade());
+        let cascade_cells = spawn_pile(&mut cascade_app, 32);
+        cascade_app.update();
+        let stable = states(&cascade_app, &cascade_cells);
+        assert!(stable.iter().all(|s| s.is_stable()));
+        // No grain reached the edges
+        assert_eq!(stable.iter().map(|s| s.0).sum::<u32>(), 32);
+
+        // Toppling once per tick reaches the same stable pile
+        let mut app = App::new();
+        app.add_plugins(Sandpile2dPlugin::new());
+        let cells = spawn_pile(&mut app, 32);
+        app.update();
+        assert_ne!(states(&app, &cells), stable);
+        for _ in 0..50 {
+            app.update();
+        }
+        assert_eq!(states(&app, &cells), stable);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn dropping_grains() {
+        use crate::{NeumannCell2d, Sandpile2dPlugin, SandpileDrop};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Sandpile2dPlugin::new())
+            .insert_resource(SandpileDrop::<NeumannCell2d>::at(IVec2::splat(3), 4));
+        let cells = spawn_pile(&mut app, 0);
+        app.update();
+        // The grains are dropped then toppled
+        assert_eq!(
+            app.world.get::<SandpileState>(cells[24]),
+            Some(&SandpileState(0))
+        );
+        assert_eq!(
+            app.world.get::<SandpileState>(cells[23]),
+            Some(&SandpileState(1))
+        );
+        let total = |app: &App| -> u32 {
+            cells
+                .iter()
+                .map(|e| app.world.get::<SandpileState>(*e).unwrap().0)
+                .sum()
+        };
+        assert_eq!(total(&app), 4);
+        app.insert_resource(SandpileDrop::<NeumannCell2d>::random(3));
+        app.update();
+        assert_eq!(total(&app), 7);
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{
+    components::{CellState, ParametricCellState, RelocatingCellState},
+    resources::SchellingParams,
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+#[cfg(feature = "auto-coloring")]
+const SCHELLING_COLORS: [Color; 6] = [
+    Color::BLUE,
+    Color::RED,
+    Color::GREEN,
+    Color::YELLOW,
+    Color::PURPLE,
+    Color::ORANGE,
+];
+
+/// [Schelling segregation model] state and rules. The rules are the
+/// following:
+///
+/// - An agent is satisfied if the ratio of agents of its group among its
+///   occupied neighbors reaches the `threshold` of the [`SchellingParams`]
+///   resource. An agent without any neighbor is satisfied.
+/// - Every unsatisfied agent moves to a random empty cell of the map.
+///
+/// Agents relocate anywhere on the map, so this state must be used with the
+/// [`RelocatingCellularAutomatonPlugin`]. With a regular plugin the agents
+/// never move.
+///
+/// [Schelling segregation model]: https://en.wikipedia.org/wiki/Schelling%27s_model_of_segregation
+/// [`RelocatingCellularAutomatonPlugin`]: crate::RelocatingCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum SchellingState {
+    /// Empty cell
+    #[default]
+    Empty,
+    /// Agent with its group
+    Agent(u8),
+}
+
+impl CellState for SchellingState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        *self
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            Self::Agent(group) => {
+                Some(SCHELLING_COLORS[usize::from(*group) % SCHELLING_COLORS.len()])
+            }
+        }
+    }
+}
+
+impl ParametricCellState for SchellingState {
+    type Params = SchellingParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        _neighbor_cells: impl Iterator<Item = &'a Self>,
+        _params: &Self::Params,
+    ) -> Self {
+        *self
+    }
+}
+
+impl RelocatingCellState for SchellingState {
+    fn is_vacant(&self) -> bool {
+        *self == Self::Empty
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn is_satisfied<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> bool {
+        let (similar, occupied) = neighbor_cells
+            .filter(|c| !c.is_vacant())
+            .fold((0_usize, 0_usize), |(similar, occupied), c| {
+                (similar + usize::from(c == self), occupied + 1)
+            });
+        occupied == 0 || similar as f32 / occupied as f32 >= params.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn satisfaction() {
+        let params = SchellingParams::new(0.5);
+        let agent = SchellingState::Agent(0);
+        let neighbors = [
+            SchellingState::Agent(0),
+            SchellingState::Agent(1),
+            SchellingState::Empty,
+            SchellingState::Empty,
+        ];
+        assert!(agent.is_satisfied(neighbors.iter(), &params));
+        assert!(!agent.is_satisfied(neighbors[1..].iter(), &params));
+        // Alone
+        assert!(agent.is_satisfied(neighbors[2..].iter(), &params));
+        assert!(!agent.is_satisfied(neighbors.iter(), &SchellingParams::new(0.6)));
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn relocation() {
+        use crate::{MooreCell2d, Schelling2dPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Schelling2dPlugin::new())
+            .insert_resource(SchellingParams::new(0.5));
+        let cells: Vec<_> = [
+            SchellingState::Agent(0),
+            SchellingState::Agent(1),
+            SchellingState::Agent(1),
+            SchellingState::Empty,
+            SchellingState::Empty,
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(state, x)| {
+            app.world
+                .spawn((MooreCell2d::new(IVec2::new(x, 0)), state))
+                .id()
+        })
+        .collect();
+        app.update();
+        let states: Vec<_> = cells
+            .iter()
+            .map(|e| *app.world.get::<SchellingState>(*e).unwrap())
+            .collect();
+        // Only the isolated agent moved to an empty cell
+        assert_eq!(states[0], SchellingState::Empty);
+        assert_eq!(states[1..3], [SchellingState::Agent(1); 2]);
+        assert_eq!(
+            states[3..]
+                .iter()
+                .filter(|s| **s == SchellingState::Agent(0))
+                .count(),
+            1
+        );
+    }
+}
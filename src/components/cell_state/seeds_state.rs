@@ -0,0 +1,93 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use std::ops::{Deref, DerefMut};
+
+/// [Seeds] cellular automaton state and rules, following the **B2/S** rule:
+///
+/// - Every live cell dies.
+/// - Any dead cell with exactly two live neighbours becomes a live cell.
+///
+/// Almost every pattern explodes into chaos, so nearly every cell changes on
+/// every generation.
+///
+/// A dead cell is `false`, a live cell is `true`
+///
+/// [Seeds]: https://conwaylife.com/wiki/OCA:Seeds
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct SeedsCellState(pub bool);
+
+impl CellState for SeedsCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        if self.0 {
+            return Self(false);
+        }
+        let alive_cells_count = neighbor_cells.filter(|&c| c.0).take(3).count();
+        Self(alive_cells_count == 2)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        if self.0 {
+            Some(Color::WHITE)
+        } else {
+            None
+        }
+    }
+}
+
+impl Deref for SeedsCellState {
+    type Target = bool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SeedsCellState {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<bool> for SeedsCellState {
+    fn from(val: bool) -> Self {
+        Self(val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn death_rule() {
+        let cell_state = SeedsCellState(true);
+
+        // 2 alive neighbors
+        let neighbors = [true.into(), true.into(), false.into(), false.into()];
+        let new_state = cell_state.new_cell_state(neighbors.iter());
+        assert!(!new_state.0);
+    }
+
+    #[test]
+    fn birth_rule() {
+        let cell_state = SeedsCellState(false);
+
+        // 2 alive neighbors
+        let neighbors = [true.into(), false.into(), true.into(), false.into()];
+        let new_state = cell_state.new_cell_state(neighbors.iter());
+        assert!(new_state.0);
+
+        // 3 alive neighbors
+        let neighbors = [true.into(), true.into(), true.into(), false.into()];
+        let new_state = cell_state.new_cell_state(neighbors.iter());
+        assert!(!new_state.0);
+
+        // 1 alive neighbor
+        let neighbors = [true.into(), false.into(), false.into(), false.into()];
+        let new_state = cell_state.new_cell_state(neighbors.iter());
+        assert!(!new_state.0);
+    }
+}
@@ -0,0 +1,427 @@
+use super::random_source;
+use crate::{
+    components::{
+        CellState, InfectableCellState, ParametricCellState, TraceableCellState,
+        VaccinableCellState,
+    },
+    resources::{CellRng, EpidemicParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// [SEIR] compartmental epidemic state and rules. The rules are the
+/// following:
+///
+/// - A susceptible cell is exposed with probability `transmission_probability`
+///   for each infectious neighbor.
+/// - An exposed cell becomes infectious after `incubation_period`
+///   generations.
+/// - An infectious cell recovers after `infectious_period` generations.
+/// - A recovered cell is immune.
+///
+/// The parameters are read from the [`EpidemicParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [SEIR]: https://en.wikipedia.org/wiki/Compartmental_models_in_epidemiology#The_SEIR_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum SeirCellState {
+    /// Susceptible cell, which may be exposed
+    #[default]
+    Susceptible,
+    /// Exposed cell with the amount of generations spent incubating
+    Exposed(u32),
+    /// Infectious cell with the amount of generations spent infectious
+    Infectious(u32),
+    /// Recovered and immune cell
+    Recovered,
+}
+
+impl SeirCellState {
+    /// Is the cell infectious
+    #[must_use]
+    #[inline]
+    pub const fn is_infectious(&self) -> bool {
+        matches!(self, Self::Infectious(_))
+    }
+}
+
+impl CellState for SeirCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &EpidemicParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Susceptible => None,
+            Self::Exposed(_) => Some(Color::ORANGE),
+            Self::Infectious(_) => Some(Color::RED),
+            Self::Recovered => Some(Color::SEA_GREEN),
+        }
+    }
+}
+
+impl ParametricCellState for SeirCellState {
+    type Params = EpidemicParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        match *self {
+            Self::Susceptible => {
+                let infectious = neighbor_cells.filter(|c| c.is_infectious()).count();
+                if infectious > 0 && rng.gen::<f64>() < params.infection_probability(infectious) {
+                    Self::Exposed(0)
+                } else {
+                    Self::Susceptible
+                }
+            }
+            Self::Exposed(ticks) if ticks + 1 >= params.incubation_period => Self::Infectious(0),
+            Self::Exposed(ticks) => Self::Exposed(ticks + 1),
+            Self::Infectious(ticks) if ticks + 1 >= params.infectious_period => Self::Recovered,
+            Self::Infectious(ticks) => Self::Infectious(ticks + 1),
+            Self::Recovered => Self::Recovered,
+        }
+    }
+}
+
+impl TraceableCellState for SeirCellState {
+    fn infection_source(&self, new_state: &Self, neighbor_cells: &[&Self]) -> Option<usize> {
+        if *self != Self::Susceptible || !new_state.is_infected() {
+            return None;
+        }
+        random_source(neighbor_cells, Self::is_infectious)
+    }
+}
+
+impl VaccinableCellState for SeirCellState {
+    fn is_infected(&self) -> bool {
+        matches!(self, Self::Exposed(_) | Self::Infectious(_))
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        match self {
+            Self::Susceptible if CellRng.gen::<f64>() < coverage => Self::Recovered,
+            state => *state,
+        }
+    }
+}
+
+impl InfectableCellState for SeirCellState {
+    fn import_infection(&self, cases: u32) -> Self {
+        match self {
+            Self::Susceptible if cases > 0 => Self::Exposed(0),
+            state => *state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disease_progression() {
+        let params = EpidemicParams::new(1.0, 2, 3);
+        let neighbors = [SeirCellState::Susceptible, SeirCellState::Infectious(1)];
+        let mut state = SeirCellState::Susceptible;
+        let mut history = vec![];
+        for _ in 0..7 {
+            state = state.new_cell_state_with_params(neighbors.iter(), &params);
+            history.push(state);
+        }
+        assert_eq!(
+            history,
+            vec![
+                SeirCellState::Exposed(0),
+                SeirCellState::Exposed(1),
+                SeirCellState::Infectious(0),
+                SeirCellState::Infectious(1),
+                SeirCellState::Infectious(2),
+                SeirCellState::Recovered,
+                SeirCellState::Recovered,
+            ]
+        );
+        // No infectious neighbor
+        assert_eq!(
+            SeirCellState::Susceptible.new_cell_state_with_params(neighbors[..1].iter(), &params),
+            SeirCellState::Susceptible
+        );
+        let params = EpidemicParams::new(0.0, 2, 3);
+        assert_eq!(
+            SeirCellState::Susceptible.new_cell_state_with_params(neighbors.iter(), &params),
+            SeirCellState::Susceptible
+        );
+    }
+
+    #[test]
+    fn infection_probability() {
+        let params = EpidemicParams::new(0.5, 2, 3);
+        assert!(params.infection_probability(0).abs() < f64::EPSILON);
+        assert!((params.infection_probability(1) - 0.5).abs() < f64::EPSILON);
+        assert!((params.infection_probability(2) - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn injected_rng() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let params = EpidemicParams::new(0.5, 2, 3);
+        let neighbors = [SeirCellState::Infectious(0)];
+        let history = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..32)
+                .map(|_| {
+                    SeirCellState::Susceptible.new_cell_state_with_params_rng(
+                        neighbors.iter(),
+                        &params,
+                        &mut rng,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        let states = history(3);
+        assert!(states.contains(&SeirCellState::Susceptible));
+        assert!(states.contains(&SeirCellState::Exposed(0)));
+        assert_eq!(history(3), states);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn outbreak() {
+        use crate::{MooreCell2d, Seir2dPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Seir2dPlugin::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, 1));
+        let cells: Vec<_> = (0..4)
+            .map(|x| {
+                let state = if x == 0 {
+                    SeirCellState::Infectious(0)
+                } else {
+                    SeirCellState::Susceptible
+                };
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, 0)), state))
+                    .id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<SeirCellState> {
+            cells
+                .iter()
+                .map(|e| *app.world.get::<SeirCellState>(*e).unwrap())
+                .collect()
+        };
+        app.update();
+        assert_eq!(
+            states(&app),
+            vec![
+                SeirCellState::Recovered,
+                SeirCellState::Exposed(0),
+                SeirCellState::Susceptible,
+                SeirCellState::Susceptible,
+            ]
+        );
+        for _ in 0..6 {
+            app.update();
+        }
+        // The epidemic went through the whole line
+        assert_eq!(states(&app), vec![SeirCellState::Recovered; 4]);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn reflected_params() {
+        use crate::{MooreCell2d, Seir2dPlugin};
+        use bevy::{prelude::*, reflect::ReflectMut};
+
+        let mut app = App::new();
+        app.add_plugins(Seir2dPlugin::new())
+            .insert_resource(EpidemicParams::new(0.0, 1, 1));
+        let patient = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::X), SeirCellState::Susceptible))
+            .id();
+        app.world
+            .spawn((MooreCell2d::new(IVec2::ZERO), SeirCellState::Infectious(0)));
+        // Edits the parameters like an inspector would
+        let reflect_resource = app
+            .world
+            .resource::<AppTypeRegistry>()
+            .read()
+            .get_type_data::<ReflectResource>(std::any::TypeId::of::<EpidemicParams>())
+            .unwrap()
+            .clone();
+        let mut params = reflect_resource.reflect_mut(&mut app.world).unwrap();
+        let ReflectMut::Struct(params) = params.reflect_mut() else {
+            panic!("parameters should be a struct");
+        };
+        *params
+            .field_mut("transmission_probability")
+            .and_then(|f| f.downcast_mut::<f64>())
+            .unwrap() = 1.0;
+        app.update();
+        assert_eq!(
+            app.world.get::<SeirCellState>(patient),
+            Some(&SeirCellState::Exposed(0))
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn ring_vaccination() {
+        use crate::{MooreCell2d, Seir2dPlugin, Vaccination, VaccinationPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins((
+            Seir2dPlugin::new(),
+            VaccinationPlugin::<MooreCell2d, SeirCellState>::new(),
+        ))
+        .insert_resource(EpidemicParams::new(1.0, 1, 10))
+        .insert_resource(Vaccination::<MooreCell2d>::ring(1.0));
+        app.world
+            .spawn((MooreCell2d::new(IVec2::ZERO), SeirCellState::Infectious(0)));
+        let contact = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::X), SeirCellState::Susceptible))
+            .id();
+        let remote = app
+            .world
+            .spawn((
+                MooreCell2d::new(IVec2::new(5, 0)),
+                SeirCellState::Susceptible,
+            ))
+            .id();
+        app.update();
+        // The contact is vaccinated before being exposed
+        assert_eq!(
+            app.world.get::<SeirCellState>(contact),
+            Some(&SeirCellState::Recovered)
+        );
+        assert_eq!(
+            app.world.get::<SeirCellState>(remote),
+            Some(&SeirCellState::Susceptible)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn transmission_events() {
+        use crate::{MooreCell2d, TracedCellularAutomatonPlugin, TransmissionEvent};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(TracedCellularAutomatonPlugin::<MooreCell2d, SeirCellState>::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, 10));
+        let cells: Vec<_> = [
+            SeirCellState::Infectious(0),
+            SeirCellState::Susceptible,
+            SeirCellState::Susceptible,
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(state, x)| {
+            app.world
+                .spawn((MooreCell2d::new(IVec2::new(x, 0)), state))
+                .id()
+        })
+        .collect();
+        let mut reader = app
+            .world
+            .resource::<Events<TransmissionEvent>>()
+            .get_reader();
+        let mut transmissions = vec![];
+        for _ in 0..3 {
+            app.update();
+            let events = app.world.resource::<Events<TransmissionEvent>>();
+            transmissions.extend(reader.read(events).copied());
+        }
+        // The chain is traced, the exposed cell infecting its other neighbor
+        // once infectious
+        assert_eq!(
+            transmissions,
+            vec![
+                TransmissionEvent {
+                    source: cells[0],
+                    target: cells[1],
+                    tick: 0
+                },
+                TransmissionEvent {
+                    source: cells[1],
+                    target: cells[2],
+                    tick: 2
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn filtered_transmissions() {
+        use crate::{
+            Generation, MooreCell2d, RegionOfInterest, TracedCellularAutomatonPlugin,
+            TransmissionEvent,
+        };
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(TracedCellularAutomatonPlugin::<MooreCell2d, SeirCellState>::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, 10))
+            .insert_resource(RegionOfInterest::new(IRect::new(0, 0, 1, 0)))
+            .insert_resource(Generation(5));
+        let cells: Vec<_> = [
+            SeirCellState::Infectious(0),
+            SeirCellState::Susceptible,
+            SeirCellState::Susceptible,
+        ]
+        .into_iter()
+        .zip(0..)
+        .map(|(state, x)| {
+            app.world
+                .spawn((MooreCell2d::new(IVec2::new(x, 0)), state))
+                .id()
+        })
+        .collect();
+        let mut reader = app
+            .world
+            .resource::<Events<TransmissionEvent>>()
+            .get_reader();
+        let mut transmissions = vec![];
+        for _ in 0..3 {
+            app.update();
+            let events = app.world.resource::<Events<TransmissionEvent>>();
+            transmissions.extend(reader.read(events).copied());
+        }
+        // The ticks follow the generation, and the cell out of the region of
+        // interest is never infected
+        assert_eq!(
+            transmissions,
+            vec![TransmissionEvent {
+                source: cells[0],
+                target: cells[1],
+                tick: 5
+            }]
+        );
+        assert_eq!(
+            app.world.get::<SeirCellState>(cells[2]),
+            Some(&SeirCellState::Susceptible)
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use super::random_source;
+use crate::{
+    components::{
+        CellState, InfectableCellState, ParametricCellState, TraceableCellState,
+        VaccinableCellState,
+    },
+    resources::{CellRng, SeverityParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// [SEIR] epidemic state with a severity pathway, for healthcare burden
+/// scenarios. The rules are the following:
+///
+/// - A susceptible cell is exposed with probability `transmission_probability`
+///   for each infectious neighbor, mild or severe.
+/// - An exposed cell becomes infectious after `incubation_period`
+///   generations, severe with probability `severe_probability` or mild.
+/// - A mild cell recovers after `infectious_period` generations.
+/// - A severe cell is then admitted in hospital with probability
+///   `admission_probability`. Otherwise it dies with probability
+///   `untreated_mortality` or recovers.
+/// - A hospitalized cell is isolated, and dies with probability
+///   `hospital_mortality` after `hospital_period` generations or recovers.
+///
+/// The parameters are read from the [`SeverityParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. The admissions follow the free beds
+/// of the [`HospitalCapacity`] with the [`HospitalCapacityPlugin`].
+///
+/// [SEIR]: https://en.wikipedia.org/wiki/Compartmental_models_in_epidemiology#The_SEIR_model
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+/// [`HospitalCapacity`]: crate::HospitalCapacity
+/// [`HospitalCapacityPlugin`]: crate::HospitalCapacityPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum SeverityCellState {
+    /// Susceptible cell, which may be exposed
+    #[default]
+    Susceptible,
+    /// Exposed cell with the amount of generations spent incubating
+    Exposed(u32),
+    /// Mild infectious cell with the amount of generations spent infectious
+    Mild(u32),
+    /// Severe infectious cell with the amount of generations spent infectious
+    Severe(u32),
+    /// Hospitalized cell with the amount of generations spent in hospital
+    Hospitalized(u32),
+    /// Recovered and immune cell
+    Recovered,
+    /// Dead cell
+    Dead,
+}
+
+impl SeverityCellState {
+    /// Is the cell infectious
+    #[must_use]
+    #[inline]
+    pub const fn is_infectious(&self) -> bool {
+        matches!(self, Self::Mild(_) | Self::Severe(_))
+    }
+
+    /// Is the severe cell waiting for a hospital bed, to be admitted during
+    /// the next generation ?
+    #[must_use]
+    #[inline]
+    pub const fn needs_bed(&self, params: &SeverityParams) -> bool {
+        matches!(self, Self::Severe(ticks) if *ticks + 1 >= params.epidemic.infectious_period)
+    }
+
+    /// Is the cell occupying a hospital bed after the next generation ?
+    #[must_use]
+    #[inline]
+    pub const fn keeps_bed(&self, params: &SeverityParams) -> bool {
+        matches!(self, Self::Hospitalized(ticks) if *ticks + 1 < params.hospital_period)
+    }
+}
+
+impl CellState for SeverityCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &SeverityParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Susceptible => None,
+            Self::Exposed(_) => Some(Color::ORANGE),
+            Self::Mild(_) => Some(Color::SALMON),
+            Self::Severe(_) => Some(Color::RED),
+            Self::Hospitalized(_) => Some(Color::PURPLE),
+            Self::Recovered => Some(Color::SEA_GREEN),
+            Self::Dead => Some(Color::BLACK),
+        }
+    }
+}
+
+impl ParametricCellState for SeverityCellState {
+    type Params = SeverityParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let epidemic = &params.epidemic;
+        match *self {
+            Self::Susceptible => {
+                let infectious = neighbor_cells.filter(|c| c.is_infectious()).count();
+                if infectious > 0 && rng.gen::<f64>() < epidemic.infection_probability(infectious) {
+                    Self::Exposed(0)
+                } else {
+                    Self::Susceptible
+                }
+            }
+            Self::Exposed(ticks) if ticks + 1 >= epidemic.incubation_period => {
+                if rng.gen::<f64>() < params.severe_probability {
+                    Self::Severe(0)
+                } else {
+                    Self::Mild(0)
+                }
+            }
+            Self::Exposed(ticks) => Self::Exposed(ticks + 1),
+            Self::Mild(ticks) if ticks + 1 >= epidemic.infectious_period => Self::Recovered,
+            Self::Mild(ticks) => Self::Mild(ticks + 1),
+            Self::Severe(ticks) if ticks + 1 >= epidemic.infectious_period => {
+                if rng.gen::<f64>() < params.admission_probability {
+                    Self::Hospitalized(0)
+                } else if rng.gen::<f64>() < params.untreated_mortality {
+                    Self::Dead
+                } else {
+                    Self::Recovered
+                }
+            }
+            Self::Severe(ticks) => Self::Severe(ticks + 1),
+            Self::Hospitalized(ticks) if ticks + 1 >= params.hospital_period => {
+                if rng.gen::<f64>() < params.hospital_mortality {
+                    Self::Dead
+                } else {
+                    Self::Recovered
+                }
+            }
+            Self::Hospitalized(ticks) => Self::Hospitalized(ticks + 1),
+            Self::Recovered => Self::Recovered,
+            Self::Dead => Self::Dead,
+        }
+    }
+}
+
+impl TraceableCellState for SeverityCellState {
+    fn infection_source(&self, new_state: &Self, neighbor_cells: &[&Self]) -> Option<usize> {
+        if *self != Self::Susceptible || !new_state.is_infected() {
+            return None;
+        }
+        random_source(neighbor_cells, Self::is_infectious)
+    }
+}
+
+impl VaccinableCellState for SeverityCellState {
+    fn is_infected(&self) -> bool {
+        matches!(
+            self,
+            Self::Exposed(_) | Self::Mild(_) | Self::Severe(_) | Self::Hospitalized(_)
+        )
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        match self {
+            Self::Susceptible if CellRng.gen::<f64>() < coverage => Self::Recovered,
+            state => *state,
+        }
+    }
+}
+
+impl InfectableCellState for SeverityCellState {
+    fn import_infection(&self, cases: u32) -> Self {
+        match self {
+            Self::Susceptible if cases > 0 => Self::Exposed(0),
+            state => *state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resources::EpidemicParams;
+
+    #[test]
+    fn severe_pathway() {
+        let params = SeverityParams::new(EpidemicParams::new(1.0, 1, 2), 1.0)
+            .with_hospital_period(2)
+            .with_mortality(0.0, 1.0);
+        let infectious = [SeverityCellState::Mild(0)];
+        let mut state = SeverityCellState::Susceptible;
+        let mut pathway = vec![];
+        for _ in 0..6 {
+            state = state.new_cell_state_with_params(infectious.iter(), &params);
+            pathway.push(state);
+        }
+        assert_eq!(
+            pathway,
+            [
+                SeverityCellState::Exposed(0),
+                SeverityCellState::Severe(0),
+                SeverityCellState::Severe(1),
+                SeverityCellState::Hospitalized(0),
+                SeverityCellState::Hospitalized(1),
+                SeverityCellState::Recovered,
+            ]
+        );
+        // Hospitalized cells aren't infectious
+        assert_eq!(
+            SeverityCellState::Susceptible.new_cell_state_with_params(
+                std::iter::once(&SeverityCellState::Hospitalized(0)),
+                &params
+            ),
+            SeverityCellState::Susceptible
+        );
+        // Without a bed
+        let params = SeverityParams {
+            admission_probability: 0.0,
+            ..params
+        };
+        assert_eq!(
+            SeverityCellState::Severe(1).new_cell_state_with_params(std::iter::empty(), &params),
+            SeverityCellState::Dead
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn saturated_hospitals() {
+        use crate::{HospitalCapacity, HospitalCapacityPlugin, MooreCell2d, Severity2dPlugin};
+        use bevy::prelude::*;
+
+        for (beds, expected) in [
+            (3, SeverityCellState::Hospitalized(0)),
+            (0, SeverityCellState::Dead),
+        ] {
+            let mut app = App::new();
+            app.add_plugins((Severity2dPlugin::new(), HospitalCapacityPlugin::new()))
+                .insert_resource(HospitalCapacity::new(beds))
+                .insert_resource(
+                    SeverityParams::new(EpidemicParams::new(0.0, 1, 1), 0.0)
+                        .with_mortality(0.0, 1.0),
+                );
+            for x in 0..3 {
+                app.world.spawn((
+                    MooreCell2d::new(IVec2::new(x * 10, 0)),
+                    SeverityCellState::Severe(0),
+                ));
+            }
+            app.update();
+            let capacity = app.world.resource::<HospitalCapacity>();
+            assert_eq!(capacity.waiting, 3);
+            assert!(capacity.saturation() >= 1.0);
+            let states: Vec<_> = app
+                .world
+                .query::<&SeverityCellState>()
+                .iter(&app.world)
+                .copied()
+                .collect();
+            assert_eq!(states, vec![expected; 3]);
+            app.update();
+            let capacity = app.world.resource::<HospitalCapacity>();
+            assert_eq!(capacity.occupied, if beds == 0 { 0 } else { 3 });
+            assert_eq!(capacity.waiting, 0);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use super::random_source;
+use crate::{
+    components::{
+        CellState, InfectableCellState, ParametricCellState, TraceableCellState,
+        VaccinableCellState,
+    },
+    resources::{CellRng, EpidemicParams},
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::Rng;
+
+/// [SIRS] compartmental epidemic state and rules, with waning immunity. The
+/// rules are the following:
+///
+/// - A susceptible cell is infected with probability
+///   `transmission_probability` for each infectious neighbor.
+/// - An infectious cell recovers after `infectious_period` generations.
+/// - A recovered cell is immune for `immunity_period` generations, then
+///   becomes susceptible again with probability `waning_probability` every
+///   generation.
+///
+/// Unlike the [`SeirCellState`], the disease may come back to recovered
+/// areas, showing recurrent waves and endemic equilibria.
+///
+/// The parameters are read from the [`EpidemicParams`] resource through the
+/// [`ParametricCellularAutomatonPlugin`]. With a regular plugin, the default
+/// parameters are used.
+///
+/// [SIRS]: https://en.wikipedia.org/wiki/Compartmental_models_in_epidemiology#The_SIRS_model
+/// [`SeirCellState`]: crate::SeirCellState
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum SirsCellState {
+    /// Susceptible cell, which may be infected
+    #[default]
+    Susceptible,
+    /// Infectious cell with the amount of generations spent infectious
+    Infectious(u32),
+    /// Recovered cell with the amount of generations spent immune
+    Recovered(u32),
+}
+
+impl SirsCellState {
+    /// Is the cell infectious
+    #[must_use]
+    #[inline]
+    pub const fn is_infectious(&self) -> bool {
+        matches!(self, Self::Infectious(_))
+    }
+}
+
+impl CellState for SirsCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &EpidemicParams::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Susceptible => None,
+            Self::Infectious(_) => Some(Color::RED),
+            Self::Recovered(_) => Some(Color::SEA_GREEN),
+        }
+    }
+}
+
+impl ParametricCellState for SirsCellState {
+    type Params = EpidemicParams;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        self.new_cell_state_with_params_rng(neighbor_cells, params, &mut CellRng)
+    }
+
+    fn new_cell_state_with_params_rng<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+        rng: &mut impl Rng,
+    ) -> Self {
+        match *self {
+            Self::Susceptible => {
+                let infectious = neighbor_cells.filter(|c| c.is_infectious()).count();
+                if infectious > 0 && rng.gen::<f64>() < params.infection_probability(infectious) {
+                    Self::Infectious(0)
+                } else {
+                    Self::Susceptible
+                }
+            }
+            Self::Infectious(ticks) if ticks + 1 >= params.infectious_period => Self::Recovered(0),
+            Self::Infectious(ticks) => Self::Infectious(ticks + 1),
+            Self::Recovered(ticks)
+                if ticks + 1 >= params.immunity_period
+                    && rng.gen::<f64>() < params.waning_probability =>
+            {
+                Self::Susceptible
+            }
+            Self::Recovered(ticks) => Self::Recovered(ticks.saturating_add(1)),
+        }
+    }
+}
+
+impl TraceableCellState for SirsCellState {
+    fn infection_source(&self, new_state: &Self, neighbor_cells: &[&Self]) -> Option<usize> {
+        if *self != Self::Susceptible || !new_state.is_infectious() {
+            return None;
+        }
+        random_source(neighbor_cells, Self::is_infectious)
+    }
+}
+
+impl VaccinableCellState for SirsCellState {
+    fn is_infected(&self) -> bool {
+        self.is_infectious()
+    }
+
+    fn vaccinate(&self, coverage: f64) -> Self {
+        match self {
+            Self::Susceptible if CellRng.gen::<f64>() < coverage => Self::Recovered(0),
+            state => *state,
+        }
+    }
+}
+
+impl InfectableCellState for SirsCellState {
+    fn import_infection(&self, cases: u32) -> Self {
+        match self {
+            Self::Susceptible if cases > 0 => Self::Infectious(0),
+            state => *state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn waning_immunity() {
+        let params = EpidemicParams::new(1.0, 1, 2).with_waning(2, 1.0);
+        let neighbors = [SirsCellState::Infectious(0)];
+        let mut state = SirsCellState::Susceptible;
+        let mut history = vec![];
+        for _ in 0..6 {
+            state = state.new_cell_state_with_params(neighbors.iter(), &params);
+            history.push(state);
+        }
+        assert_eq!(
+            history,
+            vec![
+                SirsCellState::Infectious(0),
+                SirsCellState::Infectious(1),
+                SirsCellState::Recovered(0),
+                SirsCellState::Recovered(1),
+                SirsCellState::Susceptible,
+                SirsCellState::Infectious(0),
+            ]
+        );
+        // Immunity never wanes
+        let params = params.with_waning(2, 0.0);
+        assert_eq!(
+            SirsCellState::Recovered(5).new_cell_state_with_params(neighbors.iter(), &params),
+            SirsCellState::Recovered(6)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn lost_immunity() {
+        use crate::{MooreCell2d, Sirs2dPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(Sirs2dPlugin::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, 1).with_waning(1, 1.0));
+        let cells: Vec<_> = [SirsCellState::Infectious(0), SirsCellState::Susceptible]
+            .into_iter()
+            .zip(0..)
+            .map(|(state, x)| {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, 0)), state))
+                    .id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<SirsCellState> {
+            cells
+                .iter()
+                .map(|e| *app.world.get::<SirsCellState>(*e).unwrap())
+                .collect()
+        };
+        let mut history = vec![];
+        for _ in 0..3 {
+            app.update();
+            history.push(states(&app));
+        }
+        // Once the disease is gone, every cell is susceptible again
+        assert_eq!(
+            history,
+            vec![
+                vec![SirsCellState::Recovered(0), SirsCellState::Infectious(0)],
+                vec![SirsCellState::Susceptible, SirsCellState::Recovered(0)],
+                vec![SirsCellState::Susceptible, SirsCellState::Susceptible],
+            ]
+        );
+    }
+}
@@ -0,0 +1,162 @@
+use crate::components::{CellState, WeightedCellState};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// [SmoothLife] transition function parameters.
+///
+/// The transition maps the inner disc filling `m` and the outer ring filling
+/// `n` of a cell to its new value, with smooth sigmoid steps: a cell is born
+/// if `n` is within the `birth` interval and survives if `n` is within the
+/// `death` interval, `m` blending both intervals.
+///
+/// The default parameters are the ones of the original paper, to use with a
+/// `Kernel2d::smooth_life(7.0, 21.0)` kernel.
+///
+/// [SmoothLife]: https://arxiv.org/abs/1111.1567
+#[derive(Debug, Copy, Clone, PartialEq, Reflect)]
+pub struct SmoothLifeRule {
+    /// Outer filling interval of birth
+    pub birth: (f32, f32),
+    /// Outer filling interval of survival
+    pub death: (f32, f32),
+    /// Step width of the outer filling sigmoids
+    pub alpha_n: f32,
+    /// Step width of the inner filling sigmoid
+    pub alpha_m: f32,
+    /// Fraction of the transition applied every tick, `1.0` being the discrete
+    /// time rule
+    pub time_step: f32,
+}
+
+impl Default for SmoothLifeRule {
+    fn default() -> Self {
+        Self {
+            birth: (0.278, 0.365),
+            death: (0.267, 0.445),
+            alpha_n: 0.028,
+            alpha_m: 0.147,
+            time_step: 1.0,
+        }
+    }
+}
+
+impl SmoothLifeRule {
+    /// New cell value for an inner filling `m` and an outer filling `n`, in
+    /// `[0, 1]`
+    #[must_use]
+    pub fn transition(&self, m: f32, n: f32) -> f32 {
+        let alive = sigmoid(m, 0.5, self.alpha_m);
+        let lower = self.birth.0.mul_add(1.0 - alive, self.death.0 * alive);
+        let upper = self.birth.1.mul_add(1.0 - alive, self.death.1 * alive);
+        sigmoid(n, lower, self.alpha_n) * (1.0 - sigmoid(n, upper, self.alpha_n))
+    }
+}
+
+#[inline]
+fn sigmoid(x: f32, a: f32, alpha: f32) -> f32 {
+    1.0 / (1.0 + (-(x - a) * 4.0 / alpha).exp())
+}
+
+/// [SmoothLife] continuous cellular automaton state, a continuous
+/// generalization of Conway's Game of Life.
+///
+/// Every cell has a `value` in `[0, 1]`. Each tick, the inner disc and outer
+/// ring integrals of the cell are computed and mapped to a new value by the
+/// [`SmoothLifeRule::transition`].
+///
+/// This state is meant to be used with a `KernelCell2d` and a
+/// `Kernel2d::smooth_life` kernel in the [`WeightedCellularAutomatonPlugin`]:
+/// the kernel has positive weights for the inner disc and negative weights for
+/// the outer ring, allowing both integrals in a single pass.
+///
+/// [SmoothLife]: https://arxiv.org/abs/1111.1567
+/// [`WeightedCellularAutomatonPlugin`]: crate::WeightedCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, PartialEq, Component, Reflect)]
+pub struct SmoothLifeState {
+    /// The cell value, in `[0, 1]`
+    pub value: f32,
+    /// The transition rule followed by the cell
+    pub rule: SmoothLifeRule,
+}
+
+impl SmoothLifeState {
+    /// Instantiates a new state with `value` following `rule`
+    #[must_use]
+    #[inline]
+    pub const fn new(value: f32, rule: SmoothLifeRule) -> Self {
+        Self { value, rule }
+    }
+
+    #[inline]
+    fn with_fillings(self, m: f32, n: f32) -> Self {
+        let target = self.rule.transition(m, n);
+        let value = self.rule.time_step.mul_add(target - self.value, self.value);
+        Self::new(value.clamp(0.0, 1.0), self.rule)
+    }
+}
+
+impl CellState for SmoothLifeState {
+    /// Without weights, the cell is its own inner disc and every neighbor is in
+    /// the outer ring
+    #[allow(clippy::cast_precision_loss)]
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        let (sum, count) =
+            neighbor_cells.fold((0.0, 0), |(sum, count), c| (sum + c.value, count + 1));
+        let n = if count > 0 { sum / count as f32 } else { 0.0 };
+        self.with_fillings(self.value, n)
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        (self.value > 0.01).then(|| Color::rgb(self.value, self.value, self.value))
+    }
+}
+
+impl WeightedCellState for SmoothLifeState {
+    fn new_cell_state_weighted<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = (&'a Self, f32)>,
+    ) -> Self {
+        let (m, n) = neighbor_cells.fold((0.0, 0.0), |(m, n), (c, w)| {
+            if w > 0.0 {
+                (c.value.mul_add(w, m), n)
+            } else {
+                (m, c.value.mul_add(-w, n))
+            }
+        });
+        self.with_fillings(m, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition() {
+        let rule = SmoothLifeRule::default();
+        // Birth in a dead region with a moderately filled ring
+        assert!(rule.transition(0.0, 0.32) > 0.9);
+        assert!(rule.transition(0.0, 0.1) < 0.1);
+        assert!(rule.transition(0.0, 0.42) < 0.1);
+        // Survival of a live region, and death by overcrowding
+        assert!(rule.transition(1.0, 0.42) > 0.9);
+        assert!(rule.transition(1.0, 0.6) < 0.1);
+        assert!(rule.transition(1.0, 0.1) < 0.1);
+    }
+
+    #[test]
+    fn weighted_rules() {
+        let rule = SmoothLifeRule::default();
+        let dead = SmoothLifeState::new(0.0, rule);
+        let alive = SmoothLifeState::new(1.0, rule);
+        // Inner disc with positive weights, outer ring with negative weights
+        let neighbors = [(&dead, 1.0), (&alive, -0.32), (&dead, -0.68)];
+        let new_state = dead.new_cell_state_weighted(neighbors.into_iter());
+        assert!(new_state.value > 0.9);
+        let neighbors = [(&alive, 1.0), (&alive, -0.6), (&dead, -0.4)];
+        let new_state = alive.new_cell_state_weighted(neighbors.into_iter());
+        assert!(new_state.value < 0.1);
+    }
+}
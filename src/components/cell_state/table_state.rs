@@ -0,0 +1,153 @@
+use crate::{
+    components::{CellState, ParametricCellState},
+    resources::RuleTable,
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// Cellular automaton state following the transitions of a [`RuleTable`]
+/// resource, allowing constructed automata like Codd's or Nobili's to run
+/// from their Golly rule table:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let table: RuleTable = std::fs::read_to_string("Codd.rule")
+///     .unwrap()
+///     .parse()
+///     .unwrap();
+/// App::new()
+///     .add_plugins(ParametricCellularAutomatonPlugin::<NeumannCell2d, TableCellState>::new())
+///     .insert_resource(table);
+/// ```
+///
+/// The table neighborhood must match the cell type, missing neighbors at the
+/// edge of the map are considered in state `0`. The table is read through the
+/// [`ParametricCellularAutomatonPlugin`], with a regular plugin the cells
+/// never change.
+///
+/// [`ParametricCellularAutomatonPlugin`]: crate::ParametricCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub struct TableCellState(pub u8);
+
+impl CellState for TableCellState {
+    fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.new_cell_state_with_params(neighbor_cells, &RuleTable::default())
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self.0 {
+            0 => None,
+            s => Some(Color::hsl((f32::from(s) * 137.5) % 360.0, 0.8, 0.5)),
+        }
+    }
+}
+
+impl ParametricCellState for TableCellState {
+    type Params = RuleTable;
+
+    fn new_cell_state_with_params<'a>(
+        &self,
+        neighbor_cells: impl Iterator<Item = &'a Self>,
+        params: &Self::Params,
+    ) -> Self {
+        let neighbors = neighbor_cells
+            .map(|c| c.0)
+            .chain(std::iter::repeat(0))
+            .take(params.neighbors());
+        params.transition(self.0, neighbors).map_or(*self, Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIRE_WORLD: &str = "
+@RULE WireWorld
+@TABLE
+n_states:4
+neighborhood:Moore
+symmetries:permute
+var a={0,1,2,3}
+var b=a
+var c=a
+var d=a
+var e=a
+var f=a
+var g=a
+var h=a
+var o={0,2,3}
+var p=o
+var q=o
+var r=o
+var s=o
+var t=o
+var u=o
+1,a,b,c,d,e,f,g,h,2
+2,a,b,c,d,e,f,g,h,3
+3,1,o,p,q,r,s,t,u,1
+3,1,1,o,p,q,r,s,t,1
+";
+
+    #[test]
+    fn table_rules() {
+        let table: RuleTable = WIRE_WORLD.parse().unwrap();
+        let neighbors = [1, 3, 3, 0, 2, 0, 1, 3].map(TableCellState);
+        assert_eq!(
+            TableCellState(3).new_cell_state_with_params(neighbors.iter(), &table),
+            TableCellState(1)
+        );
+        // Missing neighbors are empty
+        assert_eq!(
+            TableCellState(3).new_cell_state_with_params(neighbors[..2].iter(), &table),
+            TableCellState(1)
+        );
+        // Too many electrons
+        let neighbors = [1, 1, 1, 0, 0, 0, 0, 0].map(TableCellState);
+        assert_eq!(
+            TableCellState(3).new_cell_state_with_params(neighbors.iter(), &table),
+            TableCellState(3)
+        );
+        // Without the table
+        assert_eq!(
+            TableCellState(1).new_cell_state(neighbors.iter()),
+            TableCellState(1)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn wire() {
+        use crate::{MooreCell2d, ParametricCellularAutomatonPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(ParametricCellularAutomatonPlugin::<
+            MooreCell2d,
+            TableCellState,
+        >::new())
+            .insert_resource(WIRE_WORLD.parse::<RuleTable>().unwrap());
+        let cells: Vec<_> = [2, 1, 3, 3, 3, 3]
+            .into_iter()
+            .zip(0..)
+            .map(|(state, x)| {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, 0)), TableCellState(state)))
+                    .id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<u8> {
+            cells
+                .iter()
+                .map(|e| app.world.get::<TableCellState>(*e).unwrap().0)
+                .collect()
+        };
+        app.update();
+        assert_eq!(states(&app), vec![3, 2, 1, 3, 3, 3]);
+        app.update();
+        assert_eq!(states(&app), vec![3, 3, 2, 1, 3, 3]);
+    }
+}
@@ -0,0 +1,114 @@
+use crate::components::CellState;
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+
+/// [Nagel–Schreckenberg] traffic state: an empty road cell or a car with its
+/// velocity, in cells per generation.
+///
+/// Every generation, each car:
+///
+/// 1. accelerates by one, up to the maximum velocity
+/// 2. brakes to avoid reaching the next car
+/// 3. randomly slows down by one
+/// 4. moves forward (towards increasing coordinates) by its velocity
+///
+/// The maximum velocity and the slowdown probability are read from the
+/// [`TrafficParams`] resource. Cars move further than their neighbors, so
+/// this state must be used with the [`TrafficPlugin`]. With a regular plugin
+/// the cars never move.
+///
+/// [Nagel–Schreckenberg]: https://en.wikipedia.org/wiki/Nagel%E2%80%93Schreckenberg_model
+/// [`TrafficParams`]: crate::TrafficParams
+/// [`TrafficPlugin`]: crate::TrafficPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum TrafficState {
+    /// Empty road
+    #[default]
+    Empty,
+    /// Car with its velocity
+    Car(u8),
+}
+
+impl CellState for TrafficState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        *self
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            // From red for stopped cars to green for fast ones
+            Self::Car(velocity) => {
+                let speed = (f32::from(*velocity) / 5.0).min(1.0);
+                Some(Color::rgb(1.0 - speed, speed, 0.1))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BoundaryCondition, Cell1d, MapSize, TrafficParams, TrafficPlugin, TrafficStats};
+    use bevy::prelude::*;
+
+    fn spawn_road(app: &mut App, length: i32, cars: &[(i32, u8)]) -> Vec<Entity> {
+        (0..length)
+            .map(|x| {
+                let state = cars
+                    .iter()
+                    .find(|(c, _)| *c == x)
+                    .map_or(TrafficState::Empty, |(_, v)| TrafficState::Car(*v));
+                app.world.spawn((Cell1d::new(x), state)).id()
+            })
+            .collect()
+    }
+
+    fn cars(app: &App, road: &[Entity]) -> Vec<(usize, u8)> {
+        road.iter()
+            .enumerate()
+            .filter_map(|(x, e)| match app.world.get::<TrafficState>(*e) {
+                Some(TrafficState::Car(v)) => Some((x, *v)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn circular_road() {
+        let mut app = App::new();
+        app.add_plugins(TrafficPlugin::new())
+            .insert_resource(TrafficParams::new(5, 0.0))
+            .insert_resource(BoundaryCondition::Wrap)
+            .insert_resource(MapSize::new(10, 1));
+        let road = spawn_road(&mut app, 10, &[(3, 0), (4, 0), (8, 4)]);
+        app.update();
+        // The last car wraps around and brakes behind the first one, which
+        // brakes behind the second one
+        assert_eq!(cars(&app, &road), vec![(2, 4), (3, 0), (5, 1)]);
+        assert_eq!(
+            *app.world.resource::<TrafficStats>(),
+            TrafficStats {
+                cells: 10,
+                cars: 3,
+                total_velocity: 5
+            }
+        );
+        app.update();
+        assert_eq!(cars(&app, &road), vec![(2, 0), (4, 1), (7, 2)]);
+    }
+
+    #[test]
+    fn open_road() {
+        let mut app = App::new();
+        app.add_plugins(TrafficPlugin::new())
+            .insert_resource(TrafficParams::new(2, 0.0));
+        let road = spawn_road(&mut app, 4, &[(1, 1), (3, 0)]);
+        app.update();
+        // The leading car drove out of the road
+        assert_eq!(cars(&app, &road), vec![(2, 1)]);
+        assert_eq!(app.world.resource::<TrafficStats>().cars, 1);
+    }
+}
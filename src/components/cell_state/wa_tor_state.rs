@@ -0,0 +1,208 @@
+use crate::{
+    components::{CellMove, CellState, MovingCellState},
+    resources::CellRng,
+};
+use bevy::prelude::{Component, Reflect};
+#[cfg(feature = "auto-coloring")]
+use bevy::render::color::Color;
+use rand::{seq::IteratorRandom, Rng};
+
+/// [Wa-Tor] predator-prey cellular automaton state and rules. The rules are
+/// the following:
+///
+/// - A fish moves to a random empty neighbor. Once old enough, it leaves a
+///   new fish behind when moving.
+/// - A shark eats a random neighbor fish, or moves to a random empty neighbor
+///   if there is none. Every move costs energy and eating a fish brings some
+///   back, a shark without energy dies. Once old enough, it leaves a new shark
+///   behind when moving.
+///
+/// Fish and sharks move by claiming a neighbor cell, so this state must be
+/// used with the [`MovingCellularAutomatonPlugin`]. With a regular plugin the
+/// animals only age, without moving.
+///
+/// [Wa-Tor]: https://en.wikipedia.org/wiki/Wa-Tor
+/// [`MovingCellularAutomatonPlugin`]: crate::MovingCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, Default, Eq, Hash, PartialEq, Component, Reflect)]
+pub enum WaTorState {
+    /// Empty water
+    #[default]
+    Empty,
+    /// Fish with its age
+    Fish(u32),
+    /// Shark with its age and energy
+    Shark(u32, u32),
+}
+
+impl WaTorState {
+    /// Age at which a fish reproduces
+    pub const FISH_BREEDING_AGE: u32 = 3;
+    /// Age at which a shark reproduces
+    pub const SHARK_BREEDING_AGE: u32 = 10;
+    /// Energy of a newborn shark
+    pub const SHARK_INITIAL_ENERGY: u32 = 3;
+    /// Energy gained by a shark eating a fish
+    pub const SHARK_FOOD_ENERGY: u32 = 2;
+
+    /// A newborn fish
+    #[must_use]
+    #[inline]
+    pub const fn fish() -> Self {
+        Self::Fish(0)
+    }
+
+    /// A newborn shark
+    #[must_use]
+    #[inline]
+    pub const fn shark() -> Self {
+        Self::Shark(0, Self::SHARK_INITIAL_ENERGY)
+    }
+
+    /// Moves the animal to the `target` neighbor, leaving a newborn behind if
+    /// it is old enough
+    const fn move_to(self, target: usize) -> CellMove<Self> {
+        match self {
+            Self::Fish(age) if age >= Self::FISH_BREEDING_AGE => {
+                CellMove::claim(Self::fish(), target, Self::Fish(0))
+            }
+            Self::Shark(age, energy) if age >= Self::SHARK_BREEDING_AGE => {
+                CellMove::claim(Self::shark(), target, Self::Shark(0, energy))
+            }
+            _ => CellMove::claim(Self::Empty, target, self),
+        }
+    }
+}
+
+impl CellState for WaTorState {
+    fn new_cell_state<'a>(&self, _neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
+        self.move_cell(&[]).origin
+    }
+
+    #[cfg(feature = "auto-coloring")]
+    fn color(&self) -> Option<Color> {
+        match self {
+            Self::Empty => None,
+            Self::Fish(_) => Some(Color::LIME_GREEN),
+            Self::Shark(..) => Some(Color::RED),
+        }
+    }
+}
+
+impl MovingCellState for WaTorState {
+    fn move_cell(&self, neighbor_cells: &[Option<&Self>]) -> CellMove<Self> {
+        self.move_cell_rng(neighbor_cells, &mut CellRng)
+    }
+
+    fn move_cell_rng(
+        &self,
+        neighbor_cells: &[Option<&Self>],
+        rng: &mut impl Rng,
+    ) -> CellMove<Self> {
+        let mut random_neighbor = |filter: fn(&Self) -> bool| {
+            neighbor_cells
+                .iter()
+                .enumerate()
+                .filter(|(_, c)| c.is_some_and(filter))
+                .map(|(i, _)| i)
+                .choose(rng)
+        };
+        match *self {
+            Self::Empty => CellMove::stay(Self::Empty),
+            Self::Fish(age) => {
+                let age = age + 1;
+                random_neighbor(|c| *c == Self::Empty).map_or_else(
+                    || CellMove::stay(Self::Fish(age)),
+                    |target| Self::Fish(age).move_to(target),
+                )
+            }
+            Self::Shark(age, energy) => {
+                let age = age + 1;
+                let Some(energy) = energy.checked_sub(1).filter(|e| *e > 0) else {
+                    return CellMove::stay(Self::Empty);
+                };
+                let (target, energy) = random_neighbor(|c| matches!(c, Self::Fish(_))).map_or_else(
+                    || (random_neighbor(|c| *c == Self::Empty), energy),
+                    |prey| (Some(prey), energy + Self::SHARK_FOOD_ENERGY),
+                );
+                let shark = Self::Shark(age, energy);
+                target.map_or_else(|| CellMove::stay(shark), |target| shark.move_to(target))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fish_rules() {
+        let fish = WaTorState::Fish(0);
+        // Trapped
+        let neighbors = [Some(&WaTorState::fish()), Some(&WaTorState::shark()), None];
+        assert_eq!(
+            fish.move_cell(&neighbors),
+            CellMove::stay(WaTorState::Fish(1))
+        );
+        // Moving to the only empty cell
+        let neighbors = [Some(&WaTorState::fish()), Some(&WaTorState::Empty)];
+        assert_eq!(
+            fish.move_cell(&neighbors),
+            CellMove::claim(WaTorState::Empty, 1, WaTorState::Fish(1))
+        );
+        // Breeding
+        let fish = WaTorState::Fish(WaTorState::FISH_BREEDING_AGE - 1);
+        assert_eq!(
+            fish.move_cell(&neighbors),
+            CellMove::claim(WaTorState::fish(), 1, WaTorState::fish())
+        );
+    }
+
+    #[test]
+    fn shark_rules() {
+        let shark = WaTorState::Shark(0, 3);
+        // Eating the fish rather than moving to the empty cell
+        let neighbors = [Some(&WaTorState::Empty), Some(&WaTorState::Fish(2))];
+        assert_eq!(
+            shark.move_cell(&neighbors),
+            CellMove::claim(WaTorState::Empty, 1, WaTorState::Shark(1, 4))
+        );
+        // Moving without food
+        let neighbors = [Some(&WaTorState::Empty)];
+        assert_eq!(
+            shark.move_cell(&neighbors),
+            CellMove::claim(WaTorState::Empty, 0, WaTorState::Shark(1, 2))
+        );
+        // Starving
+        let shark = WaTorState::Shark(0, 1);
+        assert_eq!(
+            shark.move_cell(&neighbors),
+            CellMove::stay(WaTorState::Empty)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn predation() {
+        use crate::{MovingCellularAutomatonPlugin, NeumannCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(MovingCellularAutomatonPlugin::<NeumannCell2d, WaTorState>::new());
+        let shark = app
+            .world
+            .spawn((NeumannCell2d::new(IVec2::ZERO), WaTorState::Shark(0, 3)))
+            .id();
+        let fish = app
+            .world
+            .spawn((NeumannCell2d::new(IVec2::X), WaTorState::fish()))
+            .id();
+        app.update();
+        // Whatever the update order, the trapped fish is eaten
+        assert_eq!(app.world.get::<WaTorState>(shark), Some(&WaTorState::Empty));
+        assert_eq!(
+            app.world.get::<WaTorState>(fish),
+            Some(&WaTorState::Shark(1, 4))
+        );
+    }
+}
@@ -3,6 +3,8 @@ use bevy::prelude::{Component, Reflect};
 #[cfg(feature = "auto-coloring")]
 use bevy::render::color::Color;
 
+/// Wireworld cellular automaton state and rules.
+///
 /// Wireworld is a cellular automaton that simulates electronic devices and
 /// logic gates by having cells represent electrons traveling across conductors.
 /// Wireworld uses three possible cell states and has the following rules:
@@ -13,9 +15,10 @@ use bevy::render::color::Color;
 /// - Conductors (`Conductor`) become electron heads if exactly one or two
 ///   neighboring cells are electron heads. Otherwise, they remain as
 ///   conductors.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Component, Reflect)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Component, Reflect, Default)]
 pub enum WireWorldCellState {
     /// Conductor cell state
+    #[default]
     Conductor,
     /// Electron head cell state
     ElectronHead,
@@ -23,12 +26,6 @@ pub enum WireWorldCellState {
     ElectronTail,
 }
 
-impl Default for WireWorldCellState {
-    fn default() -> Self {
-        Self::Conductor
-    }
-}
-
 impl CellState for WireWorldCellState {
     fn new_cell_state<'a>(&self, neighbor_cells: impl Iterator<Item = &'a Self>) -> Self {
         match self {
@@ -0,0 +1,11 @@
+use bevy::prelude::{Component, Reflect};
+
+/// Frozen sprite copy of a past generation cell, spawned when a
+/// [`GenerationScroll`] resource is present.
+///
+/// [`GenerationScroll`]: crate::GenerationScroll
+#[derive(Debug, Clone, Component, Reflect)]
+pub struct GenerationTrail {
+    /// Amount of generations since the copy was made
+    pub age: u32,
+}
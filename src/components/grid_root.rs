@@ -0,0 +1,148 @@
+use bevy::prelude::{Component, Deref, Entity};
+
+/// Root entity of the independent grid of a cell.
+///
+/// The cells of different grids never see each other, even at the same
+/// coordinates, so two or more maps of the same cell and state types may run
+/// side by side, like for comparing two parameter sets with [`GridParams`].
+/// The [`CellMap`] and the state snapshots of the rules are scoped per grid,
+/// and the cells without `GridRoot` form the default grid. The root is usually
+/// the parent entity of the cells.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn spawn_grids(mut commands: Commands) {
+///     for _ in 0..2 {
+///         let root = commands.spawn(SpatialBundle::default()).id();
+///         commands.entity(root).with_children(|builder| {
+///             for x in 0..10 {
+///                 builder.spawn((
+///                     MooreCell2d::new(IVec2::new(x, 0)),
+///                     ConwayCellState(x % 2 == 0),
+///                     GridRoot(root),
+///                 ));
+///             }
+///         });
+///     }
+/// }
+/// ```
+///
+/// [`CellMap`]: crate::CellMap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Component, Deref)]
+pub struct GridRoot(pub Entity);
+
+/// Component of a grid root entity overriding the global `P` parameters
+/// resource of the [`ParametricCellState`] rules for the cells of its grid.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn spawn_grids(mut commands: Commands) {
+///     // The first grid follows the `EpidemicParams` resource, the second one
+///     // is twice as contagious
+///     let default = commands.spawn(SpatialBundle::default()).id();
+///     let contagious = commands
+///         .spawn((
+///             SpatialBundle::default(),
+///             GridParams(EpidemicParams::new(0.6, 5, 20)),
+///         ))
+///         .id();
+///     for root in [default, contagious] {
+///         // Spawns the cells with a `GridRoot(root)` component
+///     }
+/// }
+/// ```
+///
+/// [`ParametricCellState`]: crate::ParametricCellState
+#[derive(Debug, Clone, Component, Deref)]
+pub struct GridParams<P: Send + Sync + 'static>(pub P);
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{
+        CellularAutomatonPlugin, ConwayCellState, Excitable2dPlugin, ExcitableParams,
+        ExcitableState, Map2d, MooreCell2d,
+    };
+    use bevy::prelude::*;
+
+    fn run(cached: bool) {
+        let mut app = App::new();
+        let plugin = CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new();
+        if cached {
+            app.add_plugins(plugin.with_cached_neighbors());
+        } else {
+            app.add_plugins(plugin.with_cell_map());
+        }
+        // A blinker in the first grid, a lonely cell at its center in the
+        // second one
+        let grids: Vec<_> = (0..2).map(|_| app.world.spawn_empty().id()).collect();
+        let cells: Vec<Vec<_>> = grids
+            .iter()
+            .enumerate()
+            .map(|(i, root)| {
+                (0..9)
+                    .map(|c| {
+                        let coords = IVec2::new(c % 3, c / 3);
+                        let alive = if i == 0 { coords.y == 1 } else { c == 4 };
+                        let cell = (MooreCell2d::new(coords), ConwayCellState(alive));
+                        app.world
+                            .spawn((cell, GridRoot(*root)))
+                            .set_parent(*root)
+                            .id()
+                    })
+                    .collect()
+            })
+            .collect();
+        app.update();
+        let alive = |app: &App, grid: usize| -> Vec<usize> {
+            (0..9)
+                .filter(|c| app.world.get::<ConwayCellState>(cells[grid][*c]).unwrap().0)
+                .collect()
+        };
+        assert_eq!(alive(&app, 0), [1, 4, 7]);
+        assert!(alive(&app, 1).is_empty());
+        let map = app.world.resource::<Map2d>();
+        assert_eq!(map.get_cell(&IVec2::ONE), None);
+        assert_eq!(
+            map.get_grid_cell(Some(grids[1]), &IVec2::ONE),
+            Some(cells[1][4])
+        );
+    }
+
+    #[test]
+    fn independent_grids() {
+        run(false);
+    }
+
+    #[test]
+    fn independent_cached_grids() {
+        run(true);
+    }
+
+    #[test]
+    fn grid_params() {
+        let mut app = App::new();
+        app.add_plugins(Excitable2dPlugin::new())
+            .insert_resource(ExcitableParams::new(1, 2));
+        // The second grid needs two excited neighbors
+        let default = app.world.spawn_empty().id();
+        let strict = app.world.spawn(GridParams(ExcitableParams::new(2, 2))).id();
+        let resting: Vec<_> = [default, strict]
+            .into_iter()
+            .map(|root| {
+                let excited = (MooreCell2d::new(IVec2::ZERO), ExcitableState::Excited);
+                app.world.spawn((excited, GridRoot(root)));
+                let resting = (MooreCell2d::new(IVec2::X), ExcitableState::Resting);
+                app.world.spawn((resting, GridRoot(root))).id()
+            })
+            .collect();
+        app.update();
+        let states: Vec<_> = resting
+            .iter()
+            .map(|e| *app.world.get::<ExcitableState>(*e).unwrap())
+            .collect();
+        assert_eq!(states, [ExcitableState::Excited, ExcitableState::Resting]);
+    }
+}
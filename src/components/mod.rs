@@ -1,5 +1,19 @@
+#[cfg(feature = "2D")]
+pub use ant::*;
 pub use cell::*;
 pub use cell_state::*;
+#[cfg(all(feature = "1D", feature = "auto-coloring"))]
+pub use generation_trail::*;
+pub use grid_root::*;
+pub use neighbor_entities::*;
+pub use state_buffer::*;
 
+#[cfg(feature = "2D")]
+mod ant;
 mod cell;
 mod cell_state;
+#[cfg(all(feature = "1D", feature = "auto-coloring"))]
+mod generation_trail;
+mod grid_root;
+mod neighbor_entities;
+mod state_buffer;
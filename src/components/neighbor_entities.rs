@@ -0,0 +1,93 @@
+use bevy::{
+    prelude::{Component, Deref, Entity},
+    utils::smallvec::SmallVec,
+};
+
+/// Cached neighbor cell entities, inserted on every cell when the
+/// [`CellularAutomatonPlugin`] uses cached neighbors (see
+/// [`CellularAutomatonPlugin::with_cached_neighbors`]).
+///
+/// The cache is rebuilt when cells are spawned, moved or removed, so the
+/// per-tick state computation iterates entities directly instead of looking
+/// up neighbor coordinates every generation.
+///
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+/// [`CellularAutomatonPlugin::with_cached_neighbors`]: crate::CellularAutomatonPlugin::with_cached_neighbors
+#[derive(Debug, Clone, Default, Component, Deref)]
+pub struct NeighborEntities(pub SmallVec<[Entity; 8]>);
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CellularAutomatonPlugin, ConwayCellState, MooreCell2d};
+    use bevy::prelude::*;
+
+    fn blinker(cached: bool) -> (App, Vec<Entity>) {
+        let mut app = App::new();
+        let plugin = CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new();
+        if cached {
+            app.add_plugins(plugin.with_cached_neighbors());
+        } else {
+            app.add_plugins(plugin);
+        }
+        let cells = (0..3)
+            .flat_map(|y| (0..3).map(move |x| IVec2::new(x, y)))
+            .map(|c| {
+                app.world
+                    .spawn((MooreCell2d::new(c), ConwayCellState(c.y == 1)))
+                    .id()
+            })
+            .collect();
+        (app, cells)
+    }
+
+    fn states(app: &App, cells: &[Entity]) -> Vec<bool> {
+        cells
+            .iter()
+            .map(|e| app.world.get::<ConwayCellState>(*e).unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn cached_simulation() {
+        let (mut app, cells) = blinker(true);
+        let (mut reference, reference_cells) = blinker(false);
+        for _ in 0..4 {
+            app.update();
+            reference.update();
+            assert_eq!(states(&app, &cells), states(&reference, &reference_cells));
+        }
+        // Corner cell has 3 neighbors, center cell has 8
+        assert_eq!(
+            app.world.get::<NeighborEntities>(cells[0]).unwrap().len(),
+            3
+        );
+        assert_eq!(
+            app.world.get::<NeighborEntities>(cells[4]).unwrap().len(),
+            8
+        );
+    }
+
+    #[test]
+    fn cache_rebuild() {
+        let (mut app, cells) = blinker(true);
+        app.update();
+        // Moving the top right corner away
+        app.world.get_mut::<MooreCell2d>(cells[8]).unwrap().coords = IVec2::new(10, 10);
+        app.update();
+        assert_eq!(
+            app.world.get::<NeighborEntities>(cells[8]).unwrap().len(),
+            0
+        );
+        assert_eq!(
+            app.world.get::<NeighborEntities>(cells[4]).unwrap().len(),
+            7
+        );
+        app.world.despawn(cells[0]);
+        app.update();
+        assert_eq!(
+            app.world.get::<NeighborEntities>(cells[4]).unwrap().len(),
+            6
+        );
+    }
+}
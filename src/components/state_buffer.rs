@@ -0,0 +1,33 @@
+use bevy::prelude::Component;
+
+/// Back buffer of the `S` state of a cell, whose front buffer is the `S`
+/// component itself.
+///
+/// It is inserted once on every cell in the `MapUpdate` set of the
+/// [`CellularAutomatonSet`]. The rules write the new states of the changed
+/// cells in it in the `ComputeNewStates` set, and they are swapped into the
+/// `S` cell states in the `ApplyNewStates` set, without any archetype move.
+/// The systems ordered between both sets may compare the current and the new
+/// states of the cells.
+///
+/// [`CellularAutomatonSet`]: crate::CellularAutomatonSet
+#[derive(Debug, Clone, PartialEq, Eq, Component)]
+pub struct StateBuffer<S> {
+    /// New state computed by the rules of the current tick, if it changed
+    pub next: Option<S>,
+}
+
+impl<S> StateBuffer<S> {
+    /// Instantiates an empty buffer
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self { next: None }
+    }
+}
+
+impl<S> Default for StateBuffer<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
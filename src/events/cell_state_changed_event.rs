@@ -0,0 +1,59 @@
+use crate::components::CellState;
+use bevy::prelude::{Entity, Event};
+
+/// Event sent by the [`CellStateEventsPlugin`] every time the `S` state of a
+/// cell actually changes, with its previous and new values.
+///
+/// The events allow reacting to the changes, like gameplay hooks, audio or
+/// statistics, without scanning every cell.
+///
+/// [`CellStateEventsPlugin`]: crate::CellStateEventsPlugin
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct CellStateChangedEvent<S: CellState> {
+    /// Changed cell entity
+    pub entity: Entity,
+    /// Previous state of the cell
+    pub old: S,
+    /// New state of the cell
+    pub new: S,
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CellStateEventsPlugin, ConwayCellState, GameOfLife2dPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    #[test]
+    fn changed_states() {
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            CellStateEventsPlugin::<ConwayCellState>::new(),
+        ));
+        let cells: Vec<_> = (0..3)
+            .map(|x| {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, 0)), ConwayCellState(x == 1)))
+                    .id()
+            })
+            .collect();
+        let events = |app: &mut App| {
+            app.update();
+            app.world
+                .resource_mut::<Events<CellStateChangedEvent<ConwayCellState>>>()
+                .drain()
+                .map(|e| (e.entity, e.old.0, e.new.0))
+                .collect::<Vec<_>>()
+        };
+        // The lonely cell dies
+        assert_eq!(events(&mut app), [(cells[1], true, false)]);
+        assert!(events(&mut app).is_empty());
+        // Changes outside of the ticks are sent as well
+        app.world.entity_mut(cells[0]).insert(ConwayCellState(true));
+        assert_eq!(
+            events(&mut app),
+            [(cells[0], false, true), (cells[0], true, false)]
+        );
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::Event;
+
+/// Event sent when the new states of a generation computed on the
+/// [`AsyncComputeTaskPool`] under an [`AsyncCompute`] resource are written
+/// back to the cells, before they are applied.
+///
+/// [`AsyncComputeTaskPool`]: bevy::tasks::AsyncComputeTaskPool
+/// [`AsyncCompute`]: crate::AsyncCompute
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Event)]
+pub struct GenerationComputedEvent {
+    /// Number of cells whose state changed
+    pub changed: usize,
+}
@@ -0,0 +1,11 @@
+pub use cell_state_changed_event::*;
+pub use generation_computed_event::*;
+pub use new_generation_event::*;
+pub use simulation_finished_event::*;
+pub use transmission_event::*;
+
+mod cell_state_changed_event;
+mod generation_computed_event;
+mod new_generation_event;
+mod simulation_finished_event;
+mod transmission_event;
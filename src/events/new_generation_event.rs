@@ -0,0 +1,13 @@
+use bevy::prelude::Event;
+
+/// Event sent by the [`SimulationTickPlugin`] after the rules of every tick,
+/// with the new [`Generation`] number, so systems may synchronize on the
+/// generation boundaries.
+///
+/// [`SimulationTickPlugin`]: crate::SimulationTickPlugin
+/// [`Generation`]: crate::Generation
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Event)]
+pub struct NewGenerationEvent {
+    /// New generation number, starting at `1`
+    pub generation: u64,
+}
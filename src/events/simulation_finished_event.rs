@@ -0,0 +1,25 @@
+use bevy::prelude::Event;
+
+/// Event sent by the [`TerminationPlugin`] when a condition of the
+/// [`Termination`] resource is met.
+///
+/// [`TerminationPlugin`]: crate::TerminationPlugin
+/// [`Termination`]: crate::Termination
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Event)]
+pub struct SimulationFinishedEvent {
+    /// Generation at which the simulation finished
+    pub generation: u64,
+    /// Met termination condition
+    pub reason: FinishReason,
+}
+
+/// Termination condition met by a finished simulation
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FinishReason {
+    /// No cell state matches the extinction predicate anymore
+    Extinction,
+    /// No cell state changed during the steady amount of ticks
+    SteadyState,
+    /// The maximum generation is reached
+    MaxGeneration,
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::{Entity, Event};
+
+/// Event sent by the [`TracedCellularAutomatonPlugin`] every time a cell is
+/// infected, with the neighbor the infection is attributed to.
+///
+/// The events allow contact tracing analyses and the reconstruction of the
+/// transmission tree.
+///
+/// [`TracedCellularAutomatonPlugin`]: crate::TracedCellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Event)]
+pub struct TransmissionEvent {
+    /// Infectious neighbor cell entity
+    pub source: Entity,
+    /// Infected cell entity
+    pub target: Entity,
+    /// Generation of the infection, starting at `0`
+    pub tick: u64,
+}
@@ -0,0 +1,173 @@
+use super::{ExperimentRunner, Optimizer};
+use crate::{components::CellState, resources::TimeSeriesRecorder};
+use bevy::prelude::App;
+
+/// Loss of the recorded time series of a run
+type Loss<S> = Box<dyn Fn(&TimeSeriesRecorder<S>) -> f64>;
+
+/// Headless calibration of the parameters of a simulation against target data.
+///
+/// Every parameter vector proposed by an [`Optimizer`] is simulated by an
+/// [`ExperimentRunner`] for every seed, and the loss closure compares the
+/// recorded [`TimeSeriesRecorder`] of the `S` cell states with the target
+/// data. The loss of the parameters is the mean loss of their seeds, and runs
+/// without recorder have an infinite loss.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let observed_cases: Vec<f64> = vec![/* .. */];
+/// let ticks = observed_cases.len() as u32;
+/// let calibration = Calibration::<SeirCellState>::new(
+///     |app, parameters, _seed| {
+///         app.add_plugins(Seir2dPlugin::new())
+///             .insert_resource(EpidemicParams::new(parameters[0], 3, 7))
+///             .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+///                 ["infectious"],
+///                 |states| vec![states.iter().filter(|s| s.is_infectious()).count() as f64],
+///             ));
+///         // Spawn the cells
+///     },
+///     move |recorder| {
+///         let simulated = recorder.column("infectious").unwrap();
+///         simulated
+///             .iter()
+///             .zip(&observed_cases)
+///             .map(|(s, o)| (s - o).powi(2))
+///             .sum()
+///     },
+/// )
+/// .with_seeds(0..5)
+/// .with_ticks(ticks);
+/// let mut optimizer = NelderMead::new(vec![0.2], [(0.0, 1.0)]);
+/// let result = calibration.run(&mut optimizer);
+/// let (parameters, loss) = result.best().unwrap();
+/// ```
+pub struct Calibration<S: CellState> {
+    runner: ExperimentRunner<Vec<f64>, S>,
+    seeds: Vec<u64>,
+    loss: Loss<S>,
+}
+
+/// Result of a [`Calibration`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CalibrationResult {
+    /// Every evaluated parameter vector with its loss, in order
+    pub evaluations: Vec<(Vec<f64>, f64)>,
+}
+
+impl<S: CellState> Calibration<S> {
+    /// Instantiates a calibration building the app of every run with the
+    /// `setup` closure, from the evaluated parameters and the run seed, and
+    /// computing the run `loss` from its recorder. Every parameter vector is
+    /// run once with the seed `0` during 100 ticks.
+    #[must_use]
+    pub fn new(
+        setup: impl Fn(&mut App, &[f64], u64) + 'static,
+        loss: impl Fn(&TimeSeriesRecorder<S>) -> f64 + 'static,
+    ) -> Self {
+        Self {
+            runner: ExperimentRunner::new([], move |app, parameters: &Vec<f64>, seed| {
+                setup(app, parameters, seed);
+            }),
+            seeds: vec![0],
+            loss: Box::new(loss),
+        }
+    }
+
+    /// Runs every parameter vector once for each of the `seeds`
+    #[must_use]
+    pub fn with_seeds(mut self, seeds: impl IntoIterator<Item = u64>) -> Self {
+        self.seeds = seeds.into_iter().collect();
+        self
+    }
+
+    /// Sets the amount of `ticks` of every run
+    #[must_use]
+    pub fn with_ticks(mut self, ticks: u32) -> Self {
+        self.runner = self.runner.with_ticks(ticks);
+        self
+    }
+
+    /// Computes the mean loss of the `parameters` over every seed
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn evaluate(&self, parameters: &[f64]) -> f64 {
+        let parameters = parameters.to_vec();
+        let total: f64 = self
+            .seeds
+            .iter()
+            .map(|seed| {
+                self.runner
+                    .run_once(&parameters, *seed)
+                    .map_or(f64::INFINITY, |recorder| (self.loss)(&recorder))
+            })
+            .sum();
+        total / self.seeds.len() as f64
+    }
+
+    /// Evaluates the parameters proposed by the `optimizer` until it ends
+    pub fn run(&self, optimizer: &mut impl Optimizer) -> CalibrationResult {
+        let mut result = CalibrationResult::default();
+        while let Some(parameters) = optimizer.ask() {
+            let loss = self.evaluate(&parameters);
+            optimizer.tell(&parameters, loss);
+            result.evaluations.push((parameters, loss));
+        }
+        result
+    }
+}
+
+impl CalibrationResult {
+    /// Retrieves the evaluated parameters with the lowest loss, with their
+    /// loss
+    #[must_use]
+    pub fn best(&self) -> Option<&(Vec<f64>, f64)> {
+        self.evaluations
+            .iter()
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{EpidemicParams, GridSearch, MooreCell2d, Seir2dPlugin, SeirCellState};
+    use bevy::prelude::*;
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn calibrated_transmission() {
+        let calibration = Calibration::<SeirCellState>::new(
+            |app, parameters, _seed| {
+                app.add_plugins(Seir2dPlugin::new())
+                    .insert_resource(EpidemicParams::new(parameters[0], 1, 10))
+                    .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+                        ["susceptible"],
+                        |states| {
+                            let susceptible = states
+                                .iter()
+                                .filter(|s| ***s == SeirCellState::Susceptible)
+                                .count();
+                            vec![susceptible as f64]
+                        },
+                    ));
+                for x in 0..5 {
+                    let state = if x == 0 {
+                        SeirCellState::Infectious(0)
+                    } else {
+                        SeirCellState::Susceptible
+                    };
+                    app.world.spawn((MooreCell2d::new(IVec2::new(x, 0)), state));
+                }
+            },
+            // The infection reaches a new cell every other tick
+            |recorder| (recorder.last().unwrap().1[0] - 2.0).abs(),
+        )
+        .with_seeds([1, 2])
+        .with_ticks(3);
+        let result = calibration.run(&mut GridSearch::new([(0.0, 1.0)], 2));
+        assert_eq!(result.evaluations, [(vec![0.0], 2.0), (vec![1.0], 0.0)]);
+        assert_eq!(result.best(), Some(&(vec![1.0], 0.0)));
+    }
+}
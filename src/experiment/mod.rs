@@ -0,0 +1,7 @@
+pub use calibration::*;
+pub use optimizer::*;
+pub use runner::*;
+
+mod calibration;
+mod optimizer;
+mod runner;
@@ -0,0 +1,374 @@
+use rand::Rng;
+
+/// Optimizer of the parameters of a [`Calibration`], proposing parameter
+/// vectors and receiving their loss in turn.
+///
+/// Every parameter vector given by [`Optimizer::ask`] is evaluated and
+/// reported with [`Optimizer::tell`] before the next one is asked.
+///
+/// [`Calibration`]: crate::Calibration
+pub trait Optimizer {
+    /// Proposes the next parameters to evaluate, or `None` once the
+    /// optimization is over
+    fn ask(&mut self) -> Option<Vec<f64>>;
+
+    /// Reports the `loss` of the evaluated `parameters`
+    fn tell(&mut self, parameters: &[f64], loss: f64);
+}
+
+/// [`Optimizer`] evaluating every point of a regular grid over the parameter
+/// bounds
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridSearch {
+    bounds: Vec<(f64, f64)>,
+    steps: usize,
+    index: usize,
+}
+
+/// [`Optimizer`] evaluating uniformly random parameters within their bounds
+#[derive(Debug, Clone, PartialEq)]
+pub struct RandomSearch {
+    bounds: Vec<(f64, f64)>,
+    samples: usize,
+}
+
+/// Nelder–Mead simplex [`Optimizer`], minimizing the loss without gradient.
+///
+/// The proposed parameters are clamped within their bounds. The optimization
+/// ends after a maximum amount of evaluations or once the loss spread of the
+/// simplex is below a tolerance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NelderMead {
+    bounds: Vec<(f64, f64)>,
+    simplex: Vec<(Vec<f64>, f64)>,
+    pending: Vec<Vec<f64>>,
+    step: SimplexStep,
+    evaluations: usize,
+    max_evaluations: usize,
+    tolerance: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SimplexStep {
+    Initialization,
+    Reflection,
+    Expansion((Vec<f64>, f64)),
+    Contraction,
+}
+
+impl GridSearch {
+    /// Instantiates a grid of `steps` evenly spaced values in every parameter
+    /// `bounds`, including the bounds. A single step evaluates the middle of
+    /// the bounds.
+    #[must_use]
+    pub fn new(bounds: impl IntoIterator<Item = (f64, f64)>, steps: usize) -> Self {
+        Self {
+            bounds: bounds.into_iter().collect(),
+            steps,
+            index: 0,
+        }
+    }
+
+    /// Amount of grid points
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps
+            .pow(u32::try_from(self.bounds.len()).unwrap_or(u32::MAX))
+    }
+
+    /// Is the grid empty ?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn value(&self, (min, max): (f64, f64), step: usize) -> f64 {
+        if self.steps == 1 {
+            f64::midpoint(min, max)
+        } else {
+            (max - min).mul_add(step as f64 / (self.steps - 1) as f64, min)
+        }
+    }
+}
+
+impl Optimizer for GridSearch {
+    fn ask(&mut self) -> Option<Vec<f64>> {
+        if self.index >= self.len() {
+            return None;
+        }
+        let mut index = self.index;
+        self.index += 1;
+        Some(
+            self.bounds
+                .iter()
+                .map(|bounds| {
+                    let step = index % self.steps;
+                    index /= self.steps;
+                    self.value(*bounds, step)
+                })
+                .collect(),
+        )
+    }
+
+    fn tell(&mut self, _parameters: &[f64], _loss: f64) {}
+}
+
+impl RandomSearch {
+    /// Instantiates a search of `samples` random parameters within their
+    /// `bounds`
+    #[must_use]
+    pub fn new(bounds: impl IntoIterator<Item = (f64, f64)>, samples: usize) -> Self {
+        Self {
+            bounds: bounds.into_iter().collect(),
+            samples,
+        }
+    }
+}
+
+impl Optimizer for RandomSearch {
+    fn ask(&mut self) -> Option<Vec<f64>> {
+        self.samples = self.samples.checked_sub(1)?;
+        let mut rng = rand::thread_rng();
+        Some(
+            self.bounds
+                .iter()
+                .map(|(min, max)| (max - min).mul_add(rng.gen::<f64>(), *min))
+                .collect(),
+        )
+    }
+
+    fn tell(&mut self, _parameters: &[f64], _loss: f64) {}
+}
+
+impl NelderMead {
+    /// Instantiates an optimizer starting from the `initial` parameters, with
+    /// an initial simplex spanning a tenth of every parameter `bounds`. The
+    /// optimization ends after 200 evaluations or once the loss spread is
+    /// below `1e-8`.
+    #[must_use]
+    pub fn new(initial: Vec<f64>, bounds: impl IntoIterator<Item = (f64, f64)>) -> Self {
+        let bounds: Vec<_> = bounds.into_iter().collect();
+        let initial = Self::clamp(&bounds, initial);
+        let mut pending: Vec<_> = bounds
+            .iter()
+            .enumerate()
+            .map(|(i, (min, max))| {
+                let mut vertex = initial.clone();
+                let step = (max - min) / 10.0;
+                vertex[i] += if vertex[i] + step <= *max {
+                    step
+                } else {
+                    -step
+                };
+                vertex
+            })
+            .collect();
+        pending.push(initial);
+        Self {
+            bounds,
+            simplex: Vec::new(),
+            pending,
+            step: SimplexStep::Initialization,
+            evaluations: 0,
+            max_evaluations: 200,
+            tolerance: 1e-8,
+        }
+    }
+
+    /// Sets the maximum amount of evaluations
+    #[must_use]
+    #[inline]
+    pub const fn with_max_evaluations(mut self, max_evaluations: usize) -> Self {
+        self.max_evaluations = max_evaluations;
+        self
+    }
+
+    /// Sets the loss spread `tolerance` under which the optimization ends
+    #[must_use]
+    #[inline]
+    pub const fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn clamp(bounds: &[(f64, f64)], mut parameters: Vec<f64>) -> Vec<f64> {
+        for (value, (min, max)) in parameters.iter_mut().zip(bounds) {
+            *value = value.clamp(*min, *max);
+        }
+        parameters
+    }
+
+    /// Centroid of every vertex but the worst one
+    #[allow(clippy::cast_precision_loss)]
+    fn centroid(&self) -> Vec<f64> {
+        let vertices = &self.simplex[..self.simplex.len() - 1];
+        let mut centroid = vec![0.0; self.bounds.len()];
+        for (vertex, _) in vertices {
+            for (c, v) in centroid.iter_mut().zip(vertex) {
+                *c += v / vertices.len() as f64;
+            }
+        }
+        centroid
+    }
+
+    /// Point of the line from the centroid `c` to `x`, at the `coefficient`
+    fn towards(&self, c: &[f64], x: &[f64], coefficient: f64) -> Vec<f64> {
+        let point = c
+            .iter()
+            .zip(x)
+            .map(|(c, x)| (x - c).mul_add(coefficient, *c))
+            .collect();
+        Self::clamp(&self.bounds, point)
+    }
+
+    fn worst(&self) -> &(Vec<f64>, f64) {
+        &self.simplex[self.simplex.len() - 1]
+    }
+
+    fn replace_worst(&mut self, vertex: (Vec<f64>, f64)) {
+        let last = self.simplex.len() - 1;
+        self.simplex[last] = vertex;
+        self.sort();
+        self.step = SimplexStep::Reflection;
+    }
+
+    fn sort(&mut self) {
+        self.simplex.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+    }
+
+    /// Best parameters found so far, with their loss
+    #[must_use]
+    pub fn best(&self) -> Option<&(Vec<f64>, f64)> {
+        self.simplex.iter().min_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+}
+
+impl Optimizer for NelderMead {
+    fn ask(&mut self) -> Option<Vec<f64>> {
+        if self.evaluations >= self.max_evaluations || self.bounds.is_empty() {
+            return None;
+        }
+        let centroid = || self.centroid();
+        match &self.step {
+            SimplexStep::Initialization => self.pending.last().cloned(),
+            SimplexStep::Reflection => {
+                let spread = self.worst().1 - self.simplex[0].1;
+                (spread >= self.tolerance).then(|| self.towards(&centroid(), &self.worst().0, -1.0))
+            }
+            SimplexStep::Expansion((reflected, _)) => {
+                Some(self.towards(&centroid(), reflected, 2.0))
+            }
+            SimplexStep::Contraction => Some(self.towards(&centroid(), &self.worst().0, 0.5)),
+        }
+    }
+
+    fn tell(&mut self, parameters: &[f64], loss: f64) {
+        self.evaluations += 1;
+        let vertex = (parameters.to_vec(), loss);
+        match std::mem::replace(&mut self.step, SimplexStep::Reflection) {
+            SimplexStep::Initialization => {
+                self.pending.pop();
+                self.simplex.push(vertex);
+                if self.pending.is_empty() {
+                    self.sort();
+                } else {
+                    self.step = SimplexStep::Initialization;
+                }
+            }
+            SimplexStep::Reflection => {
+                let second_worst = self.simplex[self.simplex.len() - 2].1;
+                if loss < self.simplex[0].1 {
+                    self.step = SimplexStep::Expansion(vertex);
+                } else if loss < second_worst {
+                    self.replace_worst(vertex);
+                } else {
+                    self.step = SimplexStep::Contraction;
+                }
+            }
+            SimplexStep::Expansion(reflected) => {
+                if loss < reflected.1 {
+                    self.replace_worst(vertex);
+                } else {
+                    self.replace_worst(reflected);
+                }
+            }
+            SimplexStep::Contraction => {
+                if loss < self.worst().1 {
+                    self.replace_worst(vertex);
+                } else {
+                    // Shrinks the simplex towards the best vertex
+                    let best = self.simplex[0].0.clone();
+                    self.pending = self.simplex[1..]
+                        .iter()
+                        .map(|(x, _)| self.towards(&best, x, 0.5))
+                        .collect();
+                    self.simplex.truncate(1);
+                    self.step = SimplexStep::Initialization;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimize(optimizer: &mut impl Optimizer, loss: impl Fn(&[f64]) -> f64) -> usize {
+        let mut evaluations = 0;
+        while let Some(parameters) = optimizer.ask() {
+            optimizer.tell(&parameters, loss(&parameters));
+            evaluations += 1;
+        }
+        evaluations
+    }
+
+    #[test]
+    fn grid_search() {
+        let mut grid = GridSearch::new([(0.0, 1.0), (10.0, 20.0)], 3);
+        assert_eq!(grid.len(), 9);
+        let mut points = Vec::new();
+        while let Some(parameters) = grid.ask() {
+            points.push(parameters);
+        }
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[0], [0.0, 10.0]);
+        assert_eq!(points[5], [1.0, 15.0]);
+        assert_eq!(points[8], [1.0, 20.0]);
+        let mut grid = GridSearch::new([(0.0, 1.0)], 1);
+        assert_eq!(grid.ask(), Some(vec![0.5]));
+        assert_eq!(grid.ask(), None);
+    }
+
+    #[test]
+    fn random_search() {
+        let mut search = RandomSearch::new([(-1.0, 1.0), (5.0, 6.0)], 50);
+        let evaluations = minimize(&mut search, |_| 0.0);
+        assert_eq!(evaluations, 50);
+        let mut search = RandomSearch::new([(-1.0, 1.0), (5.0, 6.0)], 50);
+        while let Some(p) = search.ask() {
+            assert!((-1.0..=1.0).contains(&p[0]));
+            assert!((5.0..=6.0).contains(&p[1]));
+        }
+    }
+
+    #[test]
+    fn nelder_mead() {
+        let mut optimizer = NelderMead::new(vec![0.0, 0.0], [(-5.0, 5.0), (-5.0, 5.0)]);
+        let evaluations = minimize(&mut optimizer, |p| {
+            2.0f64.mul_add((p[1] + 2.0).powi(2), (p[0] - 1.0).powi(2))
+        });
+        assert!(evaluations <= 200);
+        let (best, loss) = optimizer.best().unwrap();
+        assert!(*loss < 1e-6);
+        assert!((best[0] - 1.0).abs() < 1e-2);
+        assert!((best[1] + 2.0).abs() < 1e-2);
+        // Bounded optimum
+        let mut optimizer = NelderMead::new(vec![0.5], [(0.0, 1.0)]).with_max_evaluations(50);
+        let evaluations = minimize(&mut optimizer, |p| (p[0] - 3.0).powi(2));
+        assert!(evaluations <= 50);
+        let (best, _) = optimizer.best().unwrap();
+        assert!((best[0] - 1.0).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{
+    components::CellState,
+    resources::{SimulationRng, Termination, TimeSeriesRecorder},
+    TimeSeriesPlugin,
+};
+use bevy::{
+    prelude::{App, MinimalPlugins, Real, Time, Virtual},
+    time::TimeUpdateStrategy,
+};
+use std::time::Duration;
+#[cfg(feature = "csv")]
+use std::{fmt::Debug, io, path::Path};
+
+/// Setup of the app of a run, given the run parameters and seed
+type Setup<P> = Box<dyn Fn(&mut App, &P, u64)>;
+
+/// Headless runner of parameter sweep experiments.
+///
+/// Every combination of the parameters and the seeds is simulated in a new
+/// app during an amount of ticks, and the [`TimeSeriesRecorder`] of the `S`
+/// cell states of every run is collected in the [`ExperimentResults`].
+///
+/// The setup closure builds the app of a run: it adds the cellular automaton
+/// plugins, spawns the cells, inserts the parameter resources and the
+/// [`TimeSeriesRecorder`]. The runner inserts a [`SimulationRng`] seeded with
+/// the run seed before the setup, and adds the [`TimeSeriesPlugin`] after it.
+///
+/// By default the apps have no plugin besides the ones of the setup. With
+/// [`ExperimentRunner::with_minimal_plugins`], the bevy [`MinimalPlugins`] are
+/// added to every app, without windows nor rendering, so plugins relying on
+/// the time or the task pools, like a [`SimulationSpeed`] or a
+/// [`SimulationBatch`], run as well. Stochastic ensembles are then run with
+/// [`ExperimentRunner::with_replicates`]:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// # use std::time::Duration;
+/// let results = ExperimentRunner::<_, SeirCellState>::new([0.3], |app, transmission, _seed| {
+///     app.add_plugins(Seir2dPlugin::new())
+///         .insert_resource(SimulationSpeed::new(10.0))
+///         .insert_resource(SimulationBatch::default())
+///         .insert_resource(EpidemicParams::new(*transmission, 3, 7))
+///         .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+///             ["infectious"],
+///             |states| vec![states.iter().filter(|s| s.is_infectious()).count() as f64],
+///         ));
+///     // Spawn the cells
+/// })
+/// .with_minimal_plugins(Duration::from_secs_f64(0.1))
+/// .with_replicates(1000)
+/// .with_ticks(500)
+/// .run();
+/// ```
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let transmissions = [0.1, 0.2, 0.3];
+/// let periods = [5, 10];
+/// let results = ExperimentRunner::<_, SeirCellState>::new(
+///     transmissions
+///         .iter()
+///         .flat_map(|t| periods.iter().map(move |p| (*t, *p))),
+///     |app, (transmission, period), _seed| {
+///         app.add_plugins(Seir2dPlugin::new())
+///             .insert_resource(EpidemicParams::new(*transmission, 3, *period))
+///             .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+///                 ["infectious"],
+///                 |states| vec![states.iter().filter(|s| s.is_infectious()).count() as f64],
+///             ));
+///         // Spawn the cells
+///     },
+/// )
+/// .with_seeds(0..10)
+/// .with_ticks(100)
+/// .run();
+/// assert_eq!(results.runs.len(), 60);
+/// ```
+///
+/// [`SimulationBatch`]: crate::SimulationBatch
+/// [`SimulationSpeed`]: crate::SimulationSpeed
+pub struct ExperimentRunner<P, S> {
+    parameters: Vec<P>,
+    seeds: Vec<u64>,
+    ticks: u32,
+    tick_duration: Option<Duration>,
+    setup: Setup<P>,
+    phantom_s: std::marker::PhantomData<S>,
+}
+
+/// Recorded time series of a run of an [`ExperimentRunner`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperimentRun<P> {
+    /// Parameters of the run
+    pub parameters: P,
+    /// Seed of the run
+    pub seed: u64,
+    /// Recorded rows, with their tick
+    pub rows: Vec<(u64, Vec<f64>)>,
+}
+
+/// Combined results of an [`ExperimentRunner`], in the order of the
+/// parameters then of the seeds
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExperimentResults<P> {
+    /// Column names of the recorded time series
+    pub columns: Vec<String>,
+    /// Every run
+    pub runs: Vec<ExperimentRun<P>>,
+}
+
+impl<P, S: CellState> ExperimentRunner<P, S> {
+    /// Instantiates a runner of the `parameters` combinations, building the
+    /// app of every run with the `setup` closure. Every combination is run
+    /// once with the seed `0` during 100 ticks.
+    #[must_use]
+    pub fn new(
+        parameters: impl IntoIterator<Item = P>,
+        setup: impl Fn(&mut App, &P, u64) + 'static,
+    ) -> Self {
+        Self {
+            parameters: parameters.into_iter().collect(),
+            seeds: vec![0],
+            ticks: 100,
+            tick_duration: None,
+            setup: Box::new(setup),
+            phantom_s: std::marker::PhantomData,
+        }
+    }
+
+    /// Runs every parameter combination once for each of the `seeds`
+    #[must_use]
+    pub fn with_seeds(mut self, seeds: impl IntoIterator<Item = u64>) -> Self {
+        self.seeds = seeds.into_iter().collect();
+        self
+    }
+
+    /// Runs every parameter combination `replicates` times, with the seeds
+    /// `0` to `replicates - 1`
+    #[must_use]
+    pub fn with_replicates(self, replicates: u64) -> Self {
+        self.with_seeds(0..replicates)
+    }
+
+    /// Adds the bevy [`MinimalPlugins`] to the app of every run, before the
+    /// setup. Every tick advances the time of the app by `tick_duration`,
+    /// regardless of the wall clock, so a [`SimulationSpeed`] of
+    /// `1 / tick_duration` ticks per second advances one simulation tick per
+    /// runner tick.
+    ///
+    /// [`SimulationSpeed`]: crate::SimulationSpeed
+    #[must_use]
+    #[inline]
+    pub const fn with_minimal_plugins(mut self, tick_duration: Duration) -> Self {
+        self.tick_duration = Some(tick_duration);
+        self
+    }
+
+    /// Sets the amount of `ticks` of every run. A run stops earlier when the
+    /// simulation finishes, if its app has a [`Termination`] resource.
+    #[must_use]
+    #[inline]
+    pub const fn with_ticks(mut self, ticks: u32) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Amount of runs of the experiment
+    #[must_use]
+    #[inline]
+    pub const fn runs(&self) -> usize {
+        self.parameters.len() * self.seeds.len()
+    }
+
+    /// Simulates the run of the `parameters` with the `seed`, returning its
+    /// recorder, if any
+    pub fn run_once(&self, parameters: &P, seed: u64) -> Option<TimeSeriesRecorder<S>> {
+        let mut app = App::new();
+        if let Some(tick_duration) = self.tick_duration {
+            app.add_plugins(MinimalPlugins)
+                .insert_resource(TimeUpdateStrategy::ManualDuration(tick_duration));
+            let mut time = app.world.resource_mut::<Time<Virtual>>();
+            let max_delta = time.max_delta().max(tick_duration);
+            time.set_max_delta(max_delta);
+            // The first update of the clock has no delta otherwise
+            app.world
+                .resource_mut::<Time<Real>>()
+                .update_with_duration(Duration::ZERO);
+        }
+        app.insert_resource(SimulationRng::new(seed));
+        (self.setup)(&mut app, parameters, seed);
+        if !app.is_plugin_added::<TimeSeriesPlugin<S>>() {
+            app.add_plugins(TimeSeriesPlugin::<S>::new());
+        }
+        for _ in 0..self.ticks {
+            app.update();
+            let termination = app.world.get_resource::<Termination<S>>();
+            if termination.is_some_and(Termination::is_finished) {
+                break;
+            }
+        }
+        app.world.remove_resource::<TimeSeriesRecorder<S>>()
+    }
+
+    /// Simulates every run of the experiment sequentially. Runs whose app has
+    /// no [`TimeSeriesRecorder`] are skipped.
+    #[must_use]
+    pub fn run(self) -> ExperimentResults<P>
+    where
+        P: Clone,
+    {
+        let mut results = ExperimentResults {
+            columns: Vec::new(),
+            runs: Vec::with_capacity(self.runs()),
+        };
+        for parameters in &self.parameters {
+            for seed in &self.seeds {
+                let Some(recorder) = self.run_once(parameters, *seed) else {
+                    continue;
+                };
+                if results.columns.is_empty() {
+                    results.columns = recorder.columns().to_vec();
+                }
+                results.runs.push(ExperimentRun {
+                    parameters: parameters.clone(),
+                    seed: *seed,
+                    rows: recorder
+                        .rows()
+                        .map(|(tick, row)| (tick, row.to_vec()))
+                        .collect(),
+                });
+            }
+        }
+        results
+    }
+}
+
+impl<P> ExperimentResults<P> {
+    /// Iterates over the rows of every run, with the run index, the
+    /// parameters, the seed and the tick of every row
+    pub fn rows(&self) -> impl Iterator<Item = (usize, &P, u64, u64, &[f64])> {
+        self.runs.iter().enumerate().flat_map(|(i, run)| {
+            run.rows
+                .iter()
+                .map(move |(tick, row)| (i, &run.parameters, run.seed, *tick, row.as_slice()))
+        })
+    }
+
+    /// Retrieves the values of the `name` column at the last tick of every
+    /// run, if the column exists
+    #[must_use]
+    pub fn final_values(&self, name: &str) -> Option<Vec<f64>> {
+        let index = self.columns.iter().position(|c| c == name)?;
+        Some(
+            self.runs
+                .iter()
+                .map(|run| run.rows.last().map_or(f64::NAN, |(_, row)| row[index]))
+                .collect(),
+        )
+    }
+
+    /// Writes the combined rows of every run to a CSV file at `path`, with
+    /// the `run`, `parameters` (debug formatted), `seed` and `tick` columns
+    /// followed by the recorded columns.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be written
+    #[cfg(feature = "csv")]
+    pub fn write_csv(&self, path: impl AsRef<Path>) -> io::Result<()>
+    where
+        P: Debug,
+    {
+        let mut writer = csv::Writer::from_path(path)?;
+        let header = ["run", "parameters", "seed", "tick"].map(str::to_owned);
+        writer.write_record(header.iter().chain(&self.columns))?;
+        for (run, parameters, seed, tick, row) in self.rows() {
+            let fields = [
+                run.to_string(),
+                format!("{parameters:?}"),
+                seed.to_string(),
+                tick.to_string(),
+            ];
+            writer.write_record(fields.into_iter().chain(row.iter().map(f64::to_string)))?;
+        }
+        writer.flush()
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{
+        EpidemicParams, MooreCell2d, Seir2dPlugin, SeirCellState, SimulationBatch, SimulationSpeed,
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn parameter_sweep() {
+        let runner = ExperimentRunner::<_, SeirCellState>::new([0.0, 1.0], |app, t, seed| {
+            app.add_plugins(Seir2dPlugin::new())
+                .insert_resource(EpidemicParams::new(*t, 1, 10))
+                .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+                    ["susceptible", "seed"],
+                    move |states| {
+                        let susceptible = states
+                            .iter()
+                            .filter(|s| ***s == SeirCellState::Susceptible)
+                            .count();
+                        vec![susceptible as f64, seed as f64]
+                    },
+                ));
+            for x in 0..5 {
+                let state = if x == 0 {
+                    SeirCellState::Infectious(0)
+                } else {
+                    SeirCellState::Susceptible
+                };
+                app.world.spawn((MooreCell2d::new(IVec2::new(x, 0)), state));
+            }
+        })
+        .with_seeds([3, 4])
+        .with_ticks(3);
+        assert_eq!(runner.runs(), 4);
+        let results = runner.run();
+        assert_eq!(results.columns, ["susceptible", "seed"]);
+        let runs: Vec<_> = results
+            .runs
+            .iter()
+            .map(|r| (r.parameters, r.seed))
+            .collect();
+        assert_eq!(runs, [(0.0, 3), (0.0, 4), (1.0, 3), (1.0, 4)]);
+        assert_eq!(results.rows().count(), 12);
+        assert!(results
+            .rows()
+            .all(|(_, _, seed, _, row)| (row[1] - seed as f64).abs() < f64::EPSILON));
+        // The infection reaches a new cell every other tick
+        assert_eq!(
+            results.final_values("susceptible").unwrap(),
+            [4.0, 4.0, 2.0, 2.0]
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn headless_replicates() {
+        let results = ExperimentRunner::<_, SeirCellState>::new([()], |app, (), _seed| {
+            app.add_plugins(Seir2dPlugin::new())
+                .insert_resource(SimulationSpeed::new(2.0))
+                .insert_resource(SimulationBatch::default())
+                .insert_resource(EpidemicParams::new(1.0, 1, 10))
+                .insert_resource(TimeSeriesRecorder::<SeirCellState>::new(
+                    ["susceptible"],
+                    |states| {
+                        let susceptible = states
+                            .iter()
+                            .filter(|s| ***s == SeirCellState::Susceptible)
+                            .count();
+                        vec![susceptible as f64]
+                    },
+                ));
+            for x in 0..5 {
+                let state = if x == 0 {
+                    SeirCellState::Infectious(0)
+                } else {
+                    SeirCellState::Susceptible
+                };
+                app.world.spawn((MooreCell2d::new(IVec2::new(x, 0)), state));
+            }
+        })
+        .with_minimal_plugins(Duration::from_millis(500))
+        .with_replicates(3)
+        .with_ticks(3)
+        .run();
+        let seeds: Vec<_> = results.runs.iter().map(|r| r.seed).collect();
+        assert_eq!(seeds, [0, 1, 2]);
+        // The timed rules run once per tick
+        assert_eq!(
+            results.final_values("susceptible").unwrap(),
+            [2.0, 2.0, 2.0]
+        );
+    }
+}
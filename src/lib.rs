@@ -44,11 +44,49 @@
 //! * `WireWorld3dPlugin`
 //! * `CyclicColors2dPlugin`
 //! * `CyclicColors3dPlugin`
+//! * `LifeLike2dPlugin`
+//! * `BriansBrain2dPlugin`
+//! * `StarWars2dPlugin`
+//! * `Replicator2dPlugin`
+//! * `Fredkin2dPlugin`
+//! * `Seeds2dPlugin`
+//! * `Lenia2dPlugin`
+//! * `SmoothLife2dPlugin`
+//! * `ForestFire2dPlugin`
+//! * `WaTor2dPlugin`
+//! * `Sandpile2dPlugin`
+//! * `Excitable2dPlugin`
+//! * `Potts2dPlugin`
+//! * `Ising2dPlugin`
+//! * `Schelling2dPlugin`
+//! * `FallingSand2dPlugin`
+//! * `Reiter2dPlugin`
+//! * `LangtonsLoops2dPlugin`
+//! * `Seir2dPlugin`
+//! * `Sirs2dPlugin`
+//! * `Cohort2dPlugin`
+//! * `MultiStrain2dPlugin`
+//! * `AgeCohort2dPlugin`
+//! * `Household2dPlugin`
+//! * `Severity2dPlugin`
 //!
 //! Then you may use bevy as usual and add `impl Cell` and `impl CellState`
 //! components to the entities. The lib provides some implementations like
 //! `MooreCell2d` or `MooreCell3d` for cells and `ConwayCellState`,
-//! `WireWorldCellState`, etc for states.
+//! `WireWorldCellState`, etc for states. `LifeLikeCellState` follows any Life-like
+//! rule string like `"B36/S23"`, and `GenerationsCellState` adds decay states to
+//! such rules (`BriansBrainCellState`, `StarWarsCellState`).
+//! `ReplicatorCellState` and `FredkinCellState` are self-replicating parity rules.
+//! `LeniaState` and `SmoothLifeState` are continuous states for the Lenia and
+//! Smooth Life automata, updated by a kernel convolution through the
+//! `WeightedCellularAutomatonPlugin`.
+//!
+//! The `GraphCell` type is always available and allows to simulate arbitrary
+//! networks, its neighbors being an explicit list of entities.
+//!
+//! The `TableCell` type is always available as well and reads its neighbors
+//! from an `AdjacencyTable` of cell ids shared by every cell, allowing aperiodic
+//! tilings (see the `2d_penrose_tiling` example).
 //!
 //! You may implement your own *cells* (coordinate system) and *states* (rules)
 //! as you want, the cellular automaton system is completely dynamic and
@@ -63,30 +101,385 @@
 //!
 //! ### Pausing
 //!
-//! Inserting a `SimulationPause` resource will pause the simulation, removing
-//! it wil resume the it.
+//! The systems of the plugins only run in the `SimulationState::Running` Bevy
+//! state: pressing the space key, or setting the `NextState<SimulationState>`,
+//! pauses the simulation and resumes it. Add a `SimulationStatePlugin` before the
+//! cellular automaton plugins to change or disable the key.
+//!
+//! Inserting a `SimulationPause` resource will pause the simulation as well,
+//! removing it will resume it.
+//!
+//! ### Simulation speed
+//!
+//! The systems of the plugins run in the `SimulationTick` schedule, ordered in the
+//! `PreTick`, `Tick` and `PostTick` sets of `SimulationSet`, which advances one
+//! tick per frame by default. Inserting a `SimulationSpeed` resource sets an amount
+//! of ticks per second instead, running several ticks per frame if needed up to
+//! its `max_ticks_per_frame` cap. It may be changed at runtime, unlike the
+//! deprecated `with_time_step` option of the plugins.
+//!
+//! Inside `Tick`, the sets of `CellularAutomatonSet` order the phases of the
+//! cellular automata: `MapUpdate` refreshes the cell map, `ComputeNewStates` runs
+//! the rules and `ApplyNewStates` applies the new states, which are visible to the
+//! systems ordered after it. In between, the new states are held by the persistent
+//! `StateBuffer` components, to compare them with the current ones. The sprites are colored in the `Coloring` set of
+//! `Update`, after the ticks.
+//!
+//! The ticks run in `Update` by default. The `with_schedule` option of the
+//! `CellularAutomatonPlugin`, or a `SimulationTickPlugin` added before the plugins,
+//! runs them in another schedule, like `FixedUpdate` to decouple the tick rate from
+//! the frame rate and stay deterministic under a variable frame rate.
+//!
+//! After the rules of every running tick, the `Generation` resource is incremented
+//! and a `NewGenerationEvent` is sent, so recorders, interventions or screenshots
+//! may synchronize on the generation boundaries.
+//!
+//! The opt-in `CellStateEventsPlugin` sends a `CellStateChangedEvent` with the
+//! old and new states of every cell whose state actually changed during a tick,
+//! to react to births, deaths or infections without scanning the whole grid.
+//!
+//! The `SimulationHistoryPlugin` records the cell states of the last generations in
+//! a `SimulationHistory` resource, and the `Rewind(n)` command restores the states
+//! and the `Generation` of `n` generations ago, to explore what an earlier
+//! intervention would have changed.
+//!
+//! Whole runs may be recorded by the `RecordingPlugin` in a `Recording` resource,
+//! storing the changed states of every generation by coordinates, and saved to or
+//! loaded from RON files with the `ron` feature. The `PlaybackPlugin` replays a
+//! recording without applying the rules, its `Playback` resource allowing to pause
+//! and scrub the generations forward or backward.
+//!
+//! The `TerminationPlugin` finishes the simulation when a condition of the
+//! `Termination` resource is met: the extinction of some states, like no infected
+//! cell left, no change during a given amount of ticks, or a maximum generation. A
+//! `SimulationFinishedEvent` is sent, the simulation is paused and the app may exit,
+//! ending headless runs.
+//!
+//! The `StateHashPlugin` computes a `StateHash` of every cell state after each tick,
+//! independent of the entities and stable across platforms, to check that runs are
+//! deterministic or to compare them with golden values in regression tests.
+//!
+//! ### Boundary conditions
+//!
+//! Inserting a `BoundaryCondition` resource along with a `MapSize` resource
+//! will wrap, mirror or ignore neighbors outside of the grid bounds, for the
+//! `i32`, `IVec2` and `IVec3` coordinates. 3D grids set their depth with
+//! `MapSize::with_depth`.
+//!
+//! ### Infinite grids
+//!
+//! Adding an `InfiniteGridPlugin` along the cellular automaton plugin will spawn
+//! the missing neighbors of live cells and despawn isolated dead cells, so only
+//! the live cells need to be spawned and patterns never reach a map edge.
+//!
+//! ### Level of detail
+//!
+//! Adding a `LevelOfDetailPlugin` along the cellular automaton plugin, with a
+//! `LevelOfDetail` resource, coarse-grains the regions far from the cameras into
+//! super-cells following an approximate rule, and refines them back into cells at
+//! full resolution when a camera approaches. The states must implement
+//! `CoarseCellState` to be aggregated and split, like the `CohortState`, and the
+//! `LevelOfDetail::with_on_spawn` hook inserts the sprites of the refined cells.
+//!
+//! ### Region of interest
+//!
+//! Inserting a `RegionOfInterest` resource only simulates the cells within a
+//! margin around a rect, the other cells keeping their states. Built
+//! `with_camera`, the rect follows the visible area of the cameras, to explore
+//! enormous grids interactively when global correctness isn't required.
+//!
+//! ### Map regeneration
+//!
+//! The `RegenerateMap` command despawns every cell and spawns a new map of a given
+//! size and density, rebuilding the `CellMap` and resetting the `Generation`, to
+//! start a new simulation without restarting the app.
+//!
+//! ### Multiple grids
+//!
+//! Several independent maps of the same cell and state types may run side by side,
+//! like to compare two parameter sets, by inserting a `GridRoot` component with
+//! the root entity of their grid on the cells. The `CellMap` and the neighbor
+//! lookups are scoped per grid, the cells without `GridRoot` forming the default
+//! grid. A `GridParams` component on a root entity overrides the parameters
+//! resource of the parametric rules for the cells of its grid. The sparse cells
+//! and the level of detail stay within the grid of their cells, and
+//! `RegenerateMap::with_grid` regenerates a single grid.
+//!
+//! ### Grid extraction
+//!
+//! With the `2D` feature, a `CellGrid` extracts a bounded grid of cells as
+//! `Vec<Vec<bool>>` rows or as an `Image`, for example after a number of
+//! generations of a `LifeLikeRule::MAZE` or `LifeLikeRule::MAZECTRIC` automaton
+//! to generate procedural mazes.
+//!
+//! ### Global parameters
+//!
+//! Rules may read global parameters from a resource: a `ParametricCellState`
+//! defines its `Params` resource type and is simulated by the
+//! `ParametricCellularAutomatonPlugin`. For example, the `ForestFireState` growth
+//! and lightning probabilities may be tweaked at runtime through the
+//! `ForestFireParams` resource, and the `ExcitableState` excitation threshold and
+//! refractory period through the `ExcitableParams` resource. The `PottsState` (and
+//! `IsingState`) temperature and asynchronous update probability are set by the
+//! `PottsParams` resource. The plugin registers the parameters type for reflection,
+//! so that it may be edited live with an inspector.
+//!
+//! ### Coupled layers
+//!
+//! Two automata may share the same grid, like a host population and its disease
+//! vectors: the rules of a `CoupledCellState` read the state of the other layer at
+//! the same coordinates. Each layer is simulated by a
+//! `CoupledCellularAutomatonPlugin`, and both layers are updated synchronously.
+//!
+//! ### Epidemics
+//!
+//! The `SeirCellState` follows the SEIR compartmental model: susceptible cells are
+//! exposed by infectious neighbors, then become infectious and finally recover. The
+//! transmission probability, incubation and infectious periods are set by the
+//! `EpidemicParams` resource. The `SirsCellState` skips the exposed stage but its
+//! recovered cells lose their immunity after an `immunity_period`, deterministic or
+//! stochastic, causing recurrent waves.
+//! Each `CohortState` cell holds a whole population of susceptible, infectious and
+//! recovered individuals, with binomial infections and recoveries coupled to the
+//! neighbor populations, and a `mortality_probability` removing infectious
+//! individuals. Optional vital dynamics add births and background deaths for long
+//! term endemic simulations.
+//! The `AgeCohortState` splits the cohorts in age groups mixing following the
+//! contact matrix of the `AgeMixingParams`, for age targeted interventions.
+//! The `HouseholdState` splits them in households instead, with a distinct
+//! transmission within households set by the `HouseholdParams`.
+//! The `VaccinationPlugin` vaccinates susceptible individuals following a
+//! `Vaccination` campaign resource, everywhere, in a region or around infected
+//! cells with ring vaccination.
+//! The `ImportationPlugin` seeds the infections of an `Importation` resource at a
+//! given rate or every few ticks, in random cells, border cells or chosen cells.
+//! For contact tracing, the `TracedCellularAutomatonPlugin` sends a
+//! `TransmissionEvent` for every infection of a `TraceableCellState`, with the
+//! infectious neighbor the infection is attributed to.
+//! The `ClusterDetectionPlugin` finds the clusters of neighbor cells matching the
+//! predicate of a `ClusterDetection` resource, like infected cells, every few ticks
+//! and stores their count and size distribution for spatial outbreak analysis.
+//! The clustering of a value of every cell may also be quantified on demand by the
+//! Moran's I and Geary's C of a `SpatialAutocorrelation`.
+//! Containment strategies may be tested by inserting a `Quarantine` resource: the
+//! neighbor links crossing the border of its regions are cut until it is lifted.
+//! With the `SeasonalForcingPlugin`, a sinusoidal or custom `SeasonalForcing` of the
+//! generation number scales the transmission for seasonal dynamics.
+//! Scripted scenarios may register interventions in an `InterventionTimeline`, like
+//! halving the transmission at tick 200 then lifting a `Quarantine` at tick 400:
+//! the `InterventionPlugin` runs every closure or command at its tick, with an
+//! exclusive access to the world, before the cells are updated.
+//! The `MultiStrainState` spreads several strains, past infections protecting
+//! against the others following a cross-immunity matrix of the `MultiStrainParams`,
+//! for strain replacement and immune escape dynamics.
+//! The `SeverityCellState` adds a severity pathway to the SEIR model: severe cells
+//! are hospitalized, and with the `HospitalCapacityPlugin` a saturated
+//! `HospitalCapacity` leaves some of them untreated with a higher mortality.
+//!
+//! ### Time series
+//!
+//! The `TimeSeriesPlugin` appends a row of aggregates of every cell state to a
+//! `TimeSeriesRecorder` resource every tick, like the amount of cells in every
+//! epidemic compartment. The aggregation is a user closure, and the recorded table
+//! may be read by other systems or given to an exporter closure when the app exits.
+//! With the `csv` or `parquet` feature, the `TimeSeriesExportPlugin` writes it to
+//! files following a `TimeSeriesExport` resource, along with optional snapshots of
+//! every cell, at an interval or on exit, ready for pandas or R.
+//! With the `egui-plot` feature, the `TimeSeriesPlotPlugin` plots the recorded
+//! series live in an `egui` overlay, like the epidemic curves of every compartment.
+//!
+//! ### Experiments
+//!
+//! The `ExperimentRunner` runs headless apps for every combination of a parameter
+//! grid and a list of seeds, during a given amount of ticks. A setup closure builds
+//! the app of every run from its parameters and seed, and the `TimeSeriesRecorder`
+//! of every run is collected into `ExperimentResults`, which may be written to a
+//! single CSV file with the `csv` feature.
+//!
+//! Its `with_minimal_plugins` option adds the bevy `MinimalPlugins` to every run,
+//! without windows nor rendering, advancing the clock by a fixed duration every
+//! tick so a `SimulationSpeed` or a `SimulationBatch` run headless too.
+//! Combined with `with_replicates`, large stochastic ensembles of a model may run
+//! on machines without display, the runner returning once every run is done.
+//!
+//! A `Calibration` fits the parameters of a simulation to target data: every
+//! parameter vector proposed by an `Optimizer` is run headless for a list of seeds
+//! and scored by a user loss computed from the recorded time series. The
+//! `GridSearch`, `RandomSearch` and `NelderMead` optimizers are provided.
+//!
+//! ### Scenarios
+//!
+//! With the `serde` feature, a `Scenario` defines a bounded map of epidemic cells,
+//! with its size, density and initial seeding, and the parameters resource of their
+//! rules, like `EpidemicParams`. Scenarios are loaded from RON files with the `ron`
+//! feature or from TOML files with the `toml` feature, so they may be edited and
+//! rerun without recompiling, see the `assets/scenarios` directory.
+//!
+//! ### Moving cells
+//!
+//! A `MovingCellState` rule may move the cell content to a neighbor cell, for
+//! predator-prey models like `WaTorState`. The `MovingCellularAutomatonPlugin`
+//! updates the cells one after the other in a random order, a cell claimed by a
+//! move being skipped for the rest of the tick. Its sweep mode updates the cells
+//! row by row from the bottom up instead, for falling sand automata like
+//! `FallingSandState`.
+//!
+//! A `RelocatingCellState` may instead relocate anywhere on the map, like the
+//! `SchellingState` segregation model: after each tick of the
+//! `RelocatingCellularAutomatonPlugin`, every unsatisfied cell is swapped with a
+//! random vacant cell.
+//!
+//! ### Agents
+//!
+//! The `LangtonsAntPlugin` moves `Ant` agents over a grid of `AntCellState`
+//! cells: every tick, each ant flips the cell under it, turns and moves forward.
+//! Any turmite may be simulated with a custom `TurmiteRules` transition table.
+//!
+//! ### Sandpiles
+//!
+//! The `SandpilePlugin` simulates the abelian sandpile model with `SandpileState`
+//! cells, which topple once per tick or, in cascade mode, until the pile is
+//! stable. Inserting a `SandpileDrop` resource drives the pile by dropping grains
+//! every tick.
+//!
+//! ### Snowflakes
+//!
+//! The `ReiterPlugin` grows snowflakes of `ReiterState` cells following the Reiter
+//! model on hexagonal cells, with `ReiterParams` diffusion, background and vapor
+//! addition parameters.
+//!
+//! ### Rule tables
+//!
+//! A `RuleTable` maps the state of a cell and the states of its neighbors to its new
+//! state, optionally with rotational symmetry. `LangtonsLoopCellState` follows
+//! Langton's loops table and loads the canonical self-replicating loop seed.
+//! Tables may also be parsed from the `@TABLE` section of a Golly rule file, with
+//! its variables and symmetries, and inserted as the `TableCellState` parameters to
+//! run constructed automata like Codd's or Nobili's.
 //!
 //! ### Parallel execution and batching
 //!
 //! Inserting a `SimulationBatch` resource will allow parallel computation of
-//! cells with custom batch sizes.
+//! cells, iterated in chunks. The chunk size is auto-tuned from the number of cells
+//! and of threads, split in several chunks per thread to balance the load across
+//! many cores, and clamped to configurable limits so cheap rules aren't split in
+//! tiny chunks. Its `batch_size` fixes the chunk size instead, to tune it to the
+//! cost of a state type. The `with_batch_size` option of the plugins inserts it.
+//!
+//! ### Reproducibility
+//!
+//! Inserting a `SimulationRng` resource seeds the stochastic rules of the built-in
+//! states and systems, so two runs with the same seed produce identical dynamics.
+//! Custom stochastic states should draw from the `CellRng` generator instead of
+//! `rand::thread_rng` to follow it. Every generation, each cell rule draws from its
+//! own counter-based stream, keyed by the cell coordinates, so the dynamics don't
+//! depend on the update order and parallel runs with a `SimulationBatch` are
+//! reproducible too. Stochastic states may instead override the `new_cell_state_rng`,
+//! `new_cell_state_with_params_rng` or `move_cell_rng` trait methods to receive the
+//! generator injected by the systems, which also lets them be driven by any `Rng`
+//! outside of an app. The `ExperimentRunner` seeds every run with its seed.
+//!
+//! Inserting an `UpdateProbability` resource makes every cell apply its rules with
+//! the given probability per tick, keeping its state otherwise, to model
+//! heterogeneous update rates or lighten the ticks of giant grids. The draws follow
+//! the cell streams, so seeded runs stay reproducible.
+//!
+//! Inserting a `TickBudget` resource spreads the computation of every generation
+//! across several ticks, computing at most the given number of cells per tick, to keep
+//! the frame times of giant interactive maps smooth. The new states are applied
+//! together once every cell is computed, and the `Generation` only advances then.
+//!
+//! Inserting an `AsyncCompute` resource computes every generation on the Bevy
+//! `AsyncComputeTaskPool` instead, from owned copies of the cells, so heavy rules
+//! don't block the frames. The new states are applied, and a
+//! `GenerationComputedEvent` is sent, once the task completes.
+//!
+//! Inserting a `DirtyChunks<S>` resource partitions the grid into chunks and only
+//! updates the chunks where a cell, or a neighbor, changed on the last generation,
+//! which greatly speeds up deterministic rules with sparse activity. A finer
+//! `SleepingCells<C, S>` resource puts to sleep every cell whose neighborhood is
+//! unchanged, for any coordinates, and can keep stochastic states awake with a
+//! predicate.
+//!
+//! With the `gpu` feature, the `GpuLifePlugin` simulates a `GpuLifeGrid` resource
+//! with a compute shader instead of cell entities: the grid is a texture, which may be
+//! displayed as is, and every tick applies a `LifeLikeRule` with a `BoundaryCondition`
+//! to millions of cells.
+//!
+//! For classic Life on the CPU, the `BitboardLifePlugin` simulates a `LifeBitboard`
+//! resource packing one cell per bit, the neighbors of 64 cells being counted at once
+//! with bitwise adders. The `ConwayCellState` of the cell entities is only synced from
+//! the bitboard once per frame for rendering, and edited cells are written back.
+//!
+//! The `HashLife` resource simulates two-state rules on an unbounded grid with
+//! Gosper's memoized quadtree algorithm, able to fast-forward regular patterns by
+//! `2^k` generations at once. The `HashLifePlugin` fast-forwards it every tick and
+//! re-materializes its live cells into cell entities for display, clearing its
+//! memoized nodes once they exceed its `node_budget`.
 //!
 //! ## Cargo Features
 //!
 //! No feature is required for the plugin to work and the main traits `Cell` and
 //! `CellState` are always available. But you may enable the following features
 //!
+//! * `1D`: Enables 1D types like:
+//!   * `Cell1d` (cell with 2 neighbors)
+//!   * `Elementary1dPlugin` (elementary cellular automaton of any Wolfram rule
+//!     number, with `ElementaryCellState`, see the `1d_elementary` example)
+//!   * With `auto-coloring`, the `GenerationScroll` resource to scroll past
+//!     generations down the screen
+//!   * `TrafficPlugin` (Nagel–Schreckenberg traffic of `TrafficState` cars, with
+//!     `TrafficParams` rules and `TrafficStats` density and flow measurements)
 //! * `2D` (enabled by default): Enables 2D types like:
-//!   * `MooreCell2d` (square cell with 8 neighbors)
-//!   * `NeumannCell2d` (square cell with 4 neighbors)
-//!   * `HexagonCell2d` (hexagon cell with 6 neighbors)
+//!   * `MooreCell2d` (square cell with 8 neighbors, or more with a custom `RADIUS`)
+//!   * `NeumannCell2d` (square cell with 4 neighbors, or more with a custom
+//!     `RADIUS`)
+//!   * `HexagonCell2d` (hexagon cell with 6 neighbors, or more with a custom
+//!     `RADIUS`)
+//!   * `AxialHexagonCell2d` (hexagon cell with 6 neighbors in axial space)
+//!   * `TriangleCell2d` (triangle cell with 3 or 12 neighbors)
+//!   * `CustomNeighborhoodCell2d` (square cell with runtime defined neighbors)
+//!   * `KernelCell2d` (square cell with a weighted `Kernel2d` neighborhood, to use
+//!     with a `WeightedCellState` and the `WeightedCellularAutomatonPlugin`)
+//!   * `ComplexCell2d` (square cell with 8 neighbors and configurable long-range
+//!     links)
+//!   * `SmallWorldCell2d` (square cell with 8 neighbors and random long-range
+//!     links)
+//!   * `GravityModel` (gravity model long-range links generator for
+//!     `SmallWorldCell2d` maps)
+//!   * `WrappedMooreCell2d` (square cell with 8 neighbors wrapping around a
+//!     `MapSize` grid)
 //!   * plugin presets: `GameOfLife2dPlugin`, `ImmigrationGame2dPlugin`,
-//!     `RainbowGame2dPlugin`, `WireWorld2dPlugin`, `CyclicAutomaton2dPlugin`
+//!     `RainbowGame2dPlugin`, `WireWorld2dPlugin`, `CyclicAutomaton2dPlugin`,
+//!     `LifeLike2dPlugin`, `BriansBrain2dPlugin`, `StarWars2dPlugin`,
+//!     `Replicator2dPlugin`, `Fredkin2dPlugin`, `Seeds2dPlugin`,
+//!     `Lenia2dPlugin`, `SmoothLife2dPlugin`, `ForestFire2dPlugin`, `WaTor2dPlugin`,
+//!     `Sandpile2dPlugin`, `Excitable2dPlugin`, `Potts2dPlugin`, `Ising2dPlugin`,
+//!     `Schelling2dPlugin`, `FallingSand2dPlugin`, `Reiter2dPlugin`,
+//!     `LangtonsLoops2dPlugin`, `Seir2dPlugin`, `Sirs2dPlugin`, `Cohort2dPlugin`,
+//!     `MultiStrain2dPlugin`, `AgeCohort2dPlugin`, `Household2dPlugin`,
+//!     `Severity2dPlugin`
 //! * `3D`: Enables 3D types like:
 //!   * `MooreCell3d` (cube cell with 26 neighbors)
 //!   * `NeumannCell3d` (cube cell with 6 neighbors)
+//!   * `ComplexCell3d` (cube cell with 26 neighbors and configurable long-range
+//!     links)
+//!   * `SphereCell` (geodesic sphere cell with 5 or 6 neighbors, built from an
+//!     `Icosphere`)
 //!   * plugin presets: `GameOfLife3dPlugin`, `ImmigrationGame3dPlugin`,
 //!     `RainbowGame3dPlugin`, `WireWorld3dPlugin`, `CyclicAutomaton3dPlugin`
+//! * `csv`: Enables the `TimeSeriesExportPlugin`, writing the time series and cell
+//!   snapshots and the `ExperimentResults` to CSV files
+//! * `parquet`: Enables the `TimeSeriesExportPlugin`, writing the time series and
+//!   cell snapshots to Parquet files
+//! * `egui-plot`: Enables the `TimeSeriesPlotPlugin`, plotting the time series
+//!   live in an `egui` overlay
+//! * `gpu`: Enables the `GpuLifePlugin`, simulating life-like rules on a texture
+//!   with a compute shader
+//! * `serde`: Implements `Serialize` and `Deserialize` for the parameters resources
+//!   and enables the `Scenario` configuration
+//! * `ron`: Enables `serde` and loading `Scenario` RON files
+//! * `toml`: Enables `serde` and loading `Scenario` TOML files
 //! * `auto-coloring` (Example or debug purpose):
 //!   * Enables `CellStateMaterials` resource to contain material handles
 //!   * The `CellState` type now requires to build a `CellStateMaterials`
@@ -96,7 +489,10 @@
 //! ## Disclaimer
 //!
 //! This is probably not the fastest rust implementation of a cellular automaton
-//! in rust. For example, using Gosper's [`HashLife`](https://www.drdobbs.com/jvm/an-algorithm-for-compressing-space-and-t/184406478) a classic game of life could be much faster.
+//! in rust: the generic cell entities trade speed for flexibility. For raw speed,
+//! the `LifeBitboard` and the `HashLife` resources implement the life-like rules
+//! with bitwise operations and Gosper's [HashLife](https://www.drdobbs.com/jvm/an-algorithm-for-compressing-space-and-t/184406478)
+//! algorithm.
 //!
 //! This library aim is to be generic and dynamic, so that you can integrate
 //! cellular automata to any project in bevy, with any rules, in 2D or 3D.
@@ -109,150 +505,21 @@
 )]
 #![allow(clippy::default_trait_access, clippy::module_name_repetitions)]
 
-use bevy::{log, prelude::*, time::common_conditions::on_timer};
-use std::{marker::PhantomData, time::Duration};
-
+mod analysis;
 mod components;
+mod events;
+mod experiment;
+mod plugins;
 mod resources;
+#[cfg(feature = "serde")]
+mod scenario;
 mod systems;
 
-use systems::cells::{handle_cells, handle_new_cells};
-
-use crate::systems::cells::handle_removed_cells;
+pub use analysis::*;
 pub use components::*;
+pub use events::*;
+pub use experiment::*;
+pub use plugins::*;
 pub use resources::*;
-
-#[cfg(feature = "2D")]
-/// Cellular automaton plugin type for Conway's Game of life in 2D.
-pub type GameOfLife2dPlugin = CellularAutomatonPlugin<components::MooreCell2d, ConwayCellState>;
-
-#[cfg(feature = "3D")]
-/// Cellular automaton plugin type for Conway's Game of life in 3D.
-pub type GameOfLife3dPlugin = CellularAutomatonPlugin<components::MooreCell3d, ConwayCell4555State>;
-
-#[cfg(feature = "2D")]
-/// Cellular automaton plugin type for a binary (blue and orange) Immigration
-/// Game of life variation in 2D.
-pub type ImmigrationGame2dPlugin =
-    CellularAutomatonPlugin<components::MooreCell2d, ImmigrationCellState>;
-
-#[cfg(feature = "3D")]
-/// Cellular automaton plugin type for a binary (blue and orange) Immigration
-/// Game of life variation in 3D.
-pub type ImmigrationGame3dPlugin =
-    CellularAutomatonPlugin<components::MooreCell3d, ImmigrationCellState>;
-
-#[cfg(feature = "2D")]
-/// Cellular automaton plugin type for a binary (blue and orange) Immigration
-/// Game of life variation in 2D.
-pub type RainbowGame2dPlugin = CellularAutomatonPlugin<components::MooreCell2d, RainbowCellState>;
-
-#[cfg(feature = "3D")]
-/// Cellular automaton plugin type for a binary (blue and orange) Immigration
-/// Game of life variation in 3D.
-pub type RainbowGame3dPlugin = CellularAutomatonPlugin<components::MooreCell3d, RainbowCellState>;
-
-#[cfg(feature = "2D")]
-/// Cellular automaton plugin type for `WireWorld` in 2D
-pub type WireWorld2dPlugin =
-    CellularAutomatonPlugin<components::MooreCell2d, components::WireWorldCellState>;
-
-#[cfg(feature = "3D")]
-/// Cellular automaton plugin type for `WireWorld` in 3D
-pub type WireWorld3dPlugin =
-    CellularAutomatonPlugin<components::MooreCell3d, components::WireWorldCellState>;
-
-#[cfg(feature = "2D")]
-/// Cellular automaton plugin type for Colored Cyclic cellular automaton in 2D
-pub type CyclicColors2dPlugin =
-    CellularAutomatonPlugin<components::MooreCell2d, CyclicColorCellState>;
-
-#[cfg(feature = "3D")]
-/// Cellular automaton plugin type for Colored Cyclic cellular automaton in 3D
-pub type CyclicColors3dPlugin =
-    CellularAutomatonPlugin<components::MooreCell3d, CyclicColorCellState>;
-
-/// Generic Cellular Automaton plugin. It will register systems for the matching
-/// `Cell` and `CellState` types.
-///
-/// The `BATCH_SIZE` const argument determines the size of query batches to be
-/// queried in parallel. It has a big performance impact on worlds with a lot of
-/// cells.
-pub struct CellularAutomatonPlugin<C, S> {
-    /// Custom time step (in seconds) constraint value for the systems. If not
-    /// set, the systems will run every frame.
-    pub tick_time_step: Option<f64>,
-    /// Should a [`CellMap`] be resource be added and filled ?
-    pub use_cell_map: bool,
-    /// Phantom data for the `C` (`Cell`) type
-    pub phantom_c: PhantomData<C>,
-    /// Phantom data for the `S` (`CellState`) type
-    pub phantom_s: PhantomData<S>,
-}
-
-impl<C: Cell, S: CellState> Plugin for CellularAutomatonPlugin<C, S> {
-    fn build(&self, app: &mut App) {
-        // app.register_type::<C>().register_type::<S>().
-        // register_type::<CellMap::<C>>();
-        if self.use_cell_map {
-            app.insert_resource(CellMap::<C>::default());
-            app.add_systems(Update, handle_new_cells::<C>);
-            app.add_systems(PostUpdate, handle_removed_cells::<C>);
-        }
-        if let Some(time_step) = self.tick_time_step {
-            let duration = Duration::from_secs_f64(time_step);
-            app.add_systems(Update, handle_cells::<C, S>.run_if(on_timer(duration)));
-        } else {
-            app.add_systems(Update, handle_cells::<C, S>);
-        }
-
-        #[cfg(feature = "auto-coloring")]
-        {
-            #[cfg(feature = "2D")]
-            {
-                app.add_systems(Update, systems::coloring::color_sprites::<S>);
-            }
-            #[cfg(feature = "3D")]
-            {
-                log::warn!("No auto coloring is available for 3D materials");
-            }
-        }
-        log::info!("Loaded cellular automaton plugin");
-    }
-}
-
-impl<C, S> CellularAutomatonPlugin<C, S> {
-    /// Instantiates Self with default values
-    #[must_use]
-    #[inline]
-    pub const fn new() -> Self {
-        Self {
-            tick_time_step: None,
-            use_cell_map: false,
-            phantom_c: PhantomData,
-            phantom_s: PhantomData,
-        }
-    }
-
-    /// Sets a custom `tick_time_step` value for systems execution
-    #[must_use]
-    #[inline]
-    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
-        self.tick_time_step = Some(tick_time_step);
-        self
-    }
-
-    /// The plugin will set a [`CellMap`] resource and dynamically update it
-    #[must_use]
-    #[inline]
-    pub const fn with_cell_map(mut self) -> Self {
-        self.use_cell_map = true;
-        self
-    }
-}
-
-impl<C, S> Default for CellularAutomatonPlugin<C, S> {
-    fn default() -> Self {
-        Self::new()
-    }
-}
+#[cfg(feature = "serde")]
+pub use scenario::*;
@@ -0,0 +1,187 @@
+use super::CellularAutomatonPlugin;
+#[cfg(feature = "2D")]
+use super::{
+    MovingCellularAutomatonPlugin, ParametricCellularAutomatonPlugin, ReiterPlugin,
+    RelocatingCellularAutomatonPlugin, SandpilePlugin, WeightedCellularAutomatonPlugin,
+};
+#[cfg(feature = "2D")]
+use crate::components::{
+    AgeCohortState, BriansBrainCellState, CohortState, ConwayCellState, ExcitableState,
+    FallingSandState, ForestFireState, FredkinCellState, HexagonCell2d, HouseholdState,
+    KernelCell2d, LangtonsLoopCellState, LeniaState, LifeLikeCellState, MooreCell2d,
+    MultiStrainState, NeumannCell2d, PottsState, ReplicatorCellState, SchellingState,
+    SeedsCellState, SeirCellState, SeverityCellState, SirsCellState, SmoothLifeState,
+    StarWarsCellState, WaTorState,
+};
+#[cfg(feature = "1D")]
+use crate::components::{Cell1d, ElementaryCellState};
+#[cfg(feature = "3D")]
+use crate::components::{ConwayCell4555State, MooreCell3d};
+#[cfg(any(feature = "2D", feature = "3D"))]
+use crate::components::{
+    CyclicColorCellState, ImmigrationCellState, RainbowCellState, WireWorldCellState,
+};
+
+#[cfg(feature = "1D")]
+/// Cellular automaton plugin type for the elementary cellular automaton of
+/// Wolfram rule number `RULE`
+pub type Elementary1dPlugin<const RULE: u8> =
+    CellularAutomatonPlugin<Cell1d, ElementaryCellState<RULE>>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Conway's Game of life in 2D.
+pub type GameOfLife2dPlugin = CellularAutomatonPlugin<MooreCell2d, ConwayCellState>;
+
+#[cfg(feature = "3D")]
+/// Cellular automaton plugin type for Conway's Game of life in 3D.
+pub type GameOfLife3dPlugin = CellularAutomatonPlugin<MooreCell3d, ConwayCell4555State>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for a binary (blue and orange) Immigration
+/// Game of life variation in 2D.
+pub type ImmigrationGame2dPlugin = CellularAutomatonPlugin<MooreCell2d, ImmigrationCellState>;
+
+#[cfg(feature = "3D")]
+/// Cellular automaton plugin type for a binary (blue and orange) Immigration
+/// Game of life variation in 3D.
+pub type ImmigrationGame3dPlugin = CellularAutomatonPlugin<MooreCell3d, ImmigrationCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for a binary (blue and orange) Immigration
+/// Game of life variation in 2D.
+pub type RainbowGame2dPlugin = CellularAutomatonPlugin<MooreCell2d, RainbowCellState>;
+
+#[cfg(feature = "3D")]
+/// Cellular automaton plugin type for a binary (blue and orange) Immigration
+/// Game of life variation in 3D.
+pub type RainbowGame3dPlugin = CellularAutomatonPlugin<MooreCell3d, RainbowCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for `WireWorld` in 2D
+pub type WireWorld2dPlugin = CellularAutomatonPlugin<MooreCell2d, WireWorldCellState>;
+
+#[cfg(feature = "3D")]
+/// Cellular automaton plugin type for `WireWorld` in 3D
+pub type WireWorld3dPlugin = CellularAutomatonPlugin<MooreCell3d, WireWorldCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Colored Cyclic cellular automaton in 2D
+pub type CyclicColors2dPlugin = CellularAutomatonPlugin<MooreCell2d, CyclicColorCellState>;
+
+#[cfg(feature = "3D")]
+/// Cellular automaton plugin type for Colored Cyclic cellular automaton in 3D
+pub type CyclicColors3dPlugin = CellularAutomatonPlugin<MooreCell3d, CyclicColorCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for any Life-like rule in 2D
+pub type LifeLike2dPlugin = CellularAutomatonPlugin<MooreCell2d, LifeLikeCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Brian's Brain in 2D
+pub type BriansBrain2dPlugin = CellularAutomatonPlugin<MooreCell2d, BriansBrainCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Star Wars in 2D
+pub type StarWars2dPlugin = CellularAutomatonPlugin<MooreCell2d, StarWarsCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for the Replicator parity rule in 2D
+pub type Replicator2dPlugin = CellularAutomatonPlugin<MooreCell2d, ReplicatorCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Fredkin's parity rule in 2D
+pub type Fredkin2dPlugin = CellularAutomatonPlugin<MooreCell2d, FredkinCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Seeds in 2D
+pub type Seeds2dPlugin = CellularAutomatonPlugin<MooreCell2d, SeedsCellState>;
+
+#[cfg(feature = "2D")]
+/// Cellular automaton plugin type for Langton's self-replicating loops in 2D
+pub type LangtonsLoops2dPlugin = CellularAutomatonPlugin<NeumannCell2d, LangtonsLoopCellState>;
+
+#[cfg(feature = "2D")]
+/// Weighted cellular automaton plugin type for Lenia in 2D
+pub type Lenia2dPlugin = WeightedCellularAutomatonPlugin<KernelCell2d, LeniaState>;
+
+#[cfg(feature = "2D")]
+/// Weighted cellular automaton plugin type for Smooth Life in 2D
+pub type SmoothLife2dPlugin = WeightedCellularAutomatonPlugin<KernelCell2d, SmoothLifeState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the forest-fire model in 2D
+pub type ForestFire2dPlugin = ParametricCellularAutomatonPlugin<NeumannCell2d, ForestFireState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the SEIR epidemic model in 2D
+pub type Seir2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, SeirCellState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the SIRS epidemic model with
+/// waning immunity in 2D
+pub type Sirs2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, SirsCellState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the SEIR epidemic model with
+/// a severity pathway in 2D
+pub type Severity2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, SeverityCellState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for stochastic SIR population
+/// cohorts in 2D
+pub type Cohort2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, CohortState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the multi-strain SIR epidemic
+/// model with cross-immunity in 2D
+pub type MultiStrain2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, MultiStrainState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for population cohorts with `G`
+/// age groups in 2D
+pub type AgeCohort2dPlugin<const G: usize> =
+    ParametricCellularAutomatonPlugin<MooreCell2d, AgeCohortState<G>>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for populations of `N`
+/// households in 2D
+pub type Household2dPlugin<const N: usize> =
+    ParametricCellularAutomatonPlugin<MooreCell2d, HouseholdState<N>>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for Greenberg–Hastings excitable
+/// media in 2D
+pub type Excitable2dPlugin = ParametricCellularAutomatonPlugin<MooreCell2d, ExcitableState>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the `Q`-state Potts model in
+/// 2D
+pub type Potts2dPlugin<const Q: u8> =
+    ParametricCellularAutomatonPlugin<NeumannCell2d, PottsState<Q>>;
+
+#[cfg(feature = "2D")]
+/// Parametric cellular automaton plugin type for the Ising model in 2D
+pub type Ising2dPlugin = Potts2dPlugin<2>;
+
+#[cfg(feature = "2D")]
+/// Moving cellular automaton plugin type for the Wa-Tor predator-prey model in
+/// 2D
+pub type WaTor2dPlugin = MovingCellularAutomatonPlugin<NeumannCell2d, WaTorState>;
+
+#[cfg(feature = "2D")]
+/// Relocating cellular automaton plugin type for the Schelling segregation
+/// model in 2D
+pub type Schelling2dPlugin = RelocatingCellularAutomatonPlugin<MooreCell2d, SchellingState>;
+
+#[cfg(feature = "2D")]
+/// Moving cellular automaton plugin type for falling sand in 2D. The
+/// `with_sweep` update mode should be enabled.
+pub type FallingSand2dPlugin = MovingCellularAutomatonPlugin<MooreCell2d, FallingSandState>;
+
+#[cfg(feature = "2D")]
+/// Reiter plugin type for snowflake growth on hexagonal cells in 2D
+pub type Reiter2dPlugin = ReiterPlugin<HexagonCell2d>;
+
+#[cfg(feature = "2D")]
+/// Sandpile plugin type for the abelian sandpile model in 2D
+pub type Sandpile2dPlugin = SandpilePlugin<NeumannCell2d>;
@@ -0,0 +1,450 @@
+use super::{add_tick_systems, AutomatonConfig, CellularAutomatonSet, SimulationTick};
+use crate::{
+    components::{
+        Cell, CellState, CoupledCellState, MovingCellState, ParametricCellState,
+        RelocatingCellState, TraceableCellState, WeightedCell, WeightedCellState,
+    },
+    events::TransmissionEvent,
+    resources::MapSize,
+    systems::{
+        cells::{
+            apply_next_states, cache_neighbor_entities, handle_cached_cells, handle_cells,
+            handle_changed_cells, handle_coupled_cells, handle_parametric_cells,
+            handle_traced_cells, handle_weighted_cells, DenseStateMap,
+        },
+        moving::handle_moving_cells,
+        relocation::relocate_cells,
+    },
+};
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*, utils::HashMap};
+use std::marker::PhantomData;
+
+/// Generic Cellular Automaton plugin. It will register systems for the matching
+/// `Cell` and `CellState` types.
+///
+/// The `with_batch_size` option computes the cells in parallel, in batches of
+/// a given size or of an auto-tuned one. It has a big performance impact on
+/// worlds with a lot of cells.
+pub struct CellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Should the neighbor entities be cached in a [`NeighborEntities`]
+    /// component ? Requires the [`CellMap`].
+    ///
+    /// [`NeighborEntities`]: crate::NeighborEntities
+    /// [`CellMap`]: crate::CellMap
+    pub use_cached_neighbors: bool,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: CellState> Plugin for CellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        if self.use_cached_neighbors {
+            self.config
+                .build_automaton::<C, S, _>(app, handle_cached_cells::<C, S>);
+            app.add_systems(
+                SimulationTick,
+                (cache_neighbor_entities::<C>, apply_deferred)
+                    .chain()
+                    .in_set(CellularAutomatonSet::MapUpdate)
+                    .after(handle_changed_cells::<C>),
+            );
+        } else if self.config.dense_map_size.is_some() {
+            self.config
+                .build_automaton::<C, S, _>(app, handle_cells::<C, S, DenseStateMap<S>>);
+        } else {
+            self.config
+                .build_automaton::<C, S, _>(app, handle_cells::<C, S, HashMap<C::Coordinates, S>>);
+        }
+    }
+}
+
+impl<C, S> CellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            use_cached_neighbors: false,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, cell_map, batch_size, dense_map, schedule);
+
+    /// The plugin will cache the neighbor entities of every cell in a
+    /// [`NeighborEntities`] component, skipping the per-tick neighbor
+    /// coordinates lookups. The cache is rebuilt when cells are spawned, moved
+    /// or removed.
+    ///
+    /// This enables the [`CellMap`] as well.
+    ///
+    /// [`NeighborEntities`]: crate::NeighborEntities
+    /// [`CellMap`]: crate::CellMap
+    #[must_use]
+    #[inline]
+    pub const fn with_cached_neighbors(mut self) -> Self {
+        self.config.use_cell_map = true;
+        self.use_cached_neighbors = true;
+        self
+    }
+}
+
+impl<C, S> Default for CellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Weighted Cellular Automaton plugin. It will register systems for the
+/// matching [`WeightedCell`] and [`WeightedCellState`] types, the rules
+/// receiving the weight of every neighbor.
+///
+/// It has the same options as the [`CellularAutomatonPlugin`], except the
+/// cached neighbors.
+pub struct WeightedCellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`WeightedCell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`WeightedCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: WeightedCell, S: WeightedCellState> Plugin for WeightedCellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        if self.config.dense_map_size.is_some() {
+            self.config
+                .build_automaton::<C, S, _>(app, handle_weighted_cells::<C, S, DenseStateMap<S>>);
+        } else {
+            self.config.build_automaton::<C, S, _>(
+                app,
+                handle_weighted_cells::<C, S, HashMap<C::Coordinates, S>>,
+            );
+        }
+    }
+}
+
+impl<C, S> WeightedCellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, cell_map, batch_size, dense_map, schedule);
+}
+
+impl<C, S> Default for WeightedCellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parametric Cellular Automaton plugin. It will register systems for the
+/// matching [`Cell`] and [`ParametricCellState`] types.
+///
+/// The rules read the global parameters of the
+/// [`ParametricCellState::Params`] resource. The parameters resource is
+/// initialized with its default value if it wasn't inserted. It has the same
+/// options as the [`WeightedCellularAutomatonPlugin`].
+pub struct ParametricCellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`ParametricCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: ParametricCellState> Plugin for ParametricCellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<S::Params>()
+            .register_type::<S::Params>();
+        if self.config.dense_map_size.is_some() {
+            self.config
+                .build_automaton::<C, S, _>(app, handle_parametric_cells::<C, S, DenseStateMap<S>>);
+        } else {
+            self.config.build_automaton::<C, S, _>(
+                app,
+                handle_parametric_cells::<C, S, HashMap<C::Coordinates, S>>,
+            );
+        }
+    }
+}
+
+impl<C, S> ParametricCellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, cell_map, batch_size, dense_map, schedule);
+}
+
+impl<C, S> Default for ParametricCellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Traced Cellular Automaton plugin. It will register systems for the
+/// matching [`Cell`] and [`TraceableCellState`] types.
+///
+/// The cells follow the rules of the [`ParametricCellularAutomatonPlugin`],
+/// and a [`TransmissionEvent`] is sent for every infection attributed to a
+/// neighbor, for contact tracing, with the current [`Generation`].
+///
+/// The [`DirtyChunks`], [`SleepingCells`], [`RegionOfInterest`],
+/// [`UpdateProbability`] and [`SimulationRng`] resources apply as for the
+/// other plugins. As the events are sent in order, the cells are always
+/// updated sequentially, so the [`SimulationBatch`] is ignored. The whole
+/// generation is computed within the tick, ignoring the [`TickBudget`] and
+/// the [`AsyncCompute`] resources.
+///
+/// [`Generation`]: crate::Generation
+/// [`DirtyChunks`]: crate::DirtyChunks
+/// [`SleepingCells`]: crate::SleepingCells
+/// [`RegionOfInterest`]: crate::RegionOfInterest
+/// [`UpdateProbability`]: crate::UpdateProbability
+/// [`SimulationRng`]: crate::SimulationRng
+/// [`SimulationBatch`]: crate::SimulationBatch
+/// [`TickBudget`]: crate::TickBudget
+/// [`AsyncCompute`]: crate::AsyncCompute
+pub struct TracedCellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`TraceableCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: TraceableCellState> Plugin for TracedCellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<S::Params>()
+            .register_type::<S::Params>()
+            .add_event::<TransmissionEvent>();
+        self.config
+            .build_automaton::<C, S, _>(app, handle_traced_cells::<C, S>);
+    }
+}
+
+impl<C, S> TracedCellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, cell_map, schedule);
+}
+
+impl<C, S> Default for TracedCellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Coupled Cellular Automaton plugin. It will register systems for the
+/// matching [`Cell`] and [`CoupledCellState`] types, coupled to a layer of `O`
+/// states on the same grid.
+///
+/// The rules read the `O` state at the coordinates of every cell, whether on
+/// the same entity or on another entity with the same coordinates. To couple
+/// both layers to each other, add a plugin for each layer with the same time
+/// step: both layers are updated from the states of the previous generation.
+pub struct CoupledCellularAutomatonPlugin<C, S, O> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CoupledCellState`) type
+    pub phantom_s: PhantomData<S>,
+    /// Phantom data for the `O` (`CellState`) type of the other layer
+    pub phantom_o: PhantomData<O>,
+}
+
+impl<C, S, O> Plugin for CoupledCellularAutomatonPlugin<C, S, O>
+where
+    C: Cell,
+    S: CoupledCellState<O>,
+    O: CellState,
+{
+    fn build(&self, app: &mut App) {
+        self.config
+            .build_automaton::<C, S, _>(app, handle_coupled_cells::<C, S, O>);
+    }
+}
+
+impl<C, S, O> CoupledCellularAutomatonPlugin<C, S, O> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+            phantom_o: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, cell_map, batch_size, schedule);
+}
+
+impl<C, S, O> Default for CoupledCellularAutomatonPlugin<C, S, O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Moving Cellular Automaton plugin. It will register systems for the
+/// matching [`Cell`] and [`MovingCellState`] types.
+///
+/// The cells are updated one after the other in a random order, their rules
+/// moving the cell contents by claiming neighbor cells. A [`CellMap`] resource
+/// is added to locate the neighbors.
+///
+/// In *sweep* mode, the cells are instead updated row by row from the bottom
+/// up (see [`CellCoordinates::sweep_row`]), so falling cells like the
+/// [`FallingSandState`] may fall together in a single tick.
+///
+/// [`CellMap`]: crate::CellMap
+/// [`CellCoordinates::sweep_row`]: crate::CellCoordinates::sweep_row
+/// [`FallingSandState`]: crate::FallingSandState
+pub struct MovingCellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins, the [`CellMap`]
+    /// being always added
+    ///
+    /// [`CellMap`]: crate::CellMap
+    pub config: AutomatonConfig,
+    /// Should the cells be updated in a bottom-up sequential sweep rather than
+    /// in a random order ?
+    pub sweep: bool,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`MovingCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: MovingCellState> Plugin for MovingCellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        let config = self.config.with_cell_map();
+        if self.sweep {
+            config.build_automaton::<C, S, _>(
+                app,
+                handle_moving_cells::<C, S, true>.after(handle_changed_cells::<C>),
+            );
+        } else {
+            config.build_automaton::<C, S, _>(
+                app,
+                handle_moving_cells::<C, S, false>.after(handle_changed_cells::<C>),
+            );
+        }
+    }
+}
+
+impl<C, S> MovingCellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            sweep: false,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, schedule);
+
+    /// Updates the cells in a bottom-up sequential sweep
+    #[must_use]
+    #[inline]
+    pub const fn with_sweep(mut self) -> Self {
+        self.sweep = true;
+        self
+    }
+}
+
+impl<C, S> Default for MovingCellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Relocating Cellular Automaton plugin. It will register systems for the
+/// matching [`Cell`] and [`RelocatingCellState`] types.
+///
+/// After the regular update of the [`ParametricCellularAutomatonPlugin`],
+/// every unsatisfied cell is swapped with a random vacant cell of the map.
+pub struct RelocatingCellularAutomatonPlugin<C, S> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`RelocatingCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: RelocatingCellState> Plugin for RelocatingCellularAutomatonPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<S::Params>()
+            .register_type::<S::Params>();
+        self.config.build_automaton::<C, S, _>(
+            app,
+            handle_parametric_cells::<C, S, HashMap<C::Coordinates, S>>,
+        );
+        let system = relocate_cells::<C, S>.after(apply_next_states::<S>);
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ApplyNewStates,
+            self.config.tick_time_step,
+            system,
+        );
+    }
+}
+
+impl<C, S> RelocatingCellularAutomatonPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, schedule);
+}
+
+impl<C, S> Default for RelocatingCellularAutomatonPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,196 @@
+use super::{
+    add_tick_systems, init_cell_map, CellularAutomatonSet, SimulationTick, SimulationTickPlugin,
+};
+use crate::{
+    components::{Cell, CellState},
+    resources::{MapSize, SimulationBatch},
+    systems::{
+        cells::{apply_next_states, insert_state_buffers},
+        dirty_chunks::track_dirty_chunks,
+        sleeping::track_sleeping_cells,
+    },
+};
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    log,
+    prelude::*,
+};
+
+/// Settings shared by the cellular automaton plugins, whose systems are
+/// registered by [`AutomatonConfig::build_automaton`].
+///
+/// The plugins only expose the options they support, as builder methods
+/// setting their `config`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AutomatonConfig {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+    /// Should a [`CellMap`] be resource be added and filled ?
+    ///
+    /// [`CellMap`]: crate::CellMap
+    pub use_cell_map: bool,
+    /// Bounded grid size of the dense state map. If not set, the states are
+    /// stored in a hash map.
+    pub dense_map_size: Option<MapSize>,
+    /// Parallel batching of the cells, inserted as a [`SimulationBatch`]
+    /// resource if set
+    pub batch: Option<SimulationBatch>,
+    /// Schedule running the [`SimulationTick`] schedule. If not set, the
+    /// ticks run in `Update`.
+    pub schedule: Option<InternedScheduleLabel>,
+}
+
+impl AutomatonConfig {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            use_cell_map: false,
+            dense_map_size: None,
+            batch: None,
+            schedule: None,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value for systems execution
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+
+    /// The plugin will set a [`CellMap`] resource and dynamically update it
+    ///
+    /// [`CellMap`]: crate::CellMap
+    #[must_use]
+    #[inline]
+    pub const fn with_cell_map(mut self) -> Self {
+        self.use_cell_map = true;
+        self
+    }
+
+    /// The plugin will insert a [`SimulationBatch`] resource, computing the
+    /// cells in parallel in batches of `batch_size` cells, or of an auto-tuned
+    /// size if `None`
+    #[must_use]
+    #[inline]
+    pub const fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+        self.batch = Some(SimulationBatch::new().with_batch_size(batch_size));
+        self
+    }
+
+    /// The plugin will store the cell states in a dense array of `size`
+    /// instead of a hash map, removing hashing from the neighbor lookups.
+    ///
+    /// Only available for bounded grids whose coordinates support
+    /// [`CellCoordinates::dense_index`] (`i32`, `IVec2` and `IVec3`), cells out
+    /// of the grid are ignored. The `size` is inserted as a [`MapSize`] resource.
+    ///
+    /// [`CellCoordinates::dense_index`]: crate::CellCoordinates::dense_index
+    #[must_use]
+    #[inline]
+    pub const fn with_dense_map(mut self, size: MapSize) -> Self {
+        self.dense_map_size = Some(size);
+        self
+    }
+
+    /// The simulation ticks will run in the `schedule` instead of `Update`,
+    /// like `FixedUpdate` to decouple them from the frame rate. This sets the
+    /// schedule of the [`SimulationTickPlugin`], shared by every plugin.
+    #[must_use]
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = Some(schedule.intern());
+        self
+    }
+
+    /// Registers the systems shared by the cellular automaton plugins of `C`
+    /// cells and `S` states, with `handle_cells` applying the rules.
+    ///
+    /// The [`SimulationBatch`] and the dense [`MapSize`] are inserted as
+    /// resources if set, and the [`SimulationTickPlugin`] is added with the
+    /// `schedule` if missing.
+    #[cfg_attr(
+        not(feature = "auto-coloring"),
+        allow(clippy::extra_unused_type_parameters)
+    )]
+    pub fn build_automaton<C: Cell, S: CellState, M>(
+        &self,
+        app: &mut App,
+        handle_cells: impl IntoSystemConfigs<M>,
+    ) {
+        if let Some(batch) = self.batch {
+            app.insert_resource(batch);
+        }
+        if let Some(size) = self.dense_map_size {
+            app.insert_resource(size);
+        }
+        if let Some(schedule) = self.schedule {
+            if let Some(plugin) = app.get_added_plugins::<SimulationTickPlugin>().first() {
+                if plugin.schedule != schedule {
+                    log::warn!(
+                        "The simulation ticks already run in {:?}, ignoring {schedule:?}",
+                        plugin.schedule
+                    );
+                }
+            } else {
+                app.add_plugins(SimulationTickPlugin::new().with_schedule(schedule));
+            }
+        }
+        if self.use_cell_map {
+            init_cell_map::<C>(app);
+        }
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::MapUpdate,
+            self.tick_time_step,
+            (
+                insert_state_buffers::<S>,
+                track_dirty_chunks::<C, S>,
+                track_sleeping_cells::<C, S>,
+            ),
+        );
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            handle_cells,
+        );
+        app.add_systems(
+            SimulationTick,
+            apply_next_states::<S>.in_set(CellularAutomatonSet::ApplyNewStates),
+        );
+
+        #[cfg(feature = "auto-coloring")]
+        {
+            #[cfg(any(feature = "1D", feature = "2D"))]
+            {
+                app.add_systems(
+                    Update,
+                    crate::systems::coloring::color_sprites::<S>
+                        .in_set(CellularAutomatonSet::Coloring),
+                );
+            }
+            #[cfg(feature = "1D")]
+            {
+                app.add_systems(
+                    Update,
+                    crate::systems::scrolling::scroll_generations::<C, S>
+                        .in_set(CellularAutomatonSet::Coloring)
+                        .run_if(resource_exists::<crate::GenerationScroll>),
+                );
+            }
+            #[cfg(feature = "3D")]
+            {
+                log::warn!("No auto coloring is available for 3D materials");
+            }
+        }
+        log::info!("Loaded cellular automaton plugin");
+    }
+}
@@ -0,0 +1,281 @@
+use super::{add_tick_systems, SimulationSet};
+use crate::{
+    components::{Cell, InfectableCellState, VaccinableCellState},
+    systems::{
+        hospitals::manage_hospitals, importation::import_infections,
+        interventions::run_interventions, seasons::force_seasons, vaccination::vaccinate_cells,
+    },
+};
+use bevy::{log, prelude::*};
+use std::marker::PhantomData;
+
+/// Vaccination plugin, to use along an epidemic cellular automaton plugin with
+/// the same `Cell` and [`VaccinableCellState`] types.
+///
+/// Every tick, the cells targeted by the [`Vaccination`] resource, if any, have
+/// a fraction of their susceptible individuals vaccinated: everywhere, in a
+/// region or, with ring vaccination, around the infected cells. Vaccinations
+/// happen before the cells are updated.
+///
+/// [`Vaccination`]: crate::Vaccination
+pub struct VaccinationPlugin<C, S> {
+    /// Custom time step (in seconds) between vaccinations. If not set,
+    /// vaccinations happen every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: VaccinableCellState> Plugin for VaccinationPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        let system = vaccinate_cells::<C, S>;
+        add_tick_systems(app, SimulationSet::PreTick, self.tick_time_step, system);
+        log::info!("Loaded vaccination plugin");
+    }
+}
+
+impl<C, S> VaccinationPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between vaccinations
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl<C, S> Default for VaccinationPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Importation plugin, to use along an epidemic cellular automaton plugin with
+/// the same `Cell` and [`InfectableCellState`] types.
+///
+/// Every tick, the infections of the [`Importation`] resource, if any, are
+/// seeded in random target cells before the cells are updated, for endemic
+/// and re-introduction scenarios.
+///
+/// [`Importation`]: crate::Importation
+pub struct ImportationPlugin<C, S> {
+    /// Custom time step (in seconds) between ticks. If not set, the ticks
+    /// happen every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: InfectableCellState> Plugin for ImportationPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        let system = import_infections::<C, S>;
+        add_tick_systems(app, SimulationSet::PreTick, self.tick_time_step, system);
+        log::info!("Loaded importation plugin");
+    }
+}
+
+impl<C, S> ImportationPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between ticks
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl<C, S> Default for ImportationPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Seasonal forcing plugin, to use along an epidemic cellular automaton plugin
+/// reading the [`EpidemicParams`] like the `Seir2dPlugin`.
+///
+/// Every tick, the multiplier of the [`SeasonalForcing`] resource, if any, is
+/// written to the [`EpidemicParams`] `seasonality`, scaling the transmission
+/// before the cells are updated.
+///
+/// [`EpidemicParams`]: crate::EpidemicParams
+/// [`SeasonalForcing`]: crate::SeasonalForcing
+pub struct SeasonalForcingPlugin {
+    /// Custom time step (in seconds) between generations. If not set, the
+    /// generations advance every frame.
+    pub tick_time_step: Option<f64>,
+}
+
+impl Plugin for SeasonalForcingPlugin {
+    fn build(&self, app: &mut App) {
+        add_tick_systems(
+            app,
+            SimulationSet::PreTick,
+            self.tick_time_step,
+            force_seasons,
+        );
+        log::info!("Loaded seasonal forcing plugin");
+    }
+}
+
+impl SeasonalForcingPlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between generations, which should
+    /// match the time step of the epidemic plugin
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl Default for SeasonalForcingPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Intervention plugin, running the interventions of the
+/// [`InterventionTimeline`] resource, if any, at their tick.
+///
+/// Every tick, the interventions scheduled at the current tick run with an
+/// exclusive access to the world, before the cells are updated.
+///
+/// [`InterventionTimeline`]: crate::InterventionTimeline
+pub struct InterventionPlugin {
+    /// Custom time step (in seconds) between ticks. If not set, the ticks
+    /// advance every frame.
+    pub tick_time_step: Option<f64>,
+}
+
+impl Plugin for InterventionPlugin {
+    fn build(&self, app: &mut App) {
+        add_tick_systems(
+            app,
+            SimulationSet::PreTick,
+            self.tick_time_step,
+            run_interventions,
+        );
+        log::info!("Loaded intervention plugin");
+    }
+}
+
+impl InterventionPlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between ticks, which should match
+    /// the time step of the cellular automaton plugin
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl Default for InterventionPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hospital capacity plugin, to use along a [`SeverityCellState`] cellular
+/// automaton plugin like the `Severity2dPlugin`.
+///
+/// Every tick, the beds of the [`HospitalCapacity`] resource, if any, are
+/// counted and shared between the severe cells waiting for one: the admission
+/// probability is written to the [`SeverityParams`] before the cells are
+/// updated.
+///
+/// [`SeverityCellState`]: crate::SeverityCellState
+/// [`HospitalCapacity`]: crate::HospitalCapacity
+/// [`SeverityParams`]: crate::SeverityParams
+pub struct HospitalCapacityPlugin {
+    /// Custom time step (in seconds) between generations. If not set, the
+    /// generations advance every frame.
+    pub tick_time_step: Option<f64>,
+}
+
+impl Plugin for HospitalCapacityPlugin {
+    fn build(&self, app: &mut App) {
+        add_tick_systems(
+            app,
+            SimulationSet::PreTick,
+            self.tick_time_step,
+            manage_hospitals,
+        );
+        log::info!("Loaded hospital capacity plugin");
+    }
+}
+
+impl HospitalCapacityPlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between generations, which should
+    /// match the time step of the epidemic plugin
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl Default for HospitalCapacityPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,129 @@
+use super::{
+    add_tick_systems, init_simulation, CellularAutomatonSet, SimulationSet, SimulationTick,
+};
+use crate::{
+    components::{Cell, CellState, CoarseCellState},
+    resources::LevelOfDetail,
+    systems::{
+        generation::generation_complete,
+        level_of_detail::{step_super_cells, update_level_of_detail},
+        sparse::expand_sparse_cells,
+        speed::run_simulation_ticks,
+    },
+};
+use bevy::{log, prelude::*};
+use std::marker::PhantomData;
+
+/// Infinite grid plugin, to use along a cellular automaton plugin with the
+/// same `Cell` and `CellState` types.
+///
+/// Only the live cells and their neighbors need to exist as entities: the
+/// missing neighbors of live cells are spawned with the default (dead) state,
+/// and dead cells without any live neighbor are despawned. Patterns like
+/// gliders may then travel indefinitely instead of hitting the map edges.
+///
+/// Spawned cells only have the `C` and `S` components, use an `Added<C>`
+/// query to add rendering components.
+pub struct InfiniteGridPlugin<C, S> {
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C, S> Plugin for InfiniteGridPlugin<C, S>
+where
+    C: Cell + From<C::Coordinates>,
+    S: CellState + Default,
+{
+    fn build(&self, app: &mut App) {
+        init_simulation(app);
+        app.add_systems(
+            SimulationTick,
+            expand_sparse_cells::<C, S>.in_set(SimulationSet::PreTick),
+        );
+        log::info!("Loaded infinite grid plugin");
+    }
+}
+
+impl<C, S> InfiniteGridPlugin<C, S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Default for InfiniteGridPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Level of detail plugin, to use along a cellular automaton plugin with the
+/// same `Cell` and [`CoarseCellState`] types.
+///
+/// Every frame, outside of the generations computed across several ticks, the
+/// regions far from the focus of the [`LevelOfDetail`] resource, if any, are
+/// coarse-grained into super-cells and the super-cells near the focus are
+/// refined back into cells. Every tick, the super-cells follow their
+/// approximate rule.
+///
+/// Refined cells only have the `C` and `S` components, use the
+/// [`LevelOfDetail::with_on_spawn`] hook to add rendering components. The
+/// super-cells are not drawn.
+pub struct LevelOfDetailPlugin<C, S> {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CoarseCellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C, S> Plugin for LevelOfDetailPlugin<C, S>
+where
+    C: Cell<Coordinates = IVec2> + From<IVec2>,
+    S: CoarseCellState,
+{
+    fn build(&self, app: &mut App) {
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            step_super_cells::<S>.run_if(resource_exists::<LevelOfDetail<S>>),
+        );
+        app.add_systems(
+            Update,
+            update_level_of_detail::<C, S>
+                .before(run_simulation_ticks)
+                .run_if(resource_exists::<LevelOfDetail<S>>)
+                .run_if(generation_complete),
+        );
+        log::info!("Loaded level of detail plugin");
+    }
+}
+
+impl<C, S> LevelOfDetailPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Default for LevelOfDetailPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,148 @@
+use super::{add_tick_systems, SimulationSet};
+use crate::{
+    components::{Cell, CellState},
+    resources::Playback,
+    systems::{
+        history::{record_history, rewind_history, HistoryRewinds},
+        recording::{advance_playback, apply_playback, record_cells},
+    },
+};
+use bevy::{log, prelude::*};
+use std::marker::PhantomData;
+
+/// History plugin, recording the `S` cell states of the last generations in the
+/// [`SimulationHistory`] resource, if any, to restore them with the [`Rewind`]
+/// command.
+///
+/// [`SimulationHistory`]: crate::SimulationHistory
+/// [`Rewind`]: crate::Rewind
+pub struct SimulationHistoryPlugin<S> {
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S: CellState> Plugin for SimulationHistoryPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let system = record_history::<S>;
+        // The initial states are recorded before the first tick
+        add_tick_systems(app, SimulationSet::PreTick, None, system);
+        add_tick_systems(app, SimulationSet::PostTick, None, system);
+        app.world
+            .get_resource_or_insert_with(HistoryRewinds::default)
+            .0
+            .push(rewind_history::<S>);
+        log::info!("Loaded simulation history plugin");
+    }
+}
+
+impl<S> SimulationHistoryPlugin<S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for SimulationHistoryPlugin<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Recording plugin, recording the `S` states of the `C` cells every
+/// generation in the [`Recording`] resource, if any.
+///
+/// The states of every cell are recorded before the first tick, then only the
+/// changed states after every tick.
+///
+/// [`Recording`]: crate::Recording
+pub struct RecordingPlugin<C, S> {
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: CellState> Plugin for RecordingPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        let system = record_cells::<C, S>;
+        add_tick_systems(app, SimulationSet::PreTick, None, system);
+        add_tick_systems(app, SimulationSet::PostTick, None, system);
+        log::info!("Loaded recording plugin");
+    }
+}
+
+impl<C, S> RecordingPlugin<C, S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Default for RecordingPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Playback plugin, replaying the `S` states of the `C` cells from the
+/// [`Recording`] resource without applying any rule.
+///
+/// It should be added instead of the cellular automaton plugin. The states of
+/// the [`Playback`] generation are applied at the end of every frame to the
+/// existing cells matching the recorded coordinates, the playback advancing
+/// one generation every running tick.
+///
+/// [`Recording`]: crate::Recording
+pub struct PlaybackPlugin<C, S> {
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+/// Advances the [`Playback`] once for every [`PlaybackPlugin`]
+struct PlaybackTickPlugin;
+
+impl Plugin for PlaybackTickPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Playback>();
+        add_tick_systems(app, SimulationSet::Tick, None, advance_playback);
+    }
+}
+
+impl<C: Cell, S: CellState> Plugin for PlaybackPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<PlaybackTickPlugin>() {
+            app.add_plugins(PlaybackTickPlugin);
+        }
+        app.add_systems(PostUpdate, apply_playback::<C, S>);
+        log::info!("Loaded playback plugin");
+    }
+}
+
+impl<C, S> PlaybackPlugin<C, S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Default for PlaybackPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,213 @@
+use super::{add_tick_systems, init_simulation, CellularAutomatonSet, SimulationTick};
+use crate::{
+    components::Cell,
+    resources::{HashLife, LifeBitboard},
+    systems::{
+        bitboard::{load_bitboard_cells, step_bitboard, sync_bitboard_cells},
+        hash_life::{materialize_hash_life, step_hash_life},
+        speed::run_simulation_ticks,
+    },
+};
+#[cfg(feature = "gpu")]
+use crate::{
+    resources::GpuLifeGrid,
+    systems::gpu_life::{
+        build_gpu_life_render_app, count_gpu_ticks, finish_gpu_life_render_app, reset_gpu_ticks,
+        GpuTicks,
+    },
+};
+use bevy::{log, prelude::*};
+use std::marker::PhantomData;
+
+#[cfg(feature = "gpu")]
+/// GPU life plugin. It will register the compute shader applying the
+/// [`LifeLikeRule`] of the [`GpuLifeGrid`] resource, if any.
+///
+/// Unlike the [`CellularAutomatonPlugin`], the cells are not entities but the
+/// texels of the [`GpuLifeGrid`] image, so much larger grids may be simulated.
+/// Every tick is counted on the main world, and the ticks of a frame are all
+/// run by the render world, before the cameras are drawn. The [`Generation`]
+/// and [`SimulationSpeed`] resources apply as with entity cells.
+///
+/// [`LifeLikeRule`]: crate::LifeLikeRule
+/// [`CellularAutomatonPlugin`]: super::CellularAutomatonPlugin
+/// [`Generation`]: crate::Generation
+/// [`SimulationSpeed`]: crate::SimulationSpeed
+pub struct GpuLifePlugin {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+}
+
+#[cfg(feature = "gpu")]
+impl Plugin for GpuLifePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GpuTicks>();
+        app.add_systems(First, reset_gpu_ticks);
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            count_gpu_ticks.run_if(resource_exists::<GpuLifeGrid>),
+        );
+        build_gpu_life_render_app(app);
+        log::info!("Loaded GPU life plugin");
+    }
+
+    fn finish(&self, app: &mut App) {
+        finish_gpu_life_render_app(app);
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl GpuLifePlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+        }
+    }
+}
+
+#[cfg(feature = "gpu")]
+impl Default for GpuLifePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bitboard life plugin. It will register the systems simulating the
+/// [`LifeBitboard`] resource, if any, with cells of type `C` displaying it
+/// through a [`ConwayCellState`].
+///
+/// Unlike the [`CellularAutomatonPlugin`], the rules are not applied to the
+/// cell entities but to the bitboard, 64 cells at a time, and the cell states
+/// are only synced from the bitboard once per frame for rendering. Cell states
+/// changed outside of the simulation, like spawned cells, are written back to
+/// the bitboard before every tick.
+///
+/// [`ConwayCellState`]: crate::ConwayCellState
+/// [`CellularAutomatonPlugin`]: super::CellularAutomatonPlugin
+pub struct BitboardLifePlugin<C> {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell<Coordinates = IVec2>> Plugin for BitboardLifePlugin<C> {
+    fn build(&self, app: &mut App) {
+        init_simulation(app);
+        app.add_systems(
+            SimulationTick,
+            load_bitboard_cells::<C>
+                .in_set(CellularAutomatonSet::MapUpdate)
+                .run_if(resource_exists::<LifeBitboard>),
+        );
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            step_bitboard.run_if(resource_exists::<LifeBitboard>),
+        );
+        app.add_systems(
+            Update,
+            sync_bitboard_cells::<C>
+                .after(run_simulation_ticks)
+                .before(CellularAutomatonSet::Coloring)
+                .run_if(resource_exists::<LifeBitboard>),
+        );
+        #[cfg(feature = "auto-coloring")]
+        app.add_systems(
+            Update,
+            crate::systems::coloring::color_sprites::<crate::ConwayCellState>
+                .in_set(CellularAutomatonSet::Coloring),
+        );
+        log::info!("Loaded bitboard life plugin");
+    }
+}
+
+impl<C> BitboardLifePlugin<C> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for BitboardLifePlugin<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// [HashLife] plugin. It will register the systems fast-forwarding the
+/// [`HashLife`] resource, if any, and re-materializing its live cells into
+/// cells of type `C` with a [`ConwayCellState`].
+///
+/// Every tick, the [`HashLife`] computes `2^step` generations at once, while
+/// the [`Generation`] resource counts the ticks. Once per frame, the cell
+/// entities are synced with the live cells, the missing live cells being
+/// spawned with only the `C` and [`ConwayCellState`] components: use an
+/// `Added<C>` query to add rendering components.
+///
+/// [HashLife]: https://conwaylife.com/wiki/HashLife
+/// [`ConwayCellState`]: crate::ConwayCellState
+/// [`Generation`]: crate::Generation
+pub struct HashLifePlugin<C> {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell<Coordinates = IVec2> + From<IVec2>> Plugin for HashLifePlugin<C> {
+    fn build(&self, app: &mut App) {
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            step_hash_life.run_if(resource_exists::<HashLife>),
+        );
+        app.add_systems(
+            Update,
+            materialize_hash_life::<C>
+                .after(run_simulation_ticks)
+                .before(CellularAutomatonSet::Coloring)
+                .run_if(resource_exists::<HashLife>),
+        );
+        #[cfg(feature = "auto-coloring")]
+        app.add_systems(
+            Update,
+            crate::systems::coloring::color_sprites::<crate::ConwayCellState>
+                .in_set(CellularAutomatonSet::Coloring),
+        );
+        log::info!("Loaded HashLife plugin");
+    }
+}
+
+impl<C> HashLifePlugin<C> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for HashLifePlugin<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,329 @@
+use super::{add_tick_systems, SimulationSet};
+#[cfg(any(feature = "csv", feature = "parquet"))]
+use crate::systems::export::export_files;
+use crate::{
+    components::{Cell, CellState},
+    events::{CellStateChangedEvent, SimulationFinishedEvent},
+    resources::StateHash,
+    systems::{
+        clusters::detect_clusters,
+        state_events::{send_state_changed_events, KnownStates},
+        state_hash::hash_states,
+        termination::detect_termination,
+        time_series::{export_time_series, record_time_series},
+    },
+};
+#[cfg(feature = "egui-plot")]
+use crate::{resources::TimeSeriesPlot, systems::plotting::plot_time_series};
+use bevy::{log, prelude::*};
+use std::marker::PhantomData;
+
+/// Cluster detection plugin, finding the clusters of `S` cell states matching
+/// the predicate of the [`ClusterDetection`] resource, if any.
+///
+/// The clusters are the connected components of matching [`Cell`] neighbors,
+/// detected after the cells are updated. The [`BoundaryCondition`] is applied
+/// to the neighbors of bounded grids.
+///
+/// [`ClusterDetection`]: crate::ClusterDetection
+/// [`BoundaryCondition`]: crate::BoundaryCondition
+pub struct ClusterDetectionPlugin<C, S> {
+    /// Custom time step (in seconds) between ticks. If not set, the ticks
+    /// happen every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: CellState> Plugin for ClusterDetectionPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        let system = detect_clusters::<C, S>;
+        add_tick_systems(app, SimulationSet::PostTick, self.tick_time_step, system);
+        log::info!("Loaded cluster detection plugin");
+    }
+}
+
+impl<C, S> ClusterDetectionPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between ticks, which should match
+    /// the time step of the cellular automaton plugin
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl<C, S> Default for ClusterDetectionPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// State hash plugin, computing the [`StateHash`] of the `S` states of the `C`
+/// cells after every tick.
+pub struct StateHashPlugin<C, S> {
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S: CellState + std::hash::Hash> Plugin for StateHashPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<StateHash<S>>();
+        add_tick_systems(app, SimulationSet::PostTick, None, hash_states::<C, S>);
+        log::info!("Loaded state hash plugin");
+    }
+}
+
+impl<C, S> StateHashPlugin<C, S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<C, S> Default for StateHashPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Termination plugin, finishing the simulation of the `S` cell states when a
+/// condition of the [`Termination`] resource, if any, is met after a tick.
+///
+/// [`Termination`]: crate::Termination
+pub struct TerminationPlugin<S> {
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S: CellState> Plugin for TerminationPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SimulationFinishedEvent>();
+        add_tick_systems(app, SimulationSet::PostTick, None, detect_termination::<S>);
+        log::info!("Loaded termination plugin");
+    }
+}
+
+impl<S> TerminationPlugin<S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for TerminationPlugin<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Time series plugin, recording aggregates of the `S` cell states every tick.
+///
+/// After every tick, a row computed from every cell state is appended to the
+/// [`TimeSeriesRecorder`] resource, if any. The recorded time series is
+/// exported when the app exits.
+///
+/// [`TimeSeriesRecorder`]: crate::TimeSeriesRecorder
+pub struct TimeSeriesPlugin<S> {
+    /// Custom time step (in seconds) between records. If not set, a row is
+    /// recorded every frame.
+    pub tick_time_step: Option<f64>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S: CellState> Plugin for TimeSeriesPlugin<S> {
+    fn build(&self, app: &mut App) {
+        let system = record_time_series::<S>;
+        add_tick_systems(app, SimulationSet::PostTick, self.tick_time_step, system);
+        app.add_systems(Last, export_time_series::<S>);
+        log::info!("Loaded time series plugin");
+    }
+}
+
+impl<S> TimeSeriesPlugin<S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value between records, which should
+    /// match the time step of the cellular automaton plugin
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+}
+
+impl<S> Default for TimeSeriesPlugin<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Opt-in plugin sending a [`CellStateChangedEvent`] every time the `S` state
+/// of a cell actually changes.
+///
+/// The changes are detected before and after the rules of every tick, so
+/// changes made outside of the rules, by vaccination or user systems, are sent
+/// as well. Only the changed cells are visited, but the last known state of
+/// every cell is kept in memory.
+pub struct CellStateEventsPlugin<S> {
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S: CellState> Plugin for CellStateEventsPlugin<S> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KnownStates<S>>()
+            .add_event::<CellStateChangedEvent<S>>();
+        let system = send_state_changed_events::<S>;
+        add_tick_systems(app, SimulationSet::PreTick, None, system);
+        add_tick_systems(app, SimulationSet::PostTick, None, system);
+        log::info!("Loaded cell state events plugin");
+    }
+}
+
+impl<S> CellStateEventsPlugin<S> {
+    /// Instantiates Self
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<S> Default for CellStateEventsPlugin<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+/// Time series export plugin, writing the [`TimeSeriesRecorder`] of the `S`
+/// cell states and snapshots of the `C` cells to CSV or Parquet files.
+///
+/// The files of the [`TimeSeriesExport`] resource, if any, are written every
+/// `interval` recorded ticks and when the app exits. It should be added along a
+/// [`TimeSeriesPlugin`].
+///
+/// [`TimeSeriesRecorder`]: crate::TimeSeriesRecorder
+/// [`TimeSeriesExport`]: crate::TimeSeriesExport
+pub struct TimeSeriesExportPlugin<C, S> {
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+impl<C: Cell, S: CellState> Plugin for TimeSeriesExportPlugin<C, S> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, export_files::<C, S>);
+        log::info!("Loaded time series export plugin");
+    }
+}
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+impl<C, S> TimeSeriesExportPlugin<C, S> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            phantom_c: PhantomData,
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+#[cfg(any(feature = "csv", feature = "parquet"))]
+impl<C, S> Default for TimeSeriesExportPlugin<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "egui-plot")]
+/// Time series plot plugin, drawing the [`TimeSeriesRecorder`] of the `S` cell
+/// states live in an `egui` overlay.
+///
+/// The plotted columns and the overlay visibility are set by the
+/// [`TimeSeriesPlot`] resource. It should be added along a [`TimeSeriesPlugin`],
+/// and adds the `EguiPlugin` if missing.
+///
+/// [`TimeSeriesRecorder`]: crate::TimeSeriesRecorder
+pub struct TimeSeriesPlotPlugin<S> {
+    /// Title of the overlay window
+    pub title: String,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+#[cfg(feature = "egui-plot")]
+impl<S: CellState> Plugin for TimeSeriesPlotPlugin<S> {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<bevy_egui::EguiPlugin>() {
+            app.add_plugins(bevy_egui::EguiPlugin);
+        }
+        app.insert_resource(TimeSeriesPlot::<S>::new(self.title.clone()))
+            .add_systems(Update, plot_time_series::<S>);
+        log::info!("Loaded time series plot plugin");
+    }
+}
+
+#[cfg(feature = "egui-plot")]
+impl<S> TimeSeriesPlotPlugin<S> {
+    /// Instantiates Self with the overlay `title`
+    #[must_use]
+    #[inline]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "egui-plot")]
+impl<S> Default for TimeSeriesPlotPlugin<S> {
+    fn default() -> Self {
+        Self::new("Time series")
+    }
+}
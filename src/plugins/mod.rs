@@ -0,0 +1,132 @@
+use crate::{
+    components::Cell,
+    resources::{CellMap, SimulationState},
+    systems::cells::{handle_changed_cells, handle_removed_cells},
+};
+use bevy::{prelude::*, time::common_conditions::on_timer};
+use std::time::Duration;
+
+/// Implements the builder methods of the given [`AutomatonConfig`] options
+/// for a plugin with a `config` field
+macro_rules! config_builders {
+    ($($option:ident),*) => {
+        $(config_builders!(@$option);)*
+    };
+    (@time_step) => {
+        /// Sets a custom `tick_time_step` value for systems execution
+        #[must_use]
+        #[inline]
+        #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+        pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+            self.config.tick_time_step = Some(tick_time_step);
+            self
+        }
+    };
+    (@cell_map) => {
+        /// Sets the [`AutomatonConfig::with_cell_map`] option
+        #[must_use]
+        #[inline]
+        pub const fn with_cell_map(mut self) -> Self {
+            self.config = self.config.with_cell_map();
+            self
+        }
+    };
+    (@batch_size) => {
+        /// Sets the [`AutomatonConfig::with_batch_size`] option
+        #[must_use]
+        #[inline]
+        pub const fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+            self.config = self.config.with_batch_size(batch_size);
+            self
+        }
+    };
+    (@dense_map) => {
+        /// Sets the [`AutomatonConfig::with_dense_map`] option
+        #[must_use]
+        #[inline]
+        pub const fn with_dense_map(mut self, size: MapSize) -> Self {
+            self.config = self.config.with_dense_map(size);
+            self
+        }
+    };
+    (@schedule) => {
+        /// Sets the [`AutomatonConfig::with_schedule`] option
+        #[must_use]
+        #[inline]
+        pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+            self.config = self.config.with_schedule(schedule);
+            self
+        }
+    };
+}
+
+#[cfg(any(feature = "1D", feature = "2D", feature = "3D"))]
+pub use aliases::*;
+pub use automaton::*;
+pub use config::*;
+pub use epidemics::*;
+pub use grids::*;
+pub use history::*;
+pub use life::*;
+pub use measurement::*;
+pub use models::*;
+pub use simulation::*;
+
+#[cfg(any(feature = "1D", feature = "2D", feature = "3D"))]
+mod aliases;
+mod automaton;
+mod config;
+mod epidemics;
+mod grids;
+mod history;
+mod life;
+mod measurement;
+mod models;
+mod simulation;
+
+/// Adds the [`SimulationTickPlugin`] and the [`SimulationStatePlugin`] if
+/// missing
+fn init_simulation(app: &mut App) {
+    if !app.is_plugin_added::<SimulationTickPlugin>() {
+        app.add_plugins(SimulationTickPlugin::new());
+    }
+    if !app.is_plugin_added::<SimulationStatePlugin>() {
+        app.add_plugins(SimulationStatePlugin::new());
+    }
+}
+
+/// Adds the tick `systems` of a plugin to the `set` of the [`SimulationTick`]
+/// schedule, running every `tick_time_step` if set and only in the
+/// [`SimulationState::Running`] state
+fn add_tick_systems<M>(
+    app: &mut App,
+    set: impl SystemSet,
+    tick_time_step: Option<f64>,
+    systems: impl IntoSystemConfigs<M>,
+) {
+    init_simulation(app);
+    let systems = systems
+        .in_set(set)
+        .run_if(in_state(SimulationState::Running));
+    if let Some(time_step) = tick_time_step {
+        let duration = Duration::from_secs_f64(time_step);
+        app.add_systems(SimulationTick, systems.run_if(on_timer(duration)));
+    } else {
+        app.add_systems(SimulationTick, systems);
+    }
+}
+
+/// Adds the [`CellMap`] of the `C` cells, registering the systems inserting
+/// the new and moved cells and evicting the despawned cells, both before every
+/// tick and at the end of every frame
+fn init_cell_map<C: Cell>(app: &mut App) {
+    init_simulation(app);
+    app.init_resource::<CellMap<C>>();
+    app.add_systems(
+        SimulationTick,
+        (handle_removed_cells::<C>, handle_changed_cells::<C>)
+            .chain()
+            .in_set(CellularAutomatonSet::MapUpdate),
+    );
+    app.add_systems(PostUpdate, handle_removed_cells::<C>);
+}
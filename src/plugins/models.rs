@@ -0,0 +1,276 @@
+use super::AutomatonConfig;
+#[cfg(feature = "2D")]
+use super::{add_tick_systems, init_cell_map, CellularAutomatonSet};
+use crate::{
+    components::{Cell, ReiterState, SandpileState},
+    resources::ReiterParams,
+    systems::{
+        cells::handle_cells,
+        reiter::handle_reiter,
+        sandpile::{drop_grains, topple_sandpile},
+    },
+};
+#[cfg(feature = "1D")]
+use crate::{
+    components::{Cell1d, TrafficState},
+    resources::{TrafficParams, TrafficStats},
+    systems::traffic::handle_traffic,
+};
+#[cfg(feature = "2D")]
+use crate::{resources::TurmiteRules, systems::ants::move_ants};
+#[cfg(feature = "2D")]
+use bevy::log;
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*, utils::HashMap};
+use std::marker::PhantomData;
+
+/// Reiter snowflake growth plugin. It will register systems for cells of type
+/// `C` with a [`ReiterState`].
+///
+/// The rules depend on the receptivity of the neighbors, which depends on
+/// their own neighbors, so the whole map is updated by a single system
+/// reading the [`ReiterParams`] resource.
+pub struct ReiterPlugin<C> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell> Plugin for ReiterPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ReiterParams>();
+        self.config
+            .build_automaton::<C, ReiterState, _>(app, handle_reiter::<C>);
+    }
+}
+
+impl<C> ReiterPlugin<C> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            phantom_c: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, schedule);
+}
+
+impl<C> Default for ReiterPlugin<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Abelian sandpile plugin. It will register systems for cells of type `C`
+/// with a [`SandpileState`].
+///
+/// By default every unstable cell topples once per tick, like any cellular
+/// automaton. In *cascade* mode, cells keep toppling until the whole pile is
+/// stable, so every tick resolves complete avalanches.
+///
+/// Before toppling, the grains of an optional [`SandpileDrop`] resource are
+/// dropped on the pile.
+///
+/// [`SandpileDrop`]: crate::SandpileDrop
+pub struct SandpilePlugin<C> {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+    /// Should the cells topple until the pile is stable every tick ?
+    pub cascade: bool,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell> Plugin for SandpilePlugin<C> {
+    fn build(&self, app: &mut App) {
+        if self.cascade {
+            self.config.build_automaton::<C, SandpileState, _>(
+                app,
+                (drop_grains::<C>, topple_sandpile::<C>).chain(),
+            );
+        } else {
+            self.config.build_automaton::<C, SandpileState, _>(
+                app,
+                (
+                    drop_grains::<C>,
+                    handle_cells::<C, SandpileState, HashMap<C::Coordinates, SandpileState>>,
+                )
+                    .chain(),
+            );
+        }
+    }
+}
+
+impl<C> SandpilePlugin<C> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+            cascade: false,
+            phantom_c: PhantomData,
+        }
+    }
+
+    config_builders!(time_step, schedule);
+
+    /// Resolves complete toppling cascades every tick
+    #[must_use]
+    #[inline]
+    pub const fn with_cascade(mut self) -> Self {
+        self.cascade = true;
+        self
+    }
+}
+
+impl<C> Default for SandpilePlugin<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "1D")]
+/// Nagel–Schreckenberg traffic plugin. It will register the systems moving the
+/// cars of [`Cell1d`] roads with a [`TrafficState`].
+///
+/// Cars move by several cells per generation, so unlike the
+/// [`CellularAutomatonPlugin`] every car is moved by a single system. The
+/// [`TrafficParams`] resource defines the rules and the [`TrafficStats`]
+/// resource is updated every generation with the road density and flow.
+///
+/// A road is infinite by default, use a [`BoundaryCondition`] along with a
+/// [`MapSize`] for a circular road.
+///
+/// [`CellularAutomatonPlugin`]: super::CellularAutomatonPlugin
+/// [`BoundaryCondition`]: crate::BoundaryCondition
+/// [`MapSize`]: crate::MapSize
+pub struct TrafficPlugin {
+    /// Settings shared by the cellular automaton plugins
+    pub config: AutomatonConfig,
+}
+
+#[cfg(feature = "1D")]
+impl Plugin for TrafficPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TrafficParams>();
+        app.init_resource::<TrafficStats>();
+        self.config
+            .build_automaton::<Cell1d, TrafficState, _>(app, handle_traffic);
+    }
+}
+
+#[cfg(feature = "1D")]
+impl TrafficPlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            config: AutomatonConfig::new(),
+        }
+    }
+
+    config_builders!(time_step, schedule);
+}
+
+#[cfg(feature = "1D")]
+impl Default for TrafficPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "2D")]
+/// Langton's ant plugin. It will register the systems moving the [`Ant`]
+/// agents over cells of type `C` with an [`AntCellState`].
+///
+/// Unlike the [`CellularAutomatonPlugin`], cells have no rules of their own:
+/// each tick, every ant changes the state of the cell under it, then turns and
+/// moves. A [`CellMap`] resource is added to locate the cells.
+///
+/// Any [turmite] may be simulated with custom [`TurmiteRules`], which may also
+/// be modified at runtime as a resource.
+///
+/// [turmite]: https://en.wikipedia.org/wiki/Turmite
+/// [`Ant`]: crate::Ant
+/// [`AntCellState`]: crate::AntCellState
+/// [`CellularAutomatonPlugin`]: super::CellularAutomatonPlugin
+/// [`CellMap`]: crate::CellMap
+pub struct LangtonsAntPlugin<C> {
+    /// Custom time step (in seconds) constraint value for the systems. If not
+    /// set, the systems will run every frame.
+    pub tick_time_step: Option<f64>,
+    /// Custom transition table of the ants. If not set, the ants follow
+    /// Langton's ant rules.
+    pub rules: Option<TurmiteRules>,
+    /// Phantom data for the `C` (`Cell`) type
+    pub phantom_c: PhantomData<C>,
+}
+
+#[cfg(feature = "2D")]
+impl<C: Cell<Coordinates = IVec2>> Plugin for LangtonsAntPlugin<C> {
+    fn build(&self, app: &mut App) {
+        init_cell_map::<C>(app);
+        if let Some(rules) = &self.rules {
+            app.insert_resource(rules.clone());
+        } else {
+            app.init_resource::<TurmiteRules>();
+        }
+        let system = move_ants::<C>;
+        add_tick_systems(
+            app,
+            CellularAutomatonSet::ComputeNewStates,
+            self.tick_time_step,
+            system,
+        );
+        #[cfg(feature = "auto-coloring")]
+        app.add_systems(
+            Update,
+            crate::systems::coloring::color_sprites::<crate::AntCellState>
+                .in_set(CellularAutomatonSet::Coloring),
+        );
+        log::info!("Loaded Langton's ant plugin");
+    }
+}
+
+#[cfg(feature = "2D")]
+impl<C> LangtonsAntPlugin<C> {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            tick_time_step: None,
+            rules: None,
+            phantom_c: PhantomData,
+        }
+    }
+
+    /// Sets a custom `tick_time_step` value for systems execution
+    #[must_use]
+    #[inline]
+    #[deprecated(note = "use the `SimulationSpeed` resource instead")]
+    pub const fn with_time_step(mut self, tick_time_step: f64) -> Self {
+        self.tick_time_step = Some(tick_time_step);
+        self
+    }
+
+    /// Sets custom turmite `rules` for the ants
+    #[must_use]
+    #[inline]
+    pub fn with_rules(mut self, rules: TurmiteRules) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+}
+
+#[cfg(feature = "2D")]
+impl<C> Default for LangtonsAntPlugin<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
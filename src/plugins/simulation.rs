@@ -0,0 +1,220 @@
+use crate::{
+    events::{GenerationComputedEvent, NewGenerationEvent},
+    resources::{Generation, RegionOfInterest, SimulationState},
+    systems::{
+        generation::{advance_generation, generation_complete, PendingGenerations},
+        pausing::toggle_simulation_state,
+        region_of_interest::follow_region_of_interest,
+        speed::run_simulation_ticks,
+    },
+};
+use bevy::{
+    ecs::schedule::{InternedScheduleLabel, ScheduleLabel},
+    log,
+    prelude::*,
+};
+
+/// Simulation state plugin, initializing the [`SimulationState`] and toggling
+/// it with a key, [`KeyCode::Space`] by default, when the keyboard input is
+/// available.
+///
+/// It is added by every cellular automaton plugin: to customize the key, add
+/// it before them.
+pub struct SimulationStatePlugin {
+    /// Key toggling the simulation state, if any
+    pub toggle_key: Option<KeyCode>,
+}
+
+impl Plugin for SimulationStatePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<SimulationState>();
+        if let Some(key) = self.toggle_key {
+            app.add_systems(PreUpdate, toggle_simulation_state(key));
+        }
+        log::info!("Loaded simulation state plugin");
+    }
+}
+
+impl SimulationStatePlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            toggle_key: Some(KeyCode::Space),
+        }
+    }
+
+    /// Sets the `key` toggling the simulation state, or disables the toggle
+    /// if `None`
+    #[must_use]
+    #[inline]
+    pub const fn with_toggle_key(mut self, key: Option<KeyCode>) -> Self {
+        self.toggle_key = key;
+        self
+    }
+}
+
+impl Default for SimulationStatePlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Schedule of a simulation tick, run every frame by the
+/// [`SimulationTickPlugin`] as many times as the [`SimulationSpeed`] allows.
+///
+/// The systems of the plugins run in its [`SimulationSet`]s.
+///
+/// [`SimulationSpeed`]: crate::SimulationSpeed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ScheduleLabel)]
+pub struct SimulationTick;
+
+/// Ordered system sets of the [`SimulationTick`] schedule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum SimulationSet {
+    /// Changes applied before the rules, like vaccinations or interventions
+    PreTick,
+    /// Cell rules
+    Tick,
+    /// Measurements of the new cell states, like the time series
+    PostTick,
+}
+
+/// Ordered system sets of the cellular automaton pipeline, to order custom
+/// systems before or after a given phase.
+///
+/// The `MapUpdate`, `ComputeNewStates` and `ApplyNewStates` sets run in this
+/// order in the [`SimulationSet::Tick`] set of the [`SimulationTick`]
+/// schedule. The rules write the new states of the changed cells in their
+/// [`StateBuffer`] components, so the systems ordered between
+/// `ComputeNewStates` and `ApplyNewStates` see both the current and the new
+/// states. The new cell states are visible to the systems after
+/// `ApplyNewStates`. The `Coloring` set runs in `Update`, after the ticks.
+///
+/// [`StateBuffer`]: crate::StateBuffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum CellularAutomatonSet {
+    /// Updates of the [`CellMap`] and of the cached neighbors with the moved
+    /// or spawned cells
+    ///
+    /// [`CellMap`]: crate::CellMap
+    MapUpdate,
+    /// Cell rules, computing the new cell states
+    ComputeNewStates,
+    /// Application of the [`StateBuffer`] to the cell states
+    ///
+    /// [`StateBuffer`]: crate::StateBuffer
+    ApplyNewStates,
+    /// Colors of the cell sprites, with the `auto-coloring` feature
+    Coloring,
+}
+
+/// Simulation tick plugin, running the [`SimulationTick`] schedule in the
+/// `Update` schedule by default: once per run, or following the
+/// [`SimulationSpeed`] resource if any.
+///
+/// After the rules of every running tick, the [`Generation`] resource is
+/// incremented and a [`NewGenerationEvent`] is sent.
+///
+/// In the `FixedUpdate` schedule, the ticks follow the fixed timestep instead
+/// of the frame rate, so the simulation is deterministic under a variable
+/// frame rate. Any other schedule may be chosen to drive the ticks manually.
+///
+/// It is added by every cellular automaton plugin: to customize the schedule,
+/// add it before them or use [`CellularAutomatonPlugin::with_schedule`].
+///
+/// [`SimulationSpeed`]: crate::SimulationSpeed
+/// [`CellularAutomatonPlugin::with_schedule`]: super::CellularAutomatonPlugin::with_schedule
+pub struct SimulationTickPlugin {
+    /// Schedule running the ticks
+    pub schedule: InternedScheduleLabel,
+}
+
+impl Plugin for SimulationTickPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_schedule(SimulationTick)
+            .configure_sets(
+                SimulationTick,
+                (
+                    SimulationSet::PreTick,
+                    SimulationSet::Tick,
+                    SimulationSet::PostTick,
+                )
+                    .chain(),
+            )
+            .configure_sets(
+                SimulationTick,
+                (
+                    CellularAutomatonSet::MapUpdate,
+                    CellularAutomatonSet::ComputeNewStates,
+                    CellularAutomatonSet::ApplyNewStates,
+                )
+                    .chain()
+                    .in_set(SimulationSet::Tick),
+            )
+            .configure_sets(
+                SimulationTick,
+                SimulationSet::PreTick.run_if(generation_complete),
+            )
+            .configure_sets(
+                SimulationTick,
+                SimulationSet::PostTick.run_if(generation_complete),
+            )
+            .init_state::<SimulationState>()
+            .init_resource::<Generation>()
+            .init_resource::<PendingGenerations>()
+            .register_type::<Generation>()
+            .add_event::<NewGenerationEvent>()
+            .add_event::<GenerationComputedEvent>()
+            .add_systems(
+                SimulationTick,
+                advance_generation
+                    .after(SimulationSet::Tick)
+                    .before(SimulationSet::PostTick)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(generation_complete),
+            )
+            .add_systems(
+                SimulationTick,
+                apply_deferred.in_set(CellularAutomatonSet::ApplyNewStates),
+            )
+            .add_systems(
+                self.schedule,
+                run_simulation_ticks.before(CellularAutomatonSet::Coloring),
+            )
+            .add_systems(
+                self.schedule,
+                follow_region_of_interest
+                    .before(run_simulation_ticks)
+                    .run_if(resource_exists::<RegionOfInterest>),
+            );
+        log::info!("Loaded simulation tick plugin");
+    }
+}
+
+impl SimulationTickPlugin {
+    /// Instantiates Self with default values
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            schedule: Update.intern(),
+        }
+    }
+
+    /// Runs the ticks in the `schedule`, like `FixedUpdate`
+    #[must_use]
+    #[inline]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn with_schedule(mut self, schedule: impl ScheduleLabel) -> Self {
+        self.schedule = schedule.intern();
+        self
+    }
+}
+
+impl Default for SimulationTickPlugin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
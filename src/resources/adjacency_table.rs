@@ -0,0 +1,55 @@
+use bevy::utils::HashMap;
+use std::sync::Arc;
+
+/// Table mapping cell ids to their neighbor cell ids, used by [`TableCell`]
+/// for tilings without a regular coordinate system (Penrose tilings, hat
+/// tilings, Voronoi diagrams, etc).
+///
+/// The table is shared: cloning it is cheap and every [`TableCell`] keeps a
+/// handle to the table it was created with.
+///
+/// [`TableCell`]: crate::TableCell
+#[derive(Debug, Clone, Default)]
+pub struct AdjacencyTable(Arc<HashMap<u32, Vec<u32>>>);
+
+impl AdjacencyTable {
+    /// Instantiates a new table from a `table` of cell ids to neighbor ids
+    #[must_use]
+    #[inline]
+    pub fn new(table: HashMap<u32, Vec<u32>>) -> Self {
+        Self(Arc::new(table))
+    }
+
+    /// Retrieves the neighbor ids of the cell `id`, empty if the cell is not in
+    /// the table
+    #[must_use]
+    #[inline]
+    pub fn neighbors(&self, id: u32) -> &[u32] {
+        self.0.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterates over every cell id of the table
+    pub fn ids(&self) -> impl Iterator<Item = u32> + '_ {
+        self.0.keys().copied()
+    }
+
+    /// Amount of cells in the table
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Is the table empty
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(u32, Vec<u32>)> for AdjacencyTable {
+    fn from_iter<T: IntoIterator<Item = (u32, Vec<u32>)>>(iter: T) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,50 @@
+use crate::resources::EpidemicParams;
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`AgeCohortState`], read every generation: the
+/// [`EpidemicParams`] shared by every age group and the contact matrix
+/// between the groups.
+///
+/// [`AgeCohortState`]: crate::AgeCohortState
+#[derive(Debug, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct AgeMixingParams {
+    /// Epidemic parameters of every age group
+    pub epidemic: EpidemicParams,
+    /// Contact matrix: `contacts[a][b]` scales the infection pressure of the
+    /// age group `b` on the age group `a`. Missing values are `1.0`, an empty
+    /// matrix mixing the groups homogeneously.
+    pub contacts: Vec<Vec<f64>>,
+}
+
+impl Default for AgeMixingParams {
+    fn default() -> Self {
+        Self::new(EpidemicParams::default(), Vec::new())
+    }
+}
+
+impl AgeMixingParams {
+    /// Instantiates new parameters with the `epidemic` parameters and the
+    /// `contacts` matrix between age groups
+    #[must_use]
+    #[inline]
+    pub const fn new(epidemic: EpidemicParams, contacts: Vec<Vec<f64>>) -> Self {
+        Self { epidemic, contacts }
+    }
+
+    /// Contact rate of the age group `a` with the age group `b`, never
+    /// negative
+    #[must_use]
+    #[inline]
+    pub fn contact(&self, a: usize, b: usize) -> f64 {
+        self.contacts
+            .get(a)
+            .and_then(|row| row.get(b))
+            .map_or(1.0, |c| c.max(0.0))
+    }
+}
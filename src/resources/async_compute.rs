@@ -0,0 +1,93 @@
+use bevy::prelude::Resource;
+
+/// Resource to insert to compute the new cell states of every generation on
+/// the [`AsyncComputeTaskPool`], off the thread running the schedule.
+///
+/// Every generation is computed from owned copies of the cells, spawned as a
+/// task, and polled every tick: the schedule, and the rendering, go on while
+/// heavy rules are computed, like the binomial draws of giant cohort grids.
+/// Once the task completes, the new states are written back to the cells, a
+/// [`GenerationComputedEvent`] is sent and the states are applied. Until then
+/// the [`Generation`] is not incremented and the [`SimulationSet::PreTick`]
+/// and [`SimulationSet::PostTick`] systems do not run, like under a
+/// [`TickBudget`], which it replaces. A pending generation is completed, by
+/// blocking on its task, once the resource is removed.
+///
+/// It applies to the rules of the [`CellularAutomatonPlugin`], without cached
+/// neighbors, and of its weighted, parametric and coupled variants. The task
+/// pool is initialized by the `TaskPoolPlugin`, part of the default and
+/// minimal plugins. Without the `multi-threaded` feature of Bevy, as on wasm,
+/// the task runs as soon as it is spawned.
+///
+/// [`AsyncComputeTaskPool`]: bevy::tasks::AsyncComputeTaskPool
+/// [`GenerationComputedEvent`]: crate::GenerationComputedEvent
+/// [`Generation`]: crate::Generation
+/// [`SimulationSet::PreTick`]: crate::SimulationSet::PreTick
+/// [`SimulationSet::PostTick`]: crate::SimulationSet::PostTick
+/// [`TickBudget`]: crate::TickBudget
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Clone, Resource, Default)]
+pub struct AsyncCompute;
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{
+        ConwayCellState, GameOfLife2dPlugin, Generation, GenerationComputedEvent, MooreCell2d,
+    };
+    use bevy::{core::TaskPoolPlugin, prelude::*};
+    use std::time::Duration;
+
+    fn alive(app: &App, cells: &[(IVec2, Entity)]) -> Vec<IVec2> {
+        cells
+            .iter()
+            .filter(|(_, e)| app.world.get::<ConwayCellState>(*e).unwrap().0)
+            .map(|(coords, _)| *coords)
+            .collect()
+    }
+
+    #[test]
+    fn async_generations() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), GameOfLife2dPlugin::new()))
+            .insert_resource(AsyncCompute);
+        let cells: Vec<_> = (0..5)
+            .flat_map(|y| (0..5).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                let alive = coords.y == 2 && (1..4).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect();
+        let horizontal = alive(&app, &cells);
+        let vertical = vec![IVec2::new(2, 1), IVec2::new(2, 2), IVec2::new(2, 3)];
+        // The task is spawned on the first tick and polled on the next ones
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 0);
+        assert_eq!(alive(&app, &cells), horizontal);
+        for _ in 0..1000 {
+            if app.world.resource::<Generation>().0 == 1 {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        assert_eq!(alive(&app, &cells), vertical);
+        let events = app.world.resource::<Events<GenerationComputedEvent>>();
+        let changed: Vec<_> = events
+            .iter_current_update_events()
+            .map(|e| e.changed)
+            .collect();
+        assert_eq!(changed, [4]);
+        // The pending generation completes once the resource is removed
+        app.update();
+        app.world.remove_resource::<AsyncCompute>();
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 2);
+        assert_eq!(alive(&app, &cells), horizontal);
+    }
+}
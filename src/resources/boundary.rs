@@ -0,0 +1,82 @@
+use bevy::prelude::{Reflect, Resource};
+
+/// Resource defining how neighbor coordinates outside of a bounded grid are
+/// handled. The grid bounds are defined by the [`MapSize`] resource, which
+/// must be inserted as well.
+///
+/// The condition is applied during neighbor lookup for every cell type
+/// whose coordinates support it (see [`CellCoordinates`]), so cell types
+/// don't need a dedicated bounded variant. The `i32`, `IVec2` and `IVec3`
+/// coordinates support it, 3D grids setting their depth with
+/// [`MapSize::with_depth`].
+///
+/// [`MapSize`]: crate::MapSize
+/// [`CellCoordinates`]: crate::CellCoordinates
+/// [`MapSize::with_depth`]: crate::MapSize::with_depth
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Resource, Reflect)]
+pub enum BoundaryCondition {
+    /// Coordinates wrap around the grid edges, as on a torus
+    Wrap,
+    /// Coordinates outside of the grid are ignored, as if they were dead cells
+    #[default]
+    FixedDead,
+    /// Coordinates are mirrored on the grid edges
+    Mirror,
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CellCoordinates, ConwayCellState, GameOfLife2dPlugin, MapSize, MooreCell2d};
+    use bevy::prelude::*;
+
+    #[test]
+    fn coordinates_boundaries() {
+        let size = MapSize::new(4, 3);
+        let coords = IVec2::new(-1, 3);
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::Wrap, size),
+            Some(IVec2::new(3, 0))
+        );
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::FixedDead, size),
+            None
+        );
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::Mirror, size),
+            Some(IVec2::new(0, 2))
+        );
+        assert_eq!(5.apply_boundary(BoundaryCondition::Wrap, size), Some(1));
+        assert_eq!(
+            (-1).apply_boundary(BoundaryCondition::FixedDead, size),
+            None
+        );
+    }
+
+    fn run_column_grid(condition: BoundaryCondition) -> bool {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new())
+            .insert_resource(condition)
+            .insert_resource(MapSize::new(3, 3));
+        for x in 0..3 {
+            for y in 0..3 {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, y)), ConwayCellState(x == 0)));
+            }
+        }
+        app.update();
+        let mut query = app.world.query::<(&MooreCell2d, &ConwayCellState)>();
+        let (_, state) = query
+            .iter(&app.world)
+            .find(|(cell, _)| cell.coords == IVec2::new(2, 1))
+            .unwrap();
+        state.0
+    }
+
+    #[test]
+    fn wrapping_grid() {
+        // The last column sees the first one through the edge
+        assert!(run_column_grid(BoundaryCondition::Wrap));
+        assert!(!run_column_grid(BoundaryCondition::FixedDead));
+    }
+}
@@ -0,0 +1,186 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::MapSize,
+};
+use bevy::{
+    prelude::{App, IVec2, Image, World},
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+
+/// Boolean snapshot of a bounded 2D grid of cells, for example to use a
+/// cellular automaton as a procedural generator.
+///
+/// The grid is extracted from the cell entities of a world, each cell being
+/// `true` if its state matches a filter, and cells missing from the world
+/// being `false`:
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let mut app = App::new();
+/// app.add_plugins(LifeLike2dPlugin::new());
+/// // .. spawn `MooreCell2d` cells with a `LifeLikeRule::MAZE` state
+/// let size = MapSize::new(64, 64);
+/// let maze = CellGrid::after_generations::<MooreCell2d, LifeLikeCellState>(
+///     &mut app,
+///     100,
+///     size,
+///     |s| s.alive,
+/// );
+/// let walls: Vec<Vec<bool>> = maze.into_rows();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellGrid {
+    rows: Vec<Vec<bool>>,
+}
+
+impl CellGrid {
+    /// Extracts the grid of `size` from the `C` cells of the `world`, a cell
+    /// being `true` if its `S` state matches `filter`
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn extract<C, S>(world: &mut World, size: MapSize, filter: impl Fn(&S) -> bool) -> Self
+    where
+        C: Cell<Coordinates = IVec2>,
+        S: CellState,
+    {
+        let mut rows = vec![vec![false; size.width() as usize]; size.height() as usize];
+        let mut query = world.query::<(&C, &S)>();
+        for (cell, state) in query.iter(world) {
+            let coords = *cell.coords();
+            if size.contains(coords) {
+                rows[coords.y as usize][coords.x as usize] = filter(state);
+            }
+        }
+        Self { rows }
+    }
+
+    /// Runs `generations` updates of the `app` and extracts the resulting
+    /// grid, see [`Self::extract`].
+    ///
+    /// Every update is a generation only if the cellular automaton plugin has
+    /// no custom time step.
+    #[must_use]
+    pub fn after_generations<C, S>(
+        app: &mut App,
+        generations: usize,
+        size: MapSize,
+        filter: impl Fn(&S) -> bool,
+    ) -> Self
+    where
+        C: Cell<Coordinates = IVec2>,
+        S: CellState,
+    {
+        for _ in 0..generations {
+            app.update();
+        }
+        Self::extract::<C, S>(&mut app.world, size, filter)
+    }
+
+    /// Grid width
+    #[must_use]
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    /// Grid height
+    #[must_use]
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Retrieves the value at `coords`, if inside the grid
+    #[must_use]
+    #[inline]
+    pub fn get(&self, coords: IVec2) -> Option<bool> {
+        let x = usize::try_from(coords.x).ok()?;
+        let y = usize::try_from(coords.y).ok()?;
+        self.rows.get(y)?.get(x).copied()
+    }
+
+    /// Grid rows, indexed by `y` then `x` coordinates
+    #[must_use]
+    #[inline]
+    pub fn rows(&self) -> &[Vec<bool>] {
+        &self.rows
+    }
+
+    /// Converts the grid into its rows, indexed by `y` then `x` coordinates
+    #[must_use]
+    #[inline]
+    pub fn into_rows(self) -> Vec<Vec<bool>> {
+        self.rows
+    }
+
+    /// Builds a grayscale image of the grid, `true` cells being white.
+    ///
+    /// The first image row is the last grid row, so the image looks like the
+    /// rendered cells, `y` coordinates going up.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_image(&self) -> Image {
+        let data = self
+            .rows
+            .iter()
+            .rev()
+            .flat_map(|row| row.iter().map(|v| if *v { u8::MAX } else { 0 }))
+            .collect();
+        Image::new(
+            Extent3d {
+                width: self.width() as u32,
+                height: self.height() as u32,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            data,
+            TextureFormat::R8Unorm,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LifeLike2dPlugin, LifeLikeCellState, LifeLikeRule, MooreCell2d};
+
+    #[test]
+    fn extraction() {
+        let mut app = App::new();
+        app.add_plugins(LifeLike2dPlugin::new());
+        // Blinker
+        for y in 0..5 {
+            for x in 0..5 {
+                let alive = y == 2 && (1..4).contains(&x);
+                app.world.spawn((
+                    MooreCell2d::new(IVec2::new(x, y)),
+                    LifeLikeCellState::new(alive, LifeLikeRule::CONWAY),
+                ));
+            }
+        }
+        let size = MapSize::new(5, 4);
+        let grid =
+            CellGrid::extract::<MooreCell2d, LifeLikeCellState>(&mut app.world, size, |s| s.alive);
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid.rows()[2], vec![false, true, true, true, false]);
+        assert_eq!(grid.get(IVec2::new(2, 2)), Some(true));
+        assert_eq!(grid.get(IVec2::new(2, 4)), None);
+        let grid =
+            CellGrid::after_generations::<MooreCell2d, LifeLikeCellState>(&mut app, 1, size, |s| {
+                s.alive
+            });
+        let column: Vec<_> = grid.rows().iter().map(|row| row[2]).collect();
+        assert_eq!(column, vec![false, true, true, true]);
+        let image = grid.to_image();
+        assert_eq!(image.width(), 5);
+        assert_eq!(image.height(), 4);
+        // The last grid row is the first image row
+        assert_eq!(image.data[..5], [0, 0, 255, 0, 0]);
+        assert_eq!(image.data[15..], [0; 5]);
+    }
+}
@@ -0,0 +1,155 @@
+use bevy::prelude::Resource;
+
+/// Resource detecting the clusters of `S` cell states matching a predicate,
+/// like infected cells, every `interval` ticks of the
+/// [`ClusterDetectionPlugin`].
+///
+/// A cluster is a connected component of matching neighbor cells. The sizes of
+/// the clusters found by the last detection are stored in the resource, for
+/// spatial outbreak analysis.
+///
+/// [`ClusterDetectionPlugin`]: crate::ClusterDetectionPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct ClusterDetection<S> {
+    /// Do the cells belong to a cluster ?
+    pub predicate: fn(&S) -> bool,
+    /// Amount of ticks between detections
+    pub interval: u32,
+    /// Amount of ticks since the resource was inserted
+    pub tick: u64,
+    /// Tick of the last detection, if any
+    pub detected_tick: Option<u64>,
+    sizes: Vec<usize>,
+}
+
+impl<S> ClusterDetection<S> {
+    /// Detects the clusters of cells matching the `predicate` every tick
+    #[must_use]
+    #[inline]
+    pub const fn new(predicate: fn(&S) -> bool) -> Self {
+        Self {
+            predicate,
+            interval: 1,
+            tick: 0,
+            detected_tick: None,
+            sizes: Vec::new(),
+        }
+    }
+
+    /// Sets the `interval` between detections, in ticks
+    #[must_use]
+    #[inline]
+    pub const fn every(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Stores the cluster `sizes` detected at `tick`
+    pub fn set_clusters(&mut self, tick: u64, mut sizes: Vec<usize>) {
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        self.sizes = sizes;
+        self.detected_tick = Some(tick);
+    }
+
+    /// Retrieves the sizes of the detected clusters, from the largest
+    #[must_use]
+    #[inline]
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// Amount of detected clusters
+    #[must_use]
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Size of the largest detected cluster, if any
+    #[must_use]
+    #[inline]
+    pub fn largest(&self) -> Option<usize> {
+        self.sizes.first().copied()
+    }
+
+    /// Amount of cells in the detected clusters
+    #[must_use]
+    #[inline]
+    pub fn cells(&self) -> usize {
+        self.sizes.iter().sum()
+    }
+
+    /// Retrieves the size distribution of the detected clusters, as the amount
+    /// of clusters of every size, from the smallest
+    #[must_use]
+    pub fn size_distribution(&self) -> Vec<(usize, usize)> {
+        let mut distribution: Vec<(usize, usize)> = Vec::new();
+        for size in self.sizes.iter().rev() {
+            match distribution.last_mut() {
+                Some((last, count)) if last == size => *count += 1,
+                _ => distribution.push((*size, 1)),
+            }
+        }
+        distribution
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ClusterDetectionPlugin, ConwayCellState, MooreCell2d, NeumannCell2d};
+    use bevy::prelude::*;
+
+    fn spawn_cells<C: Component>(app: &mut App, new: fn(IVec2) -> C) {
+        let alive = [
+            IVec2::new(0, 0),
+            IVec2::new(1, 1),
+            IVec2::new(4, 4),
+            IVec2::new(0, 4),
+            IVec2::new(0, 5),
+            IVec2::new(1, 5),
+        ];
+        for x in 0..6 {
+            for y in 0..6 {
+                let coords = IVec2::new(x, y);
+                app.world
+                    .spawn((new(coords), ConwayCellState(alive.contains(&coords))));
+            }
+        }
+    }
+
+    #[test]
+    fn detected_clusters() {
+        let mut app = App::new();
+        app.add_plugins(ClusterDetectionPlugin::<MooreCell2d, ConwayCellState>::new())
+            .insert_resource(ClusterDetection::<ConwayCellState>::new(|s| s.0).every(2));
+        spawn_cells(&mut app, MooreCell2d::new);
+        app.update();
+        let detection = app.world.resource::<ClusterDetection<ConwayCellState>>();
+        assert_eq!(detection.detected_tick, Some(0));
+        assert_eq!(detection.sizes(), [3, 2, 1]);
+        assert_eq!(detection.count(), 3);
+        assert_eq!(detection.largest(), Some(3));
+        assert_eq!(detection.cells(), 6);
+        assert_eq!(detection.size_distribution(), [(1, 1), (2, 1), (3, 1)]);
+        app.update();
+        let detection = app.world.resource::<ClusterDetection<ConwayCellState>>();
+        assert_eq!(detection.detected_tick, Some(0));
+        app.update();
+        let detection = app.world.resource::<ClusterDetection<ConwayCellState>>();
+        assert_eq!(detection.detected_tick, Some(2));
+    }
+
+    #[test]
+    fn neighborhood_clusters() {
+        // Diagonal cells aren't connected in a Von Neumann neighborhood
+        let mut app = App::new();
+        app.add_plugins(ClusterDetectionPlugin::<NeumannCell2d, ConwayCellState>::new())
+            .insert_resource(ClusterDetection::<ConwayCellState>::new(|s| s.0));
+        spawn_cells(&mut app, NeumannCell2d::new);
+        app.update();
+        let detection = app.world.resource::<ClusterDetection<ConwayCellState>>();
+        assert_eq!(detection.sizes(), [3, 1, 1, 1]);
+        assert_eq!(detection.size_distribution(), [(1, 3), (3, 1)]);
+    }
+}
@@ -0,0 +1,158 @@
+use crate::components::CellCoordinates;
+use bevy::{
+    prelude::{IVec3, Resource},
+    utils::HashSet,
+};
+use std::marker::PhantomData;
+
+/// Resource skipping the rules of the `S` cell states in quiescent regions.
+///
+/// The grid is partitioned into chunks of `chunk_size` cells per axis. After
+/// every generation, the chunks of the cells whose state changed and of their
+/// neighbors are marked as dirty, and only the cells of dirty chunks apply
+/// their rules on the next tick. Sparse activity, like gliders in a large
+/// Game of Life, is then much cheaper to simulate.
+///
+/// The results match an untracked run for deterministic rules with symmetric
+/// neighborhoods, where a cell can only change if itself or a neighbor
+/// changed. Stochastic rules or rules following changing parameters would
+/// miss updates. Despawned cells are not tracked, their chunks may be marked
+/// manually with [`DirtyChunks::mark`]. Coordinates without chunks, like
+/// graph nodes, are always updated. It applies to the rules of the
+/// [`CellularAutomatonPlugin`] and of its weighted and parametric variants.
+///
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct DirtyChunks<S> {
+    /// Amount of cells of a chunk along every axis
+    chunk_size: u32,
+    /// Chunks to update on the next tick
+    chunks: HashSet<IVec3>,
+    /// Should every chunk be updated ?
+    all: bool,
+    phantom_s: PhantomData<S>,
+}
+
+impl<S> DirtyChunks<S> {
+    /// Instantiates Self with chunks of `chunk_size` cells per axis, every
+    /// chunk being dirty until the first generation is tracked
+    #[must_use]
+    #[inline]
+    pub fn new(chunk_size: u32) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            chunks: HashSet::default(),
+            all: true,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Amount of cells of a chunk along every axis
+    #[must_use]
+    #[inline]
+    pub const fn chunk_size(&self) -> u32 {
+        self.chunk_size
+    }
+
+    /// Is the chunk containing `coords` dirty ?
+    #[must_use]
+    #[inline]
+    pub fn is_dirty(&self, coords: &impl CellCoordinates) -> bool {
+        self.all
+            || coords
+                .chunk(self.chunk_size)
+                .is_none_or(|chunk| self.chunks.contains(&chunk))
+    }
+
+    /// Marks the chunk containing `coords` as dirty
+    #[inline]
+    pub fn mark(&mut self, coords: &impl CellCoordinates) {
+        if let Some(chunk) = coords.chunk(self.chunk_size) {
+            self.chunks.insert(chunk);
+        }
+    }
+
+    /// Marks every chunk as clean
+    #[inline]
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.all = false;
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    #[test]
+    fn chunks() {
+        let mut chunks = DirtyChunks::<ConwayCellState>::new(4);
+        assert!(chunks.is_dirty(&IVec2::new(100, 100)));
+        chunks.clear();
+        chunks.mark(&IVec2::new(-1, 3));
+        assert!(chunks.is_dirty(&IVec2::new(-4, 0)));
+        assert!(!chunks.is_dirty(&IVec2::new(0, 0)));
+        assert!(!chunks.is_dirty(&IVec2::new(-1, 4)));
+        assert!(chunks.is_dirty(&Entity::PLACEHOLDER));
+    }
+
+    fn run(tracked: bool) -> (Vec<IVec2>, bool) {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new());
+        if tracked {
+            app.insert_resource(DirtyChunks::<ConwayCellState>::new(4));
+        }
+        let cells: Vec<_> = (0..32)
+            .flat_map(|y| (0..32).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                // A blinker
+                let alive = coords.y == 2 && (1..4).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect();
+        app.update();
+        // A lonely cell revived in a quiescent chunk, without change detection
+        let lonely = cells
+            .iter()
+            .find(|(c, _)| *c == IVec2::new(20, 20))
+            .unwrap()
+            .1;
+        app.world
+            .entity_mut(lonely)
+            .get_mut::<ConwayCellState>()
+            .unwrap()
+            .bypass_change_detection()
+            .0 = true;
+        for _ in 0..3 {
+            app.update();
+        }
+        let alive = cells
+            .iter()
+            .filter(|(c, e)| {
+                *c != IVec2::new(20, 20) && app.world.get::<ConwayCellState>(*e).unwrap().0
+            })
+            .map(|(c, _)| *c)
+            .collect();
+        (alive, app.world.get::<ConwayCellState>(lonely).unwrap().0)
+    }
+
+    #[test]
+    fn quiescent_chunks() {
+        let (untracked, lonely_untracked) = run(false);
+        let (tracked, lonely_tracked) = run(true);
+        assert_eq!(tracked, untracked);
+        assert_eq!(
+            untracked,
+            vec![IVec2::new(1, 2), IVec2::new(2, 2), IVec2::new(3, 2)]
+        );
+        assert!(!lonely_untracked);
+        // The quiescent chunk was never updated
+        assert!(lonely_tracked);
+    }
+}
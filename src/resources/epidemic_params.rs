@@ -0,0 +1,144 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the epidemic states like [`SeirCellState`], read
+/// every generation. They may be modified at runtime to tweak the
+/// simulation, directly or through reflection with an inspector.
+///
+/// [`SeirCellState`]: crate::SeirCellState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct EpidemicParams {
+    /// Probability for a susceptible cell to be infected by each of its
+    /// infectious neighbors, every generation
+    pub transmission_probability: f64,
+    /// Amount of generations an exposed cell incubates before becoming
+    /// infectious
+    pub incubation_period: u32,
+    /// Amount of generations an infectious cell stays infectious before
+    /// recovering
+    pub infectious_period: u32,
+    /// Minimum amount of generations a recovered cell stays immune, for
+    /// states with waning immunity like [`SirsCellState`]
+    ///
+    /// [`SirsCellState`]: crate::SirsCellState
+    pub immunity_period: u32,
+    /// Probability for a recovered cell to lose its immunity every generation
+    /// once the `immunity_period` is over. `1.0` makes the immunity last
+    /// exactly `immunity_period` generations, lower probabilities make it
+    /// last longer on average.
+    pub waning_probability: f64,
+    /// Weight of the neighbor populations in the infection pressure of a
+    /// [`CohortState`], `0.0` isolating the cells
+    ///
+    /// [`CohortState`]: crate::CohortState
+    pub neighbor_coupling: f64,
+    /// Probability for an infectious individual of a [`CohortState`] to die
+    /// every generation
+    ///
+    /// [`CohortState`]: crate::CohortState
+    pub mortality_probability: f64,
+    /// Per capita probability for a [`CohortState`] individual to give birth
+    /// to a susceptible individual every generation
+    ///
+    /// [`CohortState`]: crate::CohortState
+    pub birth_rate: f64,
+    /// Per capita probability for a [`CohortState`] individual of any
+    /// compartment to die of other causes every generation
+    ///
+    /// [`CohortState`]: crate::CohortState
+    pub death_rate: f64,
+    /// Seasonal multiplier of the `transmission_probability`, overwritten
+    /// every generation by the [`SeasonalForcingPlugin`]
+    ///
+    /// [`SeasonalForcingPlugin`]: crate::SeasonalForcingPlugin
+    pub seasonality: f64,
+}
+
+impl Default for EpidemicParams {
+    fn default() -> Self {
+        Self::new(0.2, 3, 7)
+    }
+}
+
+impl EpidemicParams {
+    /// Instantiates new parameters with a `transmission_probability`, an
+    /// `incubation_period` and an `infectious_period`. Recovered cells stay
+    /// immune for 20 generations and cohorts are coupled to their neighbors
+    /// with a `0.1` weight, without mortality, vital dynamics nor seasonality.
+    #[must_use]
+    #[inline]
+    pub const fn new(
+        transmission_probability: f64,
+        incubation_period: u32,
+        infectious_period: u32,
+    ) -> Self {
+        Self {
+            transmission_probability,
+            incubation_period,
+            infectious_period,
+            immunity_period: 20,
+            waning_probability: 1.0,
+            neighbor_coupling: 0.1,
+            mortality_probability: 0.0,
+            birth_rate: 0.0,
+            death_rate: 0.0,
+            seasonality: 1.0,
+        }
+    }
+
+    /// Sets the `immunity_period` and `waning_probability` of recovered cells
+    #[must_use]
+    #[inline]
+    pub const fn with_waning(mut self, immunity_period: u32, waning_probability: f64) -> Self {
+        self.immunity_period = immunity_period;
+        self.waning_probability = waning_probability;
+        self
+    }
+
+    /// Sets the `neighbor_coupling` of cohorts
+    #[must_use]
+    #[inline]
+    pub const fn with_neighbor_coupling(mut self, neighbor_coupling: f64) -> Self {
+        self.neighbor_coupling = neighbor_coupling;
+        self
+    }
+
+    /// Sets the `mortality_probability` of infectious individuals
+    #[must_use]
+    #[inline]
+    pub const fn with_mortality(mut self, mortality_probability: f64) -> Self {
+        self.mortality_probability = mortality_probability;
+        self
+    }
+
+    /// Sets the `birth_rate` and background `death_rate` of cohorts, for
+    /// long term simulations of endemic diseases
+    #[must_use]
+    #[inline]
+    pub const fn with_vital_dynamics(mut self, birth_rate: f64, death_rate: f64) -> Self {
+        self.birth_rate = birth_rate;
+        self.death_rate = death_rate;
+        self
+    }
+
+    /// Current transmission probability, scaled by the `seasonality`
+    #[must_use]
+    #[inline]
+    pub fn transmission(&self) -> f64 {
+        (self.transmission_probability * self.seasonality.max(0.0)).clamp(0.0, 1.0)
+    }
+
+    /// Probability for a susceptible cell with an amount of `infectious`
+    /// neighbors to be infected
+    #[must_use]
+    pub fn infection_probability(&self, infectious: usize) -> f64 {
+        let infectious = i32::try_from(infectious).unwrap_or(i32::MAX);
+        let escape = (1.0 - self.transmission()).powi(infectious);
+        1.0 - escape
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`ExcitableState`] rules, may be modified at
+/// runtime to tweak the simulation.
+///
+/// [`ExcitableState`]: crate::ExcitableState
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ExcitableParams {
+    /// Minimum amount of excited neighbors exciting a resting cell
+    pub threshold: usize,
+    /// Amount of generations a cell stays refractory after being excited
+    pub refractory_length: u8,
+}
+
+impl Default for ExcitableParams {
+    fn default() -> Self {
+        Self::new(1, 5)
+    }
+}
+
+impl ExcitableParams {
+    /// Instantiates new parameters with an excitation `threshold` and a
+    /// `refractory_length`
+    #[must_use]
+    #[inline]
+    pub const fn new(threshold: usize, refractory_length: u8) -> Self {
+        Self {
+            threshold,
+            refractory_length,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`ForestFireState`] rules, may be modified at
+/// runtime to tweak the simulation.
+///
+/// [`ForestFireState`]: crate::ForestFireState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ForestFireParams {
+    /// Probability `p` of a tree growing on an empty cell every generation
+    pub growth_probability: f64,
+    /// Probability `f` of a tree without burning neighbors being struck by
+    /// lightning every generation
+    pub lightning_probability: f64,
+}
+
+impl Default for ForestFireParams {
+    fn default() -> Self {
+        Self::new(0.01, 0.000_01)
+    }
+}
+
+impl ForestFireParams {
+    /// Instantiates new parameters with `growth_probability` and
+    /// `lightning_probability`
+    #[must_use]
+    #[inline]
+    pub const fn new(growth_probability: f64, lightning_probability: f64) -> Self {
+        Self {
+            growth_probability,
+            lightning_probability,
+        }
+    }
+}
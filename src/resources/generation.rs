@@ -0,0 +1,42 @@
+use bevy::prelude::{Reflect, Resource};
+
+/// Resource counting the generations of the simulation, starting at `0` and
+/// incremented by the [`SimulationTickPlugin`] after the rules of every tick.
+///
+/// The systems of the `PostTick` set see the generation of the new cell
+/// states. A [`NewGenerationEvent`] is sent along every increment.
+///
+/// [`SimulationTickPlugin`]: crate::SimulationTickPlugin
+/// [`NewGenerationEvent`]: crate::NewGenerationEvent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Resource, Reflect)]
+pub struct Generation(pub u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NewGenerationEvent, SimulationPause, SimulationTickPlugin};
+    use bevy::prelude::*;
+
+    fn new_generations(app: &mut App) -> Vec<u64> {
+        app.update();
+        app.world
+            .resource_mut::<Events<NewGenerationEvent>>()
+            .drain()
+            .map(|event| event.generation)
+            .collect()
+    }
+
+    #[test]
+    fn counted_generations() {
+        let mut app = App::new();
+        app.add_plugins(SimulationTickPlugin::new());
+        assert_eq!(*app.world.resource::<Generation>(), Generation(0));
+        assert_eq!(new_generations(&mut app), [1]);
+        assert_eq!(new_generations(&mut app), [2]);
+        app.insert_resource(SimulationPause);
+        assert!(new_generations(&mut app).is_empty());
+        app.world.remove_resource::<SimulationPause>();
+        assert_eq!(new_generations(&mut app), [3]);
+        assert_eq!(*app.world.resource::<Generation>(), Generation(3));
+    }
+}
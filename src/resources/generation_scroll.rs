@@ -0,0 +1,26 @@
+use bevy::prelude::{Reflect, Resource};
+
+/// Resource to insert to scroll past generations down the screen, mostly
+/// useful for 1D cellular automata.
+///
+/// Every generation, a frozen copy of the cell sprites is spawned and
+/// previous copies are moved down by `row_height`.
+#[derive(Debug, Clone, Resource, Reflect)]
+pub struct GenerationScroll {
+    /// Vertical distance between two generations
+    pub row_height: f32,
+    /// Amount of past generations kept on screen
+    pub max_rows: u32,
+}
+
+impl GenerationScroll {
+    /// Instantiates a new scroll configuration
+    #[must_use]
+    #[inline]
+    pub const fn new(row_height: f32, max_rows: u32) -> Self {
+        Self {
+            row_height,
+            max_rows,
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{components::LifeLikeRule, resources::BoundaryCondition};
+use bevy::{
+    prelude::*,
+    render::{
+        extract_resource::ExtractResource,
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+    },
+};
+
+/// Resource holding a [Life-like] grid simulated on the GPU by the
+/// [`GpuLifePlugin`], without any cell entity.
+///
+/// The grid lives in a texture, whose live cells are white and dead cells are
+/// black, which may be displayed directly, for instance by a sprite of the
+/// [`GpuLifeGrid::image`]. Every tick, a compute shader applies the `rule` to
+/// every cell, the `boundary` condition being applied on the grid edges.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+///     let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(UVec2::from);
+///     let grid = GpuLifeGrid::new(&mut images, UVec2::splat(4096), glider)
+///         .with_rule(LifeLikeRule::HIGH_LIFE)
+///         .with_boundary(BoundaryCondition::Wrap);
+///     commands.insert_resource(grid);
+/// }
+/// ```
+///
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+/// [`GpuLifePlugin`]: crate::GpuLifePlugin
+#[derive(Debug, Clone, Resource, ExtractResource)]
+pub struct GpuLifeGrid {
+    /// Rule applied to every cell
+    pub rule: LifeLikeRule,
+    /// Condition applied on the grid edges
+    pub boundary: BoundaryCondition,
+    /// Size of the grid, in cells
+    size: UVec2,
+    /// Current generation, displayed
+    image: Handle<Image>,
+    /// Next generation, written by the compute shader
+    output: Handle<Image>,
+}
+
+impl GpuLifeGrid {
+    /// Live cell color
+    const ALIVE: [u8; 4] = [255, 255, 255, 255];
+    /// Dead cell color
+    const DEAD: [u8; 4] = [0, 0, 0, 255];
+
+    /// Uploads a grid of `size` whose `alive` coordinates are the live cells,
+    /// following Conway's rule with dead edges
+    #[must_use]
+    pub fn new(
+        images: &mut Assets<Image>,
+        size: UVec2,
+        alive: impl IntoIterator<Item = UVec2>,
+    ) -> Self {
+        let extent = Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        };
+        let mut data = Self::DEAD.repeat(size.x as usize * size.y as usize);
+        for coords in alive {
+            if coords.x < size.x && coords.y < size.y {
+                let index = (coords.y as usize * size.x as usize + coords.x as usize) * 4;
+                data[index..index + 4].copy_from_slice(&Self::ALIVE);
+            }
+        }
+        let mut image = Image::new(
+            extent,
+            TextureDimension::D2,
+            data,
+            TextureFormat::Rgba8Unorm,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST;
+        let mut output = Image::new_fill(
+            extent,
+            TextureDimension::D2,
+            &Self::DEAD,
+            TextureFormat::Rgba8Unorm,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        output.texture_descriptor.usage = TextureUsages::STORAGE_BINDING | TextureUsages::COPY_SRC;
+        Self {
+            rule: LifeLikeRule::CONWAY,
+            boundary: BoundaryCondition::FixedDead,
+            size,
+            image: images.add(image),
+            output: images.add(output),
+        }
+    }
+
+    /// Sets the `rule` applied to every cell
+    #[must_use]
+    #[inline]
+    pub const fn with_rule(mut self, rule: LifeLikeRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Sets the `boundary` condition applied on the grid edges
+    #[must_use]
+    #[inline]
+    pub const fn with_boundary(mut self, boundary: BoundaryCondition) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Size of the grid, in cells
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Texture of the current generation, to display the grid
+    #[must_use]
+    #[inline]
+    pub const fn image(&self) -> &Handle<Image> {
+        &self.image
+    }
+
+    /// Texture of the next generation, written by the compute shader
+    #[must_use]
+    #[inline]
+    pub const fn output(&self) -> &Handle<Image> {
+        &self.output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{systems::gpu_life::GpuTicks, Generation, GpuLifePlugin};
+
+    #[test]
+    fn uploaded_cells() {
+        let mut images = Assets::<Image>::default();
+        let grid = GpuLifeGrid::new(
+            &mut images,
+            UVec2::new(3, 2),
+            [UVec2::new(1, 0), UVec2::new(2, 1), UVec2::new(5, 5)],
+        );
+        let image = images.get(grid.image()).unwrap();
+        let alive: Vec<_> = image.data.chunks(4).map(|pixel| pixel[0] == 255).collect();
+        assert_eq!(alive, [false, true, false, false, false, true]);
+        let output = images.get(grid.output()).unwrap();
+        assert!(output
+            .data
+            .chunks(4)
+            .all(|pixel| pixel == GpuLifeGrid::DEAD));
+        assert_eq!(grid.rule, LifeLikeRule::CONWAY);
+    }
+
+    #[test]
+    fn counted_ticks() {
+        let mut app = App::new();
+        app.add_plugins(GpuLifePlugin::new());
+        app.update();
+        assert_eq!(app.world.resource::<GpuTicks>().0, 0);
+        let mut images = Assets::<Image>::default();
+        let grid = GpuLifeGrid::new(&mut images, UVec2::splat(8), []);
+        app.insert_resource(grid);
+        for generation in 2..=4 {
+            app.update();
+            // The ticks of the previous frame are reset
+            assert_eq!(app.world.resource::<GpuTicks>().0, 1);
+            assert_eq!(app.world.resource::<Generation>().0, generation);
+        }
+    }
+}
@@ -0,0 +1,672 @@
+use crate::components::LifeLikeRule;
+use bevy::{
+    prelude::{IVec2, Resource},
+    utils::HashMap,
+};
+use std::fmt;
+
+/// Identifier of an interned [`HashLife`] quadtree node
+type NodeId = u32;
+
+/// Dead leaf, a single cell
+const DEAD: NodeId = 0;
+/// Live leaf, a single cell
+const ALIVE: NodeId = 1;
+/// Maximum level of the whole grid, keeping its `i64` coordinates in range
+const MAX_LEVEL: u8 = 62;
+
+/// Quadtree node of `2^level` cells wide
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    /// North west, north east, south west and south east quadrants
+    children: [NodeId; 4],
+    /// Level of the node, leaves being single cells of level 0
+    level: u8,
+    /// Number of live cells
+    population: u64,
+}
+
+/// Resource holding a [HashLife] engine, simulating an unbounded [Life-like]
+/// grid with a memoized quadtree, for deterministic two-state rules.
+///
+/// Identical regions of the grid are stored once, and the future of every
+/// region is computed once, so regular patterns may be fast-forwarded by
+/// billions of generations with [`HashLife::fast_forward`]. The
+/// [`HashLifePlugin`] re-materializes the live cells into cell entities for
+/// display, and fast-forwards `2^step` generations every tick.
+///
+/// The grid spans at most `2^62` cells along each axis, centered on the
+/// origin, and the cells leaving it are dropped. The steps are capped to
+/// [`HashLife::MAX_STEP`]. The nodes of the previous generations and the
+/// memoized futures are kept to speed up the next ones: the plugin clears
+/// them with [`HashLife::clear_cache`] once they exceed the node budget.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// # fn main() -> Result<(), HashLifeError> {
+/// let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(IVec2::from);
+/// let mut life = HashLife::from_cells(LifeLikeRule::CONWAY, glider)?;
+/// // A glider moves by one cell diagonally every 4 generations
+/// life.fast_forward(10)?;
+/// assert_eq!(life.generation(), 1024);
+/// assert!(life.get(IVec2::new(257, 256)));
+/// assert_eq!(life.population(), 5);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [HashLife]: https://conwaylife.com/wiki/HashLife
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+/// [`HashLifePlugin`]: crate::HashLifePlugin
+#[derive(Debug, Clone, Resource)]
+pub struct HashLife {
+    /// Base 2 logarithm of the generations computed every tick, capped to
+    /// [`HashLife::MAX_STEP`]
+    pub step: u32,
+    /// Number of interned nodes above which the plugin clears the cache
+    pub node_budget: usize,
+    /// Rule applied to every cell
+    rule: LifeLikeRule,
+    /// Interned nodes, starting with the leaves
+    nodes: Vec<Node>,
+    /// Interned node of every quadrants
+    ids: HashMap<[NodeId; 4], NodeId>,
+    /// Memoized futures of the nodes, by node and base 2 logarithm of the
+    /// generations
+    results: HashMap<(NodeId, u32), NodeId>,
+    /// Empty node of every level
+    empty: Vec<NodeId>,
+    /// Whole grid, centered on the origin
+    root: NodeId,
+    /// Current generation
+    generation: u64,
+}
+
+impl HashLife {
+    /// Maximum base 2 logarithm of the generations computed at once
+    pub const MAX_STEP: u32 = MAX_LEVEL as u32 - 3;
+    /// Default number of interned nodes above which the plugin clears the
+    /// cache
+    pub const DEFAULT_NODE_BUDGET: usize = 1 << 22;
+
+    /// Instantiates an empty grid following the `rule`, computing a single
+    /// generation every tick
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `rule` gives birth to cells without live neighbors, as
+    /// the empty space of the grid must stay empty
+    #[must_use]
+    pub fn new(rule: LifeLikeRule) -> Self {
+        assert!(
+            rule.birth & 1 == 0,
+            "HashLife doesn't support rules giving birth to isolated cells"
+        );
+        let leaf = |population| Node {
+            children: [DEAD; 4],
+            level: 0,
+            population,
+        };
+        let mut life = Self {
+            step: 0,
+            node_budget: Self::DEFAULT_NODE_BUDGET,
+            rule,
+            nodes: vec![leaf(0), leaf(1)],
+            ids: HashMap::default(),
+            results: HashMap::default(),
+            empty: vec![DEAD],
+            root: DEAD,
+            generation: 0,
+        };
+        // The first empty nodes, whose identifiers follow the leaves
+        for level in 1..=3 {
+            let children = [life.empty[usize::from(level) - 1]; 4];
+            let id = NodeId::from(level) + ALIVE;
+            life.nodes.push(Node {
+                children,
+                level,
+                population: 0,
+            });
+            life.ids.insert(children, id);
+            life.empty.push(id);
+        }
+        life.root = life.empty[3];
+        life
+    }
+
+    /// Instantiates a grid following the `rule` whose `alive` coordinates are
+    /// the live cells
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cells need more nodes than a [`HashLife`] can
+    /// hold
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `rule` gives birth to cells without live neighbors
+    pub fn from_cells(
+        rule: LifeLikeRule,
+        alive: impl IntoIterator<Item = IVec2>,
+    ) -> Result<Self, HashLifeError> {
+        let mut life = Self::new(rule);
+        for coords in alive {
+            life.set(coords, true)?;
+        }
+        Ok(life)
+    }
+
+    /// Sets the base 2 logarithm of the generations computed every tick,
+    /// capped to [`HashLife::MAX_STEP`]
+    #[must_use]
+    #[inline]
+    pub const fn with_step(mut self, step: u32) -> Self {
+        self.step = if step > Self::MAX_STEP {
+            Self::MAX_STEP
+        } else {
+            step
+        };
+        self
+    }
+
+    /// Sets the number of interned nodes above which the plugin clears the
+    /// cache
+    #[must_use]
+    #[inline]
+    pub const fn with_node_budget(mut self, node_budget: usize) -> Self {
+        self.node_budget = node_budget;
+        self
+    }
+
+    /// Rule applied to every cell
+    #[must_use]
+    #[inline]
+    pub const fn rule(&self) -> LifeLikeRule {
+        self.rule
+    }
+
+    /// Current generation
+    #[must_use]
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Number of interned nodes, including the nodes of the previous
+    /// generations kept until [`HashLife::clear_cache`]
+    #[must_use]
+    #[inline]
+    pub const fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Number of live cells
+    #[must_use]
+    #[inline]
+    pub fn population(&self) -> u64 {
+        self.nodes[self.root as usize].population
+    }
+
+    /// Whether the cell at `coords` is alive
+    #[must_use]
+    pub fn get(&self, coords: IVec2) -> bool {
+        let node = self.nodes[self.root as usize];
+        let half = 1_i64 << (node.level - 1);
+        let (x, y) = (i64::from(coords.x) + half, i64::from(coords.y) + half);
+        if x < 0 || y < 0 || x >= 2 * half || y >= 2 * half {
+            return false;
+        }
+        let mut id = self.root;
+        let (mut x, mut y) = (x, y);
+        for level in (0..node.level).rev() {
+            let half = 1_i64 << level;
+            let quadrant = usize::from(x >= half) + 2 * usize::from(y >= half);
+            id = self.nodes[id as usize].children[quadrant];
+            x %= half;
+            y %= half;
+        }
+        id == ALIVE
+    }
+
+    /// Sets whether the cell at `coords` is `alive`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the grid needs more nodes than a [`HashLife`] can
+    /// hold, the cell being left unchanged
+    pub fn set(&mut self, coords: IVec2, alive: bool) -> Result<(), HashLifeError> {
+        loop {
+            let half = 1_i64 << (self.nodes[self.root as usize].level - 1);
+            let range = -half..half;
+            if range.contains(&i64::from(coords.x)) && range.contains(&i64::from(coords.y)) {
+                let position = (i64::from(coords.x) + half, i64::from(coords.y) + half);
+                self.root = self.set_node(self.root, position, alive)?;
+                return Ok(());
+            }
+            self.root = self.expand(self.root)?;
+        }
+    }
+
+    /// Coordinates of every live cell, cells beyond the `i32` coordinates
+    /// being ignored
+    #[must_use]
+    pub fn alive(&self) -> Vec<IVec2> {
+        let mut alive = Vec::new();
+        let half = 1_i64 << (self.nodes[self.root as usize].level - 1);
+        self.collect_alive(self.root, (-half, -half), &mut alive);
+        alive
+    }
+
+    /// Computes the next `2^k` generations of every cell, `k` being capped to
+    /// [`HashLife::MAX_STEP`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the generations need more nodes than a
+    /// [`HashLife`] can hold, the cells being left unchanged. Clearing the
+    /// cache with [`HashLife::clear_cache`] frees most of the nodes.
+    pub fn fast_forward(&mut self, k: u32) -> Result<(), HashLifeError> {
+        let k = k.min(Self::MAX_STEP);
+        // The pattern may grow by 2^k cells on every side, up to the maximum
+        // level of the grid
+        loop {
+            let node = self.nodes[self.root as usize];
+            let [nw, ne, sw, se] = node.children.map(|c| self.nodes[c as usize].children);
+            let center = [nw[3], ne[2], sw[1], se[0]]
+                .iter()
+                .map(|&c| self.nodes[c as usize].population)
+                .sum::<u64>();
+            let grown = u32::from(node.level) >= k + 2 && center == node.population;
+            if grown || node.level >= MAX_LEVEL - 1 {
+                break;
+            }
+            self.root = self.expand(self.root)?;
+        }
+        let root = self.expand(self.root)?;
+        self.root = self.successor(root, k)?;
+        self.generation = self.generation.saturating_add(1 << k);
+        Ok(())
+    }
+
+    /// Drops the memoized futures and the nodes of the previous generations,
+    /// which are kept until then to speed up the next ones
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the live cells alone need more nodes than a
+    /// [`HashLife`] can hold, the cache being left unchanged
+    pub fn clear_cache(&mut self) -> Result<(), HashLifeError> {
+        let mut life = Self::new(self.rule);
+        let mut copies = HashMap::default();
+        life.root = life.copy(self, self.root, &mut copies)?;
+        life.step = self.step;
+        life.node_budget = self.node_budget;
+        life.generation = self.generation;
+        *self = life;
+        Ok(())
+    }
+
+    /// Interns the node of the `children` quadrants
+    fn join(&mut self, children: [NodeId; 4]) -> Result<NodeId, HashLifeError> {
+        if let Some(&id) = self.ids.get(&children) {
+            return Ok(id);
+        }
+        let nodes = children.map(|c| self.nodes[c as usize]);
+        let id = NodeId::try_from(self.nodes.len()).map_err(|_| HashLifeError::TooManyNodes)?;
+        self.nodes.push(Node {
+            children,
+            level: nodes[0].level + 1,
+            population: nodes.iter().map(|n| n.population).sum(),
+        });
+        self.ids.insert(children, id);
+        Ok(id)
+    }
+
+    /// Empty node of the `level`
+    fn empty_node(&mut self, level: u8) -> Result<NodeId, HashLifeError> {
+        while self.empty.len() <= usize::from(level) {
+            let last = self.empty[self.empty.len() - 1];
+            let id = self.join([last; 4])?;
+            self.empty.push(id);
+        }
+        Ok(self.empty[usize::from(level)])
+    }
+
+    /// Node of the next level with the node `id` at its center
+    fn expand(&mut self, id: NodeId) -> Result<NodeId, HashLifeError> {
+        let node = self.nodes[id as usize];
+        let empty = self.empty_node(node.level - 1)?;
+        let [nw, ne, sw, se] = node.children;
+        let children = [
+            self.join([empty, empty, empty, nw])?,
+            self.join([empty, empty, ne, empty])?,
+            self.join([empty, sw, empty, empty])?,
+            self.join([se, empty, empty, empty])?,
+        ];
+        self.join(children)
+    }
+
+    /// Copy of the node `id` with the cell at `position` from its north west
+    /// corner set to `alive`
+    fn set_node(
+        &mut self,
+        id: NodeId,
+        (x, y): (i64, i64),
+        alive: bool,
+    ) -> Result<NodeId, HashLifeError> {
+        let node = self.nodes[id as usize];
+        if node.level == 0 {
+            return Ok(if alive { ALIVE } else { DEAD });
+        }
+        let half = 1_i64 << (node.level - 1);
+        let quadrant = usize::from(x >= half) + 2 * usize::from(y >= half);
+        let mut children = node.children;
+        children[quadrant] = self.set_node(children[quadrant], (x % half, y % half), alive)?;
+        self.join(children)
+    }
+
+    /// Appends the live cells of the node `id` whose north west corner is at
+    /// `origin`
+    fn collect_alive(&self, id: NodeId, origin: (i64, i64), alive: &mut Vec<IVec2>) {
+        let node = self.nodes[id as usize];
+        if node.population == 0 {
+            return;
+        }
+        if node.level == 0 {
+            if let (Ok(x), Ok(y)) = (i32::try_from(origin.0), i32::try_from(origin.1)) {
+                alive.push(IVec2::new(x, y));
+            }
+            return;
+        }
+        let half = 1_i64 << (node.level - 1);
+        for (quadrant, &child) in node.children.iter().enumerate() {
+            let dx = if quadrant % 2 == 0 { 0 } else { half };
+            let dy = if quadrant < 2 { 0 } else { half };
+            self.collect_alive(child, (origin.0 + dx, origin.1 + dy), alive);
+        }
+    }
+
+    /// Interns the node `id` of the `other` engine, `copies` holding the
+    /// already copied nodes
+    fn copy(
+        &mut self,
+        other: &Self,
+        id: NodeId,
+        copies: &mut HashMap<NodeId, NodeId>,
+    ) -> Result<NodeId, HashLifeError> {
+        if id == DEAD || id == ALIVE {
+            return Ok(id);
+        }
+        if let Some(&copy) = copies.get(&id) {
+            return Ok(copy);
+        }
+        let mut children = other.nodes[id as usize].children;
+        for child in &mut children {
+            *child = self.copy(other, *child, copies)?;
+        }
+        let copy = self.join(children)?;
+        copies.insert(id, copy);
+        Ok(copy)
+    }
+
+    /// Center of the 4x4 node `id` after a generation, computed cell by cell
+    fn next_center(&mut self, id: NodeId) -> Result<NodeId, HashLifeError> {
+        let mut cells = [[false; 4]; 4];
+        for (quadrant, &child) in self.nodes[id as usize].children.iter().enumerate() {
+            for (sub, &leaf) in self.nodes[child as usize].children.iter().enumerate() {
+                let x = (quadrant % 2) * 2 + sub % 2;
+                let y = (quadrant / 2) * 2 + sub / 2;
+                cells[y][x] = leaf == ALIVE;
+            }
+        }
+        let next = [(1, 1), (2, 1), (1, 2), (2, 2)].map(|(x, y): (usize, usize)| {
+            let count = (y - 1..=y + 1)
+                .flat_map(|ny| (x - 1..=x + 1).map(move |nx| (nx, ny)))
+                .filter(|&(nx, ny)| (nx, ny) != (x, y) && cells[ny][nx])
+                .count();
+            let mask = if cells[y][x] {
+                self.rule.survival
+            } else {
+                self.rule.birth
+            };
+            if mask & (1 << count) == 0 {
+                DEAD
+            } else {
+                ALIVE
+            }
+        });
+        self.join(next)
+    }
+
+    /// Center of the node `id`, of the previous level, after `2^j`
+    /// generations, `j` being at most the node level minus two
+    fn successor(&mut self, id: NodeId, j: u32) -> Result<NodeId, HashLifeError> {
+        let node = self.nodes[id as usize];
+        let j = j.min(u32::from(node.level) - 2);
+        if node.population == 0 {
+            return self.empty_node(node.level - 1);
+        }
+        if let Some(&result) = self.results.get(&(id, j)) {
+            return Ok(result);
+        }
+        let result = if node.level == 2 {
+            self.next_center(id)?
+        } else {
+            let [nw, ne, sw, se] = node.children.map(|c| self.nodes[c as usize].children);
+            // Overlapping nodes of the previous level, row by row
+            let mut futures = [
+                node.children[0],
+                self.join([nw[1], ne[0], nw[3], ne[2]])?,
+                node.children[1],
+                self.join([nw[2], nw[3], sw[0], sw[1]])?,
+                self.join([nw[3], ne[2], sw[1], se[0]])?,
+                self.join([ne[2], ne[3], se[0], se[1]])?,
+                node.children[2],
+                self.join([sw[1], se[0], sw[3], se[2]])?,
+                node.children[3],
+            ];
+            for future in &mut futures {
+                *future = self.successor(*future, j)?;
+            }
+            let quadrants = [[0, 1, 3, 4], [1, 2, 4, 5], [3, 4, 6, 7], [4, 5, 7, 8]];
+            let mut children = [DEAD; 4];
+            for (child, quadrant) in children.iter_mut().zip(quadrants) {
+                *child = if j < u32::from(node.level) - 2 {
+                    // The generations are all computed, only the centers remain
+                    let center = [3, 2, 1, 0].map(|corner| {
+                        self.nodes[futures[quadrant[3 - corner]] as usize].children[corner]
+                    });
+                    self.join(center)?
+                } else {
+                    let quadrant = self.join(quadrant.map(|i| futures[i]))?;
+                    self.successor(quadrant, j)?
+                };
+            }
+            self.join(children)?
+        };
+        self.results.insert((id, j), result);
+        Ok(result)
+    }
+}
+
+impl Default for HashLife {
+    fn default() -> Self {
+        Self::new(LifeLikeRule::CONWAY)
+    }
+}
+
+/// Error of a [`HashLife`] operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashLifeError {
+    /// The grid needs more nodes than a [`HashLife`] can hold
+    TooManyNodes,
+}
+
+impl fmt::Display for HashLifeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyNodes => write!(f, "too many HashLife nodes"),
+        }
+    }
+}
+
+impl std::error::Error for HashLifeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LifeBitboard;
+    use bevy::prelude::UVec2;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn cells() {
+        let mut life = HashLife::default();
+        let far = IVec2::new(-1000, 12345);
+        life.set(far, true).unwrap();
+        life.set(IVec2::ZERO, true).unwrap();
+        life.set(IVec2::ZERO, false).unwrap();
+        assert!(life.get(far));
+        assert!(!life.get(IVec2::ZERO));
+        assert_eq!(life.alive(), [far]);
+        assert_eq!(life.population(), 1);
+    }
+
+    #[test]
+    fn bitboard_generations() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let alive: Vec<_> = (0..40)
+            .flat_map(|y| (0..40).map(move |x| IVec2::new(x, y)))
+            .filter(|_| rng.gen_bool(0.4))
+            .collect();
+        for rule in [LifeLikeRule::CONWAY, LifeLikeRule::HIGH_LIFE] {
+            let mut life = HashLife::from_cells(rule, alive.iter().copied()).unwrap();
+            // A bitboard large enough to never reach its dead edges
+            let offset = IVec2::splat(200);
+            let mut bitboard = LifeBitboard::new(UVec2::splat(440)).with_rule(rule);
+            for &coords in &alive {
+                bitboard.set(coords + offset, true);
+            }
+            let mut generation = 0;
+            for k in [0, 0, 1, 3, 2, 5] {
+                life.fast_forward(k).unwrap();
+                for _ in 0..1 << k {
+                    bitboard.step();
+                }
+                generation += 1 << k;
+                assert_eq!(life.generation(), generation);
+                let mut cells = life.alive();
+                cells.sort_by_key(|c| (c.y, c.x));
+                let expected: Vec<_> = bitboard.alive().map(|c| c - offset).collect();
+                assert_eq!(cells, expected);
+            }
+            life.clear_cache().unwrap();
+            let population = life.population();
+            assert_eq!(population, bitboard.population() as u64);
+            assert_eq!(life.generation(), generation);
+        }
+    }
+
+    #[test]
+    fn fast_forwarded_glider() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(IVec2::from);
+        let mut life = HashLife::from_cells(LifeLikeRule::CONWAY, glider).unwrap();
+        life.fast_forward(40).unwrap();
+        assert_eq!(life.generation(), 1 << 40);
+        // Moved beyond the i32 coordinates
+        assert!(life.alive().is_empty());
+        assert_eq!(life.population(), 5);
+        let mut life = HashLife::from_cells(LifeLikeRule::CONWAY, glider).unwrap();
+        life.fast_forward(20).unwrap();
+        let moved = IVec2::splat(1 << 18);
+        let mut cells = life.alive();
+        cells.sort_by_key(|c| (c.y, c.x));
+        let mut expected: Vec<_> = glider.iter().map(|&c| c + moved).collect();
+        expected.sort_by_key(|c| (c.y, c.x));
+        assert_eq!(cells, expected);
+    }
+
+    #[test]
+    fn capped_steps() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(IVec2::from);
+        let mut life = HashLife::from_cells(LifeLikeRule::CONWAY, glider)
+            .unwrap()
+            .with_step(100);
+        assert_eq!(life.step, HashLife::MAX_STEP);
+        for _ in 0..16 {
+            life.fast_forward(u32::MAX).unwrap();
+            assert!(life.nodes[life.root as usize].level <= MAX_LEVEL);
+        }
+        assert_eq!(life.generation(), 16 << HashLife::MAX_STEP);
+        // The glider left the grid
+        assert_eq!(life.population(), 0);
+        assert!(!life.get(IVec2::ZERO));
+        assert!(life.alive().is_empty());
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn node_budget() {
+        use crate::{HashLifePlugin, MooreCell2d};
+        use bevy::prelude::*;
+
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)].map(IVec2::from);
+        let life = HashLife::from_cells(LifeLikeRule::CONWAY, glider)
+            .unwrap()
+            .with_step(4)
+            .with_node_budget(200);
+        let mut app = App::new();
+        app.add_plugins(HashLifePlugin::<MooreCell2d>::new())
+            .insert_resource(life);
+        for _ in 0..20 {
+            app.update();
+            let life = app.world.resource::<HashLife>();
+            assert!(life.node_count() <= 200);
+        }
+        let life = app.world.resource::<HashLife>();
+        assert_eq!(life.generation(), 20 << 4);
+        assert_eq!(life.node_budget, 200);
+        assert_eq!(life.population(), 5);
+        // The glider moved by one cell diagonally every 4 generations
+        assert!(life.get(IVec2::new(1, 0) + IVec2::splat(80)));
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn materialized_cells() {
+        use crate::{ConwayCellState, Generation, HashLifePlugin, MooreCell2d};
+        use bevy::prelude::*;
+
+        let blinker = [(0, 1), (1, 1), (2, 1)].map(IVec2::from);
+        let mut app = App::new();
+        app.add_plugins(HashLifePlugin::<MooreCell2d>::new())
+            .insert_resource(
+                HashLife::from_cells(LifeLikeRule::CONWAY, blinker)
+                    .unwrap()
+                    .with_step(3),
+            );
+        let existing = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::new(0, 1)), ConwayCellState(false)))
+            .id();
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        assert_eq!(app.world.resource::<HashLife>().generation(), 8);
+        let mut cells: Vec<_> = app
+            .world
+            .query::<(&MooreCell2d, &ConwayCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| state.0)
+            .map(|(cell, _)| cell.coords)
+            .collect();
+        cells.sort_by_key(|c| (c.y, c.x));
+        // Back in the horizontal phase, the existing cell being reused
+        assert_eq!(cells, blinker);
+        assert!(app.world.get::<ConwayCellState>(existing).unwrap().0);
+        assert_eq!(
+            app.world.query::<&MooreCell2d>().iter(&app.world).count(),
+            3
+        );
+    }
+}
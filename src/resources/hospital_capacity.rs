@@ -0,0 +1,65 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Resource setting the amount of hospital `beds` for the severe cells of a
+/// [`SeverityCellState`] epidemic, used by the [`HospitalCapacityPlugin`].
+///
+/// Every generation, the plugin counts the `occupied` beds and the severe
+/// cells `waiting` for one. Once saturated, only part of the waiting cells
+/// are admitted, the others dying with the higher `untreated_mortality` of
+/// the [`SeverityParams`].
+///
+/// [`SeverityCellState`]: crate::SeverityCellState
+/// [`HospitalCapacityPlugin`]: crate::HospitalCapacityPlugin
+/// [`SeverityParams`]: crate::SeverityParams
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct HospitalCapacity {
+    /// Amount of hospital beds
+    pub beds: u32,
+    /// Amount of beds occupied by hospitalized cells
+    pub occupied: u32,
+    /// Amount of severe cells needing a bed
+    pub waiting: u32,
+}
+
+impl HospitalCapacity {
+    /// Instantiates empty hospitals with an amount of `beds`
+    #[must_use]
+    #[inline]
+    pub const fn new(beds: u32) -> Self {
+        Self {
+            beds,
+            occupied: 0,
+            waiting: 0,
+        }
+    }
+
+    /// Amount of free beds
+    #[must_use]
+    #[inline]
+    pub const fn free_beds(&self) -> u32 {
+        self.beds.saturating_sub(self.occupied)
+    }
+
+    /// Ratio of the beds needed by the hospitalized and waiting cells to the
+    /// available beds, above `1.0` once saturated
+    #[must_use]
+    #[inline]
+    pub fn saturation(&self) -> f64 {
+        let needed = f64::from(self.occupied) + f64::from(self.waiting);
+        if self.beds == 0 {
+            return if needed > 0.0 { f64::INFINITY } else { 0.0 };
+        }
+        needed / f64::from(self.beds)
+    }
+
+    /// Probability for a waiting cell to be admitted, sharing the free beds
+    #[must_use]
+    #[inline]
+    pub fn admission_probability(&self) -> f64 {
+        if self.waiting == 0 {
+            return 1.0;
+        }
+        (f64::from(self.free_beds()) / f64::from(self.waiting)).min(1.0)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::resources::EpidemicParams;
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`HouseholdState`], read every generation: the
+/// [`EpidemicParams`] of the transmission between households and cells, and
+/// the transmission within households.
+///
+/// [`HouseholdState`]: crate::HouseholdState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct HouseholdParams {
+    /// Epidemic parameters, whose transmission applies between the households
+    /// of a cell and with the neighbor cells
+    pub epidemic: EpidemicParams,
+    /// Infection pressure of every infectious individual on the susceptible
+    /// individuals of its household, every generation
+    pub household_transmission: f64,
+}
+
+impl Default for HouseholdParams {
+    fn default() -> Self {
+        Self::new(EpidemicParams::default(), 0.3)
+    }
+}
+
+impl HouseholdParams {
+    /// Instantiates new parameters with the `epidemic` parameters and the
+    /// `household_transmission`
+    #[must_use]
+    #[inline]
+    pub const fn new(epidemic: EpidemicParams, household_transmission: f64) -> Self {
+        Self {
+            epidemic,
+            household_transmission,
+        }
+    }
+}
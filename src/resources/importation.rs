@@ -0,0 +1,147 @@
+use crate::components::Cell;
+use bevy::prelude::Resource;
+
+/// Cells receiving the infections of an [`Importation`]
+#[derive(Debug, Clone)]
+pub enum ImportationTarget<C: Cell> {
+    /// Random cells of the map
+    Anywhere,
+    /// Random cells on the border of the map, missing some of their neighbors
+    Border,
+    /// Random cells among the given coordinates, like airports
+    Cells(Vec<C::Coordinates>),
+}
+
+/// Frequency of the infections of an [`Importation`]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ImportationSchedule {
+    /// Random amount of importations every tick, following a Poisson
+    /// distribution of the given mean
+    Rate(f64),
+    /// A single importation every given amount of ticks
+    Every(u32),
+}
+
+/// Resource seeding infections imported from outside of the simulation in
+/// the cells of type `C`, every tick of the [`ImportationPlugin`].
+///
+/// Every importation infects `cases` individuals of a random `target` cell.
+/// The importations stop when the resource is removed.
+///
+/// [`ImportationPlugin`]: crate::ImportationPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct Importation<C: Cell> {
+    /// Frequency of the importations
+    pub schedule: ImportationSchedule,
+    /// Amount of individuals infected by every importation
+    pub cases: u32,
+    /// Cells receiving the importations
+    pub target: ImportationTarget<C>,
+    /// Amount of ticks since the resource was inserted
+    pub tick: u64,
+}
+
+impl<C: Cell> Importation<C> {
+    /// Imports `rate` infections every tick on average, anywhere on the map
+    #[must_use]
+    #[inline]
+    pub const fn with_rate(rate: f64) -> Self {
+        Self::new(ImportationSchedule::Rate(rate))
+    }
+
+    /// Imports an infection every `ticks`, anywhere on the map
+    #[must_use]
+    #[inline]
+    pub const fn every(ticks: u32) -> Self {
+        Self::new(ImportationSchedule::Every(ticks))
+    }
+
+    #[inline]
+    const fn new(schedule: ImportationSchedule) -> Self {
+        Self {
+            schedule,
+            cases: 1,
+            target: ImportationTarget::Anywhere,
+            tick: 0,
+        }
+    }
+
+    /// Sets the amount of `cases` of every importation
+    #[must_use]
+    #[inline]
+    pub const fn with_cases(mut self, cases: u32) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Sets the `target` cells of the importations
+    #[must_use]
+    #[inline]
+    pub fn with_target(mut self, target: ImportationTarget<C>) -> Self {
+        self.target = target;
+        self
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{
+        Cohort2dPlugin, CohortState, EpidemicParams, ImportationPlugin, MooreCell2d, Seir2dPlugin,
+        SeirCellState,
+    };
+    use bevy::prelude::*;
+
+    #[test]
+    fn scheduled_importation() {
+        let mut app = App::new();
+        app.add_plugins((
+            Cohort2dPlugin::new(),
+            ImportationPlugin::<MooreCell2d, CohortState>::new(),
+        ))
+        .insert_resource(EpidemicParams::new(0.0, 1, u32::MAX))
+        .insert_resource(Importation::<MooreCell2d>::every(2).with_cases(3));
+        let cell = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), CohortState::new(100, 0, 0)))
+            .id();
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(
+            app.world.get::<CohortState>(cell),
+            Some(&CohortState::new(94, 6, 0))
+        );
+    }
+
+    #[test]
+    fn border_importation() {
+        let mut app = App::new();
+        app.add_plugins((
+            Seir2dPlugin::new(),
+            ImportationPlugin::<MooreCell2d, SeirCellState>::new(),
+        ))
+        .insert_resource(EpidemicParams::new(0.0, 10, 10))
+        .insert_resource(
+            Importation::<MooreCell2d>::with_rate(50.0).with_target(ImportationTarget::Border),
+        );
+        for x in 0..3 {
+            for y in 0..3 {
+                app.world.spawn((
+                    MooreCell2d::new(IVec2::new(x, y)),
+                    SeirCellState::Susceptible,
+                ));
+            }
+        }
+        app.update();
+        let exposed: Vec<_> = app
+            .world
+            .query::<(&MooreCell2d, &SeirCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| **state != SeirCellState::Susceptible)
+            .map(|(cell, _)| cell.coords)
+            .collect();
+        assert!(!exposed.is_empty());
+        assert!(!exposed.contains(&IVec2::ONE));
+    }
+}
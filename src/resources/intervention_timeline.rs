@@ -0,0 +1,169 @@
+use bevy::{
+    ecs::system::Command,
+    prelude::{Resource, World},
+};
+use std::fmt;
+
+/// Scheduled change of the simulation
+pub type Intervention = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Resource scheduling interventions on the simulation at given ticks, for
+/// scripted scenarios like halving the transmission then lifting a lockdown.
+///
+/// Used by the [`InterventionPlugin`], which counts the ticks and runs every
+/// intervention of the current tick with an exclusive access to the world,
+/// before the cells are updated. Interventions scheduled at a past tick run at
+/// the next one.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let timeline = InterventionTimeline::new()
+///     .at(200, |world: &mut World| {
+///         world.resource_mut::<EpidemicParams>().transmission_probability /= 2.0;
+///     })
+///     .at(400, |world: &mut World| {
+///         world.remove_resource::<Quarantine<MooreCell2d>>();
+///     });
+/// ```
+///
+/// [`InterventionPlugin`]: crate::InterventionPlugin
+#[derive(Resource, Default)]
+pub struct InterventionTimeline {
+    /// Pending interventions, sorted by tick
+    interventions: Vec<(u64, Intervention)>,
+    /// Current tick number
+    pub tick: u64,
+}
+
+impl fmt::Debug for InterventionTimeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InterventionTimeline")
+            .field("ticks", &self.ticks().collect::<Vec<_>>())
+            .field("tick", &self.tick)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InterventionTimeline {
+    /// Instantiates an empty timeline at the first tick
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            interventions: Vec::new(),
+            tick: 0,
+        }
+    }
+
+    /// Schedules the `intervention` closure at `tick`
+    #[must_use]
+    pub fn at(
+        mut self,
+        tick: u64,
+        intervention: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) -> Self {
+        self.schedule(tick, intervention);
+        self
+    }
+
+    /// Schedules the `command` at `tick`
+    #[must_use]
+    pub fn command_at(mut self, tick: u64, command: impl Command + Sync) -> Self {
+        self.schedule(tick, move |world: &mut World| command.apply(world));
+        self
+    }
+
+    /// Schedules the `intervention` closure at `tick`, after the interventions
+    /// already scheduled at the same tick
+    pub fn schedule(
+        &mut self,
+        tick: u64,
+        intervention: impl FnOnce(&mut World) + Send + Sync + 'static,
+    ) {
+        let index = self.interventions.partition_point(|(t, _)| *t <= tick);
+        self.interventions
+            .insert(index, (tick, Box::new(intervention)));
+    }
+
+    /// Iterates over the ticks of the pending interventions, in order
+    pub fn ticks(&self) -> impl Iterator<Item = u64> + '_ {
+        self.interventions.iter().map(|(tick, _)| *tick)
+    }
+
+    /// Is there no pending intervention ?
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.interventions.is_empty()
+    }
+
+    /// Removes the interventions due at the current tick or before, in order
+    pub fn take_due(&mut self) -> Vec<Intervention> {
+        let due = self.interventions.partition_point(|(t, _)| *t <= self.tick);
+        self.interventions
+            .drain(..due)
+            .map(|(_, intervention)| intervention)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EpidemicParams, InterventionPlugin};
+    use bevy::prelude::*;
+
+    #[derive(Debug, Default, Resource)]
+    struct Log(Vec<(u64, &'static str)>);
+
+    #[test]
+    fn scheduled_interventions() {
+        let mut app = App::new();
+        let log = |name| {
+            move |world: &mut World| {
+                let tick = world.resource::<InterventionTimeline>().tick;
+                world.resource_mut::<Log>().0.push((tick, name));
+            }
+        };
+        app.add_plugins(InterventionPlugin::new())
+            .init_resource::<Log>()
+            .insert_resource(EpidemicParams::new(0.4, 1, 1))
+            .insert_resource(
+                InterventionTimeline::new()
+                    .at(2, log("b"))
+                    .at(0, log("a"))
+                    .at(2, log("c"))
+                    .at(1, move |world: &mut World| {
+                        world
+                            .resource_mut::<EpidemicParams>()
+                            .transmission_probability /= 2.0;
+                        // Interventions may schedule new ones
+                        world
+                            .resource_mut::<InterventionTimeline>()
+                            .schedule(0, log("late"));
+                    })
+                    .command_at(3, |world: &mut World| {
+                        world.remove_resource::<Log>();
+                    }),
+            );
+        assert_eq!(
+            app.world
+                .resource::<InterventionTimeline>()
+                .ticks()
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 2, 3]
+        );
+        for _ in 0..3 {
+            app.update();
+        }
+        assert!((app.world.resource::<EpidemicParams>().transmission() - 0.2).abs() < 1e-9);
+        assert_eq!(
+            app.world.resource::<Log>().0,
+            [(0, "a"), (2, "late"), (2, "b"), (2, "c")]
+        );
+        app.update();
+        assert!(app.world.get_resource::<Log>().is_none());
+        assert!(app.world.resource::<InterventionTimeline>().is_empty());
+    }
+}
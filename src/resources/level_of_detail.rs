@@ -0,0 +1,389 @@
+use crate::{
+    components::{CellState, CoarseCellState},
+    resources::CellRng,
+};
+use bevy::{
+    ecs::system::EntityCommands,
+    prelude::{Entity, IVec2, Resource},
+    utils::HashMap,
+};
+use std::fmt::{self, Debug, Formatter};
+
+/// Approximate rule of the super-cells, given the states of the neighbor
+/// super-cells
+type CoarseRule<S> = Box<dyn Fn(&S, &[&S]) -> S + Send + Sync>;
+
+/// Region of cells coarse-grained into a single state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuperCell<S> {
+    /// Aggregated state of the cells
+    pub state: S,
+    /// Coordinates of the coarse-grained cells, spawned back when refined
+    pub cells: Vec<IVec2>,
+}
+
+/// Resource coarse-graining the regions of `S` cell states far from a focus,
+/// like the camera, into super-cells.
+///
+/// The grid is partitioned into square regions of `region_size` cells per
+/// axis. Used by the [`LevelOfDetailPlugin`], the regions farther than the
+/// `detail_radius` (in regions) from every focus point have their cells
+/// despawned and aggregated into a [`SuperCell`], whose state follows an
+/// approximate rule from its neighbor super-cells every tick. Once a focus
+/// comes near again, the super-cell is refined back into cells at full
+/// resolution. Huge world maps then only pay for the simulation of the cells
+/// around the player.
+///
+/// The rule defaults to the [`CellState`] rule applied to the neighbor
+/// super-cells, and may be replaced with [`LevelOfDetail::with_rule`], for
+/// instance to read rule parameters. The cells at the border of a coarse
+/// region don't see it, and the other way around. Without any focus point,
+/// every region is kept at full resolution. The super-cells are scoped per
+/// [`GridRoot`], the methods without a `grid` reading the default grid.
+///
+/// The refined cells only have the cell, state and [`GridRoot`] components,
+/// the [`LevelOfDetail::with_on_spawn`] hook may insert their other
+/// components, like their sprites.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let params = EpidemicParams::new(0.3, 5, 20);
+/// // Regions of 32x32 cells, at full resolution up to 2 regions around the
+/// // cameras, which draw cells of 10 pixels
+/// let lod = LevelOfDetail::<CohortState>::new(32, 2)
+///     .with_camera(10.0)
+///     .with_rule(move |state, neighbors| {
+///         state.new_cell_state_with_params(neighbors.iter().copied(), &params)
+///     });
+/// ```
+///
+/// [`LevelOfDetailPlugin`]: crate::LevelOfDetailPlugin
+/// [`GridRoot`]: crate::GridRoot
+#[derive(Resource)]
+pub struct LevelOfDetail<S: CellState> {
+    /// Amount of cells of a region along every axis
+    region_size: u32,
+    /// Distance, in regions, from a focus point up to which the regions are
+    /// kept at full resolution
+    pub detail_radius: u32,
+    /// Cell coordinates of the focus points
+    pub focus: Vec<IVec2>,
+    /// World size of a cell, the cell at `coords` being drawn at
+    /// `coords * cell_size`. If set, the focus points follow the cameras.
+    pub camera_cell_size: Option<f32>,
+    /// Super-cells by grid root and region
+    super_cells: HashMap<(Option<Entity>, IVec2), SuperCell<S>>,
+    /// Approximate rule of the super-cells
+    rule: CoarseRule<S>,
+    /// Hook called on every refined cell with its coordinates
+    pub on_spawn: Option<fn(IVec2, &mut EntityCommands)>,
+}
+
+impl<S: CellState> LevelOfDetail<S> {
+    /// Instantiates Self with regions of `region_size` cells per axis, kept at
+    /// full resolution up to `detail_radius` regions from a focus point
+    #[must_use]
+    pub fn new(region_size: u32, detail_radius: u32) -> Self {
+        Self {
+            region_size: region_size.max(1),
+            detail_radius,
+            focus: Vec::new(),
+            camera_cell_size: None,
+            super_cells: HashMap::default(),
+            rule: Box::new(|state: &S, neighbors: &[&S]| {
+                state.new_cell_state_rng(neighbors.iter().copied(), &mut CellRng)
+            }),
+            on_spawn: None,
+        }
+    }
+
+    /// The focus points follow the cameras, drawing cells of `cell_size`
+    #[must_use]
+    #[inline]
+    pub const fn with_camera(mut self, cell_size: f32) -> Self {
+        self.camera_cell_size = Some(cell_size);
+        self
+    }
+
+    /// Calls `on_spawn` on every refined cell with its coordinates
+    #[must_use]
+    #[inline]
+    pub const fn with_on_spawn(mut self, on_spawn: fn(IVec2, &mut EntityCommands)) -> Self {
+        self.on_spawn = Some(on_spawn);
+        self
+    }
+
+    /// Sets the approximate `rule` of the super-cells, given the states of
+    /// the neighbor super-cells
+    #[must_use]
+    pub fn with_rule(mut self, rule: impl Fn(&S, &[&S]) -> S + Send + Sync + 'static) -> Self {
+        self.rule = Box::new(rule);
+        self
+    }
+
+    /// Amount of cells of a region along every axis
+    #[must_use]
+    #[inline]
+    pub const fn region_size(&self) -> u32 {
+        self.region_size
+    }
+
+    /// Region containing the cell at `coords`
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn region(&self, coords: IVec2) -> IVec2 {
+        coords.div_euclid(IVec2::splat(self.region_size as i32))
+    }
+
+    /// Is the `region` kept at full resolution ?
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn is_detailed(&self, region: IVec2) -> bool {
+        self.focus.is_empty()
+            || self.focus.iter().any(|focus| {
+                let distance = (self.region(*focus) - region).abs().max_element();
+                distance <= self.detail_radius as i32
+            })
+    }
+
+    /// Retrieves the super-cell of the `region`, if coarse
+    #[must_use]
+    #[inline]
+    pub fn super_cell(&self, region: IVec2) -> Option<&SuperCell<S>> {
+        self.grid_super_cell(None, region)
+    }
+
+    /// Retrieves the super-cell of the `region` of the `grid`, if coarse
+    #[must_use]
+    #[inline]
+    pub fn grid_super_cell(&self, grid: Option<Entity>, region: IVec2) -> Option<&SuperCell<S>> {
+        self.super_cells.get(&(grid, region))
+    }
+
+    /// Iterates over every super-cell of the default grid with its region
+    pub fn super_cells(&self) -> impl Iterator<Item = (IVec2, &SuperCell<S>)> {
+        self.grid_super_cells()
+            .filter(|(grid, _, _)| grid.is_none())
+            .map(|(_, region, cell)| (region, cell))
+    }
+
+    /// Iterates over every super-cell with its grid root and region
+    pub fn grid_super_cells(&self) -> impl Iterator<Item = (Option<Entity>, IVec2, &SuperCell<S>)> {
+        self.super_cells
+            .iter()
+            .map(|((grid, region), cell)| (*grid, *region, cell))
+    }
+
+    /// Computes the next state of every super-cell from its neighbor
+    /// super-cells of the same grid, in a stable order
+    pub fn step(&mut self) {
+        let mut regions: Vec<_> = self.super_cells.keys().copied().collect();
+        regions.sort_unstable_by_key(|(grid, r)| (*grid, r.y, r.x));
+        let states: Vec<_> = regions
+            .iter()
+            .map(|(grid, region)| {
+                let neighbors: Vec<_> = (-1..=1)
+                    .flat_map(|y| (-1..=1).map(move |x| IVec2::new(x, y)))
+                    .filter(|offset| *offset != IVec2::ZERO)
+                    .filter_map(|offset| self.super_cells.get(&(*grid, *region + offset)))
+                    .map(|cell| &cell.state)
+                    .collect();
+                (self.rule)(&self.super_cells[&(*grid, *region)].state, &neighbors)
+            })
+            .collect();
+        for (region, state) in regions.iter().zip(states) {
+            if let Some(cell) = self.super_cells.get_mut(region) {
+                cell.state = state;
+            }
+        }
+    }
+}
+
+impl<S: CoarseCellState> LevelOfDetail<S> {
+    /// Coarse-grains the `cells` of the `region` with their `states` into its
+    /// super-cell, merged with the existing one if any
+    pub fn coarsen(&mut self, region: IVec2, cells: Vec<IVec2>, states: &[&S]) {
+        self.coarsen_grid(None, region, cells, states);
+    }
+
+    /// Coarse-grains the `cells` of the `region` of the `grid` with their
+    /// `states` into its super-cell, merged with the existing one if any
+    pub fn coarsen_grid(
+        &mut self,
+        grid: Option<Entity>,
+        region: IVec2,
+        mut cells: Vec<IVec2>,
+        states: &[&S],
+    ) {
+        let state = match self.super_cells.remove(&(grid, region)) {
+            Some(existing) => {
+                cells.extend(existing.cells);
+                let mut states = states.to_vec();
+                states.push(&existing.state);
+                S::coarsen(&states)
+            }
+            None => S::coarsen(states),
+        };
+        self.super_cells
+            .insert((grid, region), SuperCell { state, cells });
+    }
+
+    /// Refines the super-cell of the `region`, if any, back into the
+    /// coordinates and states of its cells
+    pub fn refine(&mut self, region: IVec2) -> Option<Vec<(IVec2, S)>> {
+        self.refine_grid(None, region)
+    }
+
+    /// Refines the super-cell of the `region` of the `grid`, if any, back
+    /// into the coordinates and states of its cells
+    pub fn refine_grid(&mut self, grid: Option<Entity>, region: IVec2) -> Option<Vec<(IVec2, S)>> {
+        let SuperCell { state, cells } = self.super_cells.remove(&(grid, region))?;
+        let states = state.refine(cells.len());
+        Some(cells.into_iter().zip(states).collect())
+    }
+}
+
+impl<S: CellState> Debug for LevelOfDetail<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LevelOfDetail")
+            .field("region_size", &self.region_size)
+            .field("detail_radius", &self.detail_radius)
+            .field("focus", &self.focus)
+            .field("camera_cell_size", &self.camera_cell_size)
+            .field("super_cells", &self.super_cells.len())
+            .field("on_spawn", &self.on_spawn.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CohortState, GridRoot, LevelOfDetailPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    fn cells(app: &mut App) -> Vec<(IVec2, CohortState)> {
+        let mut cells: Vec<_> = app
+            .world
+            .query::<(&MooreCell2d, &CohortState)>()
+            .iter(&app.world)
+            .map(|(cell, state)| (cell.coords, *state))
+            .collect();
+        cells.sort_by_key(|(c, _)| (c.y, c.x));
+        cells
+    }
+
+    #[test]
+    fn coarse_regions() {
+        let mut app = App::new();
+        let mut lod = LevelOfDetail::<CohortState>::new(4, 0).with_rule(|state, neighbors| {
+            // Counts the neighbor super-cells
+            let neighbors = u32::try_from(neighbors.len()).unwrap();
+            CohortState::new(state.susceptible, state.infectious + neighbors, 0)
+        });
+        lod.focus = vec![IVec2::ZERO];
+        app.add_plugins(LevelOfDetailPlugin::<MooreCell2d, CohortState>::new())
+            .insert_resource(lod);
+        for y in 0..8 {
+            for x in 0..8 {
+                let cell = MooreCell2d::new(IVec2::new(x, y));
+                app.world.spawn((cell, CohortState::new(10, 1, 0)));
+            }
+        }
+        app.update();
+        let detailed = cells(&mut app);
+        assert_eq!(detailed.len(), 16);
+        assert!(detailed.iter().all(|(c, _)| c.max_element() < 4));
+        let lod = app.world.resource::<LevelOfDetail<CohortState>>();
+        assert_eq!(lod.super_cells().count(), 3);
+        let super_cell = lod.super_cell(IVec2::new(1, 1)).unwrap();
+        assert_eq!(super_cell.state, CohortState::new(160, 18, 0));
+        assert_eq!(super_cell.cells.len(), 16);
+        // The focus moves to the opposite region
+        app.world.resource_mut::<LevelOfDetail<CohortState>>().focus = vec![IVec2::splat(7)];
+        app.update();
+        let detailed = cells(&mut app);
+        assert_eq!(detailed.len(), 16);
+        assert!(detailed.iter().all(|(c, _)| c.min_element() >= 4));
+        let states: Vec<_> = detailed.iter().map(|(_, s)| s).collect();
+        assert_eq!(CohortState::coarsen(&states), CohortState::new(160, 18, 0));
+        let lod = app.world.resource::<LevelOfDetail<CohortState>>();
+        assert!(lod.super_cell(IVec2::new(1, 1)).is_none());
+        assert_eq!(
+            lod.super_cell(IVec2::ZERO).unwrap().state,
+            CohortState::new(160, 18, 0)
+        );
+    }
+
+    #[test]
+    fn coarse_grids() {
+        let mut app = App::new();
+        let mut lod = LevelOfDetail::<CohortState>::new(4, 0);
+        lod.focus = vec![IVec2::ZERO];
+        app.add_plugins(LevelOfDetailPlugin::<MooreCell2d, CohortState>::new())
+            .insert_resource(lod);
+        let root = app.world.spawn_empty().id();
+        for y in 4..8 {
+            for x in 4..8 {
+                let cell = MooreCell2d::new(IVec2::new(x, y));
+                app.world.spawn((cell.clone(), CohortState::new(10, 1, 0)));
+                let infected = CohortState::new(0, 11, 0);
+                app.world.spawn((cell, infected, GridRoot(root)));
+            }
+        }
+        app.update();
+        assert!(cells(&mut app).is_empty());
+        let lod = app.world.resource::<LevelOfDetail<CohortState>>();
+        assert_eq!(lod.grid_super_cells().count(), 2);
+        let super_cell = lod.grid_super_cell(Some(root), IVec2::ONE).unwrap();
+        assert_eq!(super_cell.cells.len(), 16);
+        assert_ne!(lod.super_cell(IVec2::ONE).unwrap().state, super_cell.state);
+        // Both grids are refined back into their own cells
+        app.world.resource_mut::<LevelOfDetail<CohortState>>().focus = vec![IVec2::splat(7)];
+        app.update();
+        let mut cells = app.world.query::<(&CohortState, Option<&GridRoot>)>();
+        let grid_cells: Vec<_> = cells
+            .iter(&app.world)
+            .filter(|(_, grid)| grid.is_some())
+            .collect();
+        assert_eq!(grid_cells.len(), 16);
+        assert!(grid_cells
+            .iter()
+            .all(|(state, grid)| state.susceptible == 0 && grid == &Some(&GridRoot(root))));
+        assert_eq!(cells.iter(&app.world).count(), 32);
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    #[test]
+    fn refined_components() {
+        let mut app = App::new();
+        let mut lod = LevelOfDetail::<CohortState>::new(4, 0).with_on_spawn(|_, cell| {
+            cell.insert(Marker);
+        });
+        lod.focus = vec![IVec2::ZERO];
+        app.add_plugins(LevelOfDetailPlugin::<MooreCell2d, CohortState>::new())
+            .insert_resource(lod);
+        for y in 4..8 {
+            for x in 4..8 {
+                let cell = MooreCell2d::new(IVec2::new(x, y));
+                app.world.spawn((cell, CohortState::new(10, 1, 0), Marker));
+            }
+        }
+        app.update();
+        assert!(cells(&mut app).is_empty());
+        // The region is refined back with its components
+        app.world.resource_mut::<LevelOfDetail<CohortState>>().focus = vec![IVec2::splat(7)];
+        app.update();
+        let refined = cells(&mut app);
+        assert_eq!(refined.len(), 16);
+        assert!(refined.iter().all(|(c, _)| c.min_element() >= 4));
+        let mut markers = app
+            .world
+            .query_filtered::<(), (With<MooreCell2d>, With<Marker>)>();
+        assert_eq!(markers.iter(&app.world).count(), 16);
+    }
+}
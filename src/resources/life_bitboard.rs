@@ -0,0 +1,383 @@
+use crate::{components::LifeLikeRule, resources::BoundaryCondition};
+use bevy::prelude::{IVec2, Resource, UVec2};
+
+/// Resource holding a [Life-like] grid packed in a bitboard, one bit per cell
+/// and 64 cells per word, simulated by the [`BitboardLifePlugin`].
+///
+/// Every tick, the neighbor counts of 64 cells are computed at once by
+/// bitwise adders over the shifted words of the three rows around them, then
+/// the `rule` is applied to the counts with bitwise masks. Cells are only
+/// read from the bitboard to sync the cell entities for rendering, so very
+/// large grids of classic Life may be simulated.
+///
+/// The grid covers the coordinates from zero to its size, the `boundary`
+/// condition being applied on the grid edges.
+///
+/// [Life-like]: https://conwaylife.com/wiki/Life-like_cellular_automaton
+/// [`BitboardLifePlugin`]: crate::BitboardLifePlugin
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+pub struct LifeBitboard {
+    /// Rule applied to every cell
+    pub rule: LifeLikeRule,
+    /// Condition applied on the grid edges
+    pub boundary: BoundaryCondition,
+    /// Size of the grid, in cells
+    size: UVec2,
+    /// Number of words of every row
+    row_words: usize,
+    /// Current generation, row by row
+    words: Vec<u64>,
+    /// Next generation, reused every tick
+    next: Vec<u64>,
+}
+
+impl LifeBitboard {
+    /// Number of cells of a word
+    const WORD_BITS: u32 = u64::BITS;
+
+    /// Instantiates a dead grid of `size`, following Conway's rule with dead
+    /// edges
+    #[must_use]
+    pub fn new(size: UVec2) -> Self {
+        let row_words = size.x.div_ceil(Self::WORD_BITS) as usize;
+        let words = vec![0; row_words * size.y as usize];
+        Self {
+            rule: LifeLikeRule::CONWAY,
+            boundary: BoundaryCondition::FixedDead,
+            size,
+            row_words,
+            next: words.clone(),
+            words,
+        }
+    }
+
+    /// Sets the `rule` applied to every cell
+    #[must_use]
+    #[inline]
+    pub const fn with_rule(mut self, rule: LifeLikeRule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Sets the `boundary` condition applied on the grid edges
+    #[must_use]
+    #[inline]
+    pub const fn with_boundary(mut self, boundary: BoundaryCondition) -> Self {
+        self.boundary = boundary;
+        self
+    }
+
+    /// Size of the grid, in cells
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> UVec2 {
+        self.size
+    }
+
+    /// Word index and bit of the cell at `coords`, if inside the grid
+    #[allow(clippy::cast_sign_loss)]
+    fn index(&self, coords: IVec2) -> Option<(usize, u64)> {
+        let inside = coords.cmpge(IVec2::ZERO).all() && coords.as_uvec2().cmplt(self.size).all();
+        inside.then(|| {
+            let (x, y) = (coords.x as u32, coords.y as usize);
+            let word = y * self.row_words + (x / Self::WORD_BITS) as usize;
+            (word, 1 << (x % Self::WORD_BITS))
+        })
+    }
+
+    /// Whether the cell at `coords` is alive, cells outside of the grid being
+    /// dead
+    #[must_use]
+    pub fn get(&self, coords: IVec2) -> bool {
+        self.index(coords)
+            .is_some_and(|(word, bit)| self.words[word] & bit != 0)
+    }
+
+    /// Sets whether the cell at `coords` is `alive`, ignoring cells outside of
+    /// the grid
+    pub fn set(&mut self, coords: IVec2, alive: bool) {
+        if let Some((word, bit)) = self.index(coords) {
+            if alive {
+                self.words[word] |= bit;
+            } else {
+                self.words[word] &= !bit;
+            }
+        }
+    }
+
+    /// Number of live cells
+    #[must_use]
+    pub fn population(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Coordinates of every live cell, row by row
+    #[allow(clippy::cast_possible_wrap, clippy::cast_possible_truncation)]
+    pub fn alive(&self) -> impl Iterator<Item = IVec2> + '_ {
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let y = (i / self.row_words) as i32;
+            let x0 = ((i % self.row_words) as u32 * Self::WORD_BITS) as i32;
+            let mut bits = word;
+            std::iter::from_fn(move || {
+                (bits != 0).then(|| {
+                    let x = bits.trailing_zeros() as i32;
+                    bits &= bits - 1;
+                    IVec2::new(x0 + x, y)
+                })
+            })
+        })
+    }
+
+    /// Row index of the neighbor row `y`, following the boundary condition
+    fn row(&self, y: i64) -> Option<usize> {
+        let height = i64::from(self.size.y);
+        let y = match self.boundary {
+            _ if (0..height).contains(&y) => y,
+            BoundaryCondition::Wrap => y.rem_euclid(height),
+            BoundaryCondition::Mirror => y.clamp(0, height - 1),
+            BoundaryCondition::FixedDead => return None,
+        };
+        usize::try_from(y).ok()
+    }
+
+    /// Words of the `row` holding the cells west and east of the cells of
+    /// `word`, following the boundary condition on the grid edges
+    fn shifted(&self, row: &[u64], word: usize) -> (u64, u64) {
+        let last = self.size.x - 1;
+        let bit = |x: u32| (row[(x / Self::WORD_BITS) as usize] >> (x % Self::WORD_BITS)) & 1;
+        // Cells beyond the west and east edges
+        let (before, after) = match self.boundary {
+            BoundaryCondition::Wrap => (bit(last), bit(0)),
+            BoundaryCondition::Mirror => (bit(0), bit(last)),
+            BoundaryCondition::FixedDead => (0, 0),
+        };
+        let center = row[word];
+        let west_carry = if word == 0 {
+            before
+        } else {
+            row[word - 1] >> (Self::WORD_BITS - 1)
+        };
+        let east_carry = if word + 1 == self.row_words {
+            after << (last % Self::WORD_BITS)
+        } else {
+            row[word + 1] << (Self::WORD_BITS - 1)
+        };
+        ((center << 1) | west_carry, (center >> 1) | east_carry)
+    }
+
+    /// Computes the next generation of every cell
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn step(&mut self) {
+        if self.words.is_empty() {
+            return;
+        }
+        let padding = match self.size.x % Self::WORD_BITS {
+            0 => u64::MAX,
+            bits => (1 << bits) - 1,
+        };
+        let birth = self.rule.birth;
+        let survival = self.rule.survival;
+        let mut words = std::mem::take(&mut self.next);
+        for y in 0..self.size.y as usize {
+            let rows = [y as i64 - 1, y as i64, y as i64 + 1].map(|r| {
+                self.row(r)
+                    .map(|r| &self.words[r * self.row_words..(r + 1) * self.row_words])
+            });
+            for word in 0..self.row_words {
+                // Bit-sliced neighbor counts of the 64 cells, up to 8
+                let mut count = [0_u64; 4];
+                let mut add = |mut carry: u64| {
+                    for slice in &mut count {
+                        let next_carry = *slice & carry;
+                        *slice ^= carry;
+                        carry = next_carry;
+                    }
+                };
+                for (i, row) in rows.iter().enumerate() {
+                    let Some(row) = row else {
+                        continue;
+                    };
+                    let (west, east) = self.shifted(row, word);
+                    add(west);
+                    add(east);
+                    if i != 1 {
+                        add(row[word]);
+                    }
+                }
+                let alive = self.words[y * self.row_words + word];
+                let mut next = 0;
+                for n in 0..=8 {
+                    let born = if birth & (1 << n) == 0 { 0 } else { !alive };
+                    let kept = if survival & (1 << n) == 0 { 0 } else { alive };
+                    if born | kept == 0 {
+                        continue;
+                    }
+                    let matching = count
+                        .iter()
+                        .enumerate()
+                        .fold(u64::MAX, |acc, (bit, &slice)| {
+                            acc & if n & (1 << bit) == 0 { !slice } else { slice }
+                        });
+                    next |= matching & (born | kept);
+                }
+                if word + 1 == self.row_words {
+                    next &= padding;
+                }
+                words[y * self.row_words + word] = next;
+            }
+        }
+        self.next = std::mem::replace(&mut self.words, words);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CellCoordinates, ConwayCellState, MapSize};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn board(size: UVec2, alive: &[(i32, i32)]) -> LifeBitboard {
+        let mut board = LifeBitboard::new(size);
+        for &coords in alive {
+            board.set(coords.into(), true);
+        }
+        board
+    }
+
+    #[test]
+    fn blinker() {
+        // Straddles two words
+        let mut board = board(UVec2::new(100, 5), &[(63, 2), (64, 2), (65, 2)]);
+        board.step();
+        let vertical = [(64, 1), (64, 2), (64, 3)].map(IVec2::from);
+        assert_eq!(board.alive().collect::<Vec<_>>(), vertical);
+        board.step();
+        let horizontal = [(63, 2), (64, 2), (65, 2)].map(IVec2::from);
+        assert_eq!(board.alive().collect::<Vec<_>>(), horizontal);
+        assert!(!board.get(IVec2::new(-1, 2)));
+    }
+
+    #[test]
+    fn gliders() {
+        let glider = [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)];
+        // A glider crossing the edges of a wrapping grid comes back
+        let mut torus = board(UVec2::new(70, 6), &glider).with_boundary(BoundaryCondition::Wrap);
+        let start: Vec<_> = torus.alive().collect();
+        for _ in 0..4 * 70 {
+            torus.step();
+        }
+        // Moved by 70 cells along both axes
+        let moved: Vec<_> = torus.alive().collect();
+        let mut expected: Vec<_> = start
+            .iter()
+            .map(|c| IVec2::new(c.x, (c.y + 70).rem_euclid(6)))
+            .collect();
+        expected.sort_by_key(|c| (c.y, c.x));
+        assert_eq!(moved, expected);
+        // It becomes a block on dead edges
+        let mut board = board(UVec2::new(5, 5), &glider);
+        for _ in 0..20 {
+            board.step();
+        }
+        let block = [(3, 3), (4, 3), (3, 4), (4, 4)].map(IVec2::from);
+        assert_eq!(board.alive().collect::<Vec<_>>(), block);
+    }
+
+    /// Next generation computed cell by cell
+    #[allow(clippy::cast_possible_wrap)]
+    fn reference_step(board: &LifeBitboard) -> Vec<IVec2> {
+        let size = MapSize::new(board.size().x, board.size().y);
+        let mut alive = Vec::new();
+        for y in 0..size.height() as i32 {
+            for x in 0..size.width() as i32 {
+                let coords = IVec2::new(x, y);
+                let count = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| IVec2::new(dx, dy)))
+                    .filter(|&delta| delta != IVec2::ZERO)
+                    .filter_map(|delta| (coords + delta).apply_boundary(board.boundary, size))
+                    .filter(|&c| board.get(c))
+                    .count();
+                let mask = if board.get(coords) {
+                    board.rule.survival
+                } else {
+                    board.rule.birth
+                };
+                if mask & (1 << count) != 0 {
+                    alive.push(coords);
+                }
+            }
+        }
+        alive
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn reference_rules() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let boundaries = [
+            BoundaryCondition::FixedDead,
+            BoundaryCondition::Wrap,
+            BoundaryCondition::Mirror,
+        ];
+        for boundary in boundaries {
+            for rule in [LifeLikeRule::CONWAY, LifeLikeRule::HIGH_LIFE] {
+                for size in [UVec2::new(64, 3), UVec2::new(70, 7), UVec2::new(130, 4)] {
+                    let mut board = LifeBitboard::new(size)
+                        .with_rule(rule)
+                        .with_boundary(boundary);
+                    for y in 0..size.y as i32 {
+                        for x in 0..size.x as i32 {
+                            board.set(IVec2::new(x, y), rng.gen_bool(0.4));
+                        }
+                    }
+                    for _ in 0..5 {
+                        let expected = reference_step(&board);
+                        board.step();
+                        assert_eq!(board.alive().collect::<Vec<_>>(), expected);
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn synced_cells() {
+        use crate::{BitboardLifePlugin, Generation, MooreCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(BitboardLifePlugin::<MooreCell2d>::new())
+            .insert_resource(LifeBitboard::new(UVec2::splat(5)));
+        let cells: Vec<_> = (0..5)
+            .flat_map(|y| (0..5).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                let alive = coords.y == 2 && (1..=3).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect();
+        let alive = |app: &App| -> Vec<IVec2> {
+            cells
+                .iter()
+                .filter(|(_, e)| app.world.get::<ConwayCellState>(*e).unwrap().0)
+                .map(|(coords, _)| *coords)
+                .collect()
+        };
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        let vertical = [(2, 1), (2, 2), (2, 3)].map(IVec2::from);
+        assert_eq!(alive(&app), vertical);
+        // Edited cells are written back to the bitboard
+        let (_, corner) = cells[0];
+        app.world.get_mut::<ConwayCellState>(corner).unwrap().0 = true;
+        app.update();
+        // The lonely corner dies, giving birth to its neighbor
+        assert!(!app.world.resource::<LifeBitboard>().get(IVec2::ZERO));
+        let horizontal = [(1, 1), (1, 2), (2, 2), (3, 2)].map(IVec2::from);
+        assert_eq!(alive(&app), horizontal);
+    }
+}
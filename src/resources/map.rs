@@ -1,12 +1,19 @@
-use crate::components::Cell;
 #[cfg(feature = "2D")]
 use crate::components::MooreCell2d;
 #[cfg(feature = "3D")]
 use crate::components::NeumannCell3d;
+use crate::{
+    components::{Cell, CellState, GridRoot},
+    resources::{CellRng, Generation, MapSize, SimulationRng},
+};
 use bevy::{
-    prelude::{Entity, Resource},
-    utils::{HashMap, HashSet},
+    ecs::{system::Command, world::EntityWorldMut},
+    hierarchy::despawn_with_children_recursive,
+    prelude::{Entity, IVec2, Resource, With, World},
+    utils::HashMap,
 };
+use rand::Rng;
+use std::marker::PhantomData;
 
 #[cfg(feature = "2D")]
 /// A `CellMap` implementation for `Cell2d`
@@ -20,15 +27,26 @@ pub type Map3d = CellMap<NeumannCell3d>;
 ///
 /// The resource is automatically added and refreshed, it may be used for
 /// clearing (see examples).
+///
+/// Cells may move: when the coordinates of a `Cell` component change, the map
+/// is updated accordingly. Cells may be despawned at any time, like destroyed
+/// terrain: the despawned cells are evicted from the map before every tick and
+/// at the end of every frame, so the map holds no dangling entity.
+///
+/// The cells are mapped per grid: the cells with a [`GridRoot`] are only
+/// retrieved from the `_grid` methods with their root entity, the other cells
+/// forming the default grid of the other methods.
 #[derive(Clone, Resource)]
 pub struct CellMap<C: Cell> {
-    cells: HashMap<C::Coordinates, Entity>,
+    cells: HashMap<Option<Entity>, HashMap<C::Coordinates, Entity>>,
+    entities: HashMap<Entity, (Option<Entity>, C::Coordinates)>,
 }
 
 impl<C: Cell> Default for CellMap<C> {
     fn default() -> Self {
         Self {
             cells: Default::default(),
+            entities: Default::default(),
         }
     }
 }
@@ -36,11 +54,27 @@ impl<C: Cell> Default for CellMap<C> {
 impl<C: Cell> CellMap<C> {
     /// Retrieves every cell entity matching `coords`.
     /// If some coordinates are not stored in the cell map they will be ignored.
+    ///
+    /// `coords` may be of any length, allowing to directly use
+    /// [`Cell::neighbor_coordinates`].
     pub fn get_cell_entities<'a>(
         &'a self,
-        coords: &'a [C::Coordinates],
-    ) -> impl Iterator<Item = &Entity> + 'a {
-        coords.iter().filter_map(|c| self.cells.get(c))
+        coords: impl IntoIterator<Item = C::Coordinates> + 'a,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        self.get_grid_cell_entities(None, coords)
+    }
+
+    /// Retrieves every cell entity of the `grid` matching `coords`, like
+    /// [`CellMap::get_cell_entities`]
+    pub fn get_grid_cell_entities<'a>(
+        &'a self,
+        grid: Option<Entity>,
+        coords: impl IntoIterator<Item = C::Coordinates> + 'a,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        let cells = self.cells.get(&grid);
+        coords
+            .into_iter()
+            .filter_map(move |c| cells?.get(&c).copied())
     }
 
     /// Adds a `Cell` entity to the map at `coordinates`.
@@ -48,7 +82,10 @@ impl<C: Cell> CellMap<C> {
     /// # Note:
     ///
     /// This operation is done automatically when you add a `Cell` component to
-    /// an entity.
+    /// an entity, or when its coordinates change.
+    ///
+    /// If `entity` was already in the map at other coordinates, it is moved to
+    /// `coordinates`.
     ///
     /// # Returns
     ///
@@ -56,7 +93,36 @@ impl<C: Cell> CellMap<C> {
     /// If the map did have this key present, the value is updated, and the old
     /// value is returned
     pub fn insert_cell(&mut self, coordinates: C::Coordinates, entity: Entity) -> Option<Entity> {
-        self.cells.insert(coordinates, entity)
+        self.insert_grid_cell(None, coordinates, entity)
+    }
+
+    /// Adds a `Cell` entity to the map of the `grid` at `coordinates`, like
+    /// [`CellMap::insert_cell`]
+    pub fn insert_grid_cell(
+        &mut self,
+        grid: Option<Entity>,
+        coordinates: C::Coordinates,
+        entity: Entity,
+    ) -> Option<Entity> {
+        let key = (grid, coordinates.clone());
+        if let Some((previous_grid, previous)) = self.entities.insert(entity, key) {
+            if let Some(cells) = self.cells.get_mut(&previous_grid) {
+                if (previous_grid, &previous) != (grid, &coordinates)
+                    && cells.get(&previous) == Some(&entity)
+                {
+                    cells.remove(&previous);
+                }
+            }
+        }
+        let old_entity = self
+            .cells
+            .entry(grid)
+            .or_default()
+            .insert(coordinates, entity);
+        if let Some(e) = old_entity.filter(|e| *e != entity) {
+            self.entities.remove(&e);
+        }
+        old_entity
     }
 
     /// Removes a cell from the map, returning the `Entity` value if it was
@@ -64,29 +130,350 @@ impl<C: Cell> CellMap<C> {
     ///
     /// # Note:
     ///
-    /// Use this method to remove cell entities from the map if you remove a
-    /// `Cell` component from an `Entity` or *despawn* an `Entity` with a
-    /// `Cell` component.
+    /// This operation is done automatically when you remove a `Cell` component
+    /// from an `Entity` or *despawn* an `Entity` with a `Cell` component.
     pub fn remove_cell(&mut self, coordinates: &C::Coordinates) -> Option<Entity> {
-        self.cells.remove(coordinates)
+        let entity = self.cells.get_mut(&None)?.remove(coordinates)?;
+        self.entities.remove(&entity);
+        Some(entity)
     }
 
     /// Removes a cell entities from the map
     pub fn remove_entities(&mut self, entities: impl Iterator<Item = Entity>) {
-        let entities: HashSet<_> = entities.collect();
-        if entities.is_empty() {
-            return;
+        for entity in entities {
+            let Some((grid, coordinates)) = self.entities.remove(&entity) else {
+                continue;
+            };
+            let Some(cells) = self.cells.get_mut(&grid) else {
+                continue;
+            };
+            if cells.get(&coordinates) == Some(&entity) {
+                cells.remove(&coordinates);
+            }
+            if cells.is_empty() {
+                self.cells.remove(&grid);
+            }
         }
-        self.cells.retain(|_, entity| !entities.contains(entity));
     }
 
     /// Retrieves a cell entity using its `coordinates`
     pub fn get_cell(&self, coordinates: &C::Coordinates) -> Option<Entity> {
-        self.cells.get(coordinates).copied()
+        self.get_grid_cell(None, coordinates)
+    }
+
+    /// Retrieves a cell entity of the `grid` using its `coordinates`
+    pub fn get_grid_cell(
+        &self,
+        grid: Option<Entity>,
+        coordinates: &C::Coordinates,
+    ) -> Option<Entity> {
+        self.cells.get(&grid)?.get(coordinates).copied()
+    }
+
+    /// Retrieves the coordinates of a cell `entity`
+    #[must_use]
+    pub fn get_coordinates(&self, entity: Entity) -> Option<&C::Coordinates> {
+        self.entities
+            .get(&entity)
+            .map(|(_, coordinates)| coordinates)
     }
 
     /// Clears the entire map
     pub fn clear(&mut self) {
         self.cells.clear();
+        self.entities.clear();
+    }
+}
+
+/// Command despawning every `C` cell and spawning a new map of `size`,
+/// without restarting the app, like for a "new simulation" button.
+///
+/// The cells go from `(0, 0)` to `(width - 1, height - 1)`, every cell
+/// having a `density` probability to spawn with the `seeded` state, drawn
+/// from the [`SimulationRng`] if any, and the default state otherwise. The
+/// [`MapSize`] is replaced, the [`CellMap`] is rebuilt with the new cells and
+/// the [`Generation`] goes back to `0`. The `on_spawn` hook may insert the
+/// rendering components of the new cells, like their sprites.
+///
+/// With [`RegenerateMap::with_grid`], only the cells of that [`GridRoot`] are
+/// despawned, and the new cells are spawned in that grid. The other grids
+/// keep running, so the `MapSize` and the `Generation` are left unchanged.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn new_simulation(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::KeyN) {
+///         commands.add(RegenerateMap::<MooreCell2d, _>::new(
+///             MapSize::new(200, 100),
+///             0.3,
+///             ConwayCellState(true),
+///         ));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegenerateMap<C, S> {
+    /// Size of the new map
+    pub size: MapSize,
+    /// Probability for every cell to spawn with the `seeded` state
+    pub density: f64,
+    /// State of the seeded cells
+    pub seeded: S,
+    /// Hook called on every spawned cell with its coordinates
+    pub on_spawn: Option<fn(IVec2, &mut EntityWorldMut)>,
+    /// Root entity of the regenerated grid. If not set, every grid is
+    /// despawned and the new cells are spawned in the default grid.
+    pub grid: Option<Entity>,
+    phantom_c: PhantomData<C>,
+}
+
+impl<C, S> RegenerateMap<C, S> {
+    /// Instantiates Self with a new map of `size`, seeded with the `seeded`
+    /// state at the given `density`
+    #[must_use]
+    #[inline]
+    pub const fn new(size: MapSize, density: f64, seeded: S) -> Self {
+        Self {
+            size,
+            density,
+            seeded,
+            on_spawn: None,
+            grid: None,
+            phantom_c: PhantomData,
+        }
+    }
+
+    /// Calls `on_spawn` on every spawned cell with its coordinates
+    #[must_use]
+    #[inline]
+    pub const fn with_on_spawn(mut self, on_spawn: fn(IVec2, &mut EntityWorldMut)) -> Self {
+        self.on_spawn = Some(on_spawn);
+        self
+    }
+
+    /// Only regenerates the grid of the `root` entity
+    #[must_use]
+    #[inline]
+    pub const fn with_grid(mut self, root: Entity) -> Self {
+        self.grid = Some(root);
+        self
+    }
+}
+
+impl<C, S> Command for RegenerateMap<C, S>
+where
+    C: Cell + From<IVec2>,
+    S: CellState + Default,
+{
+    #[allow(clippy::cast_possible_wrap)]
+    fn apply(self, world: &mut World) {
+        let cells: Vec<_> = world
+            .query_filtered::<(Entity, Option<&GridRoot>), With<C>>()
+            .iter(world)
+            .filter(|(_, root)| self.grid.is_none() || root.map(|r| r.0) == self.grid)
+            .map(|(entity, _)| entity)
+            .collect();
+        for entity in &cells {
+            despawn_with_children_recursive(world, *entity);
+        }
+        let seeded: Vec<_> = {
+            let mut simulation_rng = world.get_resource_mut::<SimulationRng>();
+            let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+            (0..self.size.area())
+                .map(|_| CellRng.gen::<f64>() < self.density)
+                .collect()
+        };
+        let coords = (0..self.size.height() as i32)
+            .flat_map(|y| (0..self.size.width() as i32).map(move |x| IVec2::new(x, y)));
+        let mut spawned = Vec::with_capacity(seeded.len());
+        for (coords, seeded) in coords.zip(seeded) {
+            let state = if seeded {
+                self.seeded.clone()
+            } else {
+                S::default()
+            };
+            let mut entity = world.spawn((C::from(coords), state));
+            if let Some(root) = self.grid {
+                entity.insert(GridRoot(root));
+            }
+            if let Some(on_spawn) = self.on_spawn {
+                on_spawn(coords, &mut entity);
+            }
+            let grid = entity.get::<GridRoot>().map(|g| g.0);
+            if let Some(cell) = entity.get::<C>() {
+                spawned.push((grid, cell.coords().clone(), entity.id()));
+            }
+        }
+        if let Some(mut map) = world.get_resource_mut::<CellMap<C>>() {
+            map.remove_entities(cells.into_iter());
+            for (grid, coords, entity) in spawned {
+                map.insert_grid_cell(grid, coords, entity);
+            }
+        }
+        if self.grid.is_none() {
+            world.insert_resource(self.size);
+            world.insert_resource(Generation::default());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{CellularAutomatonPlugin, ConwayCellState, SimulationTick};
+    use bevy::prelude::*;
+
+    #[test]
+    fn moving_cells() {
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new().with_cell_map(),
+        );
+        let cell = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), ConwayCellState(false)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.resource::<Map2d>().get_cell(&IVec2::ZERO),
+            Some(cell)
+        );
+        app.world.get_mut::<MooreCell2d>(cell).unwrap().coords = IVec2::new(5, 5);
+        app.update();
+        let map = app.world.resource::<Map2d>();
+        assert_eq!(map.get_cell(&IVec2::ZERO), None);
+        assert_eq!(map.get_cell(&IVec2::new(5, 5)), Some(cell));
+        assert_eq!(map.get_coordinates(cell), Some(&IVec2::new(5, 5)));
+        app.world.despawn(cell);
+        app.update();
+        let map = app.world.resource::<Map2d>();
+        assert_eq!(map.get_cell(&IVec2::new(5, 5)), None);
+        assert_eq!(map.get_coordinates(cell), None);
+    }
+
+    #[test]
+    fn replaced_cells() {
+        let mut map = Map2d::default();
+        let (a, b) = (Entity::from_raw(0), Entity::from_raw(1));
+        assert_eq!(map.insert_cell(IVec2::ZERO, a), None);
+        assert_eq!(map.insert_cell(IVec2::X, b), None);
+        // `b` moves over `a`
+        assert_eq!(map.insert_cell(IVec2::ZERO, b), Some(a));
+        assert_eq!(map.get_cell(&IVec2::X), None);
+        assert_eq!(map.get_coordinates(a), None);
+        // Removing the replaced `a` keeps `b`
+        map.remove_entities(std::iter::once(a));
+        assert_eq!(map.get_cell(&IVec2::ZERO), Some(b));
+    }
+
+    #[test]
+    fn despawned_cells() {
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new().with_cell_map(),
+        );
+        let cells: Vec<_> = (0..3)
+            .map(|x| {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, 0)), ConwayCellState(true)))
+                    .id()
+            })
+            .collect();
+        app.update();
+        // Destroyed terrain, despawned before the next tick
+        app.world.despawn(cells[1]);
+        app.world.run_schedule(SimulationTick);
+        let map = app.world.resource::<Map2d>();
+        assert_eq!(map.get_cell(&IVec2::new(1, 0)), None);
+        assert_eq!(map.get_coordinates(cells[1]), None);
+        assert_eq!(map.get_cell(&IVec2::new(2, 0)), Some(cells[2]));
+        app.update();
+        assert_eq!(
+            app.world.resource::<Map2d>().get_cell(&IVec2::new(1, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn regenerated_map() {
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new().with_cell_map(),
+        );
+        let old = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), ConwayCellState(true)))
+            .id();
+        app.update();
+        RegenerateMap::<MooreCell2d, _>::new(MapSize::new(4, 3), 1.0, ConwayCellState(true))
+            .apply(&mut app.world);
+        assert!(app.world.get_entity(old).is_none());
+        assert_eq!(*app.world.resource::<MapSize>(), MapSize::new(4, 3));
+        assert_eq!(app.world.resource::<Generation>().0, 0);
+        let map = app.world.resource::<Map2d>();
+        assert_ne!(map.get_cell(&IVec2::ZERO), Some(old));
+        let cell = map.get_cell(&IVec2::new(3, 2)).unwrap();
+        assert_eq!(
+            app.world.get::<ConwayCellState>(cell),
+            Some(&ConwayCellState(true))
+        );
+        let mut cells = app.world.query::<&ConwayCellState>();
+        assert_eq!(cells.iter(&app.world).count(), 12);
+        // The regenerated map keeps running
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        let corner = app
+            .world
+            .resource::<Map2d>()
+            .get_cell(&IVec2::ZERO)
+            .unwrap();
+        assert_eq!(
+            app.world.get::<ConwayCellState>(corner),
+            Some(&ConwayCellState(true))
+        );
+        assert_eq!(
+            app.world.get::<ConwayCellState>(cell),
+            Some(&ConwayCellState(true))
+        );
+        let edge = app
+            .world
+            .resource::<Map2d>()
+            .get_cell(&IVec2::new(1, 0))
+            .unwrap();
+        assert_eq!(
+            app.world.get::<ConwayCellState>(edge),
+            Some(&ConwayCellState(false))
+        );
+    }
+
+    #[test]
+    fn regenerated_grid() {
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new().with_cell_map(),
+        );
+        let [regenerated, kept] = [(); 2].map(|()| app.world.spawn_empty().id());
+        let cells = [regenerated, kept].map(|root| {
+            let cell = (MooreCell2d::new(IVec2::ZERO), ConwayCellState(false));
+            app.world.spawn((cell, GridRoot(root))).id()
+        });
+        app.update();
+        RegenerateMap::<MooreCell2d, _>::new(MapSize::new(2, 2), 1.0, ConwayCellState(true))
+            .with_grid(regenerated)
+            .apply(&mut app.world);
+        assert!(app.world.get_entity(cells[0]).is_none());
+        assert!(app.world.get_entity(cells[1]).is_some());
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        let map = app.world.resource::<Map2d>();
+        assert_eq!(map.get_grid_cell(Some(kept), &IVec2::ZERO), Some(cells[1]));
+        let cell = map.get_grid_cell(Some(regenerated), &IVec2::ONE).unwrap();
+        assert_eq!(
+            app.world.get::<GridRoot>(cell),
+            Some(&GridRoot(regenerated))
+        );
+        assert!(map.get_grid_cell(Some(kept), &IVec2::ONE).is_none());
+        let mut cells = app.world.query::<&ConwayCellState>();
+        assert_eq!(cells.iter(&app.world).count(), 5);
     }
 }
@@ -0,0 +1,310 @@
+use bevy::prelude::{IVec2, IVec3, Reflect, Resource, UVec3};
+
+/// Resource defining the size of a bounded 2D or 3D grid, whose cell
+/// coordinates go from `(0, 0, 0)` to `(width - 1, height - 1, depth - 1)`.
+///
+/// 2D grids have a depth of `1`, set with [`MapSize::with_depth`] for 3D
+/// grids.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Resource, Reflect)]
+pub struct MapSize(pub UVec3);
+
+impl MapSize {
+    /// Instantiates a new 2D map size with `width` and `height` values
+    #[must_use]
+    #[inline]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self(UVec3::new(width, height, 1))
+    }
+
+    /// Sets the `depth` of a 3D grid
+    #[must_use]
+    #[inline]
+    pub const fn with_depth(mut self, depth: u32) -> Self {
+        self.0.z = depth;
+        self
+    }
+
+    /// Grid width
+    #[must_use]
+    #[inline]
+    pub const fn width(&self) -> u32 {
+        self.0.x
+    }
+
+    /// Grid height
+    #[must_use]
+    #[inline]
+    pub const fn height(&self) -> u32 {
+        self.0.y
+    }
+
+    /// Grid depth, `1` for 2D grids
+    #[must_use]
+    #[inline]
+    pub const fn depth(&self) -> u32 {
+        self.0.z
+    }
+
+    /// Has the grid no cell, with a width, a height or a depth of `0`
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0.x == 0 || self.0.y == 0 || self.0.z == 0
+    }
+
+    /// Are the `coords` inside the grid bounds
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn contains(&self, coords: IVec2) -> bool {
+        coords.x >= 0 && coords.y >= 0 && coords.x < self.0.x as i32 && coords.y < self.0.y as i32
+    }
+
+    /// Wraps `coords` around the grid edges, as on a torus
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn wrap(&self, coords: IVec2) -> IVec2 {
+        IVec2::new(
+            coords.x.rem_euclid(self.0.x as i32),
+            coords.y.rem_euclid(self.0.y as i32),
+        )
+    }
+
+    /// Mirrors `coords` on the grid edges, as if the grid was reflected on
+    /// each side: the cell left of the first column is the first column
+    /// itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn mirror(&self, coords: IVec2) -> IVec2 {
+        IVec2::new(
+            mirror_axis(coords.x, self.0.x as i32),
+            mirror_axis(coords.y, self.0.y as i32),
+        )
+    }
+
+    /// Are the 3D `coords` inside the grid bounds
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn contains_3d(&self, coords: IVec3) -> bool {
+        self.contains(IVec2::new(coords.x, coords.y)) && coords.z >= 0 && coords.z < self.0.z as i32
+    }
+
+    /// Wraps the 3D `coords` around the grid faces, as on a 3-torus
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn wrap_3d(&self, coords: IVec3) -> IVec3 {
+        let wrapped = self.wrap(IVec2::new(coords.x, coords.y));
+        IVec3::new(wrapped.x, wrapped.y, coords.z.rem_euclid(self.0.z as i32))
+    }
+
+    /// Mirrors the 3D `coords` on the grid faces, as [`MapSize::mirror`] does
+    /// on the edges of 2D grids
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn mirror_3d(&self, coords: IVec3) -> IVec3 {
+        let mirrored = self.mirror(IVec2::new(coords.x, coords.y));
+        IVec3::new(
+            mirrored.x,
+            mirrored.y,
+            mirror_axis(coords.z, self.0.z as i32),
+        )
+    }
+
+    /// Amount of cells in a 2D grid
+    #[must_use]
+    #[inline]
+    pub const fn area(&self) -> usize {
+        self.0.x as usize * self.0.y as usize
+    }
+
+    /// Amount of cells in the grid, its area for 2D grids
+    #[must_use]
+    #[inline]
+    pub const fn volume(&self) -> usize {
+        self.area() * self.0.z as usize
+    }
+
+    /// Retrieves the row-major index of `coords`, or `None` if out of bounds
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn index(&self, coords: IVec2) -> Option<usize> {
+        if self.contains(coords) {
+            Some(coords.y as usize * self.0.x as usize + coords.x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the layer-major index of the 3D `coords`, or `None` if out
+    /// of bounds
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn index_3d(&self, coords: IVec3) -> Option<usize> {
+        match self.index(IVec2::new(coords.x, coords.y)) {
+            Some(index) if self.contains_3d(coords) => {
+                Some(coords.z as usize * self.area() + index)
+            }
+            _ => None,
+        }
+    }
+}
+
+const fn mirror_axis(value: i32, size: i32) -> i32 {
+    let period = value.rem_euclid(2 * size);
+    if period >= size {
+        2 * size - 1 - period
+    } else {
+        period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping() {
+        let size = MapSize::new(10, 5);
+        assert_eq!(size.wrap(IVec2::new(3, 2)), IVec2::new(3, 2));
+        assert_eq!(size.wrap(IVec2::new(-1, -1)), IVec2::new(9, 4));
+        assert_eq!(size.wrap(IVec2::new(10, 5)), IVec2::new(0, 0));
+        assert_eq!(size.wrap(IVec2::new(-21, 12)), IVec2::new(9, 2));
+    }
+
+    #[test]
+    fn mirroring() {
+        let size = MapSize::new(10, 5);
+        assert_eq!(size.mirror(IVec2::new(3, 2)), IVec2::new(3, 2));
+        assert_eq!(size.mirror(IVec2::new(-1, -1)), IVec2::new(0, 0));
+        assert_eq!(size.mirror(IVec2::new(-2, 5)), IVec2::new(1, 4));
+        assert_eq!(size.mirror(IVec2::new(11, 6)), IVec2::new(8, 3));
+    }
+
+    #[test]
+    fn empty_boundaries() {
+        use crate::{components::CellCoordinates, BoundaryCondition};
+
+        for size in [MapSize::new(0, 5), MapSize::new(10, 0)] {
+            assert!(size.is_empty());
+            for condition in [
+                BoundaryCondition::Wrap,
+                BoundaryCondition::FixedDead,
+                BoundaryCondition::Mirror,
+            ] {
+                assert_eq!(IVec2::new(3, 2).apply_boundary(condition, size), None);
+            }
+        }
+        // 1D grids only have a width
+        let size = MapSize::new(0, 1);
+        assert_eq!(3.apply_boundary(BoundaryCondition::Wrap, size), None);
+        assert!(!MapSize::new(10, 5).is_empty());
+    }
+
+    #[test]
+    fn boundaries_3d() {
+        use crate::{components::CellCoordinates, BoundaryCondition};
+
+        let size = MapSize::new(4, 3).with_depth(2);
+        assert_eq!(size.depth(), 2);
+        assert_eq!(size.volume(), 24);
+        let coords = IVec3::new(-1, 3, 2);
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::Wrap, size),
+            Some(IVec3::new(3, 0, 0))
+        );
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::FixedDead, size),
+            None
+        );
+        assert_eq!(
+            coords.apply_boundary(BoundaryCondition::Mirror, size),
+            Some(IVec3::new(0, 2, 1))
+        );
+        assert_eq!(IVec3::new(3, 2, 1).dense_index(size), Some(23));
+        assert_eq!(IVec3::new(1, 2, 0).dense_index(size), Some(9));
+        assert_eq!(IVec3::new(1, 2, 2).dense_index(size), None);
+        // 2D grids are a single layer deep
+        assert_eq!(IVec3::new(1, 1, 0).dense_index(MapSize::new(4, 3)), Some(5));
+        let empty = size.with_depth(0);
+        assert!(empty.is_empty());
+        assert_eq!(
+            IVec3::ZERO.apply_boundary(BoundaryCondition::Wrap, empty),
+            None
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let size = MapSize::new(10, 5);
+        assert!(size.contains(IVec2::ZERO));
+        assert!(size.contains(IVec2::new(9, 4)));
+        assert!(!size.contains(IVec2::new(10, 4)));
+        assert!(!size.contains(IVec2::new(-1, 0)));
+    }
+
+    #[test]
+    fn indexes() {
+        let size = MapSize::new(10, 5);
+        assert_eq!(size.area(), 50);
+        assert_eq!(size.index(IVec2::ZERO), Some(0));
+        assert_eq!(size.index(IVec2::new(3, 2)), Some(23));
+        assert_eq!(size.index(IVec2::new(9, 4)), Some(49));
+        assert_eq!(size.index(IVec2::new(10, 0)), None);
+    }
+
+    #[test]
+    #[cfg(feature = "2D")]
+    fn dense_simulation() {
+        use crate::{CellularAutomatonPlugin, ConwayCellState, MooreCell2d};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new()
+                .with_dense_map(MapSize::new(5, 5)),
+        );
+        // Vertical blinker, and a lone live cell out of the grid
+        let blinker: Vec<_> = (0..5)
+            .flat_map(|y| (0..5).map(move |x| IVec2::new(x, y)))
+            .map(|c| {
+                let state = ConwayCellState(c.x == 2 && (1..=3).contains(&c.y));
+                app.world.spawn((MooreCell2d::new(c), state)).id()
+            })
+            .collect();
+        app.world
+            .spawn((MooreCell2d::new(IVec2::new(5, 2)), ConwayCellState(true)));
+        app.update();
+        assert_eq!(*app.world.resource::<MapSize>(), MapSize::new(5, 5));
+        let alive: Vec<_> = blinker
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| app.world.get::<ConwayCellState>(**e).unwrap().0)
+            .map(|(i, _)| i)
+            .collect();
+        // Horizontal blinker, the out of grid cell being ignored
+        assert_eq!(alive, vec![11, 12, 13]);
+    }
+}
@@ -1,5 +1,113 @@
+pub use adjacency_table::*;
+pub use age_mixing_params::*;
+pub use async_compute::*;
+pub use boundary::*;
+#[cfg(feature = "2D")]
+pub use cell_grid::*;
+pub use cluster_detection::*;
+pub use dirty_chunks::*;
+pub use epidemic_params::*;
+pub use excitable_params::*;
+pub use forest_fire_params::*;
+pub use generation::*;
+#[cfg(all(feature = "1D", feature = "auto-coloring"))]
+pub use generation_scroll::*;
+#[cfg(feature = "gpu")]
+pub use gpu_life_grid::*;
+pub use hash_life::*;
+pub use hospital_capacity::*;
+pub use household_params::*;
+pub use importation::*;
+pub use intervention_timeline::*;
+pub use level_of_detail::*;
+pub use life_bitboard::*;
 pub use map::*;
+pub use map_size::*;
+pub use multi_strain_params::*;
+pub use playback::*;
+pub use potts_params::*;
+pub use quarantine::*;
+pub use recording::*;
+pub use region_of_interest::*;
+pub use reiter_params::*;
+pub use rule_table::*;
+pub use sandpile_drop::*;
+pub use schelling_params::*;
+pub use seasonal_forcing::*;
+pub use severity_params::*;
 pub use simulation::*;
+pub use simulation_history::*;
+pub use simulation_rng::*;
+pub use simulation_speed::*;
+pub use sleeping_cells::*;
+pub use state_hash::*;
+pub use termination::*;
+pub use tick_budget::*;
+pub use time_series::*;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+pub use time_series_export::*;
+#[cfg(feature = "egui-plot")]
+pub use time_series_plot::*;
+#[cfg(feature = "1D")]
+pub use traffic::*;
+#[cfg(feature = "2D")]
+pub use turmite_rules::*;
+pub use update_probability::*;
+pub use vaccination::*;
 
+mod adjacency_table;
+mod age_mixing_params;
+mod async_compute;
+mod boundary;
+#[cfg(feature = "2D")]
+mod cell_grid;
+mod cluster_detection;
+mod dirty_chunks;
+mod epidemic_params;
+mod excitable_params;
+mod forest_fire_params;
+mod generation;
+#[cfg(all(feature = "1D", feature = "auto-coloring"))]
+mod generation_scroll;
+#[cfg(feature = "gpu")]
+mod gpu_life_grid;
+mod hash_life;
+mod hospital_capacity;
+mod household_params;
+mod importation;
+mod intervention_timeline;
+mod level_of_detail;
+mod life_bitboard;
 mod map;
+mod map_size;
+mod multi_strain_params;
+mod playback;
+mod potts_params;
+mod quarantine;
+mod recording;
+mod region_of_interest;
+mod reiter_params;
+mod rule_table;
+mod sandpile_drop;
+mod schelling_params;
+mod seasonal_forcing;
+mod severity_params;
 mod simulation;
+mod simulation_history;
+mod simulation_rng;
+mod simulation_speed;
+mod sleeping_cells;
+mod state_hash;
+mod termination;
+mod tick_budget;
+mod time_series;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+mod time_series_export;
+#[cfg(feature = "egui-plot")]
+mod time_series_plot;
+#[cfg(feature = "1D")]
+mod traffic;
+#[cfg(feature = "2D")]
+mod turmite_rules;
+mod update_probability;
+mod vaccination;
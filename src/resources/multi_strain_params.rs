@@ -0,0 +1,84 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`MultiStrainState`], read every generation. The
+/// amount of strains is the length of `transmission_probabilities`, up to 32.
+///
+/// [`MultiStrainState`]: crate::MultiStrainState
+#[derive(Debug, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct MultiStrainParams {
+    /// Probability for a susceptible cell to be infected by each of its
+    /// neighbors infectious with a strain, every generation, for every strain
+    pub transmission_probabilities: Vec<f64>,
+    /// Cross-immunity matrix: `cross_immunity[i][j]` is the probability for a
+    /// cell recovered from the strain `i` to be protected against the strain
+    /// `j`. Missing values give no protection.
+    pub cross_immunity: Vec<Vec<f64>>,
+    /// Amount of generations an infected cell stays infectious before
+    /// recovering
+    pub infectious_period: u32,
+    /// Probability for a cell to lose the immunity of all its past infections
+    /// every generation
+    pub waning_probability: f64,
+}
+
+impl Default for MultiStrainParams {
+    fn default() -> Self {
+        Self::new(vec![0.3, 0.3], vec![vec![1.0, 0.5], vec![0.5, 1.0]])
+    }
+}
+
+impl MultiStrainParams {
+    /// Instantiates new parameters with the `transmission_probabilities` of
+    /// every strain and the `cross_immunity` matrix. Cells are infectious for
+    /// 5 generations and lose their immunity with a `0.01` probability.
+    #[must_use]
+    #[inline]
+    pub const fn new(transmission_probabilities: Vec<f64>, cross_immunity: Vec<Vec<f64>>) -> Self {
+        Self {
+            transmission_probabilities,
+            cross_immunity,
+            infectious_period: 5,
+            waning_probability: 0.01,
+        }
+    }
+
+    /// Sets the `infectious_period` of infected cells
+    #[must_use]
+    #[inline]
+    pub const fn with_infectious_period(mut self, infectious_period: u32) -> Self {
+        self.infectious_period = infectious_period;
+        self
+    }
+
+    /// Sets the `waning_probability` of the immunity
+    #[must_use]
+    #[inline]
+    pub const fn with_waning(mut self, waning_probability: f64) -> Self {
+        self.waning_probability = waning_probability;
+        self
+    }
+
+    /// Amount of simulated strains
+    #[must_use]
+    #[inline]
+    pub fn strains(&self) -> usize {
+        self.transmission_probabilities.len().min(32)
+    }
+
+    /// Probability for a cell recovered from the `past` strain to be protected
+    /// against the `strain`
+    #[must_use]
+    #[inline]
+    pub fn protection(&self, past: usize, strain: usize) -> f64 {
+        self.cross_immunity
+            .get(past)
+            .and_then(|row| row.get(strain))
+            .map_or(0.0, |p| p.clamp(0.0, 1.0))
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::Resource;
+
+/// Resource controlling the replay of a [`Recording`] by the
+/// [`PlaybackPlugin`].
+///
+/// While playing, the playback advances one generation every tick, following
+/// the [`SimulationSpeed`] and pausing with the [`SimulationState`], until
+/// the last recorded generation. The `generation` may be set at any time to
+/// scrub forward or backward.
+///
+/// [`Recording`]: crate::Recording
+/// [`PlaybackPlugin`]: crate::PlaybackPlugin
+/// [`SimulationSpeed`]: crate::SimulationSpeed
+/// [`SimulationState`]: crate::SimulationState
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct Playback {
+    /// Replayed generation
+    pub generation: u64,
+    /// Does the playback advance every tick ?
+    pub playing: bool,
+}
+
+impl Playback {
+    /// Instantiates a playback playing from the first generation
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            generation: 0,
+            playing: true,
+        }
+    }
+
+    /// Stops the playback at `generation`
+    #[inline]
+    pub const fn seek(&mut self, generation: u64) {
+        self.generation = generation;
+        self.playing = false;
+    }
+
+    /// Stops the playback and moves it `generations` forward
+    #[inline]
+    pub const fn step_forward(&mut self, generations: u64) {
+        self.seek(self.generation.saturating_add(generations));
+    }
+
+    /// Stops the playback and moves it `generations` backward
+    #[inline]
+    pub const fn step_backward(&mut self, generations: u64) {
+        self.seek(self.generation.saturating_sub(generations));
+    }
+}
+
+impl Default for Playback {
+    fn default() -> Self {
+        Self::new()
+    }
+}
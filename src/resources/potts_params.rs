@@ -0,0 +1,42 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`PottsState`] rules, may be modified at runtime
+/// to tweak the simulation.
+///
+/// [`PottsState`]: crate::PottsState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct PottsParams {
+    /// Temperature of the system. Higher temperatures accept more energy
+    /// increasing flips, a zero temperature only accepts flips lowering or
+    /// keeping the energy.
+    pub temperature: f64,
+    /// Probability of every cell attempting a flip each generation. Low
+    /// values make the update asynchronous, avoiding the oscillations of
+    /// neighbor cells flipping together.
+    pub update_probability: f64,
+}
+
+impl Default for PottsParams {
+    fn default() -> Self {
+        Self::new(0.5, 0.1)
+    }
+}
+
+impl PottsParams {
+    /// Instantiates new parameters with `temperature` and
+    /// `update_probability`
+    #[must_use]
+    #[inline]
+    pub const fn new(temperature: f64, update_probability: f64) -> Self {
+        Self {
+            temperature,
+            update_probability,
+        }
+    }
+}
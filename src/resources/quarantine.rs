@@ -0,0 +1,152 @@
+use crate::components::Cell;
+use bevy::{
+    prelude::{IVec2, Resource},
+    utils::HashSet,
+};
+
+/// Resource quarantining regions of the cells of type `C`.
+///
+/// Neighbor links crossing the border of a quarantined region are cut: the
+/// cells inside of a region no longer see the cells outside of it, and the
+/// other way around. Regions may be added or cleared at any time, and the
+/// links are restored once the resource is removed.
+#[derive(Debug, Clone, Resource)]
+pub struct Quarantine<C: Cell> {
+    regions: Vec<HashSet<C::Coordinates>>,
+}
+
+impl<C: Cell> Quarantine<C> {
+    /// Instantiates a quarantine without any region
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            regions: Vec::new(),
+        }
+    }
+
+    /// Adds the region made of the cells at `coords`
+    #[must_use]
+    #[inline]
+    pub fn with_region(mut self, coords: impl IntoIterator<Item = C::Coordinates>) -> Self {
+        self.add_region(coords);
+        self
+    }
+
+    /// Quarantines the region made of the cells at `coords`
+    pub fn add_region(&mut self, coords: impl IntoIterator<Item = C::Coordinates>) {
+        self.regions.push(coords.into_iter().collect());
+    }
+
+    /// Lifts the quarantine of every region
+    #[inline]
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+
+    /// Retrieves the quarantined regions
+    #[must_use]
+    #[inline]
+    pub fn regions(&self) -> &[HashSet<C::Coordinates>] {
+        &self.regions
+    }
+
+    /// Is the link between the cells at `a` and `b` crossing the border of a
+    /// quarantined region ?
+    #[must_use]
+    pub fn cuts(&self, a: &C::Coordinates, b: &C::Coordinates) -> bool {
+        self.regions
+            .iter()
+            .any(|region| region.contains(a) != region.contains(b))
+    }
+}
+
+impl<C: Cell<Coordinates = IVec2>> Quarantine<C> {
+    /// Adds the rectangular region between the `min` and `max` coordinates,
+    /// inclusive
+    #[must_use]
+    #[inline]
+    pub fn with_rectangle(mut self, min: IVec2, max: IVec2) -> Self {
+        self.add_rectangle(min, max);
+        self
+    }
+
+    /// Quarantines the rectangular region between the `min` and `max`
+    /// coordinates, inclusive
+    pub fn add_rectangle(&mut self, min: IVec2, max: IVec2) {
+        self.add_region(
+            (min.y..=max.y).flat_map(|y| (min.x..=max.x).map(move |x| IVec2::new(x, y))),
+        );
+    }
+}
+
+impl<C: Cell> Default for Quarantine<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    #[test]
+    fn cut_links() {
+        let quarantine = Quarantine::<MooreCell2d>::new().with_rectangle(IVec2::ZERO, IVec2::ONE);
+        assert_eq!(quarantine.regions()[0].len(), 4);
+        assert!(quarantine.cuts(&IVec2::ONE, &IVec2::new(2, 1)));
+        assert!(quarantine.cuts(&IVec2::NEG_X, &IVec2::ZERO));
+        assert!(!quarantine.cuts(&IVec2::ZERO, &IVec2::ONE));
+        assert!(!quarantine.cuts(&IVec2::NEG_ONE, &IVec2::NEG_X));
+    }
+
+    fn live_cells(app: &mut App) -> HashSet<IVec2> {
+        app.world
+            .query::<(&MooreCell2d, &ConwayCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| state.0)
+            .map(|(cell, _)| cell.coords)
+            .collect()
+    }
+
+    fn reset_blinker(app: &mut App) {
+        for (cell, mut state) in app
+            .world
+            .query::<(&MooreCell2d, &mut ConwayCellState)>()
+            .iter_mut(&mut app.world)
+        {
+            state.0 = cell.coords.y == 0 && cell.coords.x.abs() <= 1;
+        }
+    }
+
+    #[test]
+    fn quarantined_blinker() {
+        for plugin in [
+            GameOfLife2dPlugin::new(),
+            GameOfLife2dPlugin::new().with_cached_neighbors(),
+        ] {
+            let mut app = App::new();
+            app.add_plugins(plugin)
+                .insert_resource(Quarantine::<MooreCell2d>::new().with_region([IVec2::Y]));
+            for x in -2..=2 {
+                for y in -2..=2 {
+                    app.world
+                        .spawn((MooreCell2d::new(IVec2::new(x, y)), ConwayCellState(false)));
+                }
+            }
+            reset_blinker(&mut app);
+            app.update();
+            // The quarantined cell above the blinker doesn't come to life
+            let expected: HashSet<_> = [IVec2::ZERO, IVec2::NEG_Y].into_iter().collect();
+            assert_eq!(live_cells(&mut app), expected);
+            // Once lifted, the blinker oscillates again
+            app.world.remove_resource::<Quarantine<MooreCell2d>>();
+            reset_blinker(&mut app);
+            app.update();
+            let expected: HashSet<_> = [IVec2::NEG_Y, IVec2::ZERO, IVec2::Y].into_iter().collect();
+            assert_eq!(live_cells(&mut app), expected);
+        }
+    }
+}
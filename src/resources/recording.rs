@@ -0,0 +1,210 @@
+use crate::components::Cell;
+use bevy::{prelude::Resource, utils::HashMap};
+use std::marker::PhantomData;
+#[cfg(feature = "ron")]
+use std::{io, path::Path};
+
+/// Recorded generation and cell states
+type Frame<C, S> = (u64, Vec<(<C as Cell>::Coordinates, S)>);
+
+/// Resource recording the `S` states of the `C` cells every generation, to
+/// replay them with the [`PlaybackPlugin`] without simulating again.
+///
+/// Used by the [`RecordingPlugin`]. The states of every cell are recorded
+/// before the first tick, then only the changed states of every generation,
+/// by coordinates so recordings may be replayed on other entities. With the
+/// `ron` feature, recordings of serializable states may be saved to disk and
+/// loaded back.
+///
+/// [`PlaybackPlugin`]: crate::PlaybackPlugin
+/// [`RecordingPlugin`]: crate::RecordingPlugin
+#[derive(Debug, Clone, Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "C::Coordinates: serde::Serialize, S: serde::Serialize",
+        deserialize = "C::Coordinates: serde::Deserialize<'de>, S: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Recording<C: Cell, S> {
+    /// Changed states of every recorded generation, in order
+    frames: Vec<Frame<C, S>>,
+    /// Phantom data for the `C` (`Cell`) type
+    #[cfg_attr(feature = "serde", serde(skip))]
+    phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell, S> Default for Recording<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Cell, S> Recording<C, S> {
+    /// Instantiates an empty recording
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            frames: Vec::new(),
+            phantom_c: PhantomData,
+        }
+    }
+
+    /// Is there no recorded generation ?
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// First recorded generation, if any
+    #[must_use]
+    #[inline]
+    pub fn first_generation(&self) -> Option<u64> {
+        self.frames.first().map(|(generation, _)| *generation)
+    }
+
+    /// Last recorded generation, if any
+    #[must_use]
+    #[inline]
+    pub fn last_generation(&self) -> Option<u64> {
+        self.frames.last().map(|(generation, _)| *generation)
+    }
+
+    /// Retrieves the states recorded at `generation`, which are every state
+    /// for the first generation and the changed states for the next ones
+    #[must_use]
+    pub fn changes(&self, generation: u64) -> Option<&[(C::Coordinates, S)]> {
+        self.frames
+            .iter()
+            .find(|(g, _)| *g == generation)
+            .map(|(_, changes)| changes.as_slice())
+    }
+
+    /// Records the `changes` of `generation`, merged with the changes already
+    /// recorded if it is the last recorded generation. Older generations are
+    /// ignored.
+    pub fn record(
+        &mut self,
+        generation: u64,
+        changes: impl IntoIterator<Item = (C::Coordinates, S)>,
+    ) {
+        match self.frames.last_mut() {
+            Some((last, frame)) if *last == generation => {
+                let mut indices: HashMap<C::Coordinates, usize> = frame
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (coords, _))| (coords.clone(), i))
+                    .collect();
+                for (coords, state) in changes {
+                    if let Some(&i) = indices.get(&coords) {
+                        frame[i].1 = state;
+                    } else {
+                        indices.insert(coords.clone(), frame.len());
+                        frame.push((coords, state));
+                    }
+                }
+            }
+            Some((last, _)) if *last > generation => (),
+            _ => self
+                .frames
+                .push((generation, changes.into_iter().collect())),
+        }
+    }
+}
+
+impl<C: Cell, S: Clone> Recording<C, S> {
+    /// Computes the states of every recorded cell at `generation`
+    #[must_use]
+    pub fn states_at(&self, generation: u64) -> HashMap<C::Coordinates, S> {
+        self.changes_between(None, generation)
+    }
+
+    /// Computes the states changed after the `from` generation, or every state
+    /// if not set, until the `to` generation
+    #[must_use]
+    pub fn changes_between(&self, from: Option<u64>, to: u64) -> HashMap<C::Coordinates, S> {
+        self.frames
+            .iter()
+            .filter(|(g, _)| from.is_none_or(|from| *g > from) && *g <= to)
+            .flat_map(|(_, changes)| changes.iter().cloned())
+            .collect()
+    }
+}
+
+#[cfg(feature = "ron")]
+impl<C: Cell, S> Recording<C, S> {
+    /// Saves the recording to a RON file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the recording can't be serialized or written
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()>
+    where
+        C::Coordinates: serde::Serialize,
+        S: serde::Serialize,
+    {
+        let ron =
+            ron::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        std::fs::write(path, ron)
+    }
+
+    /// Loads a recording from the RON file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be read or if the recording is invalid
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self>
+    where
+        C::Coordinates: for<'de> serde::Deserialize<'de>,
+        S: for<'de> serde::Deserialize<'de>,
+    {
+        ron::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::MooreCell2d;
+    use bevy::prelude::IVec2;
+
+    #[test]
+    fn recorded_changes() {
+        let mut recording = Recording::<MooreCell2d, u8>::new();
+        recording.record(0, [(IVec2::ZERO, 0), (IVec2::X, 0)]);
+        recording.record(1, [(IVec2::X, 1)]);
+        // Changes of the same generation are merged
+        recording.record(1, [(IVec2::X, 2), (IVec2::Y, 2)]);
+        recording.record(0, [(IVec2::ZERO, 9)]);
+        recording.record(3, [(IVec2::ZERO, 3)]);
+        assert_eq!(recording.first_generation(), Some(0));
+        assert_eq!(recording.last_generation(), Some(3));
+        assert_eq!(
+            recording.changes(1),
+            Some([(IVec2::X, 2), (IVec2::Y, 2)].as_slice())
+        );
+        let states = recording.states_at(2);
+        assert_eq!(states.len(), 3);
+        assert_eq!(states[&IVec2::ZERO], 0);
+        assert_eq!(states[&IVec2::X], 2);
+        assert_eq!(recording.states_at(3)[&IVec2::ZERO], 3);
+        assert_eq!(recording.changes_between(Some(1), 3).len(), 1);
+    }
+
+    #[cfg(feature = "ron")]
+    #[test]
+    fn saved_recording() {
+        let mut recording = Recording::<MooreCell2d, u8>::new();
+        recording.record(0, [(IVec2::ZERO, 0), (IVec2::X, 0)]);
+        recording.record(1, [(IVec2::X, 1)]);
+        let path = std::env::temp_dir().join("bevy_life_recording.ron");
+        recording.save(&path).unwrap();
+        let loaded = Recording::<MooreCell2d, u8>::load(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(loaded.frames, recording.frames);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::components::CellCoordinates;
+use bevy::prelude::{IRect, IVec2, Resource};
+
+/// Resource restricting the simulation to the cells within a margin around a
+/// rect, like the visible area of the cameras.
+///
+/// The cells outside of the rect inflated by `margin` cells skip their rules
+/// and keep their states until the rect comes near again, while the cells
+/// inside read them as frozen neighbors. Enormous grids may then be explored
+/// interactively, at the cost of global correctness. The rect is set in cell
+/// coordinates along the `x` and `y` axes, and coordinates without axes, like
+/// graph nodes, are always simulated.
+///
+/// With [`RegionOfInterest::with_camera`], the rect follows the visible area
+/// of the active orthographic cameras, drawing the cell at `coords` at
+/// `coords * cell_size`. It applies to the rules of the
+/// [`CellularAutomatonPlugin`] and of its weighted, parametric and coupled
+/// variants.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// // Simulates the cells drawn by the cameras, with 10 pixels per cell, and
+/// // 16 cells around them
+/// let interest = RegionOfInterest::default()
+///     .with_camera(10.0)
+///     .with_margin(16);
+/// ```
+///
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Clone, Copy, PartialEq, Resource, Default)]
+pub struct RegionOfInterest {
+    /// Rect of the simulated cells, bounds included
+    pub rect: IRect,
+    /// Amount of cells simulated around the rect
+    pub margin: u32,
+    /// World size of a cell. If set, the rect follows the cameras.
+    pub camera_cell_size: Option<f32>,
+}
+
+impl RegionOfInterest {
+    /// Instantiates Self simulating the cells of the `rect`
+    #[must_use]
+    #[inline]
+    pub const fn new(rect: IRect) -> Self {
+        Self {
+            rect,
+            margin: 0,
+            camera_cell_size: None,
+        }
+    }
+
+    /// Simulates `margin` cells around the rect
+    #[must_use]
+    #[inline]
+    pub const fn with_margin(mut self, margin: u32) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// The rect follows the visible area of the cameras, drawing cells of
+    /// `cell_size`
+    #[must_use]
+    #[inline]
+    pub const fn with_camera(mut self, cell_size: f32) -> Self {
+        self.camera_cell_size = Some(cell_size);
+        self
+    }
+
+    /// Is the cell at `coords` simulated ?
+    #[must_use]
+    #[inline]
+    pub fn contains(&self, coords: &impl CellCoordinates) -> bool {
+        let margin = i32::try_from(self.margin).unwrap_or(i32::MAX);
+        coords.chunk(1).is_none_or(|position| {
+            self.rect
+                .inset(margin)
+                .contains(IVec2::new(position.x, position.y))
+        })
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    #[test]
+    fn contains() {
+        let interest = RegionOfInterest::new(IRect::new(0, 0, 3, 3)).with_margin(1);
+        assert!(interest.contains(&IVec2::new(-1, 4)));
+        assert!(!interest.contains(&IVec2::new(-2, 0)));
+        assert!(!interest.contains(&IVec2::new(0, 5)));
+        assert!(interest.contains(&Entity::PLACEHOLDER));
+    }
+
+    #[test]
+    fn camera_interest() {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new())
+            .insert_resource(RegionOfInterest::default().with_camera(10.0));
+        let projection = OrthographicProjection {
+            area: Rect::new(-25.0, -25.0, 25.0, 25.0),
+            ..default()
+        };
+        app.world.spawn((
+            Camera::default(),
+            projection,
+            GlobalTransform::from_translation(Vec3::new(20.0, 20.0, 0.0)),
+        ));
+        // Two blinkers, the second one far from the camera
+        let cells: Vec<_> = (0..16)
+            .flat_map(|y| (0..16).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                let alive = matches!(coords.y, 2 | 12) && (1..4).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect();
+        app.update();
+        let interest = app.world.resource::<RegionOfInterest>();
+        assert_eq!(interest.rect, IRect::new(-1, -1, 5, 5));
+        let alive = |app: &App| {
+            cells
+                .iter()
+                .filter(|(_, e)| app.world.get::<ConwayCellState>(*e).unwrap().0)
+                .map(|(c, _)| *c)
+                .collect::<Vec<_>>()
+        };
+        let vertical = [IVec2::new(2, 1), IVec2::new(2, 2), IVec2::new(2, 3)];
+        let frozen = [IVec2::new(1, 12), IVec2::new(2, 12), IVec2::new(3, 12)];
+        assert_eq!(alive(&app), [vertical, frozen].concat());
+        // Without the resource every cell is simulated again
+        app.world.remove_resource::<RegionOfInterest>();
+        app.update();
+        let horizontal = [IVec2::new(1, 2), IVec2::new(2, 2), IVec2::new(3, 2)];
+        let flipped = [IVec2::new(2, 11), IVec2::new(2, 12), IVec2::new(2, 13)];
+        let mut expected = [horizontal, flipped].concat();
+        expected.sort_by_key(|c| (c.y, c.x));
+        assert_eq!(alive(&app), expected);
+    }
+}
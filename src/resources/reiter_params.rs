@@ -0,0 +1,36 @@
+use bevy::prelude::{Reflect, Resource};
+
+/// Global parameters of the [`ReiterState`] snowflake growth rules, may be
+/// modified at runtime to tweak the simulation.
+///
+/// [`ReiterState`]: crate::ReiterState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ReiterParams {
+    /// Diffusion coefficient of the water vapor
+    pub alpha: f32,
+    /// Background vapor level, of the initial non frozen cells and of the
+    /// missing cells beyond the map edges
+    pub beta: f32,
+    /// Vapor added to receptive cells every generation
+    pub gamma: f32,
+}
+
+impl Default for ReiterParams {
+    fn default() -> Self {
+        Self::new(1.0, 0.4, 0.001)
+    }
+}
+
+impl ReiterParams {
+    /// Instantiates new parameters with `alpha`, `beta` and `gamma`
+    #[must_use]
+    #[inline]
+    pub const fn new(alpha: f32, beta: f32, gamma: f32) -> Self {
+        Self { alpha, beta, gamma }
+    }
+}
@@ -0,0 +1,611 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+use bevy::utils::HashMap;
+use std::{fmt, str::FromStr};
+
+/// Symmetry of the neighborhood of a [`RuleTable`], every transition applying
+/// to all the symmetric neighbor arrangements
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect)]
+pub enum TableSymmetry {
+    /// Transitions only apply to the exact neighbor order
+    #[default]
+    None,
+    /// Transitions apply to the given amount of evenly spaced rotations of
+    /// the neighbors, which must be listed clockwise. `Rotate(4)` on 4
+    /// neighbors applies to every rotation, on 8 neighbors to quarter turns.
+    Rotate(usize),
+    /// Like [`Self::Rotate`], also applying to the mirrored arrangements
+    RotateReflect(usize),
+    /// Transitions apply to the mirrored neighbors
+    Reflect,
+    /// Transitions apply to any permutation of the neighbors: only the
+    /// amount of neighbors in each state matters
+    Permute,
+}
+
+/// Transition table of a table driven cellular automaton, mapping the state
+/// of a cell and the ordered states of its neighbors to its new state.
+///
+/// Tables may be built from strings of digits listing the cell state, the
+/// neighbor states and the new state, for example `"000012"` is a transition
+/// from state `0` with neighbors `0, 0, 0, 1` to state `2`. They may also be
+/// parsed from the `@TABLE` section of a [Golly] rule file, with its
+/// variables and symmetries:
+///
+/// ```rust,no_run
+/// # use bevy_life::RuleTable;
+/// let table: RuleTable = "
+/// @TABLE
+/// n_states:3
+/// neighborhood:vonNeumann
+/// symmetries:rotate4
+/// var a={1,2}
+/// var b={0,1,2}
+/// 0,a,a,b,b,a
+/// a,b,b,b,b,0
+/// "
+/// .parse()
+/// .unwrap();
+/// ```
+///
+/// Golly neighbors are reordered to match the neighbor order of the
+/// `NeumannCell2d`, `MooreCell2d`, `HexagonCell2d` and `Cell1d` cells for
+/// the `vonNeumann`, `Moore`, `hexagonal` and `oneDimensional` neighborhoods.
+///
+/// The first matching transition applies, cells without a matching
+/// transition keep their state. The table is the [`TableCellState`] rules
+/// resource.
+///
+/// [Golly]: https://golly.sourceforge.io/Help/formats.html#table
+/// [`TableCellState`]: crate::TableCellState
+#[derive(Debug, Clone, Default, PartialEq, Eq, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct RuleTable {
+    neighbors: usize,
+    symmetry: TableSymmetry,
+    /// New state of every transition
+    outputs: Vec<u8>,
+    /// Bitsets of the transitions accepting each state, for the cell then
+    /// for each of its neighbors
+    matches: Vec<Vec<Vec<u64>>>,
+}
+
+impl RuleTable {
+    /// Instantiates an empty table for cells with an amount of `neighbors`
+    /// and a `symmetry`
+    #[must_use]
+    pub fn new(neighbors: usize, symmetry: TableSymmetry) -> Self {
+        Self {
+            neighbors,
+            symmetry,
+            outputs: Vec::new(),
+            matches: vec![Vec::new(); neighbors + 1],
+        }
+    }
+
+    /// Parses a table for cells with an amount of `neighbors` and a
+    /// `symmetry` from whitespace separated transitions, ignoring `#` comments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a transition isn't made of `neighbors + 2` digits
+    pub fn from_digits(
+        neighbors: usize,
+        symmetry: TableSymmetry,
+        table: &str,
+    ) -> Result<Self, RuleTableError> {
+        let mut rules = Self::new(neighbors, symmetry);
+        for (index, line) in table.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default();
+            for transition in line.split_whitespace() {
+                let digits = transition
+                    .chars()
+                    .map(|c| c.to_digit(10).and_then(|d| u8::try_from(d).ok()))
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|d| d.len() == neighbors + 2)
+                    .ok_or(RuleTableError::InvalidLine(index + 1))?;
+                rules.insert(digits[0], &digits[1..=neighbors], digits[neighbors + 1]);
+            }
+        }
+        Ok(rules)
+    }
+
+    /// Adds the transition from `state` with `neighbors` to `new_state`, and
+    /// its symmetric transitions
+    ///
+    /// # Panics
+    ///
+    /// Panics if the amount of `neighbors` doesn't match the table
+    pub fn insert(&mut self, state: u8, neighbors: &[u8], new_state: u8) {
+        assert_eq!(
+            neighbors.len(),
+            self.neighbors,
+            "invalid amount of neighbors"
+        );
+        let neighbors: Vec<_> = neighbors.iter().map(|s| vec![*s]).collect();
+        self.insert_sets(&[state], &neighbors, new_state, None);
+    }
+
+    /// Adds the transitions from any of the `states` with any of the
+    /// `neighbors` states to `new_state`, in every symmetric arrangement.
+    /// Arrangements are computed in the `order` of the neighbors, then
+    /// reordered so that the neighbor `i` is the `order[i]` one.
+    fn insert_sets(
+        &mut self,
+        states: &[u8],
+        neighbors: &[Vec<u8>],
+        new_state: u8,
+        order: Option<&[usize]>,
+    ) {
+        for arrangement in self.arrangements(neighbors) {
+            let arrangement: Vec<_> = order.map_or_else(
+                || arrangement.iter().collect(),
+                |order| order.iter().map(|i| &arrangement[*i]).collect(),
+            );
+            let index = self.outputs.len();
+            self.outputs.push(new_state);
+            let (word, bit) = (index / 64, 1 << (index % 64));
+            let inputs = std::iter::once(states).chain(arrangement.into_iter().map(Vec::as_slice));
+            for (states, matches) in inputs.zip(&mut self.matches) {
+                for state in states.iter().map(|s| usize::from(*s)) {
+                    if matches.len() <= state {
+                        matches.resize(state + 1, Vec::new());
+                    }
+                    let words = &mut matches[state];
+                    if words.len() <= word {
+                        words.resize(word + 1, 0);
+                    }
+                    words[word] |= bit;
+                }
+            }
+        }
+    }
+
+    /// Distinct symmetric arrangements of `neighbors`
+    fn arrangements<T: Clone + PartialEq>(&self, neighbors: &[T]) -> Vec<Vec<T>> {
+        let n = neighbors.len();
+        let rotations = |count: usize| {
+            let step = n.checked_div(count).unwrap_or(n).max(1);
+            (0..count.max(1)).map(move |r| (0..n).map(|i| (i + r * step) % n).collect::<Vec<_>>())
+        };
+        // Mirrors clockwise neighbors, or swaps the left and right ones
+        let mirror = |p: Vec<usize>| -> Vec<usize> {
+            (0..n)
+                .map(|i| if n == 2 { p[1 - i] } else { p[(n - i) % n] })
+                .collect()
+        };
+        let permutations: Vec<Vec<usize>> = match self.symmetry {
+            TableSymmetry::None => vec![(0..n).collect()],
+            TableSymmetry::Rotate(count) => rotations(count).collect(),
+            TableSymmetry::RotateReflect(count) => rotations(count)
+                .flat_map(|p| [p.clone(), mirror(p)])
+                .collect(),
+            TableSymmetry::Reflect => vec![(0..n).collect(), mirror((0..n).collect())],
+            TableSymmetry::Permute => return distinct_permutations(neighbors),
+        };
+        let mut arrangements: Vec<Vec<T>> = Vec::new();
+        for permutation in permutations {
+            let arrangement: Vec<_> = permutation.iter().map(|i| neighbors[*i].clone()).collect();
+            if !arrangements.contains(&arrangement) {
+                arrangements.push(arrangement);
+            }
+        }
+        arrangements
+    }
+
+    /// Retrieves the new state of a cell in `state` with the `neighbors`
+    /// states, if defined
+    #[must_use]
+    pub fn transition(&self, state: u8, neighbors: impl IntoIterator<Item = u8>) -> Option<u8> {
+        let matches: Vec<&[u64]> = std::iter::once(state)
+            .chain(neighbors)
+            .zip(&self.matches)
+            .map(|(s, matches)| matches.get(usize::from(s)).map_or(&[][..], Vec::as_slice))
+            .collect();
+        if matches.len() != self.neighbors + 1 {
+            return None;
+        }
+        (0..self.outputs.len().div_ceil(64)).find_map(|word| {
+            let bits = matches
+                .iter()
+                .fold(u64::MAX, |bits, m| bits & m.get(word).copied().unwrap_or(0));
+            (bits != 0).then(|| self.outputs[word * 64 + bits.trailing_zeros() as usize])
+        })
+    }
+
+    /// Amount of neighbors of the cells
+    #[must_use]
+    #[inline]
+    pub const fn neighbors(&self) -> usize {
+        self.neighbors
+    }
+
+    /// Amount of transitions, including the symmetric ones
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// Does the table have no transition
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+}
+
+/// Every distinct permutation of `items`, in lexicographic order of their
+/// first occurrence
+fn distinct_permutations<T: Clone + PartialEq>(items: &[T]) -> Vec<Vec<T>> {
+    let mut classes: Vec<_> = items
+        .iter()
+        .map(|item| items.iter().position(|i| i == item).unwrap_or(0))
+        .collect();
+    classes.sort_unstable();
+    let mut permutations = Vec::new();
+    loop {
+        permutations.push(classes.iter().map(|c| items[*c].clone()).collect());
+        let Some(i) = (1..classes.len())
+            .rev()
+            .find(|i| classes[i - 1] < classes[*i])
+        else {
+            return permutations;
+        };
+        let j = (i..classes.len())
+            .rev()
+            .find(|j| classes[*j] > classes[i - 1])
+            .unwrap_or(i);
+        classes.swap(i - 1, j);
+        classes[i..].reverse();
+    }
+}
+
+/// Golly neighborhood `name` as the Golly index of each cell neighbor
+fn golly_neighborhood(name: &str) -> Option<&'static [usize]> {
+    match name {
+        // Golly lists N, E, S, W and `NeumannCell2d` W, N, E, S
+        "vonNeumann" => Some(&[3, 0, 1, 2]),
+        // Golly lists N, NE, E, SE, S, SW, W, NW and `MooreCell2d` W, NW, N, ..
+        "Moore" => Some(&[6, 7, 0, 1, 2, 3, 4, 5]),
+        "hexagonal" => Some(&[0, 1, 2, 3, 4, 5]),
+        "oneDimensional" => Some(&[0, 1]),
+        _ => None,
+    }
+}
+
+/// Golly symmetry `name` for an amount of `neighbors`
+fn golly_symmetry(name: &str, neighbors: usize) -> Option<TableSymmetry> {
+    match name {
+        "none" => Some(TableSymmetry::None),
+        "permute" => Some(TableSymmetry::Permute),
+        "reflect" | "reflect_horizontal" => Some(TableSymmetry::Reflect),
+        _ => {
+            let rotations = name.strip_prefix("rotate")?;
+            let (count, reflect) = rotations
+                .strip_suffix("reflect")
+                .map_or((rotations, false), |count| (count, true));
+            let count: usize = count.parse().ok()?;
+            if count == 0 || !neighbors.is_multiple_of(count) {
+                return None;
+            }
+            Some(if reflect {
+                TableSymmetry::RotateReflect(count)
+            } else {
+                TableSymmetry::Rotate(count)
+            })
+        }
+    }
+}
+
+/// Splits a Golly transition into its cell, neighbors and new state values,
+/// either comma separated or as single digits
+fn golly_values(line: &str) -> Vec<&str> {
+    if !line.contains(',') {
+        return line
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| &line[i..i + c.len_utf8()])
+            .collect();
+    }
+    let mut values = Vec::new();
+    let (mut start, mut depth) = (0, 0_usize);
+    for (i, c) in line.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                values.push(line[start..i].trim());
+                start = i + 1;
+            }
+            _ => (),
+        }
+    }
+    values.push(line[start..].trim());
+    values
+}
+
+/// Parser state of a Golly `@TABLE` section
+struct GollyTable {
+    states: usize,
+    order: &'static [usize],
+    symmetry: String,
+    variables: HashMap<String, Vec<u8>>,
+    table: Option<RuleTable>,
+}
+
+impl GollyTable {
+    /// States of a single state, variable or `{..}` set `value`
+    fn states(&self, value: &str) -> Option<Vec<u8>> {
+        if let Some(set) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+            let mut states = Vec::new();
+            for value in set.split(',') {
+                states.extend(self.states(value.trim())?);
+            }
+            return Some(states);
+        }
+        if let Some(states) = self.variables.get(value) {
+            return Some(states.clone());
+        }
+        value
+            .parse::<u8>()
+            .ok()
+            .filter(|s| usize::from(*s) < self.states)
+            .map(|s| vec![s])
+    }
+
+    /// Table of the transitions, created with the header settings
+    fn table(&mut self) -> Result<&mut RuleTable, RuleTableError> {
+        if self.table.is_none() {
+            let neighbors = self.order.len();
+            let symmetry = golly_symmetry(&self.symmetry, neighbors)
+                .ok_or_else(|| RuleTableError::UnsupportedSymmetry(self.symmetry.clone()))?;
+            self.table = Some(RuleTable::new(neighbors, symmetry));
+        }
+        Ok(self.table.get_or_insert_with(RuleTable::default))
+    }
+
+    /// Parses a header or variable `line`, returning `None` if it is invalid
+    fn parse_definition(&mut self, line: &str) -> Option<Result<(), RuleTableError>> {
+        if let Some(variable) = line.strip_prefix("var ") {
+            let (name, states) = variable.split_once('=')?;
+            let states = self.states(states.trim())?;
+            self.variables.insert(name.trim().to_string(), states);
+            return Some(Ok(()));
+        }
+        let (key, value) = line.split_once(':')?;
+        let value = value.trim();
+        // The header must precede the transitions
+        if self.table.is_some() {
+            return None;
+        }
+        match key.trim() {
+            "n_states" => self.states = value.parse().ok().filter(|n| (2..=256).contains(n))?,
+            "neighborhood" => match golly_neighborhood(value) {
+                Some(order) => self.order = order,
+                None => {
+                    return Some(Err(RuleTableError::UnsupportedNeighborhood(
+                        value.to_string(),
+                    )))
+                }
+            },
+            "symmetries" => self.symmetry = value.to_string(),
+            _ => return None,
+        }
+        Some(Ok(()))
+    }
+
+    /// Parses a transition `line`, returning `None` if it is invalid
+    fn parse_transition(&mut self, line: &str) -> Option<Result<(), RuleTableError>> {
+        let values = golly_values(line);
+        let neighbors = self.order.len();
+        if values.len() != neighbors + 2 {
+            return None;
+        }
+        let (inputs, output) = values.split_at(neighbors + 1);
+        let output = output[0];
+        // Variables appearing more than once, or as the new state, are bound
+        // to the same state in the whole transition
+        let mut bound: Vec<&str> = Vec::new();
+        for value in inputs {
+            let repeated = inputs.iter().filter(|v| *v == value).count() > 1;
+            if self.variables.contains_key(*value)
+                && (repeated || *value == output)
+                && !bound.contains(value)
+            {
+                bound.push(value);
+            }
+        }
+        if self.variables.contains_key(output) && !bound.contains(&output) {
+            return None;
+        }
+        let bound_states: Vec<_> = bound.iter().map(|v| self.variables[*v].clone()).collect();
+        let mut indices = vec![0; bound.len()];
+        let order = self.order;
+        loop {
+            let resolve = |value: &str| -> Option<Vec<u8>> {
+                bound.iter().position(|b| *b == value).map_or_else(
+                    || self.states(value),
+                    |b| Some(vec![bound_states[b][indices[b]]]),
+                )
+            };
+            let sets = inputs
+                .iter()
+                .map(|v| resolve(v))
+                .collect::<Option<Vec<_>>>()?;
+            let new_state = match resolve(output)?.as_slice() {
+                [state] => *state,
+                _ => return None,
+            };
+            match self.table() {
+                Ok(table) => table.insert_sets(&sets[0], &sets[1..], new_state, Some(order)),
+                Err(e) => return Some(Err(e)),
+            }
+            // Next assignment of the bound variables
+            let Some(b) = (0..bound.len()).find(|b| indices[*b] + 1 < bound_states[*b].len())
+            else {
+                return Some(Ok(()));
+            };
+            indices[b] += 1;
+            indices[..b].fill(0);
+        }
+    }
+}
+
+/// Error returned when parsing an invalid [`RuleTable`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleTableError {
+    /// The line at the given number is invalid
+    InvalidLine(usize),
+    /// The Golly neighborhood isn't supported
+    UnsupportedNeighborhood(String),
+    /// The Golly symmetry isn't supported by the neighborhood
+    UnsupportedSymmetry(String),
+}
+
+impl fmt::Display for RuleTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLine(line) => write!(f, "invalid rule table line {line}"),
+            Self::UnsupportedNeighborhood(n) => write!(f, "unsupported neighborhood `{n}`"),
+            Self::UnsupportedSymmetry(s) => write!(f, "unsupported symmetries `{s}`"),
+        }
+    }
+}
+
+impl std::error::Error for RuleTableError {}
+
+impl FromStr for RuleTable {
+    type Err = RuleTableError;
+
+    /// Parses the `@TABLE` section of a Golly rule file, or the whole string
+    /// if it has no such section. The neighborhood defaults to `vonNeumann`
+    /// and the symmetries to `none`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut golly = GollyTable {
+            states: 256,
+            order: golly_neighborhood("vonNeumann").unwrap_or_default(),
+            symmetry: "none".to_string(),
+            variables: HashMap::new(),
+            table: None,
+        };
+        let mut in_table = !s.lines().any(|l| l.trim() == "@TABLE");
+        for (index, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.starts_with('@') {
+                in_table = line == "@TABLE";
+                continue;
+            }
+            if !in_table || line.is_empty() {
+                continue;
+            }
+            let parsed = if line.starts_with("var ") || line.contains(':') {
+                golly.parse_definition(line)
+            } else {
+                golly.parse_transition(line)
+            };
+            parsed.unwrap_or(Err(RuleTableError::InvalidLine(index + 1)))?;
+        }
+        golly.table()?;
+        Ok(golly.table.unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotations() {
+        let table = RuleTable::from_digits(
+            4,
+            TableSymmetry::Rotate(4),
+            "# comment\n000012\n\n 123453 # with comment\n",
+        )
+        .unwrap();
+        assert_eq!(table.len(), 8);
+        assert_eq!(table.transition(0, [0, 0, 0, 1]), Some(2));
+        assert_eq!(table.transition(0, [1, 0, 0, 0]), Some(2));
+        assert_eq!(table.transition(1, [4, 5, 2, 3]), Some(3));
+        assert_eq!(table.transition(1, [2, 5, 4, 3]), None);
+        let table = RuleTable::from_digits(4, TableSymmetry::None, "000012").unwrap();
+        assert_eq!(table.transition(0, [1, 0, 0, 0]), None);
+        assert_eq!(
+            RuleTable::from_digits(4, TableSymmetry::None, "000012\n000012 00001"),
+            Err(RuleTableError::InvalidLine(2))
+        );
+    }
+
+    #[test]
+    fn symmetries() {
+        let mut table = RuleTable::new(8, TableSymmetry::Rotate(4));
+        table.insert(0, &[1, 2, 0, 0, 0, 0, 0, 0], 1);
+        assert_eq!(table.len(), 4);
+        assert_eq!(table.transition(0, [0, 0, 1, 2, 0, 0, 0, 0]), Some(1));
+        assert_eq!(table.transition(0, [0, 1, 2, 0, 0, 0, 0, 0]), None);
+        let mut table = RuleTable::new(4, TableSymmetry::Reflect);
+        table.insert(0, &[1, 2, 0, 3], 1);
+        assert_eq!(table.transition(0, [1, 3, 0, 2]), Some(1));
+        assert_eq!(table.transition(0, [2, 1, 3, 0]), None);
+        let mut table = RuleTable::new(4, TableSymmetry::Permute);
+        table.insert(0, &[1, 1, 2, 0], 1);
+        assert_eq!(table.len(), 12);
+        assert_eq!(table.transition(0, [2, 1, 0, 1]), Some(1));
+        let mut table = RuleTable::new(2, TableSymmetry::Reflect);
+        table.insert(0, &[1, 0], 1);
+        assert_eq!(table.transition(0, [0, 1]), Some(1));
+    }
+
+    #[test]
+    fn golly_tables() {
+        let table: RuleTable = "@RULE Test
+@TABLE
+n_states:3
+neighborhood:vonNeumann
+symmetries:none
+var a={1,2}
+var b={0,a}
+# N, E, S, W neighbors
+0,a,a,b,b,a
+0,1,2,0,0,2
+a,0,0,0,{1,2},0
+@COLORS
+1 255 0 0"
+            .parse()
+            .unwrap();
+        // `NeumannCell2d` neighbors are W, N, E, S
+        assert_eq!(table.transition(0, [1, 2, 2, 1]), Some(2));
+        assert_eq!(table.transition(0, [0, 1, 1, 0]), Some(1));
+        // Bound variables must match
+        assert_eq!(table.transition(0, [0, 1, 2, 0]), Some(2));
+        assert_eq!(table.transition(0, [0, 2, 1, 0]), None);
+        assert_eq!(table.transition(1, [2, 0, 0, 0]), Some(0));
+        assert_eq!(table.transition(2, [0, 0, 0, 1]), None);
+
+        let table: RuleTable = "neighborhood:Moore\nsymmetries:rotate8\n0110000002\n"
+            .parse()
+            .unwrap();
+        assert_eq!(table.neighbors(), 8);
+        assert_eq!(table.len(), 8);
+        assert_eq!(table.transition(0, [0, 0, 0, 0, 1, 1, 0, 0]), Some(2));
+
+        for (invalid, error) in [
+            ("n_states:2\n0,0,0,0,2,1", RuleTableError::InvalidLine(2)),
+            ("0,0,0,0,1", RuleTableError::InvalidLine(1)),
+            ("var a={0,1}\n0,0,0,0,1,a", RuleTableError::InvalidLine(2)),
+            (
+                "0,0,0,0,1,1\nsymmetries:permute",
+                RuleTableError::InvalidLine(2),
+            ),
+            (
+                "neighborhood:triangular",
+                RuleTableError::UnsupportedNeighborhood("triangular".to_string()),
+            ),
+            (
+                "neighborhood:hexagonal\nsymmetries:rotate4",
+                RuleTableError::UnsupportedSymmetry("rotate4".to_string()),
+            ),
+        ] {
+            assert_eq!(invalid.parse::<RuleTable>(), Err(error), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use crate::components::Cell;
+use bevy::prelude::Resource;
+
+/// Resource driving a sandpile by dropping `grains` on the cells of type `C`
+/// every tick of the [`SandpilePlugin`].
+///
+/// The grains are all dropped on the cell at `coords`, or each on a random
+/// cell if not set.
+///
+/// [`SandpilePlugin`]: crate::SandpilePlugin
+#[derive(Debug, Clone, Resource)]
+pub struct SandpileDrop<C: Cell> {
+    /// Amount of grains dropped every tick
+    pub grains: u32,
+    /// Coordinates of the cell receiving the grains. If not set, every grain
+    /// falls on a random cell.
+    pub coords: Option<C::Coordinates>,
+}
+
+impl<C: Cell> SandpileDrop<C> {
+    /// Drops `grains` every tick on the cell at `coords`
+    #[must_use]
+    #[inline]
+    pub const fn at(coords: C::Coordinates, grains: u32) -> Self {
+        Self {
+            grains,
+            coords: Some(coords),
+        }
+    }
+
+    /// Drops `grains` every tick on random cells
+    #[must_use]
+    #[inline]
+    pub const fn random(grains: u32) -> Self {
+        Self {
+            grains,
+            coords: None,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`SchellingState`] rules, may be modified at
+/// runtime to tweak the simulation.
+///
+/// [`SchellingState`]: crate::SchellingState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SchellingParams {
+    /// Minimum ratio of similar agents among the occupied neighbors for an
+    /// agent to be satisfied
+    pub threshold: f32,
+}
+
+impl Default for SchellingParams {
+    fn default() -> Self {
+        Self::new(0.3)
+    }
+}
+
+impl SchellingParams {
+    /// Instantiates new parameters with a satisfaction `threshold`
+    #[must_use]
+    #[inline]
+    pub const fn new(threshold: f32) -> Self {
+        Self { threshold }
+    }
+}
@@ -0,0 +1,136 @@
+use bevy::prelude::Resource;
+use std::f64::consts::TAU;
+
+/// Seasonal variation of a [`SeasonalForcing`]
+#[derive(Debug, Copy, Clone)]
+pub enum ForcingProfile {
+    /// Sinusoidal variation, peaking at the `phase` generation
+    Sinusoidal {
+        /// Relative amplitude of the variation: the transmission varies
+        /// between `1 - amplitude` and `1 + amplitude` times its base value
+        amplitude: f64,
+        /// Amount of generations of a season cycle
+        period: f64,
+        /// Generation of the first transmission peak
+        phase: f64,
+    },
+    /// Custom multiplier of the generation number
+    Custom(fn(u64) -> f64),
+}
+
+/// Resource scaling the transmission of the epidemic states like
+/// [`SeirCellState`] every generation, for seasonal diseases like influenza.
+///
+/// Used by the [`SeasonalForcingPlugin`], which writes the current multiplier
+/// to the [`EpidemicParams`] `seasonality` and counts the generations.
+///
+/// [`SeirCellState`]: crate::SeirCellState
+/// [`SeasonalForcingPlugin`]: crate::SeasonalForcingPlugin
+/// [`EpidemicParams`]: crate::EpidemicParams
+#[derive(Debug, Copy, Clone, Resource)]
+pub struct SeasonalForcing {
+    /// Seasonal variation of the transmission
+    pub profile: ForcingProfile,
+    /// Current generation number
+    pub generation: u64,
+}
+
+impl SeasonalForcing {
+    /// Sinusoidal forcing of relative `amplitude` with a `period` in
+    /// generations, peaking at the first generation
+    #[must_use]
+    #[inline]
+    pub const fn sinusoidal(amplitude: f64, period: f64) -> Self {
+        Self {
+            profile: ForcingProfile::Sinusoidal {
+                amplitude,
+                period,
+                phase: 0.0,
+            },
+            generation: 0,
+        }
+    }
+
+    /// Forcing following a custom `multiplier` of the generation number
+    #[must_use]
+    #[inline]
+    pub const fn custom(multiplier: fn(u64) -> f64) -> Self {
+        Self {
+            profile: ForcingProfile::Custom(multiplier),
+            generation: 0,
+        }
+    }
+
+    /// Shifts the sinusoidal peaks to the `phase` generation
+    #[must_use]
+    #[inline]
+    pub const fn with_phase(mut self, phase: f64) -> Self {
+        if let ForcingProfile::Sinusoidal {
+            amplitude, period, ..
+        } = self.profile
+        {
+            self.profile = ForcingProfile::Sinusoidal {
+                amplitude,
+                period,
+                phase,
+            };
+        }
+        self
+    }
+
+    /// Transmission multiplier at the given `generation`, never negative
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn multiplier(&self, generation: u64) -> f64 {
+        let multiplier = match self.profile {
+            ForcingProfile::Sinusoidal {
+                amplitude,
+                period,
+                phase,
+            } => amplitude.mul_add((TAU * (generation as f64 - phase) / period).cos(), 1.0),
+            ForcingProfile::Custom(multiplier) => multiplier(generation),
+        };
+        multiplier.max(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sinusoidal_forcing() {
+        let forcing = SeasonalForcing::sinusoidal(0.5, 4.0);
+        let multipliers: Vec<_> = (0..5).map(|g| forcing.multiplier(g)).collect();
+        for (multiplier, expected) in multipliers.into_iter().zip([1.5, 1.0, 0.5, 1.0, 1.5]) {
+            assert!((multiplier - expected).abs() < 1e-9);
+        }
+        let forcing = forcing.with_phase(2.0);
+        assert!((forcing.multiplier(2) - 1.5).abs() < 1e-9);
+        // Large amplitudes stop the transmission without turning negative
+        assert!(SeasonalForcing::sinusoidal(2.0, 4.0).multiplier(2).abs() < f64::EPSILON);
+        let forcing = SeasonalForcing::custom(|g| if g % 2 == 0 { 1.0 } else { 0.0 });
+        assert!((forcing.multiplier(4) - 1.0).abs() < f64::EPSILON);
+        assert!(forcing.multiplier(5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn forced_transmission() {
+        use crate::{EpidemicParams, SeasonalForcingPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins(SeasonalForcingPlugin::new())
+            .insert_resource(EpidemicParams::new(0.4, 1, 1))
+            .insert_resource(SeasonalForcing::sinusoidal(1.0, 4.0));
+        let mut transmissions = vec![];
+        for _ in 0..3 {
+            app.update();
+            transmissions.push(app.world.resource::<EpidemicParams>().transmission());
+        }
+        for (transmission, expected) in transmissions.into_iter().zip([0.8, 0.4, 0.0]) {
+            assert!((transmission - expected).abs() < 1e-9);
+        }
+        assert_eq!(app.world.resource::<SeasonalForcing>().generation, 3);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::resources::EpidemicParams;
+use bevy::prelude::{Reflect, ReflectResource, Resource};
+
+/// Global parameters of the [`SeverityCellState`], read every generation: the
+/// [`EpidemicParams`] of the transmission, and the severe pathway of the
+/// infections.
+///
+/// [`SeverityCellState`]: crate::SeverityCellState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+#[reflect(Resource)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct SeverityParams {
+    /// Epidemic parameters, whose `infectious_period` is the duration of the
+    /// mild and severe stages
+    pub epidemic: EpidemicParams,
+    /// Probability for an infection to become severe at the end of the
+    /// incubation
+    pub severe_probability: f64,
+    /// Amount of generations a hospitalized cell stays in hospital
+    pub hospital_period: u32,
+    /// Probability for a hospitalized cell to die at the end of its stay
+    pub hospital_mortality: f64,
+    /// Probability for a severe cell which couldn't be hospitalized to die
+    pub untreated_mortality: f64,
+    /// Probability for a severe cell to be admitted in hospital, overwritten
+    /// every generation by the [`HospitalCapacityPlugin`] following the free
+    /// beds of the [`HospitalCapacity`]
+    ///
+    /// [`HospitalCapacityPlugin`]: crate::HospitalCapacityPlugin
+    /// [`HospitalCapacity`]: crate::HospitalCapacity
+    pub admission_probability: f64,
+}
+
+impl Default for SeverityParams {
+    fn default() -> Self {
+        Self::new(EpidemicParams::default(), 0.1)
+    }
+}
+
+impl SeverityParams {
+    /// Instantiates new parameters with the `epidemic` parameters and the
+    /// `severe_probability` of infections. Hospitalized cells stay 10
+    /// generations in hospital, with a `0.1` mortality rising to `0.5`
+    /// untreated, and every severe cell is admitted.
+    #[must_use]
+    #[inline]
+    pub const fn new(epidemic: EpidemicParams, severe_probability: f64) -> Self {
+        Self {
+            epidemic,
+            severe_probability,
+            hospital_period: 10,
+            hospital_mortality: 0.1,
+            untreated_mortality: 0.5,
+            admission_probability: 1.0,
+        }
+    }
+
+    /// Sets the `hospital_period` of hospitalized cells
+    #[must_use]
+    #[inline]
+    pub const fn with_hospital_period(mut self, hospital_period: u32) -> Self {
+        self.hospital_period = hospital_period;
+        self
+    }
+
+    /// Sets the `hospital_mortality` of hospitalized cells and the
+    /// `untreated_mortality` of severe cells without a bed
+    #[must_use]
+    #[inline]
+    pub const fn with_mortality(
+        mut self,
+        hospital_mortality: f64,
+        untreated_mortality: f64,
+    ) -> Self {
+        self.hospital_mortality = hospital_mortality;
+        self.untreated_mortality = untreated_mortality;
+        self
+    }
+}
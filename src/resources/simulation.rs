@@ -1,9 +1,217 @@
-use bevy::prelude::Resource;
+use bevy::{
+    ecs::query::BatchingStrategy,
+    prelude::{Resource, States},
+};
 
-/// Resource to insert for parallel queries and batching
-#[derive(Debug, Clone, Resource, Default)]
-pub struct SimulationBatch;
+/// Resource to insert for parallel queries and batching.
+///
+/// The cells are iterated in chunks processed in parallel on the
+/// `ComputeTaskPool`. The chunk size is auto-tuned every tick from the number
+/// of cells, split in `chunks_per_thread` chunks per thread to balance the
+/// load across many cores, and clamped between the `min_chunk_size` and the
+/// `max_chunk_size`, so cheap rules don't pay the scheduling of a task every
+/// few cells. A `batch_size` fixes the size of the chunks instead, to tune it
+/// for the cost of a state type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct SimulationBatch {
+    /// Fixed number of cells of a chunk. If not set, the chunk size is
+    /// auto-tuned.
+    pub batch_size: Option<usize>,
+    /// Minimum number of cells of a chunk
+    pub min_chunk_size: usize,
+    /// Maximum number of cells of a chunk
+    pub max_chunk_size: usize,
+    /// Number of chunks per thread
+    pub chunks_per_thread: usize,
+}
 
 /// Resource to insert to pause the cellular automaton simulation
 #[derive(Debug, Resource)]
 pub struct SimulationPause;
+
+/// Running state of the cellular automaton simulation. The systems of the
+/// plugins only run in the [`SimulationState::Running`] state.
+///
+/// The state is toggled by the key of the [`SimulationStatePlugin`], or by
+/// setting the `NextState<SimulationState>` resource.
+///
+/// [`SimulationStatePlugin`]: crate::SimulationStatePlugin
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, States)]
+pub enum SimulationState {
+    /// The simulation advances every tick
+    #[default]
+    Running,
+    /// The simulation is paused
+    Paused,
+}
+
+impl SimulationBatch {
+    /// Instantiates Self with default values: chunks of at least 256 cells,
+    /// 4 per thread
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            batch_size: None,
+            min_chunk_size: 256,
+            max_chunk_size: usize::MAX,
+            chunks_per_thread: 4,
+        }
+    }
+
+    /// Sets the fixed number of cells of a chunk, or auto-tunes it if `None`
+    #[must_use]
+    #[inline]
+    pub const fn with_batch_size(mut self, batch_size: Option<usize>) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Sets the `min` and `max` number of cells of an auto-tuned chunk
+    #[must_use]
+    #[inline]
+    pub const fn with_chunk_size_limits(mut self, min: usize, max: usize) -> Self {
+        self.min_chunk_size = min;
+        self.max_chunk_size = max;
+        self
+    }
+
+    /// Sets the number of chunks per thread
+    #[must_use]
+    #[inline]
+    pub const fn with_chunks_per_thread(mut self, chunks_per_thread: usize) -> Self {
+        self.chunks_per_thread = chunks_per_thread;
+        self
+    }
+
+    /// Batching strategy of the parallel queries
+    #[must_use]
+    pub fn batching_strategy(&self) -> BatchingStrategy {
+        if let Some(batch_size) = self.batch_size {
+            return BatchingStrategy::fixed(batch_size.max(1));
+        }
+        let min = self.min_chunk_size.max(1);
+        BatchingStrategy {
+            batch_size_limits: min..self.max_chunk_size.max(min),
+            batches_per_thread: self.chunks_per_thread.max(1),
+        }
+    }
+}
+
+impl Default for SimulationBatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SimulationState {
+    /// The opposite state
+    #[must_use]
+    #[inline]
+    pub const fn toggled(self) -> Self {
+        match self {
+            Self::Running => Self::Paused,
+            Self::Paused => Self::Running,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{
+        ConwayCellState, EpidemicParams, GameOfLife2dPlugin, MooreCell2d, Seir2dPlugin,
+        SeirCellState,
+    };
+    use bevy::{core::TaskPoolPlugin, prelude::*};
+
+    fn game_of_life(batch: Option<SimulationBatch>) -> Vec<bool> {
+        let mut app = App::new();
+        if let Some(batch) = batch {
+            app.add_plugins(TaskPoolPlugin::default())
+                .insert_resource(batch);
+        }
+        app.add_plugins(GameOfLife2dPlugin::new());
+        let cells: Vec<_> = (0..30)
+            .flat_map(|y| (0..30).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                let alive = (coords.x * 7 + coords.y * 13) % 5 < 2;
+                app.world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id()
+            })
+            .collect();
+        for _ in 0..10 {
+            app.update();
+        }
+        cells
+            .iter()
+            .map(|e| app.world.get::<ConwayCellState>(*e).unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn chunked_generations() {
+        let strategy = SimulationBatch::new()
+            .with_chunk_size_limits(64, 0)
+            .with_chunks_per_thread(0)
+            .batching_strategy();
+        assert_eq!(strategy.batch_size_limits, 64..64);
+        assert_eq!(strategy.batches_per_thread, 1);
+        let strategy = SimulationBatch::new()
+            .with_batch_size(Some(0))
+            .batching_strategy();
+        assert_eq!(strategy.batch_size_limits, 1..1);
+        let sequential = game_of_life(None);
+        for batch in [
+            SimulationBatch::default(),
+            SimulationBatch::new().with_batch_size(Some(7)),
+            SimulationBatch::new()
+                .with_chunk_size_limits(1, 50)
+                .with_chunks_per_thread(16),
+        ] {
+            assert_eq!(game_of_life(Some(batch)), sequential);
+        }
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new().with_batch_size(Some(32)));
+        assert_eq!(app.world.resource::<SimulationBatch>().batch_size, Some(32));
+    }
+
+    #[test]
+    fn paused_simulation() {
+        let mut app = App::new();
+        app.add_plugins(Seir2dPlugin::new())
+            .insert_resource(EpidemicParams::new(1.0, 1, 10));
+        app.world
+            .spawn((MooreCell2d::new(IVec2::ZERO), SeirCellState::Infectious(0)));
+        let cell = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::X), SeirCellState::Susceptible))
+            .id();
+        app.world
+            .resource_mut::<NextState<SimulationState>>()
+            .set(SimulationState::Paused);
+        app.update();
+        assert_eq!(
+            *app.world.resource::<State<SimulationState>>(),
+            SimulationState::Paused
+        );
+        assert_eq!(
+            app.world.get::<SeirCellState>(cell),
+            Some(&SeirCellState::Susceptible)
+        );
+        // The default key resumes the simulation
+        let mut keys = ButtonInput::<KeyCode>::default();
+        keys.press(KeyCode::Space);
+        app.insert_resource(keys);
+        app.update();
+        assert_eq!(
+            *app.world.resource::<State<SimulationState>>(),
+            SimulationState::Running
+        );
+        assert_eq!(
+            app.world.get::<SeirCellState>(cell),
+            Some(&SeirCellState::Exposed(0))
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::systems::history::HistoryRewinds;
+use bevy::{
+    ecs::system::Command,
+    prelude::{Entity, Resource, World},
+};
+use std::collections::VecDeque;
+
+/// Resource storing the `S` cell states of the last generations, to restore
+/// them with the [`Rewind`] command.
+///
+/// Used by the [`SimulationHistoryPlugin`], which records a full snapshot of
+/// the states after the rules of every tick, and before the first one. The
+/// oldest snapshots are dropped beyond `capacity` generations.
+///
+/// [`SimulationHistoryPlugin`]: crate::SimulationHistoryPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct SimulationHistory<S> {
+    /// Recorded generations and cell states, from the oldest to the newest
+    snapshots: VecDeque<(u64, Vec<(Entity, S)>)>,
+    /// Maximum amount of recorded generations
+    pub capacity: usize,
+}
+
+impl<S> SimulationHistory<S> {
+    /// Instantiates an empty history storing up to `capacity` generations
+    #[must_use]
+    #[inline]
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            snapshots: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Amount of recorded generations
+    #[must_use]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Is there no recorded generation ?
+    #[must_use]
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Iterates over the recorded generations, from the oldest to the newest
+    pub fn generations(&self) -> impl Iterator<Item = u64> + '_ {
+        self.snapshots.iter().map(|(generation, _)| *generation)
+    }
+
+    /// Retrieves the recorded cell states of `generation`, if any
+    #[must_use]
+    pub fn get(&self, generation: u64) -> Option<&[(Entity, S)]> {
+        self.snapshots
+            .iter()
+            .find(|(g, _)| *g == generation)
+            .map(|(_, states)| states.as_slice())
+    }
+
+    /// Records the cell `states` of `generation`, unless already recorded as
+    /// the newest generation
+    pub fn record(&mut self, generation: u64, states: impl Iterator<Item = (Entity, S)>) {
+        if self.snapshots.back().is_some_and(|(g, _)| *g == generation) {
+            return;
+        }
+        self.snapshots.push_back((generation, states.collect()));
+        while self.snapshots.len() > self.capacity {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Removes the generations recorded after `generation` and retrieves the
+    /// newest remaining one, which is the oldest recorded generation if
+    /// `generation` was dropped.
+    pub fn rewind(&mut self, generation: u64) -> Option<(u64, &[(Entity, S)])> {
+        let kept = self
+            .snapshots
+            .partition_point(|(g, _)| *g <= generation)
+            .max(1);
+        self.snapshots.truncate(kept);
+        self.snapshots
+            .back()
+            .map(|(generation, states)| (*generation, states.as_slice()))
+    }
+}
+
+impl<S> Default for SimulationHistory<S> {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+/// Command restoring the cell states and the [`Generation`] of `n`
+/// generations ago from every [`SimulationHistory`], forgetting the newer
+/// generations.
+///
+/// Only the states of the existing cells are restored, and the oldest
+/// recorded generation is restored if `n` goes past it. The simulation then
+/// branches from the restored generation.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// fn rewind(mut commands: Commands, keys: Res<ButtonInput<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::Backspace) {
+///         commands.add(Rewind(50));
+///     }
+/// }
+/// ```
+///
+/// [`Generation`]: crate::Generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rewind(pub u64);
+
+impl Command for Rewind {
+    fn apply(self, world: &mut World) {
+        let Some(rewinds) = world.get_resource::<HistoryRewinds>() else {
+            return;
+        };
+        for rewind in rewinds.0.clone() {
+            rewind(world, self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Generation;
+
+    #[test]
+    fn bounded_history() {
+        let mut history = SimulationHistory::new(3);
+        let entity = Entity::from_raw(0);
+        for generation in 0..5 {
+            history.record(generation, std::iter::once((entity, generation)));
+        }
+        history.record(4, std::iter::once((entity, 10)));
+        assert_eq!(history.generations().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(history.get(4), Some([(entity, 4)].as_slice()));
+        assert_eq!(history.rewind(3), Some((3, [(entity, 3)].as_slice())));
+        assert_eq!(history.len(), 2);
+        // Too far back
+        assert_eq!(history.rewind(0), Some((2, [(entity, 2)].as_slice())));
+        assert_eq!(history.len(), 1);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn rewound_blinker() {
+        use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d, SimulationHistoryPlugin};
+        use bevy::prelude::*;
+
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            SimulationHistoryPlugin::<ConwayCellState>::new(),
+        ))
+        .insert_resource(SimulationHistory::<ConwayCellState>::new(10));
+        let cells: Vec<_> = (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| IVec2::new(x, y)))
+            .map(|pos| {
+                let state = ConwayCellState(pos.y == 0);
+                app.world.spawn((MooreCell2d::new(pos), state)).id()
+            })
+            .collect();
+        let states = |app: &App| -> Vec<bool> {
+            cells
+                .iter()
+                .map(|e| app.world.get::<ConwayCellState>(*e).unwrap().0)
+                .collect()
+        };
+        let horizontal = states(&app);
+        app.update();
+        let vertical = states(&app);
+        assert_ne!(horizontal, vertical);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Generation>().0, 4);
+        Rewind(3).apply(&mut app.world);
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        assert_eq!(states(&app), vertical);
+        Rewind(5).apply(&mut app.world);
+        assert_eq!(app.world.resource::<Generation>().0, 0);
+        assert_eq!(states(&app), horizontal);
+        // The simulation branches from the restored generation
+        app.update();
+        assert_eq!(states(&app), vertical);
+        let history = app.world.resource::<SimulationHistory<ConwayCellState>>();
+        assert_eq!(history.generations().collect::<Vec<_>>(), [0, 1]);
+    }
+}
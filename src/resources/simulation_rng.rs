@@ -0,0 +1,257 @@
+use bevy::prelude::Resource;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use std::{
+    cell::RefCell,
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+thread_local! {
+    /// Generator of the [`SimulationRng`] installed on the current thread
+    static SCOPED_RNG: RefCell<Option<ChaCha8Rng>> = const { RefCell::new(None) };
+}
+
+/// Resource holding the seeded random number generator of the simulation, so
+/// two runs with the same seed produce identical dynamics.
+///
+/// While the built-in systems run, the generator is installed on their thread
+/// and every stochastic rule draws from it through [`CellRng`]. Without the
+/// resource, the rules draw from [`rand::thread_rng`].
+///
+/// The cell rules draw from counter-based [`CellStreams`] instead, keyed by
+/// the cell coordinates and a key drawn from the generator every generation,
+/// so the dynamics don't depend on the update order of the cells and rules
+/// applied in parallel with a [`SimulationBatch`] are reproducible too.
+///
+/// [`SimulationBatch`]: crate::SimulationBatch
+#[derive(Debug, Clone, Resource)]
+pub struct SimulationRng {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+/// Random number generator of the cell rules, drawing from the
+/// [`SimulationRng`] installed on the current thread, if any, and from
+/// [`rand::thread_rng`] otherwise.
+///
+/// Custom stochastic states should use it instead of [`rand::thread_rng`] to
+/// be reproducible.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CellRng;
+
+/// Per-cell random number streams of a generation, drawn from a
+/// [`SimulationRng`].
+///
+/// Every cell draws from its own `ChaCha8` stream, selected by the hash of its
+/// coordinates, of a generator keyed for the generation. The streams may be
+/// installed from any thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellStreams {
+    key: u64,
+}
+
+/// Installation of a [`SimulationRng`] or of a [`CellStreams`] stream on the
+/// current thread, which is uninstalled when dropped
+#[derive(Debug)]
+pub struct SimulationRngScope<'a> {
+    rng: Option<&'a mut SimulationRng>,
+    previous: Option<ChaCha8Rng>,
+    installed: bool,
+}
+
+impl Default for SimulationRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl SimulationRng {
+    /// Instantiates a generator with a `seed`
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Retrieves the seed of the generator
+    #[must_use]
+    #[inline]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Installs the generator of `rng`, if any, as the [`CellRng`] of the
+    /// current thread until the returned scope is dropped
+    #[must_use]
+    pub fn scope(rng: Option<&mut Self>) -> SimulationRngScope<'_> {
+        let installed = rng.is_some();
+        let previous = rng.as_ref().and_then(|rng| {
+            let generator = rng.rng.clone();
+            SCOPED_RNG.with(|scoped| scoped.replace(Some(generator)))
+        });
+        SimulationRngScope {
+            rng,
+            previous,
+            installed,
+        }
+    }
+
+    /// Draws the key of the per-cell streams of a generation
+    #[must_use]
+    pub fn cell_streams(&mut self) -> CellStreams {
+        CellStreams {
+            key: self.rng.next_u64(),
+        }
+    }
+}
+
+impl CellStreams {
+    /// Installs the stream of the cell at `coords` as the [`CellRng`] of the
+    /// current thread until the returned scope is dropped
+    #[must_use]
+    pub fn scope(&self, coords: &impl Hash) -> SimulationRngScope<'static> {
+        let mut hasher = DefaultHasher::new();
+        coords.hash(&mut hasher);
+        let mut generator = ChaCha8Rng::seed_from_u64(self.key);
+        generator.set_stream(hasher.finish());
+        let previous = SCOPED_RNG.with(|scoped| scoped.replace(Some(generator)));
+        SimulationRngScope {
+            rng: None,
+            previous,
+            installed: true,
+        }
+    }
+}
+
+impl Drop for SimulationRngScope<'_> {
+    fn drop(&mut self) {
+        if !self.installed {
+            return;
+        }
+        let generator = SCOPED_RNG.with(|scoped| scoped.replace(self.previous.take()));
+        if let Some((rng, generator)) = self.rng.as_deref_mut().zip(generator) {
+            rng.rng = generator;
+        }
+    }
+}
+
+impl RngCore for SimulationRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+impl CellRng {
+    fn with<R>(f: impl FnOnce(&mut dyn RngCore) -> R) -> R {
+        SCOPED_RNG.with(|scoped| match scoped.borrow_mut().as_mut() {
+            Some(rng) => f(rng),
+            None => f(&mut rand::thread_rng()),
+        })
+    }
+}
+
+impl RngCore for CellRng {
+    fn next_u32(&mut self) -> u32 {
+        Self::with(|rng| rng.next_u32())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        Self::with(|rng| rng.next_u64())
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        Self::with(|rng| rng.fill_bytes(dest));
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        Self::with(|rng| rng.try_fill_bytes(dest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "2D")]
+    use crate::{EpidemicParams, MooreCell2d, Seir2dPlugin, SeirCellState, SimulationBatch};
+    use bevy::prelude::*;
+    use rand::Rng;
+
+    fn draws(rng: &mut SimulationRng) -> Vec<u32> {
+        let _scope = SimulationRng::scope(Some(rng));
+        (0..10).map(|_| CellRng.gen()).collect()
+    }
+
+    #[test]
+    fn seeded_draws() {
+        let mut rng = SimulationRng::new(42);
+        let first = draws(&mut rng);
+        // The generator advanced
+        assert_ne!(draws(&mut rng), first);
+        assert_eq!(draws(&mut SimulationRng::new(42)), first);
+        assert_ne!(draws(&mut SimulationRng::new(43)), first);
+        // Every cell has its own stream
+        let streams = SimulationRng::new(42).cell_streams();
+        let cell_draws = |coords: IVec2| {
+            let _scope = streams.scope(&coords);
+            CellRng.gen::<u64>()
+        };
+        assert_eq!(cell_draws(IVec2::ZERO), cell_draws(IVec2::ZERO));
+        assert_ne!(cell_draws(IVec2::ZERO), cell_draws(IVec2::X));
+        // Nothing is installed outside of the scope
+        assert!(SCOPED_RNG.with(|scoped| scoped.borrow().is_none()));
+    }
+
+    #[cfg(feature = "2D")]
+    fn epidemic(seed: u64, batch: bool) -> Vec<SeirCellState> {
+        let mut app = App::new();
+        if batch {
+            app.add_plugins(TaskPoolPlugin::default())
+                .insert_resource(SimulationBatch::default());
+        }
+        app.add_plugins(Seir2dPlugin::new())
+            .insert_resource(EpidemicParams::new(0.3, 2, 5))
+            .insert_resource(SimulationRng::new(seed));
+        for x in 0..10 {
+            for y in 0..10 {
+                let state = if x == 5 && y == 5 {
+                    SeirCellState::Infectious(0)
+                } else {
+                    SeirCellState::Susceptible
+                };
+                app.world.spawn((MooreCell2d::new(IVec2::new(x, y)), state));
+            }
+        }
+        for _ in 0..10 {
+            app.update();
+        }
+        let mut states = app.world.query::<(&MooreCell2d, &SeirCellState)>();
+        let mut states: Vec<_> = states.iter(&app.world).collect();
+        states.sort_by_key(|(cell, _)| (cell.coords.x, cell.coords.y));
+        states.into_iter().map(|(_, state)| *state).collect()
+    }
+
+    #[test]
+    #[cfg(feature = "2D")]
+    fn reproducible_epidemic() {
+        let states = epidemic(7, false);
+        assert!(states.iter().any(|s| *s != SeirCellState::Susceptible));
+        assert_eq!(epidemic(7, false), states);
+        // The cell streams don't depend on the update order
+        assert_eq!(epidemic(7, true), states);
+    }
+}
@@ -0,0 +1,93 @@
+use bevy::prelude::Resource;
+use std::time::Duration;
+
+/// Resource controlling the speed of the simulation, which may be changed at
+/// runtime.
+///
+/// Without the resource, or with [`SimulationSpeed::per_frame`], the
+/// simulation advances one tick per frame. With an amount of ticks per second,
+/// the ticks follow the elapsed time instead: a frame may run no tick or
+/// several ticks, up to `max_ticks_per_frame`, the late ticks being dropped so
+/// slow frames don't pile up.
+#[derive(Debug, Clone, Copy, PartialEq, Resource)]
+pub struct SimulationSpeed {
+    /// Amount of ticks per second. If not set, the simulation advances one
+    /// tick per frame.
+    pub ticks_per_second: Option<f64>,
+    /// Maximum amount of ticks per frame
+    pub max_ticks_per_frame: u32,
+    /// Fraction of a tick elapsed since the last tick
+    elapsed: f64,
+}
+
+impl SimulationSpeed {
+    /// Default maximum amount of ticks per frame
+    pub const DEFAULT_MAX_TICKS_PER_FRAME: u32 = 10;
+
+    /// Advances `ticks_per_second` ticks every second
+    #[must_use]
+    #[inline]
+    pub const fn new(ticks_per_second: f64) -> Self {
+        Self {
+            ticks_per_second: Some(ticks_per_second),
+            max_ticks_per_frame: Self::DEFAULT_MAX_TICKS_PER_FRAME,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Advances one tick every frame
+    #[must_use]
+    #[inline]
+    pub const fn per_frame() -> Self {
+        Self {
+            ticks_per_second: None,
+            max_ticks_per_frame: Self::DEFAULT_MAX_TICKS_PER_FRAME,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Sets the maximum amount of ticks per frame
+    #[must_use]
+    #[inline]
+    pub const fn with_max_ticks_per_frame(mut self, max_ticks_per_frame: u32) -> Self {
+        self.max_ticks_per_frame = max_ticks_per_frame;
+        self
+    }
+
+    /// Computes the amount of ticks of a frame lasting `delta`
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn advance(&mut self, delta: Duration) -> u32 {
+        let Some(ticks_per_second) = self.ticks_per_second else {
+            return 1;
+        };
+        self.elapsed += ticks_per_second.max(0.0) * delta.as_secs_f64();
+        let ticks = self.elapsed.floor();
+        self.elapsed -= ticks;
+        (ticks.min(f64::from(u32::MAX)) as u32).min(self.max_ticks_per_frame)
+    }
+}
+
+impl Default for SimulationSpeed {
+    fn default() -> Self {
+        Self::per_frame()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ticks_per_frame() {
+        let mut speed = SimulationSpeed::per_frame();
+        assert_eq!(speed.advance(Duration::ZERO), 1);
+        let mut speed = SimulationSpeed::new(10.0).with_max_ticks_per_frame(3);
+        let frame = Duration::from_millis(50);
+        let ticks: Vec<_> = (0..4).map(|_| speed.advance(frame)).collect();
+        assert_eq!(ticks, [0, 1, 0, 1]);
+        assert_eq!(speed.advance(Duration::from_millis(250)), 2);
+        // The late ticks are dropped
+        assert_eq!(speed.advance(Duration::from_secs(1)), 3);
+        assert_eq!(speed.advance(frame), 1);
+    }
+}
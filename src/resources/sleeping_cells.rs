@@ -0,0 +1,157 @@
+use crate::components::Cell;
+use bevy::{prelude::Resource, utils::HashSet};
+use std::marker::PhantomData;
+
+/// Resource putting to sleep the cells of type `C` with `S` states whose
+/// neighborhood is unchanged.
+///
+/// After every generation, the cells whose state changed and their neighbors
+/// are awake, the other cells are asleep and skip their rules until a
+/// neighbor wakes them. Unlike the coarser [`DirtyChunks`], it works for any
+/// coordinates, like graph nodes, at the cost of tracking every awake cell.
+///
+/// The results match a run without sleeping for deterministic rules with
+/// symmetric neighborhoods. Stochastic rules may change a quiet cell, like an
+/// infected cell recovering: the states matching the `keep_awake` predicate
+/// keep themselves and their neighbors awake. Despawned cells are not
+/// tracked, their neighbors may be woken manually with
+/// [`SleepingCells::wake`]. It applies to the rules of the
+/// [`CellularAutomatonPlugin`] and of its weighted and parametric variants.
+///
+/// ```rust,no_run
+/// # use bevy_life::*;
+/// let sleeping = SleepingCells::<MooreCell2d, SeirCellState>::new()
+///     .keep_awake(|state| state.is_infected());
+/// ```
+///
+/// [`DirtyChunks`]: crate::DirtyChunks
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct SleepingCells<C: Cell, S> {
+    /// The cells with these states, and their neighbors, are always awake
+    pub keep_awake: Option<fn(&S) -> bool>,
+    /// Cells to update on the next tick
+    awake: HashSet<C::Coordinates>,
+    /// Is every cell awake ?
+    all: bool,
+    phantom_s: PhantomData<S>,
+}
+
+impl<C: Cell, S> SleepingCells<C, S> {
+    /// Instantiates Self, every cell being awake until the first generation
+    /// is tracked
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            keep_awake: None,
+            awake: HashSet::default(),
+            all: true,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Keeps the cells matching the `predicate`, and their neighbors, awake
+    #[must_use]
+    #[inline]
+    pub fn keep_awake(mut self, predicate: fn(&S) -> bool) -> Self {
+        self.keep_awake = Some(predicate);
+        self
+    }
+
+    /// Is the cell at `coords` awake ?
+    #[must_use]
+    #[inline]
+    pub fn is_awake(&self, coords: &C::Coordinates) -> bool {
+        self.all || self.awake.contains(coords)
+    }
+
+    /// Wakes the cell at `coords`
+    #[inline]
+    pub fn wake(&mut self, coords: C::Coordinates) {
+        self.awake.insert(coords);
+    }
+
+    /// Puts every cell to sleep
+    #[inline]
+    pub fn clear(&mut self) {
+        self.awake.clear();
+        self.all = false;
+    }
+}
+
+impl<C: Cell, S> Default for SleepingCells<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    fn run(sleeping: Option<SleepingCells<MooreCell2d, ConwayCellState>>) -> (Vec<IVec2>, bool) {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new());
+        if let Some(sleeping) = sleeping {
+            app.insert_resource(sleeping);
+        }
+        let cells: Vec<_> = (0..12)
+            .flat_map(|y| (0..12).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                // A blinker
+                let alive = coords.y == 2 && (1..4).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect();
+        app.update();
+        // A lonely cell revived in a quiet neighborhood, without change
+        // detection
+        let lonely = cells
+            .iter()
+            .find(|(c, _)| *c == IVec2::new(8, 8))
+            .unwrap()
+            .1;
+        app.world
+            .entity_mut(lonely)
+            .get_mut::<ConwayCellState>()
+            .unwrap()
+            .bypass_change_detection()
+            .0 = true;
+        for _ in 0..3 {
+            app.update();
+        }
+        let alive = cells
+            .iter()
+            .filter(|(c, e)| {
+                *c != IVec2::new(8, 8) && app.world.get::<ConwayCellState>(*e).unwrap().0
+            })
+            .map(|(c, _)| *c)
+            .collect();
+        (alive, app.world.get::<ConwayCellState>(lonely).unwrap().0)
+    }
+
+    #[test]
+    fn sleeping_cells() {
+        let (awake, lonely_awake) = run(None);
+        let (asleep, lonely_asleep) = run(Some(SleepingCells::new()));
+        let (restless, lonely_restless) =
+            run(Some(SleepingCells::new().keep_awake(|state| state.0)));
+        assert_eq!(
+            awake,
+            vec![IVec2::new(1, 2), IVec2::new(2, 2), IVec2::new(3, 2)]
+        );
+        assert_eq!(asleep, awake);
+        assert_eq!(restless, awake);
+        assert!(!lonely_awake);
+        // The lonely cell slept through its death
+        assert!(lonely_asleep);
+        assert!(!lonely_restless);
+    }
+}
@@ -0,0 +1,195 @@
+use bevy::prelude::Resource;
+use std::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Resource storing a hash of every `S` cell state, computed after every tick
+/// by the [`StateHashPlugin`].
+///
+/// The hash doesn't depend on the entities nor on their iteration order, and
+/// is computed with [`StableHasher`], so it is stable across runs and
+/// platforms. Comparing the hashes of two runs checks their determinism, and
+/// golden hashes may be used in regression tests.
+///
+/// [`StateHashPlugin`]: crate::StateHashPlugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Resource)]
+pub struct StateHash<S> {
+    /// Generation of the hashed states
+    pub generation: u64,
+    /// Hash of the cell states
+    pub hash: u64,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S> Default for StateHash<S> {
+    fn default() -> Self {
+        Self {
+            generation: 0,
+            hash: StableHasher::new().finish(),
+            phantom_s: PhantomData,
+        }
+    }
+}
+
+impl<S: Hash> StateHash<S> {
+    /// Computes the hash of the `cells` coordinates and states, which are
+    /// sorted by coordinates hash first
+    pub fn compute<'a, K>(cells: impl Iterator<Item = (&'a K, &'a S)>) -> u64
+    where
+        K: Hash + 'a,
+        S: 'a,
+    {
+        let mut hashes: Vec<(u64, u64)> = cells
+            .map(|(coords, state)| (StableHasher::hash(coords), StableHasher::hash(state)))
+            .collect();
+        hashes.sort_unstable();
+        StableHasher::hash(&hashes)
+    }
+}
+
+/// [FNV-1a] hasher, writing the integers in little endian so the hashes
+/// don't depend on the platform, unlike the default hasher whose algorithm
+/// may change.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    /// Instantiates a new hasher
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    /// Computes the hash of `value`
+    #[must_use]
+    pub fn hash(value: &(impl Hash + ?Sized)) -> u64 {
+        let mut hasher = Self::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stable_hashes() {
+        let mut hasher = StableHasher::new();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+        let cells = [(1_i32, 'a'), (2, 'b'), (3, 'c')];
+        let hash = StateHash::compute(cells.iter().map(|(k, s)| (k, s)));
+        // The iteration order doesn't matter
+        let hash_rev = StateHash::compute(cells.iter().rev().map(|(k, s)| (k, s)));
+        assert_eq!(hash, hash_rev);
+        let other = StateHash::compute(cells[..2].iter().map(|(k, s)| (k, s)));
+        assert_ne!(hash, other);
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn deterministic_runs() {
+        use crate::{
+            EpidemicParams, Generation, MooreCell2d, Seir2dPlugin, SeirCellState, SimulationRng,
+            StateHashPlugin,
+        };
+        use bevy::prelude::*;
+
+        let run = |seed| {
+            let mut app = App::new();
+            app.add_plugins((
+                Seir2dPlugin::new(),
+                StateHashPlugin::<MooreCell2d, SeirCellState>::new(),
+            ))
+            .insert_resource(EpidemicParams::new(0.5, 1, 3))
+            .insert_resource(SimulationRng::new(seed));
+            for x in 0..10 {
+                for y in 0..10 {
+                    let state = if x == 5 && y == 5 {
+                        SeirCellState::Infectious(0)
+                    } else {
+                        SeirCellState::Susceptible
+                    };
+                    app.world.spawn((MooreCell2d::new(IVec2::new(x, y)), state));
+                }
+            }
+            let mut hashes = vec![];
+            for _ in 0..8 {
+                app.update();
+                let hash = app.world.resource::<StateHash<SeirCellState>>();
+                assert_eq!(hash.generation, app.world.resource::<Generation>().0);
+                hashes.push(hash.hash);
+            }
+            hashes
+        };
+        assert_eq!(run(1), run(1));
+        assert_ne!(run(1), run(2));
+    }
+}
@@ -0,0 +1,224 @@
+use crate::events::FinishReason;
+use bevy::prelude::Resource;
+
+/// Resource defining when the simulation of `S` cell states is finished, for
+/// the [`TerminationPlugin`].
+///
+/// After every tick, the conditions are checked in order: the maximum
+/// generation, the extinction of the states matching a predicate, then the
+/// steady state. When one is met, a [`SimulationFinishedEvent`] is sent, the
+/// simulation is paused and, if `exit` is set, the app exits, which ends
+/// headless runs.
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let termination = Termination::<SeirCellState>::new()
+///     .until_extinct(|state| state.is_infected())
+///     .until_generation(1_000)
+///     .exit_on_finish();
+/// ```
+///
+/// [`TerminationPlugin`]: crate::TerminationPlugin
+/// [`SimulationFinishedEvent`]: crate::SimulationFinishedEvent
+#[derive(Debug, Clone, Resource)]
+pub struct Termination<S> {
+    /// The simulation finishes when no state matches this predicate
+    pub extinct: Option<fn(&S) -> bool>,
+    /// The simulation finishes when no state changed during this amount of
+    /// ticks
+    pub steady_ticks: Option<u32>,
+    /// The simulation finishes at this generation
+    pub max_generation: Option<u64>,
+    /// Should the app exit when the simulation finishes ?
+    pub exit: bool,
+    /// Amount of ticks without any state change
+    unchanged_ticks: u32,
+    /// Met condition, if finished
+    reason: Option<FinishReason>,
+}
+
+impl<S> Termination<S> {
+    /// Instantiates Self without condition
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            extinct: None,
+            steady_ticks: None,
+            max_generation: None,
+            exit: false,
+            unchanged_ticks: 0,
+            reason: None,
+        }
+    }
+
+    /// Finishes the simulation when no state matches the `predicate`, like
+    /// when no infected cell is left
+    #[must_use]
+    #[inline]
+    pub const fn until_extinct(mut self, predicate: fn(&S) -> bool) -> Self {
+        self.extinct = Some(predicate);
+        self
+    }
+
+    /// Finishes the simulation when no state changed during `ticks` ticks
+    #[must_use]
+    #[inline]
+    pub const fn until_steady(mut self, ticks: u32) -> Self {
+        self.steady_ticks = Some(ticks);
+        self
+    }
+
+    /// Finishes the simulation at `generation`
+    #[must_use]
+    #[inline]
+    pub const fn until_generation(mut self, generation: u64) -> Self {
+        self.max_generation = Some(generation);
+        self
+    }
+
+    /// Exits the app when the simulation finishes
+    #[must_use]
+    #[inline]
+    pub const fn exit_on_finish(mut self) -> Self {
+        self.exit = true;
+        self
+    }
+
+    /// Met condition, if the simulation is finished
+    #[must_use]
+    #[inline]
+    pub const fn reason(&self) -> Option<FinishReason> {
+        self.reason
+    }
+
+    /// Is the simulation finished ?
+    #[must_use]
+    #[inline]
+    pub const fn is_finished(&self) -> bool {
+        self.reason.is_some()
+    }
+
+    /// Checks the conditions after the tick reaching `generation`, with
+    /// whether a state `changed` during the tick and the current `states`.
+    /// Returns the met condition, if the simulation just finished.
+    pub fn check<'a>(
+        &mut self,
+        generation: u64,
+        changed: bool,
+        mut states: impl Iterator<Item = &'a S>,
+    ) -> Option<FinishReason>
+    where
+        S: 'a,
+    {
+        if self.is_finished() {
+            return None;
+        }
+        self.unchanged_ticks = if changed { 0 } else { self.unchanged_ticks + 1 };
+        let reason = if self.max_generation.is_some_and(|g| generation >= g) {
+            FinishReason::MaxGeneration
+        } else if self.extinct.is_some_and(|predicate| !states.any(predicate)) {
+            FinishReason::Extinction
+        } else if self
+            .steady_ticks
+            .is_some_and(|ticks| self.unchanged_ticks >= ticks)
+        {
+            FinishReason::SteadyState
+        } else {
+            return None;
+        };
+        self.reason = Some(reason);
+        Some(reason)
+    }
+}
+
+impl<S> Default for Termination<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_conditions() {
+        let mut termination = Termination::<u8>::new()
+            .until_extinct(|s| *s > 0)
+            .until_steady(2)
+            .until_generation(10);
+        assert_eq!(termination.check(1, true, [0, 1].iter()), None);
+        assert_eq!(termination.check(2, false, [0, 1].iter()), None);
+        assert_eq!(
+            termination.check(3, false, [0, 1].iter()),
+            Some(FinishReason::SteadyState)
+        );
+        assert!(termination.is_finished());
+        assert_eq!(termination.check(10, true, std::iter::once(&0)), None);
+        let mut termination = termination.clone();
+        termination.reason = None;
+        assert_eq!(
+            termination.check(4, true, [0, 0].iter()),
+            Some(FinishReason::Extinction)
+        );
+        let mut termination = Termination::<u8>::new().until_generation(10);
+        assert_eq!(
+            termination.check(10, true, std::iter::empty()),
+            Some(FinishReason::MaxGeneration)
+        );
+    }
+
+    #[cfg(feature = "2D")]
+    #[test]
+    fn finished_simulation() {
+        use crate::{
+            ConwayCellState, GameOfLife2dPlugin, MooreCell2d, SimulationFinishedEvent,
+            SimulationState, TerminationPlugin,
+        };
+        use bevy::{app::AppExit, prelude::*};
+
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            TerminationPlugin::<ConwayCellState>::new(),
+        ))
+        .insert_resource(
+            Termination::<ConwayCellState>::new()
+                .until_steady(2)
+                .exit_on_finish(),
+        );
+        // A still block
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+            app.world
+                .spawn((MooreCell2d::new(IVec2::new(x, y)), ConwayCellState(true)));
+        }
+        for _ in 0..2 {
+            app.update();
+            assert!(app
+                .world
+                .resource::<Events<SimulationFinishedEvent>>()
+                .is_empty());
+        }
+        app.update();
+        let events: Vec<_> = app
+            .world
+            .resource_mut::<Events<SimulationFinishedEvent>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            events,
+            [SimulationFinishedEvent {
+                generation: 3,
+                reason: FinishReason::SteadyState
+            }]
+        );
+        assert!(!app.world.resource::<Events<AppExit>>().is_empty());
+        app.update();
+        assert_eq!(
+            *app.world.resource::<State<SimulationState>>(),
+            SimulationState::Paused
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::Resource;
+
+/// Resource spreading the computation of every generation across several
+/// ticks, computing the new states of at most the given number of cells per
+/// tick.
+///
+/// The new states are staged from a snapshot of the states the generation
+/// started from, and applied together once every cell is computed, so the
+/// results match an unbudgeted run. Until then the [`Generation`] is not
+/// incremented and the [`SimulationSet::PreTick`] and
+/// [`SimulationSet::PostTick`] systems do not run. It keeps the frame times of
+/// giant interactive maps smooth, as a tick runs once per frame by default. It
+/// applies to the rules of the [`CellularAutomatonPlugin`] and of its
+/// weighted, parametric and coupled variants, computed sequentially.
+///
+/// [`Generation`]: crate::Generation
+/// [`SimulationSet::PreTick`]: crate::SimulationSet::PreTick
+/// [`SimulationSet::PostTick`]: crate::SimulationSet::PostTick
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Resource)]
+pub struct TickBudget(pub usize);
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, Generation, MooreCell2d};
+    use bevy::prelude::*;
+
+    fn blinker(app: &mut App) -> Vec<(IVec2, Entity)> {
+        (0..5)
+            .flat_map(|y| (0..5).map(move |x| IVec2::new(x, y)))
+            .map(|coords| {
+                let alive = coords.y == 2 && (1..4).contains(&coords.x);
+                let entity = app
+                    .world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(alive)))
+                    .id();
+                (coords, entity)
+            })
+            .collect()
+    }
+
+    fn alive(app: &App, cells: &[(IVec2, Entity)]) -> Vec<IVec2> {
+        cells
+            .iter()
+            .filter(|(_, e)| app.world.get::<ConwayCellState>(*e).unwrap().0)
+            .map(|(coords, _)| *coords)
+            .collect()
+    }
+
+    #[test]
+    fn budgeted_generations() {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new())
+            .insert_resource(TickBudget(10));
+        let cells = blinker(&mut app);
+        let horizontal = alive(&app, &cells);
+        let vertical = vec![IVec2::new(2, 1), IVec2::new(2, 2), IVec2::new(2, 3)];
+        // 25 cells, computed over 3 ticks
+        for _ in 0..2 {
+            app.update();
+            assert_eq!(app.world.resource::<Generation>().0, 0);
+            assert_eq!(alive(&app, &cells), horizontal);
+        }
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        assert_eq!(alive(&app, &cells), vertical);
+        for _ in 0..3 {
+            app.update();
+        }
+        assert_eq!(app.world.resource::<Generation>().0, 2);
+        assert_eq!(alive(&app, &cells), horizontal);
+        // The staged generation completes once the budget is removed
+        app.update();
+        app.world.remove_resource::<TickBudget>();
+        app.update();
+        assert_eq!(app.world.resource::<Generation>().0, 3);
+        assert_eq!(alive(&app, &cells), vertical);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::components::CellState;
+use bevy::prelude::Resource;
+use std::fmt::{self, Debug, Formatter};
+
+/// Aggregation of every cell state into the values of a time series row
+type Aggregation<S> = Box<dyn Fn(&[&S]) -> Vec<f64> + Send + Sync>;
+
+/// Export of the recorded time series
+type Exporter<S> = Box<dyn Fn(&TimeSeriesRecorder<S>) + Send + Sync>;
+
+/// Resource recording a time series of aggregates of the `S` cell states, like
+/// the amount of cells in every compartment of an epidemic.
+///
+/// Used by the [`TimeSeriesPlugin`], which appends a row every tick by
+/// applying the aggregation closure to every cell state. The table may be read
+/// by other systems at any time, and is given to the exporter closure, if any,
+/// when the app exits.
+///
+/// [`TimeSeriesPlugin`]: crate::TimeSeriesPlugin
+#[derive(Resource)]
+pub struct TimeSeriesRecorder<S: CellState> {
+    columns: Vec<String>,
+    ticks: Vec<u64>,
+    rows: Vec<Vec<f64>>,
+    tick: u64,
+    aggregation: Aggregation<S>,
+    exporter: Option<Exporter<S>>,
+}
+
+impl<S: CellState> TimeSeriesRecorder<S> {
+    /// Instantiates an empty recorder of the named `columns`, whose values are
+    /// computed by the `aggregation` closure from every cell state. Missing
+    /// values are filled with `NaN` and extra values are ignored.
+    #[must_use]
+    pub fn new(
+        columns: impl IntoIterator<Item = impl Into<String>>,
+        aggregation: impl Fn(&[&S]) -> Vec<f64> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            columns: columns.into_iter().map(Into::into).collect(),
+            ticks: Vec::new(),
+            rows: Vec::new(),
+            tick: 0,
+            aggregation: Box::new(aggregation),
+            exporter: None,
+        }
+    }
+
+    /// Sets the `exporter` closure, called with the recorded time series when
+    /// the app exits
+    #[must_use]
+    pub fn with_exporter(mut self, exporter: impl Fn(&Self) + Send + Sync + 'static) -> Self {
+        self.exporter = Some(Box::new(exporter));
+        self
+    }
+
+    /// Appends the aggregates of the `states` as the row of the next tick
+    pub fn record(&mut self, states: &[&S]) {
+        let mut values = (self.aggregation)(states);
+        values.resize(self.columns.len(), f64::NAN);
+        self.ticks.push(self.tick);
+        self.tick += 1;
+        self.rows.push(values);
+    }
+
+    /// Calls the exporter closure, if any, with the recorded time series
+    pub fn export(&self) {
+        if let Some(exporter) = &self.exporter {
+            exporter(self);
+        }
+    }
+
+    /// Retrieves the column names
+    #[must_use]
+    #[inline]
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Amount of recorded rows
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Is the time series empty ?
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Iterates over the recorded rows, with their tick
+    pub fn rows(&self) -> impl Iterator<Item = (u64, &[f64])> {
+        self.ticks
+            .iter()
+            .copied()
+            .zip(self.rows.iter().map(Vec::as_slice))
+    }
+
+    /// Retrieves the last recorded row, with its tick
+    #[must_use]
+    pub fn last(&self) -> Option<(u64, &[f64])> {
+        self.rows().last()
+    }
+
+    /// Retrieves the values of the `name` column, if it exists
+    #[must_use]
+    pub fn column(&self, name: &str) -> Option<Vec<f64>> {
+        let index = self.columns.iter().position(|c| c == name)?;
+        Some(self.rows.iter().map(|row| row[index]).collect())
+    }
+
+    /// Removes every recorded row. The ticks keep counting.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.ticks.clear();
+        self.rows.clear();
+    }
+}
+
+impl<S: CellState> Debug for TimeSeriesRecorder<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeSeriesRecorder")
+            .field("columns", &self.columns)
+            .field("ticks", &self.ticks)
+            .field("rows", &self.rows)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d, TimeSeriesPlugin};
+    use bevy::{app::AppExit, prelude::*};
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn recorded_blinker() {
+        let exported = Arc::new(Mutex::new(Vec::new()));
+        let output = Arc::clone(&exported);
+        let recorder =
+            TimeSeriesRecorder::<ConwayCellState>::new(["alive", "dead", "missing"], |states| {
+                let alive = states.iter().filter(|s| s.0).count();
+                vec![alive as f64, (states.len() - alive) as f64]
+            })
+            .with_exporter(move |recorder| {
+                *output.lock().unwrap() = recorder.column("alive").unwrap();
+            });
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            TimeSeriesPlugin::<ConwayCellState>::new(),
+        ))
+        .insert_resource(recorder);
+        for x in -2..=2 {
+            for y in -2..=2 {
+                let alive = y == 0 && x != 2 && x != -2;
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, y)), ConwayCellState(alive)));
+            }
+        }
+        for _ in 0..3 {
+            app.update();
+        }
+        let recorder = app.world.resource::<TimeSeriesRecorder<ConwayCellState>>();
+        assert_eq!(recorder.len(), 3);
+        let (tick, row) = recorder.last().unwrap();
+        assert_eq!(tick, 2);
+        assert_eq!(row[..2], [3.0, 22.0]);
+        // Missing values are filled
+        assert!(row[2].is_nan());
+        assert!(recorder.column("unknown").is_none());
+        assert!(exported.lock().unwrap().is_empty());
+        app.world.send_event(AppExit);
+        app.update();
+        assert_eq!(*exported.lock().unwrap(), vec![3.0; 4]);
+    }
+}
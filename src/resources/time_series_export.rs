@@ -0,0 +1,390 @@
+use crate::components::{Cell, CellCoordinates, CellState};
+use crate::resources::TimeSeriesRecorder;
+use bevy::prelude::Resource;
+use std::{
+    fmt::{self, Debug, Formatter},
+    fs, io,
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+/// Values of every cell state exported in the snapshots
+type Snapshot<S> = Box<dyn Fn(&S) -> Vec<f64> + Send + Sync>;
+
+/// File format of the [`TimeSeriesExport`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// Comma separated values, with a header row
+    #[cfg(feature = "csv")]
+    Csv,
+    /// Apache Parquet columnar format
+    #[cfg(feature = "parquet")]
+    Parquet,
+}
+
+impl ExportFormat {
+    /// File extension of the format
+    #[must_use]
+    #[inline]
+    pub const fn extension(self) -> &'static str {
+        match self {
+            #[cfg(feature = "csv")]
+            Self::Csv => "csv",
+            #[cfg(feature = "parquet")]
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Column of an exported table
+enum Column {
+    Int(Vec<i64>),
+    Float(Vec<f64>),
+}
+
+#[cfg(feature = "csv")]
+impl Column {
+    const fn len(&self) -> usize {
+        match self {
+            Self::Int(values) => values.len(),
+            Self::Float(values) => values.len(),
+        }
+    }
+}
+
+/// Resource exporting the [`TimeSeriesRecorder`] of the `S` cell states to
+/// files, with optional snapshots of the `C` cells.
+///
+/// Used by the [`TimeSeriesExportPlugin`], which writes the files every
+/// `interval` recorded ticks, if any, and when the app exits. In the `directory`:
+///
+/// - `time_series.<ext>` holds the whole time series, with a `tick` column
+///   followed by the recorder columns.
+/// - `cells_<tick>.<ext>` holds the snapshot of every cell at a tick, with the
+///   cell coordinates in the `x`, `y` and `z` columns followed by the snapshot
+///   columns.
+///
+/// [`TimeSeriesExportPlugin`]: crate::TimeSeriesExportPlugin
+#[derive(Resource)]
+pub struct TimeSeriesExport<C, S> {
+    /// Directory of the exported files, created if missing
+    pub directory: PathBuf,
+    /// Format of the exported files
+    pub format: ExportFormat,
+    /// Amount of recorded ticks between exports. If not set, the files are
+    /// only written when the app exits.
+    pub interval: Option<u64>,
+    snapshot: Option<(Vec<String>, Snapshot<S>)>,
+    phantom_c: PhantomData<C>,
+}
+
+impl<C: Cell, S: CellState> TimeSeriesExport<C, S> {
+    /// Instantiates an export to `directory` in the given `format`, when the
+    /// app exits and without cell snapshots
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>, format: ExportFormat) -> Self {
+        Self {
+            directory: directory.into(),
+            format,
+            interval: None,
+            snapshot: None,
+            phantom_c: PhantomData,
+        }
+    }
+
+    /// Sets the `interval` between exports, in recorded ticks
+    #[must_use]
+    #[inline]
+    pub const fn every(mut self, interval: u64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Enables the cell snapshots, whose named `columns` are computed by the
+    /// `snapshot` closure from every cell state. Missing values are filled
+    /// with `NaN` and extra values are ignored.
+    #[must_use]
+    pub fn with_snapshots(
+        mut self,
+        columns: impl IntoIterator<Item = impl Into<String>>,
+        snapshot: impl Fn(&S) -> Vec<f64> + Send + Sync + 'static,
+    ) -> Self {
+        let columns = columns.into_iter().map(Into::into).collect();
+        self.snapshot = Some((columns, Box::new(snapshot)));
+        self
+    }
+
+    /// Is an export due after the recorded `tick` ?
+    #[must_use]
+    #[inline]
+    pub fn is_due(&self, tick: u64) -> bool {
+        self.interval
+            .is_some_and(|interval| interval > 0 && (tick + 1).is_multiple_of(interval))
+    }
+
+    /// Writes the whole `recorder` time series, returning the file path
+    ///
+    /// # Errors
+    ///
+    /// Fails if the directory can't be created or the file can't be written
+    pub fn write_time_series(&self, recorder: &TimeSeriesRecorder<S>) -> io::Result<PathBuf> {
+        let mut table = vec![(
+            "tick".to_owned(),
+            Column::Int(
+                recorder
+                    .rows()
+                    .map(|(tick, _)| i64::try_from(tick).unwrap_or(i64::MAX))
+                    .collect(),
+            ),
+        )];
+        for (i, name) in recorder.columns().iter().enumerate() {
+            let values = recorder.rows().map(|(_, row)| row[i]).collect();
+            table.push((name.clone(), Column::Float(values)));
+        }
+        self.write_table("time_series", &table)
+    }
+
+    /// Writes the snapshot of the `cells` at `tick`, returning the file path.
+    /// Nothing is written if the snapshots aren't enabled.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the directory can't be created or the file can't be written
+    pub fn write_snapshot<'a>(
+        &self,
+        tick: u64,
+        cells: impl IntoIterator<Item = (&'a C, &'a S)>,
+    ) -> io::Result<Option<PathBuf>>
+    where
+        C: 'a,
+        S: 'a,
+    {
+        let Some((columns, snapshot)) = &self.snapshot else {
+            return Ok(None);
+        };
+        let mut axes: Vec<Vec<i64>> = Vec::new();
+        let mut values: Vec<Vec<f64>> = vec![Vec::new(); columns.len()];
+        for (row, (cell, state)) in cells.into_iter().enumerate() {
+            for (axis, value) in cell.coords().axes().into_iter().enumerate() {
+                if axes.len() <= axis {
+                    axes.push(vec![0; row]);
+                }
+                axes[axis].push(value);
+            }
+            let mut state_values = snapshot(state);
+            state_values.resize(columns.len(), f64::NAN);
+            for (column, value) in values.iter_mut().zip(state_values) {
+                column.push(value);
+            }
+        }
+        let table: Vec<_> = ["x", "y", "z"]
+            .into_iter()
+            .map(str::to_owned)
+            .zip(axes.into_iter().map(Column::Int))
+            .chain(
+                columns
+                    .iter()
+                    .cloned()
+                    .zip(values.into_iter().map(Column::Float)),
+            )
+            .collect();
+        self.write_table(&format!("cells_{tick}"), &table).map(Some)
+    }
+
+    fn write_table(&self, name: &str, table: &[(String, Column)]) -> io::Result<PathBuf> {
+        fs::create_dir_all(&self.directory)?;
+        let path = self
+            .directory
+            .join(name)
+            .with_extension(self.format.extension());
+        match self.format {
+            #[cfg(feature = "csv")]
+            ExportFormat::Csv => write_csv(&path, table)?,
+            #[cfg(feature = "parquet")]
+            ExportFormat::Parquet => write_parquet(&path, table)?,
+        }
+        Ok(path)
+    }
+}
+
+impl<C, S> Debug for TimeSeriesExport<C, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeSeriesExport")
+            .field("directory", &self.directory)
+            .field("format", &self.format)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "csv")]
+fn write_csv(path: &Path, table: &[(String, Column)]) -> io::Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(table.iter().map(|(name, _)| name))?;
+    let rows = table.first().map_or(0, |(_, column)| column.len());
+    for row in 0..rows {
+        writer.write_record(table.iter().map(|(_, column)| match column {
+            Column::Int(values) => values[row].to_string(),
+            Column::Float(values) => values[row].to_string(),
+        }))?;
+    }
+    writer.flush()
+}
+
+#[cfg(feature = "parquet")]
+fn write_parquet(path: &Path, table: &[(String, Column)]) -> io::Result<()> {
+    use parquet::{
+        basic::{Repetition, Type as PhysicalType},
+        data_type::{DoubleType, Int64Type},
+        file::{properties::WriterProperties, writer::SerializedFileWriter},
+        schema::types::Type,
+    };
+    use std::sync::Arc;
+
+    let fields = table
+        .iter()
+        .map(|(name, column)| {
+            let physical_type = match column {
+                Column::Int(_) => PhysicalType::INT64,
+                Column::Float(_) => PhysicalType::DOUBLE,
+            };
+            Type::primitive_type_builder(name, physical_type)
+                .with_repetition(Repetition::REQUIRED)
+                .build()
+                .map(Arc::new)
+        })
+        .collect::<Result<_, _>>()
+        .map_err(io::Error::other)?;
+    let schema = Type::group_type_builder("schema")
+        .with_fields(fields)
+        .build()
+        .map_err(io::Error::other)?;
+    let file = fs::File::create(path)?;
+    let properties = Arc::new(WriterProperties::builder().build());
+    let write = || -> parquet::errors::Result<()> {
+        let mut writer = SerializedFileWriter::new(file, Arc::new(schema), properties)?;
+        let mut row_group = writer.next_row_group()?;
+        for (_, column) in table {
+            let Some(mut writer) = row_group.next_column()? else {
+                break;
+            };
+            match column {
+                Column::Int(values) => {
+                    writer
+                        .typed::<Int64Type>()
+                        .write_batch(values, None, None)?;
+                }
+                Column::Float(values) => {
+                    writer
+                        .typed::<DoubleType>()
+                        .write_batch(values, None, None)?;
+                }
+            }
+            writer.close()?;
+        }
+        row_group.close()?;
+        writer.close()?;
+        Ok(())
+    };
+    write().map_err(io::Error::other)
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d, TimeSeriesExportPlugin};
+    use crate::{TimeSeriesPlugin, TimeSeriesRecorder};
+    use bevy::prelude::*;
+
+    fn blinker_app(format: ExportFormat, directory: &Path) -> App {
+        let recorder = TimeSeriesRecorder::<ConwayCellState>::new(["alive"], |states| {
+            vec![states.iter().filter(|s| s.0).fold(0.0, |n, _| n + 1.0)]
+        });
+        let export = TimeSeriesExport::<MooreCell2d, ConwayCellState>::new(directory, format)
+            .every(2)
+            .with_snapshots(["alive"], |state| vec![f64::from(u8::from(state.0))]);
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            TimeSeriesPlugin::<ConwayCellState>::new(),
+            TimeSeriesExportPlugin::<MooreCell2d, ConwayCellState>::new(),
+        ))
+        .insert_resource(recorder)
+        .insert_resource(export);
+        for x in -1..=1 {
+            for y in -1..=1 {
+                app.world
+                    .spawn((MooreCell2d::new(IVec2::new(x, y)), ConwayCellState(y == 0)));
+            }
+        }
+        app
+    }
+
+    fn export_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("bevy_life_{name}"));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn csv_export() {
+        use bevy::app::AppExit;
+
+        let directory = export_directory("csv_export");
+        let mut app = blinker_app(ExportFormat::Csv, &directory);
+        app.update();
+        assert!(!directory.exists());
+        app.update();
+        // Exported after the second tick
+        assert_eq!(
+            fs::read_to_string(directory.join("time_series.csv")).unwrap(),
+            "tick,alive\n0,3\n1,3\n"
+        );
+        let snapshot = fs::read_to_string(directory.join("cells_1.csv")).unwrap();
+        let mut lines: Vec<_> = snapshot.lines().collect();
+        assert_eq!(lines.remove(0), "x,y,alive");
+        lines.sort_unstable();
+        assert_eq!(
+            lines,
+            [
+                "-1,-1,0", "-1,0,1", "-1,1,0", "0,-1,0", "0,0,1", "0,1,0", "1,-1,0", "1,0,1",
+                "1,1,0"
+            ]
+        );
+        // And on exit
+        app.update();
+        app.world.send_event(AppExit);
+        app.update();
+        assert!(directory.join("cells_3.csv").exists());
+        let time_series = fs::read_to_string(directory.join("time_series.csv")).unwrap();
+        assert_eq!(time_series.lines().count(), 5);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn parquet_export() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let directory = export_directory("parquet_export");
+        let mut app = blinker_app(ExportFormat::Parquet, &directory);
+        app.update();
+        app.update();
+        let file = fs::File::open(directory.join("time_series.parquet")).unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        let metadata = reader.metadata().file_metadata();
+        assert_eq!(metadata.num_rows(), 2);
+        let columns: Vec<_> = metadata
+            .schema_descr()
+            .columns()
+            .iter()
+            .map(|c| c.name().to_owned())
+            .collect();
+        assert_eq!(columns, ["tick", "alive"]);
+        let file = fs::File::open(directory.join("cells_1.parquet")).unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 9);
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{components::CellState, resources::TimeSeriesRecorder};
+use bevy::prelude::Resource;
+use std::marker::PhantomData;
+
+/// Resource setting the overlay plotting the [`TimeSeriesRecorder`] of the `S`
+/// cell states, like the epidemic curves of every compartment.
+///
+/// Inserted by the [`TimeSeriesPlotPlugin`], it may be edited at runtime to
+/// hide the overlay or to change the plotted columns.
+///
+/// [`TimeSeriesPlotPlugin`]: crate::TimeSeriesPlotPlugin
+#[derive(Debug, Clone, PartialEq, Eq, Resource)]
+pub struct TimeSeriesPlot<S> {
+    /// Title of the overlay window
+    pub title: String,
+    /// Plotted columns of the recorder. If empty, every column is plotted.
+    pub columns: Vec<String>,
+    /// Is the overlay shown ?
+    pub visible: bool,
+    /// Phantom data for the `S` (`CellState`) type
+    pub phantom_s: PhantomData<S>,
+}
+
+impl<S: CellState> TimeSeriesPlot<S> {
+    /// Instantiates a visible overlay titled `title`, plotting every column
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            columns: Vec::new(),
+            visible: true,
+            phantom_s: PhantomData,
+        }
+    }
+
+    /// Only plots the given `columns`
+    #[must_use]
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Retrieves the plotted lines of the `recorder`, as the column name and
+    /// its `[tick, value]` points
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn lines(&self, recorder: &TimeSeriesRecorder<S>) -> Vec<(String, Vec<[f64; 2]>)> {
+        recorder
+            .columns()
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| self.columns.is_empty() || self.columns.contains(name))
+            .map(|(i, name)| {
+                let points = recorder
+                    .rows()
+                    .map(|(tick, row)| [tick as f64, row[i]])
+                    .collect();
+                (name.clone(), points)
+            })
+            .collect()
+    }
+}
+
+impl<S: CellState> Default for TimeSeriesPlot<S> {
+    fn default() -> Self {
+        Self::new("Time series")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SeirCellState;
+
+    #[test]
+    fn plotted_lines() {
+        let mut recorder =
+            TimeSeriesRecorder::<SeirCellState>::new(["S", "E", "I", "R"], |states| {
+                ["S", "E", "I", "R"]
+                    .iter()
+                    .map(|c| {
+                        states
+                            .iter()
+                            .filter(|s| format!("{s:?}").starts_with(&c[..1]))
+                            .fold(0.0, |n, _| n + 1.0)
+                    })
+                    .collect()
+            });
+        recorder.record(&[&SeirCellState::Susceptible, &SeirCellState::Infectious(0)]);
+        recorder.record(&[&SeirCellState::Infectious(0), &SeirCellState::Infectious(1)]);
+        let plot = TimeSeriesPlot::new("Epidemic").with_columns(["I", "S"]);
+        assert_eq!(
+            plot.lines(&recorder),
+            vec![
+                ("S".to_owned(), vec![[0.0, 1.0], [1.0, 0.0]]),
+                ("I".to_owned(), vec![[0.0, 1.0], [1.0, 2.0]]),
+            ]
+        );
+        assert_eq!(TimeSeriesPlot::default().lines(&recorder).len(), 4);
+    }
+}
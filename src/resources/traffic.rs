@@ -0,0 +1,100 @@
+use bevy::prelude::{Reflect, Resource};
+
+/// Global parameters of the [`TrafficState`] rules, may be modified at
+/// runtime to tweak the simulation.
+///
+/// [`TrafficState`]: crate::TrafficState
+#[derive(Debug, Copy, Clone, PartialEq, Resource, Reflect)]
+pub struct TrafficParams {
+    /// Maximum velocity of the cars, in cells per generation
+    pub max_velocity: u8,
+    /// Probability of a moving car randomly slowing down every generation
+    pub slowdown_probability: f64,
+}
+
+impl Default for TrafficParams {
+    fn default() -> Self {
+        Self::new(5, 0.3)
+    }
+}
+
+impl TrafficParams {
+    /// Instantiates new parameters with `max_velocity` and
+    /// `slowdown_probability`
+    #[must_use]
+    #[inline]
+    pub const fn new(max_velocity: u8, slowdown_probability: f64) -> Self {
+        Self {
+            max_velocity,
+            slowdown_probability,
+        }
+    }
+}
+
+/// Traffic measurements of the last generation, updated by the
+/// [`TrafficPlugin`] for fundamental diagram experiments.
+///
+/// [`TrafficPlugin`]: crate::TrafficPlugin
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Resource, Reflect)]
+pub struct TrafficStats {
+    /// Amount of road cells
+    pub cells: usize,
+    /// Amount of cars
+    pub cars: usize,
+    /// Sum of the car velocities
+    pub total_velocity: u32,
+}
+
+impl TrafficStats {
+    /// Ratio of road cells occupied by a car
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn density(&self) -> f32 {
+        if self.cells == 0 {
+            return 0.0;
+        }
+        self.cars as f32 / self.cells as f32
+    }
+
+    /// Average velocity of the cars
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean_velocity(&self) -> f32 {
+        if self.cars == 0 {
+            return 0.0;
+        }
+        self.total_velocity as f32 / self.cars as f32
+    }
+
+    /// Traffic flow: the average amount of cars passing a road cell per
+    /// generation, which is the density times the mean velocity
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn flow(&self) -> f32 {
+        if self.cells == 0 {
+            return 0.0;
+        }
+        self.total_velocity as f32 / self.cells as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measurements() {
+        let stats = TrafficStats {
+            cells: 10,
+            cars: 4,
+            total_velocity: 6,
+        };
+        assert!((stats.density() - 0.4).abs() < f32::EPSILON);
+        assert!((stats.mean_velocity() - 1.5).abs() < f32::EPSILON);
+        assert!((stats.flow() - 0.6).abs() < f32::EPSILON);
+        assert!(TrafficStats::default().flow().abs() < f32::EPSILON);
+    }
+}
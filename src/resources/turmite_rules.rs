@@ -0,0 +1,263 @@
+use crate::components::Turn;
+use bevy::prelude::{Reflect, Resource};
+use std::{fmt, str::FromStr};
+
+/// Transition of a [`TurmiteRules`] table entry
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Reflect)]
+pub struct TurmiteTransition {
+    /// Color written to the cell under the ant
+    pub write: u8,
+    /// Turn of the ant before moving forward
+    pub turn: Turn,
+    /// Next internal state of the ant
+    pub next_state: u8,
+}
+
+impl TurmiteTransition {
+    /// Instantiates a new transition
+    #[must_use]
+    #[inline]
+    pub const fn new(write: u8, turn: Turn, next_state: u8) -> Self {
+        Self {
+            write,
+            turn,
+            next_state,
+        }
+    }
+}
+
+/// Resource defining the [turmite] transition table followed by every `Ant`:
+/// for each ant internal state and cell color, the color to write, the turn
+/// and the next ant state.
+///
+/// The default rules are [Langton's ant]. Rules may be built from a turn
+/// string for multi-color ants, or parsed from the usual turmite notation
+/// where turns are `1` (none), `2` (right), `4` (u-turn) and `8` (left):
+///
+/// ```rust,no_run
+/// # use bevy_life::TurmiteRules;
+/// let langtons_ant: TurmiteRules = "{{{1, 2, 0}, {0, 8, 0}}}".parse().unwrap();
+/// assert_eq!(langtons_ant, TurmiteRules::from_turns("RL").unwrap());
+/// // Fibonacci spiral
+/// let fibonacci: TurmiteRules = "{{{1, 8, 1}, {1, 8, 1}}, {{1, 2, 1}, {0, 1, 0}}}"
+///     .parse()
+///     .unwrap();
+/// ```
+///
+/// Ants on a cell color or in a state missing from the table don't move.
+///
+/// [turmite]: https://en.wikipedia.org/wiki/Turmite
+/// [Langton's ant]: https://en.wikipedia.org/wiki/Langton%27s_ant
+#[derive(Debug, Clone, PartialEq, Eq, Resource, Reflect)]
+pub struct TurmiteRules {
+    colors: usize,
+    transitions: Vec<TurmiteTransition>,
+}
+
+impl Default for TurmiteRules {
+    fn default() -> Self {
+        Self::new(vec![vec![
+            TurmiteTransition::new(1, Turn::Right, 0),
+            TurmiteTransition::new(0, Turn::Left, 0),
+        ]])
+    }
+}
+
+impl TurmiteRules {
+    /// Instantiates new rules from the transitions of every ant state, each
+    /// state having a transition for every cell color.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the states don't have the same amount of colors
+    #[must_use]
+    pub fn new(states: Vec<Vec<TurmiteTransition>>) -> Self {
+        let colors = states.first().map_or(0, Vec::len);
+        assert!(
+            states.iter().all(|s| s.len() == colors),
+            "every turmite state must have a transition for every color"
+        );
+        Self {
+            colors,
+            transitions: states.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Instantiates the rules of a single state multi-color ant from its
+    /// `turns`, made of `L`, `R`, `N` (none) and `U` (u-turn) characters: on
+    /// a cell of color `i` the ant takes the `i`-th turn and writes the color
+    /// `i + 1`. `"RL"` is Langton's ant.
+    ///
+    /// Returns `None` if `turns` contains another character or more than 256
+    /// turns.
+    #[must_use]
+    pub fn from_turns(turns: &str) -> Option<Self> {
+        let count = turns.chars().count();
+        let transitions = turns
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                let turn = match c.to_ascii_uppercase() {
+                    'N' => Turn::None,
+                    'R' => Turn::Right,
+                    'U' => Turn::UTurn,
+                    'L' => Turn::Left,
+                    _ => return None,
+                };
+                let write = u8::try_from((i + 1) % count).ok()?;
+                Some(TurmiteTransition::new(write, turn, 0))
+            })
+            .collect::<Option<_>>()?;
+        Some(Self::new(vec![transitions]))
+    }
+
+    /// Retrieves the transition of an ant in `state` on a cell of `color`
+    #[must_use]
+    #[inline]
+    pub fn transition(&self, state: u8, color: u8) -> Option<TurmiteTransition> {
+        let color = usize::from(color);
+        if color >= self.colors {
+            return None;
+        }
+        self.transitions
+            .get(usize::from(state) * self.colors + color)
+            .copied()
+    }
+
+    /// Amount of ant states
+    #[must_use]
+    #[inline]
+    pub fn states(&self) -> usize {
+        self.transitions.len().checked_div(self.colors).unwrap_or(0)
+    }
+
+    /// Amount of cell colors
+    #[must_use]
+    #[inline]
+    pub const fn colors(&self) -> usize {
+        self.colors
+    }
+}
+
+/// Error returned when parsing invalid [`TurmiteRules`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TurmiteParseError {
+    /// The table isn't made of nested `{}` lists of `{write, turn, next}`
+    /// triplets with the same amount of colors per state
+    InvalidFormat,
+    /// A value isn't a valid color, turn or state
+    InvalidValue(String),
+}
+
+impl fmt::Display for TurmiteParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(
+                f,
+                "expected a `{{{{{{write, turn, next}}, ..}}, ..}}` table"
+            ),
+            Self::InvalidValue(v) => write!(f, "invalid turmite value `{v}`"),
+        }
+    }
+}
+
+impl std::error::Error for TurmiteParseError {}
+
+impl FromStr for TurmiteRules {
+    type Err = TurmiteParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let inner = s
+            .strip_prefix("{{{")
+            .and_then(|s| s.strip_suffix("}}}"))
+            .ok_or(TurmiteParseError::InvalidFormat)?;
+        let states = inner
+            .split("}},{{")
+            .map(|state| {
+                state
+                    .split("},{")
+                    .map(parse_transition)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let colors = states[0].len();
+        if states.iter().any(|s| s.len() != colors) {
+            return Err(TurmiteParseError::InvalidFormat);
+        }
+        Ok(Self::new(states))
+    }
+}
+
+fn parse_transition(s: &str) -> Result<TurmiteTransition, TurmiteParseError> {
+    let values: Vec<_> = s.split(',').collect();
+    let [write, turn, next_state] = values.as_slice() else {
+        return Err(TurmiteParseError::InvalidFormat);
+    };
+    let parse = |v: &str| {
+        v.parse::<u8>()
+            .map_err(|_| TurmiteParseError::InvalidValue(v.to_string()))
+    };
+    let turn = match parse(turn)? {
+        1 => Turn::None,
+        2 => Turn::Right,
+        4 => Turn::UTurn,
+        8 => Turn::Left,
+        _ => return Err(TurmiteParseError::InvalidValue((*turn).to_string())),
+    };
+    Ok(TurmiteTransition::new(
+        parse(write)?,
+        turn,
+        parse(next_state)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let langtons_ant: TurmiteRules = "{{{1, 2, 0}, {0, 8, 0}}}".parse().unwrap();
+        assert_eq!(langtons_ant, TurmiteRules::default());
+        assert_eq!(Some(langtons_ant), TurmiteRules::from_turns("RL"));
+        let fibonacci: TurmiteRules = "{{{1, 8, 1}, {1, 8, 1}}, {{1, 2, 1}, {0, 1, 0}}}"
+            .parse()
+            .unwrap();
+        assert_eq!(fibonacci.states(), 2);
+        assert_eq!(fibonacci.colors(), 2);
+        assert_eq!(
+            fibonacci.transition(1, 1),
+            Some(TurmiteTransition::new(0, Turn::None, 0))
+        );
+        assert_eq!(fibonacci.transition(2, 0), None);
+        assert_eq!(fibonacci.transition(0, 2), None);
+        assert_eq!(
+            "{{{1, 3, 0}}}".parse::<TurmiteRules>(),
+            Err(TurmiteParseError::InvalidValue("3".to_string()))
+        );
+        for invalid in [
+            "",
+            "{{1, 2, 0}}",
+            "{{{1, 2}}}",
+            "{{{1, 2, 0}}, {{1, 2, 0}, {0, 8, 0}}}",
+        ] {
+            assert_eq!(
+                invalid.parse::<TurmiteRules>(),
+                Err(TurmiteParseError::InvalidFormat),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn turns() {
+        let rules = TurmiteRules::from_turns("LLRR").unwrap();
+        assert_eq!(rules.colors(), 4);
+        assert_eq!(
+            rules.transition(0, 3),
+            Some(TurmiteTransition::new(0, Turn::Right, 0))
+        );
+        assert_eq!(TurmiteRules::from_turns("RX"), None);
+    }
+}
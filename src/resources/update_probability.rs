@@ -0,0 +1,54 @@
+use bevy::prelude::Resource;
+
+/// Resource making every cell apply its rules with a probability per tick
+/// instead of every tick, keeping its state otherwise.
+///
+/// It models heterogeneous or asynchronous update rates and lightens the
+/// ticks of giant grids. The draws follow the [`SimulationRng`], if any, so
+/// seeded runs stay deterministic. It applies to the rules of the
+/// [`CellularAutomatonPlugin`] and of its weighted, parametric, traced and
+/// coupled variants.
+///
+/// [`SimulationRng`]: crate::SimulationRng
+/// [`CellularAutomatonPlugin`]: crate::CellularAutomatonPlugin
+#[derive(Debug, Copy, Clone, PartialEq, Resource)]
+pub struct UpdateProbability(pub f64);
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{ConwayCellState, GameOfLife2dPlugin, MooreCell2d, SimulationRng};
+    use bevy::prelude::*;
+
+    fn alive_cells(probability: f64, seed: u64) -> Vec<bool> {
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new())
+            .insert_resource(UpdateProbability(probability))
+            .insert_resource(SimulationRng::new(seed));
+        // Lonely cells, which die when updated
+        let cells: Vec<_> = (0..100)
+            .map(|x| {
+                let coords = IVec2::new(x * 2, 0);
+                app.world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(true)))
+                    .id()
+            })
+            .collect();
+        app.update();
+        cells
+            .iter()
+            .map(|e| app.world.get::<ConwayCellState>(*e).unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn partial_updates() {
+        assert!(alive_cells(0.0, 0).iter().all(|alive| *alive));
+        assert!(alive_cells(1.0, 0).iter().all(|alive| !*alive));
+        let alive = alive_cells(0.5, 0);
+        let count = alive.iter().filter(|alive| **alive).count();
+        assert!((20..80).contains(&count), "{count} cells kept their state");
+        assert_eq!(alive_cells(0.5, 0), alive);
+        assert_ne!(alive_cells(0.5, 1), alive);
+    }
+}
@@ -0,0 +1,60 @@
+use crate::components::Cell;
+use bevy::prelude::Resource;
+
+/// Cells targeted by a [`Vaccination`] campaign
+#[derive(Debug, Clone)]
+pub enum VaccinationTarget<C: Cell> {
+    /// Every cell of the map
+    Everywhere,
+    /// The cells at the given coordinates
+    Region(Vec<C::Coordinates>),
+    /// The neighbors of the infected cells, containing the outbreaks
+    Ring,
+}
+
+/// Vaccination campaign resource for the cells of type `C`.
+///
+/// Every tick of the [`VaccinationPlugin`], a `coverage` fraction of the
+/// susceptible individuals of the `target` cells are vaccinated. The campaign stops when the resource is removed.
+///
+/// [`VaccinationPlugin`]: crate::VaccinationPlugin
+#[derive(Debug, Clone, Resource)]
+pub struct Vaccination<C: Cell> {
+    /// Fraction of the susceptible individuals vaccinated every tick
+    pub coverage: f64,
+    /// Cells targeted by the campaign
+    pub target: VaccinationTarget<C>,
+}
+
+impl<C: Cell> Vaccination<C> {
+    /// Vaccinates a `coverage` fraction of every cell every tick
+    #[must_use]
+    #[inline]
+    pub const fn everywhere(coverage: f64) -> Self {
+        Self {
+            coverage,
+            target: VaccinationTarget::Everywhere,
+        }
+    }
+
+    /// Vaccinates a `coverage` fraction of the cells at `coords` every tick
+    #[must_use]
+    #[inline]
+    pub const fn region(coords: Vec<C::Coordinates>, coverage: f64) -> Self {
+        Self {
+            coverage,
+            target: VaccinationTarget::Region(coords),
+        }
+    }
+
+    /// Vaccinates a `coverage` fraction of the neighbors of infected cells
+    /// every tick
+    #[must_use]
+    #[inline]
+    pub const fn ring(coverage: f64) -> Self {
+        Self {
+            coverage,
+            target: VaccinationTarget::Ring,
+        }
+    }
+}
@@ -0,0 +1,343 @@
+use crate::{
+    components::{Cell, InfectableCellState},
+    resources::{CellRng, MapSize, SimulationRng},
+};
+use bevy::{
+    prelude::{IVec2, Resource, World},
+    utils::HashSet,
+};
+use rand::{seq::IteratorRandom, Rng};
+use serde::{Deserialize, Serialize};
+use std::{fmt, io, path::Path};
+
+/// Simulation scenario, defining a bounded 2D map of epidemic cells and the
+/// `P` parameters resource of their rules, like [`EpidemicParams`].
+///
+/// Scenarios may be loaded from RON files with the `ron` feature or from TOML
+/// files with the `toml` feature, so they can be edited and rerun without
+/// recompiling. Missing parameters keep their default values:
+///
+/// ```ron
+/// (
+///     map: (
+///         width: 50,
+///         height: 50,
+///         density: 0.8,
+///         seeding: Random(3),
+///     ),
+///     params: (
+///         transmission_probability: 0.3,
+///         infectious_period: 10,
+///     ),
+/// )
+/// ```
+///
+/// ```rust,no_run
+/// # use bevy::prelude::*;
+/// # use bevy_life::*;
+/// let scenario = Scenario::<EpidemicParams>::load("assets/scenarios/seir.ron").unwrap();
+/// let mut app = App::new();
+/// app.add_plugins(Seir2dPlugin::new());
+/// scenario.spawn::<MooreCell2d, SeirCellState>(&mut app.world);
+/// ```
+///
+/// [`EpidemicParams`]: crate::EpidemicParams
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Scenario<P> {
+    /// Map of the cells
+    pub map: ScenarioMap,
+    /// Parameters of the cell rules
+    #[serde(default)]
+    pub params: P,
+}
+
+/// Bounded 2D map of a [`Scenario`], whose cell coordinates go from `(0, 0)`
+/// to `(width - 1, height - 1)`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioMap {
+    /// Map width
+    pub width: u32,
+    /// Map height
+    pub height: u32,
+    /// Probability for every coordinate to hold a cell, `1.0` by default
+    #[serde(default = "ScenarioMap::default_density")]
+    pub density: f64,
+    /// Initially infected cells, none by default
+    #[serde(default)]
+    pub seeding: Seeding,
+    /// Cases imported in every seeded cell, `1` by default
+    #[serde(default = "ScenarioMap::default_cases")]
+    pub cases: u32,
+}
+
+/// Initial infections of a [`ScenarioMap`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum Seeding {
+    /// No infected cell
+    #[default]
+    None,
+    /// Amount of random cells to infect
+    Random(u32),
+    /// Probability for every cell to be infected
+    Fraction(f64),
+    /// Coordinates of the cells to infect, which are always spawned
+    Cells(Vec<[i32; 2]>),
+}
+
+/// Error returned when loading an invalid [`Scenario`]
+#[derive(Debug)]
+pub enum ScenarioError {
+    /// The scenario file can't be read
+    Io(io::Error),
+    /// The file extension isn't a supported format
+    UnsupportedFormat(String),
+    /// The RON scenario is invalid
+    #[cfg(feature = "ron")]
+    Ron(ron::error::SpannedError),
+    /// The TOML scenario is invalid
+    #[cfg(feature = "toml")]
+    Toml(toml::de::Error),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read the scenario: {error}"),
+            Self::UnsupportedFormat(extension) => {
+                write!(f, "unsupported scenario format `{extension}`")
+            }
+            #[cfg(feature = "ron")]
+            Self::Ron(error) => write!(f, "invalid RON scenario: {error}"),
+            #[cfg(feature = "toml")]
+            Self::Toml(error) => write!(f, "invalid TOML scenario: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::UnsupportedFormat(_) => None,
+            #[cfg(feature = "ron")]
+            Self::Ron(error) => Some(error),
+            #[cfg(feature = "toml")]
+            Self::Toml(error) => Some(error),
+        }
+    }
+}
+
+impl ScenarioMap {
+    const fn default_density() -> f64 {
+        1.0
+    }
+
+    const fn default_cases() -> u32 {
+        1
+    }
+
+    /// Instantiates a full map of `width` and `height` without infection
+    #[must_use]
+    #[inline]
+    pub const fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            density: Self::default_density(),
+            seeding: Seeding::None,
+            cases: Self::default_cases(),
+        }
+    }
+
+    /// Size of the map
+    #[must_use]
+    #[inline]
+    pub const fn size(&self) -> MapSize {
+        MapSize::new(self.width, self.height)
+    }
+
+    /// Draws the coordinates of the cells of the map, with whether they are
+    /// seeded
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn cells(&self, rng: &mut impl Rng) -> Vec<(IVec2, bool)> {
+        let forced: HashSet<IVec2> = match &self.seeding {
+            Seeding::Cells(cells) => cells.iter().map(|c| IVec2::from_array(*c)).collect(),
+            _ => HashSet::default(),
+        };
+        let mut cells: Vec<(IVec2, bool)> = (0..self.height as i32)
+            .flat_map(|y| (0..self.width as i32).map(move |x| IVec2::new(x, y)))
+            .filter_map(|coords| {
+                if forced.contains(&coords) {
+                    Some((coords, true))
+                } else {
+                    (rng.gen::<f64>() < self.density).then_some((coords, false))
+                }
+            })
+            .collect();
+        match self.seeding {
+            Seeding::None | Seeding::Cells(_) => (),
+            Seeding::Random(count) => {
+                for (_, seeded) in cells.iter_mut().choose_multiple(rng, count as usize) {
+                    *seeded = true;
+                }
+            }
+            Seeding::Fraction(probability) => {
+                for (_, seeded) in &mut cells {
+                    *seeded = rng.gen::<f64>() < probability;
+                }
+            }
+        }
+        cells
+    }
+}
+
+impl<P> Scenario<P> {
+    /// Parses a RON scenario
+    ///
+    /// # Errors
+    ///
+    /// Fails if the scenario is invalid
+    #[cfg(feature = "ron")]
+    pub fn from_ron(s: &str) -> Result<Self, ScenarioError>
+    where
+        P: for<'de> Deserialize<'de> + Default,
+    {
+        ron::from_str(s).map_err(ScenarioError::Ron)
+    }
+
+    /// Parses a TOML scenario
+    ///
+    /// # Errors
+    ///
+    /// Fails if the scenario is invalid
+    #[cfg(feature = "toml")]
+    pub fn from_toml(s: &str) -> Result<Self, ScenarioError>
+    where
+        P: for<'de> Deserialize<'de> + Default,
+    {
+        toml::from_str(s).map_err(ScenarioError::Toml)
+    }
+
+    /// Loads the scenario file at `path`, whose format follows its `ron` or
+    /// `toml` extension
+    ///
+    /// # Errors
+    ///
+    /// Fails if the file can't be read, if its format isn't enabled or if the
+    /// scenario is invalid
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError>
+    where
+        P: for<'de> Deserialize<'de> + Default,
+    {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default();
+        match extension {
+            #[cfg(feature = "ron")]
+            "ron" => Self::from_ron(&std::fs::read_to_string(path).map_err(ScenarioError::Io)?),
+            #[cfg(feature = "toml")]
+            "toml" => Self::from_toml(&std::fs::read_to_string(path).map_err(ScenarioError::Io)?),
+            _ => Err(ScenarioError::UnsupportedFormat(extension.to_owned())),
+        }
+    }
+
+    /// Inserts the [`MapSize`] and the parameters resources in the `world`,
+    /// and spawns the `C` cells of the map with the default `S` state, seeded
+    /// cells importing the map `cases`. The cells are drawn from the
+    /// [`SimulationRng`] of the `world`, if any.
+    pub fn spawn<C, S>(&self, world: &mut World)
+    where
+        C: Cell + From<IVec2>,
+        S: InfectableCellState + Default,
+        P: Resource + Clone,
+    {
+        world.insert_resource(self.map.size());
+        world.insert_resource(self.params.clone());
+        let cells = {
+            let mut simulation_rng = world.get_resource_mut::<SimulationRng>();
+            let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+            self.map.cells(&mut CellRng)
+        };
+        world.spawn_batch(cells.into_iter().map(|(coords, seeded)| {
+            let state = if seeded {
+                S::default().import_infection(self.map.cases)
+            } else {
+                S::default()
+            };
+            (C::from(coords), state)
+        }));
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use super::*;
+    use crate::{EpidemicParams, MooreCell2d, SeirCellState};
+
+    #[test]
+    fn seeded_map() {
+        let mut map = ScenarioMap::new(10, 5);
+        map.seeding = Seeding::Random(3);
+        let cells = map.cells(&mut rand::thread_rng());
+        assert_eq!(cells.len(), 50);
+        assert_eq!(cells.iter().filter(|(_, seeded)| *seeded).count(), 3);
+        map.density = 0.0;
+        map.seeding = Seeding::Cells(vec![[1, 2], [3, 4]]);
+        let cells = map.cells(&mut rand::thread_rng());
+        assert_eq!(cells, [(IVec2::new(1, 2), true), (IVec2::new(3, 4), true)]);
+    }
+
+    #[test]
+    fn spawned_scenario() {
+        let scenario = Scenario {
+            map: ScenarioMap {
+                seeding: Seeding::Fraction(1.0),
+                ..ScenarioMap::new(4, 4)
+            },
+            params: EpidemicParams::new(0.5, 2, 4),
+        };
+        let mut world = World::new();
+        scenario.spawn::<MooreCell2d, SeirCellState>(&mut world);
+        assert_eq!(*world.resource::<MapSize>(), MapSize::new(4, 4));
+        assert_eq!(world.resource::<EpidemicParams>().infectious_period, 4);
+        let mut states = world.query::<&SeirCellState>();
+        assert_eq!(states.iter(&world).count(), 16);
+        assert!(states
+            .iter(&world)
+            .all(|s| *s == SeirCellState::Susceptible.import_infection(1)));
+    }
+
+    #[test]
+    #[cfg(feature = "ron")]
+    fn ron_scenario() {
+        let scenario = Scenario::<EpidemicParams>::load("assets/scenarios/seir.ron").unwrap();
+        assert_eq!(scenario.map.size(), MapSize::new(50, 50));
+        assert_eq!(scenario.map.seeding, Seeding::Random(3));
+        assert_eq!(scenario.params.infectious_period, 10);
+        // Missing parameters keep their default value
+        assert_eq!(
+            scenario.params.incubation_period,
+            EpidemicParams::default().incubation_period
+        );
+        let error = Scenario::<EpidemicParams>::from_ron("(params: ())").unwrap_err();
+        assert!(matches!(error, ScenarioError::Ron(_)));
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_scenario() {
+        let scenario = Scenario::<EpidemicParams>::load("assets/scenarios/seir.toml").unwrap();
+        assert_eq!(scenario.map.size(), MapSize::new(50, 50));
+        assert_eq!(scenario.map.cases, 1);
+        assert_eq!(
+            scenario.map.seeding,
+            Seeding::Cells(vec![[25, 25], [10, 40]])
+        );
+        assert_eq!(scenario.params.infectious_period, 10);
+        let error = Scenario::<EpidemicParams>::load("scenario.yaml").unwrap_err();
+        assert!(matches!(error, ScenarioError::UnsupportedFormat(e) if e == "yaml"));
+    }
+}
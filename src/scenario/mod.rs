@@ -0,0 +1,3 @@
+pub use loader::*;
+
+mod loader;
@@ -0,0 +1,50 @@
+use crate::{
+    components::{Ant, AntCellState, Cell, CellCoordinates},
+    resources::{BoundaryCondition, CellMap, MapSize, TurmiteRules},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Moves every [`Ant`] following the [`TurmiteRules`]: the ant writes a new
+/// color to the cell under it, turns, changes its internal state and moves
+/// forward.
+///
+/// Ants are moved one after the other, ants outside of the grid or without a
+/// matching transition don't move.
+#[allow(clippy::needless_pass_by_value)]
+pub fn move_ants<C>(
+    mut ants: Query<&mut Ant>,
+    mut states: Query<&mut AntCellState>,
+    map: Res<CellMap<C>>,
+    rules: Res<TurmiteRules>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+) where
+    C: Cell<Coordinates = IVec2>,
+{
+    if pause.is_some() {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    for mut ant in &mut ants {
+        let Some(mut state) = map
+            .get_cell(&ant.coords)
+            .and_then(|entity| states.get_mut(entity).ok())
+        else {
+            continue;
+        };
+        let Some(transition) = rules.transition(ant.state, state.0) else {
+            continue;
+        };
+        state.0 = transition.write;
+        ant.heading = ant.heading.turn(transition.turn);
+        ant.state = transition.next_state;
+        ant.step();
+        if let Some((condition, size)) = boundary {
+            if let Some(coords) = ant.coords.apply_boundary(condition, size) {
+                ant.coords = coords;
+            }
+        }
+    }
+}
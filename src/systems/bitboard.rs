@@ -0,0 +1,45 @@
+use crate::{
+    components::{Cell, ConwayCellState},
+    resources::LifeBitboard,
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Writes the cell states changed outside of the simulation, like spawned or
+/// edited cells, to the [`LifeBitboard`]
+#[allow(clippy::needless_pass_by_value)]
+pub fn load_bitboard_cells<C: Cell<Coordinates = IVec2>>(
+    cells: Query<(&C, &ConwayCellState), Changed<ConwayCellState>>,
+    mut bitboard: ResMut<LifeBitboard>,
+) {
+    for (cell, state) in &cells {
+        bitboard.set(*cell.coords(), state.0);
+    }
+}
+
+/// Computes the next generation of the [`LifeBitboard`]
+#[allow(clippy::needless_pass_by_value)]
+pub fn step_bitboard(mut bitboard: ResMut<LifeBitboard>, pause: Option<Res<SimulationPause>>) {
+    if pause.is_some() {
+        return;
+    }
+    bitboard.step();
+}
+
+/// Syncs the cell entities with the [`LifeBitboard`] for rendering, once per
+/// frame, only the cells whose state differs being changed
+#[allow(clippy::needless_pass_by_value)]
+pub fn sync_bitboard_cells<C: Cell<Coordinates = IVec2>>(
+    mut cells: Query<(&C, &mut ConwayCellState)>,
+    bitboard: Res<LifeBitboard>,
+) {
+    if !bitboard.is_changed() {
+        return;
+    }
+    cells.par_iter_mut().for_each(|(cell, mut state)| {
+        let alive = bitboard.get(*cell.coords());
+        if state.0 != alive {
+            state.0 = alive;
+        }
+    });
+}
@@ -1,67 +1,1148 @@
 use crate::{
-    components::{Cell, CellState},
-    resources::CellMap,
+    components::{
+        Cell, CellCoordinates, CellState, CoupledCellState, GridParams, GridRoot, NeighborEntities,
+        ParametricCellState, StateBuffer, TraceableCellState, WeightedCell, WeightedCellState,
+    },
+    events::{GenerationComputedEvent, TransmissionEvent},
+    resources::{
+        AsyncCompute, BoundaryCondition, CellMap, CellRng, CellStreams, DirtyChunks, Generation,
+        MapSize, Quarantine, RegionOfInterest, SimulationRng, SleepingCells, TickBudget,
+        UpdateProbability,
+    },
+    systems::generation::PendingGenerations,
     SimulationBatch, SimulationPause,
 };
-use bevy::{log, prelude::*, utils::HashMap};
+use bevy::{
+    log,
+    prelude::*,
+    tasks::AsyncComputeTaskPool,
+    utils::{smallvec::SmallVec, HashMap},
+};
+use rand::Rng;
+use std::{
+    cell::RefCell,
+    sync::{
+        mpsc::{self, Receiver, TryRecvError},
+        Arc,
+    },
+};
+
+/// Cells of the rules, with the root of their grid if any
+pub type GridCells<'w, 's, C, S> =
+    Query<'w, 's, (Entity, &'static C, &'static S, Option<&'static GridRoot>)>;
+
+/// State buffers of the cells of the rules, with the root of their grid if any
+pub type GridBuffers<'w, 's, C, S> = Query<
+    'w,
+    's,
+    (
+        &'static C,
+        &'static S,
+        &'static mut StateBuffer<S>,
+        Option<&'static GridRoot>,
+    ),
+>;
+
+/// Snapshot of every cell state of a grid, used to look up neighbor states
+pub trait StateMap<C: Cell, S: CellState>: Sync + Sized {
+    /// Builds the snapshot from the `cells` of a grid
+    fn build<'a>(cells: impl Iterator<Item = (&'a C, &'a S)>, size: Option<MapSize>) -> Self
+    where
+        C: 'a,
+        S: 'a;
+
+    /// Rebuilds the snapshot from the `cells` of a grid, reusing its
+    /// allocations
+    #[inline]
+    fn rebuild<'a>(&mut self, cells: impl Iterator<Item = (&'a C, &'a S)>, size: Option<MapSize>)
+    where
+        C: 'a,
+        S: 'a,
+    {
+        *self = Self::build(cells, size);
+    }
+
+    /// Retrieves the state at `coords`
+    fn get_state(&self, coords: &C::Coordinates) -> Option<&S>;
+}
+
+impl<C: Cell, S: CellState> StateMap<C, S> for HashMap<C::Coordinates, S> {
+    fn build<'a>(cells: impl Iterator<Item = (&'a C, &'a S)>, _size: Option<MapSize>) -> Self
+    where
+        C: 'a,
+        S: 'a,
+    {
+        cells
+            .map(|(cell, state)| (cell.coords().clone(), state.clone()))
+            .collect()
+    }
+
+    fn rebuild<'a>(&mut self, cells: impl Iterator<Item = (&'a C, &'a S)>, _size: Option<MapSize>)
+    where
+        C: 'a,
+        S: 'a,
+    {
+        self.clear();
+        self.extend(cells.map(|(cell, state)| (cell.coords().clone(), state.clone())));
+    }
+
+    #[inline]
+    fn get_state(&self, coords: &C::Coordinates) -> Option<&S> {
+        self.get(coords)
+    }
+}
+
+/// Neighbors gathered by the rules needing them as a slice, stored inline up to
+/// the size of the built-in neighborhoods to avoid an allocation per cell
+pub type NeighborBuffer<T> = SmallVec<[T; 32]>;
+
+/// Dense, row-major snapshot of the cell states of a bounded grid
+pub struct DenseStateMap<S> {
+    size: MapSize,
+    states: Vec<Option<S>>,
+}
+
+impl<C: Cell, S: CellState> StateMap<C, S> for DenseStateMap<S> {
+    fn build<'a>(cells: impl Iterator<Item = (&'a C, &'a S)>, size: Option<MapSize>) -> Self
+    where
+        C: 'a,
+        S: 'a,
+    {
+        let Some(size) = size else {
+            log::error!("Dense cell maps require a `MapSize` resource");
+            return Self {
+                size: MapSize::new(0, 0),
+                states: Vec::new(),
+            };
+        };
+        let mut states = vec![None; size.volume()];
+        for (cell, state) in cells {
+            if let Some(index) = cell.coords().dense_index(size) {
+                states[index] = Some(state.clone());
+            }
+        }
+        Self { size, states }
+    }
+
+    fn rebuild<'a>(&mut self, cells: impl Iterator<Item = (&'a C, &'a S)>, size: Option<MapSize>)
+    where
+        C: 'a,
+        S: 'a,
+    {
+        let Some(size) = size else {
+            *self = <Self as StateMap<C, S>>::build(cells, size);
+            return;
+        };
+        self.size = size;
+        self.states.clear();
+        self.states.resize(size.volume(), None);
+        for (cell, state) in cells {
+            if let Some(index) = cell.coords().dense_index(size) {
+                self.states[index] = Some(state.clone());
+            }
+        }
+    }
+
+    #[inline]
+    fn get_state(&self, coords: &C::Coordinates) -> Option<&S> {
+        let index = coords.dense_index(self.size)?;
+        self.states.get(index)?.as_ref()
+    }
+}
+
+/// Snapshots of every grid by [`GridRoot`], the cells without root forming
+/// the default grid
+pub struct GridStateMaps<M> {
+    maps: HashMap<Option<Entity>, M>,
+}
 
-fn handle_cell<C, S>((cell, state): (&C, &S), map: &HashMap<C::Coordinates, S>) -> Option<S>
+impl<M> GridStateMaps<M> {
+    /// Builds the snapshots of the grids of the `cells`, rebuilding the
+    /// snapshots of the `spare` grids if any.
+    ///
+    /// Without any [`GridRoot`], the default grid is rebuilt straight from the
+    /// `cells`. Otherwise the cells are bucketed by grid in a single pass, in
+    /// the reused `buckets`.
+    fn build<C, S>(
+        cells: &GridCells<C, S>,
+        size: Option<MapSize>,
+        spare: Option<Self>,
+        buckets: &mut GridBuckets,
+    ) -> Self
+    where
+        C: Cell,
+        S: CellState,
+        M: StateMap<C, S>,
+    {
+        let mut maps = spare.map(|spare| spare.maps).unwrap_or_default();
+        if cells.iter().all(|(_, _, _, root)| root.is_none()) {
+            buckets.clear();
+            maps.retain(|grid, _| grid.is_none());
+            let cells = cells.iter().map(|(_, cell, state, _)| (cell, state));
+            match maps.get_mut(&None) {
+                Some(map) => map.rebuild(cells, size),
+                None => {
+                    maps.insert(None, M::build(cells, size));
+                }
+            }
+            return Self { maps };
+        }
+        for bucket in buckets.values_mut() {
+            bucket.clear();
+        }
+        for (entity, _, _, root) in cells {
+            buckets.entry(root.map(|r| r.0)).or_default().push(entity);
+        }
+        buckets.retain(|_, bucket| !bucket.is_empty());
+        maps.retain(|grid, _| buckets.contains_key(grid));
+        for (grid, bucket) in buckets.iter() {
+            let cells = cells
+                .iter_many(bucket)
+                .map(|(_, cell, state, _)| (cell, state));
+            match maps.get_mut(grid) {
+                Some(map) => map.rebuild(cells, size),
+                None => {
+                    maps.insert(*grid, M::build(cells, size));
+                }
+            }
+        }
+        Self { maps }
+    }
+
+    /// Retrieves the snapshot of the `grid`
+    #[inline]
+    fn grid(&self, grid: Option<Entity>) -> Option<&M> {
+        self.maps.get(&grid)
+    }
+}
+
+fn handle_cell<C, S, M>((cell, state): (&C, &S), map: &M, lookup: &NeighborLookup<C>) -> Option<S>
 where
     C: Cell,
     S: CellState,
+    M: StateMap<C, S>,
 {
     let neighbor_coords = cell.neighbor_coordinates();
-    let neighbor_states = neighbor_coords.into_iter().filter_map(|c| map.get(&c));
-    let new_state = state.new_cell_state(neighbor_states);
-    if &new_state == state {
-        None
-    } else {
-        Some(new_state)
+    let neighbor_states = neighbor_coords
+        .into_iter()
+        .filter_map(|c| lookup.neighbor(cell.coords(), c))
+        .filter_map(|c| map.get_state(&c));
+    let new_state = state.new_cell_state_rng(neighbor_states, &mut CellRng);
+    (&new_state != state).then_some(new_state)
+}
+
+fn handle_weighted_cell<C, S, M>(
+    (cell, state): (&C, &S),
+    map: &M,
+    lookup: &NeighborLookup<C>,
+) -> Option<S>
+where
+    C: WeightedCell,
+    S: WeightedCellState,
+    M: StateMap<C, S>,
+{
+    let neighbor_coords = cell.weighted_neighbor_coordinates();
+    let neighbor_states = neighbor_coords
+        .into_iter()
+        .filter_map(|(c, w)| Some((lookup.neighbor(cell.coords(), c)?, w)))
+        .filter_map(|(c, w)| Some((map.get_state(&c)?, w)));
+    let new_state = state.new_cell_state_weighted(neighbor_states);
+    (&new_state != state).then_some(new_state)
+}
+
+fn handle_parametric_cell<C, S, M>(
+    (cell, state): (&C, &S),
+    map: &M,
+    lookup: &NeighborLookup<C>,
+    params: &S::Params,
+) -> Option<S>
+where
+    C: Cell,
+    S: ParametricCellState,
+    M: StateMap<C, S>,
+{
+    let neighbor_coords = cell.neighbor_coordinates();
+    let neighbor_states = neighbor_coords
+        .into_iter()
+        .filter_map(|c| lookup.neighbor(cell.coords(), c))
+        .filter_map(|c| map.get_state(&c));
+    let new_state = state.new_cell_state_with_params_rng(neighbor_states, params, &mut CellRng);
+    (&new_state != state).then_some(new_state)
+}
+
+fn handle_coupled_cell<C, S, O, M>(
+    (cell, state): (&C, &S),
+    map: &M,
+    lookup: &NeighborLookup<C>,
+    coupled: Option<&O>,
+) -> Option<S>
+where
+    C: Cell,
+    S: CoupledCellState<O>,
+    O: CellState,
+    M: StateMap<C, S>,
+{
+    let neighbor_coords = cell.neighbor_coordinates();
+    let neighbor_states = neighbor_coords
+        .into_iter()
+        .filter_map(|c| lookup.neighbor(cell.coords(), c))
+        .filter_map(|c| map.get_state(&c));
+    let new_state = state.new_cell_state_coupled(neighbor_states, coupled);
+    (&new_state != state).then_some(new_state)
+}
+
+/// Neighbor lookup settings: the boundary condition of bounded grids, the
+/// quarantined regions and the root of the grid of the cell
+struct NeighborLookup<'a, C: Cell> {
+    boundary: Option<(BoundaryCondition, MapSize)>,
+    quarantine: Option<&'a Quarantine<C>>,
+    grid: Option<Entity>,
+}
+
+impl<C: Cell> NeighborLookup<'_, C> {
+    /// Applies the boundary condition to the `coords` neighbor of the cell at
+    /// `origin`, discarding neighbors outside of the grid or cut by the
+    /// quarantine
+    fn neighbor(&self, origin: &C::Coordinates, coords: C::Coordinates) -> Option<C::Coordinates> {
+        let coords = match self.boundary {
+            Some((condition, size)) => coords.apply_boundary(condition, size)?,
+            None => coords,
+        };
+        match self.quarantine {
+            Some(quarantine) if quarantine.cuts(origin, &coords) => None,
+            _ => Some(coords),
+        }
     }
 }
 
-#[allow(clippy::needless_pass_by_value)]
-pub fn handle_cells<C, S>(
-    mut commands: Commands,
-    par_commands: ParallelCommands,
-    query: Query<(Entity, &C, &S)>,
+/// Retrieves the neighbor coordinates of `cell`, applying the boundary
+/// condition of a bounded grid, if any
+pub fn bounded_neighbors<C: Cell>(
+    cell: &C,
+    boundary: Option<(BoundaryCondition, MapSize)>,
+) -> impl Iterator<Item = C::Coordinates> + '_ {
+    cell.neighbor_coordinates()
+        .into_iter()
+        .filter_map(move |coords| match boundary {
+            Some((condition, size)) => coords.apply_boundary(condition, size),
+            None => Some(coords),
+        })
+}
+
+/// Draws whether a cell skips its update, following the update `probability`
+/// if any
+#[inline]
+fn is_skipped(probability: Option<f64>) -> bool {
+    probability.is_some_and(|p| CellRng.gen::<f64>() >= p)
+}
+
+/// Cells skipping their rules: the quiescent chunks, the sleeping cells, the
+/// cells out of the region of interest and the update probability
+struct CellFilter<'a, C: Cell, S> {
+    dirty: Option<&'a DirtyChunks<S>>,
+    sleeping: Option<&'a SleepingCells<C, S>>,
+    interest: Option<&'a RegionOfInterest>,
+    probability: Option<f64>,
+}
+
+impl<C: Cell, S: CellState> CellFilter<'_, C, S> {
+    /// Applies the `rule` to the cell unless it is skipped, drawing from its
+    /// stream of the generation `streams`, if any
+    fn apply<M>(
+        &self,
+        (cell, state, root): (&C, &S, Option<&GridRoot>),
+        maps: &GridStateMaps<M>,
+        lookup: &NeighborLookup<C>,
+        streams: Option<CellStreams>,
+        rule: &impl Fn((&C, &S), &M, &NeighborLookup<C>) -> Option<S>,
+    ) -> Option<S> {
+        if self.dirty.is_some_and(|d| !d.is_dirty(cell.coords()))
+            || self.sleeping.is_some_and(|s| !s.is_awake(cell.coords()))
+            || self.interest.is_some_and(|i| !i.contains(cell.coords()))
+        {
+            return None;
+        }
+        let _scope = streams.map(|s| s.scope(cell.coords()));
+        if is_skipped(self.probability) {
+            return None;
+        }
+        let grid = root.map(|r| r.0);
+        let lookup = NeighborLookup { grid, ..*lookup };
+        rule((cell, state), maps.grid(grid)?, &lookup)
+    }
+}
+
+/// Sleeping cells and region of interest, skipping the rules of the other
+/// cells
+type SkippedCells<'w, C, S> = (
+    Option<Res<'w, SleepingCells<C, S>>>,
+    Option<Res<'w, RegionOfInterest>>,
+);
+
+/// Global rule parameters, the [`GridParams`] overriding them per grid and
+/// their copy, only rebuilt when they change
+type RuleParams<'w, 's, P> = (
+    Res<'w, P>,
+    Query<'w, 's, (Entity, Ref<'static, GridParams<P>>)>,
+    RemovedComponents<'w, 's, GridParams<P>>,
+    Local<'s, Option<Arc<GridRuleParams<P>>>>,
+);
+
+/// Rule parameters of the default grid and of the grids with [`GridParams`]
+pub struct GridRuleParams<P> {
+    default: P,
+    grids: HashMap<Entity, P>,
+}
+
+impl<P: Resource + Clone> GridRuleParams<P> {
+    /// Retrieves the copy of the rule parameters, rebuilt in place if they
+    /// changed since the last tick
+    fn cached((params, grids, mut removed, mut cache): RuleParams<P>) -> Arc<Self> {
+        let removed = removed.read().count() > 0;
+        let changed = removed || params.is_changed() || grids.iter().any(|(_, p)| p.is_changed());
+        if let Some(cached) = cache.as_mut() {
+            if !changed {
+                return Arc::clone(cached);
+            }
+            if let Some(cached) = Arc::get_mut(cached) {
+                cached.default.clone_from(&params);
+                cached.grids.clear();
+                cached
+                    .grids
+                    .extend(grids.iter().map(|(root, p)| (root, p.0.clone())));
+            }
+        }
+        let cached = cache.get_or_insert_with(|| {
+            Arc::new(Self {
+                default: params.clone(),
+                grids: grids.iter().map(|(root, p)| (root, p.0.clone())).collect(),
+            })
+        });
+        Arc::clone(cached)
+    }
+
+    /// Retrieves the rule parameters of the `grid`
+    #[inline]
+    fn get(&self, grid: Option<Entity>) -> &P {
+        grid.and_then(|root| self.grids.get(&root))
+            .unwrap_or(&self.default)
+    }
+}
+
+/// Reused buffers of the sparse snapshots of `S` states
+type SparseSnapshots<'s, C, S> = Local<'s, SnapshotBuffers<HashMap<<C as Cell>::Coordinates, S>>>;
+
+/// Cell entities by grid and coordinates, refilled every tick
+type CellEntities<'s, C> = Local<'s, HashMap<(Option<Entity>, <C as Cell>::Coordinates), Entity>>;
+
+/// Cell entities of every grid, bucketed to build their snapshots
+type GridBuckets = HashMap<Option<Entity>, Vec<Entity>>;
+
+/// Snapshots of the last generation and the buckets of cells, reused to build
+/// the next snapshots without allocating
+pub struct SnapshotBuffers<M> {
+    spare: Option<GridStateMaps<M>>,
+    buckets: GridBuckets,
+}
+
+impl<M> Default for SnapshotBuffers<M> {
+    fn default() -> Self {
+        Self {
+            spare: None,
+            buckets: HashMap::default(),
+        }
+    }
+}
+
+impl<M> SnapshotBuffers<M> {
+    /// Snapshots the `cells`, rebuilding the snapshot of the last generation
+    /// if any
+    fn snapshot<C, S>(&mut self, cells: &GridCells<C, S>, size: Option<MapSize>) -> GridStateMaps<M>
+    where
+        C: Cell,
+        S: CellState,
+        M: StateMap<C, S>,
+    {
+        GridStateMaps::build(cells, size, self.spare.take(), &mut self.buckets)
+    }
+
+    /// Keeps the `maps` to be rebuilt by the next snapshot
+    #[inline]
+    fn recycle(&mut self, maps: GridStateMaps<M>) {
+        self.spare = Some(maps);
+    }
+}
+
+/// Receiver of the generation computed on the [`AsyncComputeTaskPool`]: its
+/// snapshot, to be reused, and the new states
+type GenerationTask<S, M> = Receiver<(GridStateMaps<M>, Vec<(Entity, S)>)>;
+
+/// Generation computed across several ticks under a [`TickBudget`]: the
+/// snapshot of the states it started from and the new states computed so far.
+///
+/// The snapshot of the last generation is kept to be rebuilt in place by the
+/// next one. Under an [`AsyncCompute`] resource, the generation is computed by
+/// a task instead.
+pub struct StagedGeneration<S, M> {
+    map: Option<GridStateMaps<M>>,
+    snapshots: SnapshotBuffers<M>,
+    streams: Option<CellStreams>,
+    cells: Vec<Entity>,
+    cursor: usize,
+    states: Vec<(Entity, S)>,
+    task: Option<GenerationTask<S, M>>,
+}
+
+impl<S, M> Default for StagedGeneration<S, M> {
+    fn default() -> Self {
+        Self {
+            map: None,
+            snapshots: SnapshotBuffers::default(),
+            streams: None,
+            cells: Vec::new(),
+            cursor: 0,
+            states: Vec::new(),
+            task: None,
+        }
+    }
+}
+
+impl<S: CellState, M> StagedGeneration<S, M> {
+    /// Snapshots the `cells`, rebuilding the snapshot of the last generation
+    /// if any
+    fn snapshot<C: Cell>(
+        &mut self,
+        cells: &GridCells<C, S>,
+        size: Option<MapSize>,
+    ) -> GridStateMaps<M>
+    where
+        M: StateMap<C, S>,
+    {
+        self.snapshots.snapshot(cells, size)
+    }
+}
+
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments,
+    clippy::too_many_lines
+)]
+fn apply_rules<C, S, M>(
+    query: GridCells<C, S>,
+    mut buffers: GridBuffers<C, S>,
     pause: Option<Res<SimulationPause>>,
     batch: Option<Res<SimulationBatch>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    budget: Option<Res<TickBudget>>,
+    mut pending: Option<ResMut<PendingGenerations>>,
+    mut staged: Local<StagedGeneration<S, M>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    (sleeping, interest): SkippedCells<'_, C, S>,
+    (async_compute, mut computed): (
+        Option<Res<AsyncCompute>>,
+        EventWriter<GenerationComputedEvent>,
+    ),
+    rule: impl Fn((&C, &S), &M, &NeighborLookup<C>) -> Option<S> + Send + Sync + 'static,
 ) where
     C: Cell,
     S: CellState,
+    M: StateMap<C, S> + Send + 'static,
 {
     if pause.is_some() {
         return;
     }
-    let map: HashMap<_, _> = query
-        .iter()
-        .map(|(_entity, cell, state)| (cell.coords().clone(), state.clone()))
-        .collect();
-    if batch.is_some() {
-        query.par_iter().for_each(|(entity, cell, state)| {
-            if let Some(new_state) = handle_cell((cell, state), &map) {
-                par_commands.command_scope(|mut cmd| {
-                    cmd.entity(entity).insert(new_state);
-                });
+    let size = map_size.as_deref().copied();
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let probability = update_probability.map(|p| p.0);
+    // A pending task is completed even if the resource was removed
+    if let Some(task) = staged.task.take() {
+        let result = match task.try_recv() {
+            Err(TryRecvError::Empty) if async_compute.is_some() => {
+                staged.task = Some(task);
+                return;
             }
+            Err(TryRecvError::Empty) => task.recv().ok(),
+            result => result.ok(),
+        };
+        if let Some(pending) = pending.as_deref_mut() {
+            pending.0 = pending.0.saturating_sub(1);
+        }
+        let Some((map, states)) = result else {
+            log::error!("The asynchronous generation task was dropped");
+            return;
+        };
+        computed.send(GenerationComputedEvent {
+            changed: states.len(),
         });
+        for (entity, new_state) in states {
+            if let Ok((_, _, mut buffer, _)) = buffers.get_mut(entity) {
+                buffer.next = Some(new_state);
+            }
+        }
+        staged.snapshots.recycle(map);
+        return;
+    }
+    if async_compute.is_some() && staged.map.is_none() {
+        let map = staged.snapshot(&query, size);
+        let cells: Vec<_> = query
+            .iter()
+            .map(|(entity, cell, state, root)| (entity, cell.clone(), state.clone(), root.copied()))
+            .collect();
+        let streams = simulation_rng
+            .as_deref_mut()
+            .map(SimulationRng::cell_streams);
+        let quarantine = quarantine.as_deref().cloned();
+        let dirty = dirty.as_deref().cloned();
+        let sleeping = sleeping.as_deref().cloned();
+        let interest = interest.as_deref().copied();
+        let (sender, receiver) = mpsc::channel();
+        let task = AsyncComputeTaskPool::get().spawn(async move {
+            let lookup = NeighborLookup {
+                boundary,
+                quarantine: quarantine.as_ref(),
+                grid: None,
+            };
+            let filter = CellFilter {
+                dirty: dirty.as_ref(),
+                sleeping: sleeping.as_ref(),
+                interest: interest.as_ref(),
+                probability,
+            };
+            let states = cells
+                .into_iter()
+                .filter_map(|(entity, cell, state, root)| {
+                    let cell = (&cell, &state, root.as_ref());
+                    let new_state = filter.apply(cell, &map, &lookup, streams, &rule)?;
+                    Some((entity, new_state))
+                })
+                .collect();
+            // The receiver is gone if the system was removed
+            sender.send((map, states)).ok();
+        });
+        task.detach();
+        staged.task = Some(receiver);
+        if let Some(pending) = pending.as_deref_mut() {
+            pending.0 += 1;
+        }
+        return;
+    }
+    let lookup = NeighborLookup {
+        boundary,
+        quarantine: quarantine.as_deref(),
+        grid: None,
+    };
+    let filter = CellFilter {
+        dirty: dirty.as_deref(),
+        sleeping: sleeping.as_deref(),
+        interest: interest.as_deref(),
+        probability,
+    };
+    // A staged generation is completed even if the budget was removed
+    let budget = match budget {
+        Some(budget) => Some(budget.0.max(1)),
+        None => staged.map.is_some().then_some(usize::MAX),
+    };
+    if let Some(budget) = budget {
+        let staged = &mut *staged;
+        if staged.map.is_none() {
+            staged.map = Some(staged.snapshot(&query, size));
+            staged.streams = simulation_rng
+                .as_deref_mut()
+                .map(SimulationRng::cell_streams);
+            staged.cells.clear();
+            staged
+                .cells
+                .extend(query.iter().map(|(entity, _, _, _)| entity));
+            staged.cursor = 0;
+            if let Some(pending) = pending.as_deref_mut() {
+                pending.0 += 1;
+            }
+        }
+        let end = staged.cursor.saturating_add(budget).min(staged.cells.len());
+        if let Some(map) = &staged.map {
+            for (entity, cell, state, root) in query.iter_many(&staged.cells[staged.cursor..end]) {
+                if let Some(new_state) =
+                    filter.apply((cell, state, root), map, &lookup, staged.streams, &rule)
+                {
+                    staged.states.push((entity, new_state));
+                }
+            }
+        }
+        staged.cursor = end;
+        if end == staged.cells.len() {
+            for (entity, new_state) in staged.states.drain(..) {
+                if let Ok((_, _, mut buffer, _)) = buffers.get_mut(entity) {
+                    buffer.next = Some(new_state);
+                }
+            }
+            if let Some(map) = staged.map.take() {
+                staged.snapshots.recycle(map);
+            }
+            staged.cells.clear();
+            if let Some(pending) = pending.as_deref_mut() {
+                pending.0 = pending.0.saturating_sub(1);
+            }
+        }
+        return;
+    }
+    let streams = simulation_rng
+        .as_deref_mut()
+        .map(SimulationRng::cell_streams);
+    let map = staged.snapshot(&query, size);
+    let update =
+        |(cell, state, mut buffer, root): (&C, &S, Mut<StateBuffer<S>>, Option<&GridRoot>)| {
+            if let Some(new_state) =
+                filter.apply((cell, state, root), &map, &lookup, streams, &rule)
+            {
+                buffer.next = Some(new_state);
+            }
+        };
+    if let Some(batch) = batch {
+        buffers
+            .par_iter_mut()
+            .batching_strategy(batch.batching_strategy())
+            .for_each(update);
     } else {
-        for (entity, cell, state) in query.iter() {
-            if let Some(new_state) = handle_cell((cell, state), &map) {
-                commands.entity(entity).insert(new_state);
+        buffers.iter_mut().for_each(update);
+    }
+    staged.snapshots.recycle(map);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_cells<C, S, M>(
+    query: GridCells<C, S>,
+    buffers: GridBuffers<C, S>,
+    pause: Option<Res<SimulationPause>>,
+    batch: Option<Res<SimulationBatch>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    budget: Option<Res<TickBudget>>,
+    pending: Option<ResMut<PendingGenerations>>,
+    staged: Local<StagedGeneration<S, M>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    skipped: SkippedCells<'_, C, S>,
+    async_compute: (
+        Option<Res<AsyncCompute>>,
+        EventWriter<GenerationComputedEvent>,
+    ),
+) where
+    C: Cell,
+    S: CellState,
+    M: StateMap<C, S> + Send + 'static,
+{
+    apply_rules(
+        query,
+        buffers,
+        pause,
+        batch,
+        boundary,
+        map_size,
+        quarantine,
+        simulation_rng,
+        update_probability,
+        budget,
+        pending,
+        staged,
+        dirty,
+        skipped,
+        async_compute,
+        handle_cell::<C, S, M>,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_weighted_cells<C, S, M>(
+    query: GridCells<C, S>,
+    buffers: GridBuffers<C, S>,
+    pause: Option<Res<SimulationPause>>,
+    batch: Option<Res<SimulationBatch>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    budget: Option<Res<TickBudget>>,
+    pending: Option<ResMut<PendingGenerations>>,
+    staged: Local<StagedGeneration<S, M>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    skipped: SkippedCells<'_, C, S>,
+    async_compute: (
+        Option<Res<AsyncCompute>>,
+        EventWriter<GenerationComputedEvent>,
+    ),
+) where
+    C: WeightedCell,
+    S: WeightedCellState,
+    M: StateMap<C, S> + Send + 'static,
+{
+    apply_rules(
+        query,
+        buffers,
+        pause,
+        batch,
+        boundary,
+        map_size,
+        quarantine,
+        simulation_rng,
+        update_probability,
+        budget,
+        pending,
+        staged,
+        dirty,
+        skipped,
+        async_compute,
+        handle_weighted_cell::<C, S, M>,
+    );
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn handle_parametric_cells<C, S, M>(
+    query: GridCells<C, S>,
+    buffers: GridBuffers<C, S>,
+    params: RuleParams<S::Params>,
+    pause: Option<Res<SimulationPause>>,
+    batch: Option<Res<SimulationBatch>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    budget: Option<Res<TickBudget>>,
+    pending: Option<ResMut<PendingGenerations>>,
+    staged: Local<StagedGeneration<S, M>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    skipped: SkippedCells<'_, C, S>,
+    async_compute: (
+        Option<Res<AsyncCompute>>,
+        EventWriter<GenerationComputedEvent>,
+    ),
+) where
+    C: Cell,
+    S: ParametricCellState,
+    M: StateMap<C, S> + Send + 'static,
+{
+    let params = GridRuleParams::cached(params);
+    apply_rules(
+        query,
+        buffers,
+        pause,
+        batch,
+        boundary,
+        map_size,
+        quarantine,
+        simulation_rng,
+        update_probability,
+        budget,
+        pending,
+        staged,
+        dirty,
+        skipped,
+        async_compute,
+        move |cell, map: &M, lookup| {
+            handle_parametric_cell(cell, map, lookup, params.get(lookup.grid))
+        },
+    );
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn handle_coupled_cells<C, S, O>(
+    query: GridCells<C, S>,
+    buffers: GridBuffers<C, S>,
+    (coupled, mut snapshots): (GridCells<C, O>, SparseSnapshots<C, O>),
+    pause: Option<Res<SimulationPause>>,
+    batch: Option<Res<SimulationBatch>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    budget: Option<Res<TickBudget>>,
+    pending: Option<ResMut<PendingGenerations>>,
+    staged: Local<StagedGeneration<S, HashMap<C::Coordinates, S>>>,
+    interest: Option<Res<RegionOfInterest>>,
+    async_compute: (
+        Option<Res<AsyncCompute>>,
+        EventWriter<GenerationComputedEvent>,
+    ),
+) where
+    C: Cell,
+    S: CoupledCellState<O>,
+    O: CellState,
+{
+    let coupled = Arc::new(snapshots.snapshot(&coupled, None));
+    let rule_coupled = Arc::clone(&coupled);
+    apply_rules(
+        query,
+        buffers,
+        pause,
+        batch,
+        boundary,
+        map_size,
+        quarantine,
+        simulation_rng,
+        update_probability,
+        budget,
+        pending,
+        staged,
+        None,
+        (None, interest),
+        async_compute,
+        move |cell, map: &HashMap<C::Coordinates, S>, lookup| {
+            let coupled = rule_coupled.grid(lookup.grid);
+            handle_coupled_cell(
+                cell,
+                map,
+                lookup,
+                coupled.and_then(|c| c.get(cell.0.coords())),
+            )
+        },
+    );
+    // The snapshot is still shared while a task computes the generation
+    if let Ok(coupled) = Arc::try_unwrap(coupled) {
+        snapshots.recycle(coupled);
+    }
+}
+
+/// Updates the cells like [`handle_parametric_cells`], sending a
+/// [`TransmissionEvent`] for every infection attributed to a neighbor.
+///
+/// The cells are updated sequentially, as the events are sent in order, and
+/// the whole generation is computed every tick.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn handle_traced_cells<C, S>(
+    query: GridCells<C, S>,
+    mut buffers: Query<&mut StateBuffer<S>>,
+    params: RuleParams<S::Params>,
+    (mut snapshots, mut entities): (SparseSnapshots<C, S>, CellEntities<C>),
+    mut events: EventWriter<TransmissionEvent>,
+    generation: Res<Generation>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    (sleeping, interest): SkippedCells<'_, C, S>,
+) where
+    C: Cell,
+    S: TraceableCellState,
+{
+    if pause.is_some() {
+        return;
+    }
+    let streams = simulation_rng
+        .as_deref_mut()
+        .map(SimulationRng::cell_streams);
+    let maps = snapshots.snapshot(&query, None);
+    entities.clear();
+    entities.extend(
+        query
+            .iter()
+            .map(|(entity, cell, _, root)| ((root.map(|r| r.0), cell.coords().clone()), entity)),
+    );
+    let params = GridRuleParams::cached(params);
+    let lookup = NeighborLookup {
+        boundary: boundary.zip(map_size).map(|(b, s)| (*b, *s)),
+        quarantine: quarantine.as_deref(),
+        grid: None,
+    };
+    let filter = CellFilter {
+        dirty: dirty.as_deref(),
+        sleeping: sleeping.as_deref(),
+        interest: interest.as_deref(),
+        probability: update_probability.map(|p| p.0),
+    };
+    // Neighbor entity the last infection is attributed to
+    let source = RefCell::new(None);
+    let rule =
+        |(cell, state): (&C, &S), map: &HashMap<C::Coordinates, S>, lookup: &NeighborLookup<C>| {
+            let (neighbor_coords, neighbor_states): (NeighborBuffer<_>, NeighborBuffer<&S>) = cell
+                .neighbor_coordinates()
+                .into_iter()
+                .filter_map(|c| lookup.neighbor(cell.coords(), c))
+                .filter_map(|c| map.get(&c).map(|state| (c, state)))
+                .unzip();
+            let new_state = state.new_cell_state_with_params_rng(
+                neighbor_states.iter().copied(),
+                params.get(lookup.grid),
+                &mut CellRng,
+            );
+            if &new_state == state {
+                return None;
             }
+            *source.borrow_mut() = state
+                .infection_source(&new_state, &neighbor_states)
+                .and_then(|i| neighbor_coords.get(i))
+                .and_then(|c| entities.get(&(lookup.grid, c.clone())).copied());
+            Some(new_state)
+        };
+    for (entity, cell, state, root) in &query {
+        let Some(new_state) = filter.apply((cell, state, root), &maps, &lookup, streams, &rule)
+        else {
+            continue;
+        };
+        if let Some(source) = source.take() {
+            events.send(TransmissionEvent {
+                source,
+                target: entity,
+                tick: generation.0,
+            });
+        }
+        if let Ok(mut buffer) = buffers.get_mut(entity) {
+            buffer.next = Some(new_state);
         }
     }
 }
 
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn handle_cached_cells<C, S>(
+    mut query: Query<(&C, &S, &NeighborEntities, &mut StateBuffer<S>)>,
+    states: Query<&S>,
+    pause: Option<Res<SimulationPause>>,
+    batch: Option<Res<SimulationBatch>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+    update_probability: Option<Res<UpdateProbability>>,
+    dirty: Option<Res<DirtyChunks<S>>>,
+    sleeping: Option<Res<SleepingCells<C, S>>>,
+    interest: Option<Res<RegionOfInterest>>,
+) where
+    C: Cell,
+    S: CellState,
+{
+    if pause.is_some() {
+        return;
+    }
+    let streams = simulation_rng
+        .as_deref_mut()
+        .map(SimulationRng::cell_streams);
+    let probability = update_probability.map(|p| p.0);
+    let handle_cell = |cell: &C, state: &S, neighbors: &NeighborEntities| {
+        if dirty.as_ref().is_some_and(|d| !d.is_dirty(cell.coords()))
+            || sleeping
+                .as_ref()
+                .is_some_and(|s| !s.is_awake(cell.coords()))
+            || interest
+                .as_ref()
+                .is_some_and(|i| !i.contains(cell.coords()))
+        {
+            return None;
+        }
+        let _scope = streams.map(|s| s.scope(cell.coords()));
+        if is_skipped(probability) {
+            return None;
+        }
+        let neighbor_states = neighbors.iter().filter_map(|e| states.get(*e).ok());
+        let new_state = state.new_cell_state_rng(neighbor_states, &mut CellRng);
+        (&new_state != state).then_some(new_state)
+    };
+    let update =
+        |(cell, state, neighbors, mut buffer): (&C, &S, &NeighborEntities, Mut<StateBuffer<S>>)| {
+            if let Some(new_state) = handle_cell(cell, state, neighbors) {
+                buffer.next = Some(new_state);
+            }
+        };
+    if let Some(batch) = batch {
+        query
+            .par_iter_mut()
+            .batching_strategy(batch.batching_strategy())
+            .for_each(update);
+    } else {
+        query.iter_mut().for_each(update);
+    }
+}
+
+/// Inserts the [`StateBuffer`] of the new cells
 #[allow(clippy::needless_pass_by_value)]
-pub fn handle_new_cells<C>(query: Query<(Entity, &C), Added<C>>, mut map: ResMut<CellMap<C>>)
-where
+pub fn insert_state_buffers<S: CellState>(
+    mut commands: Commands,
+    cells: Query<Entity, (With<S>, Without<StateBuffer<S>>)>,
+) {
+    for entity in &cells {
+        commands.entity(entity).insert(StateBuffer::<S>::new());
+    }
+}
+
+/// Swaps the new states of the [`StateBuffer`] written by the rules into the
+/// cell states
+pub fn apply_next_states<S: CellState>(
+    mut cells: Query<(&mut S, &mut StateBuffer<S>), Changed<StateBuffer<S>>>,
+) {
+    for (mut state, mut buffer) in &mut cells {
+        if let Some(next) = buffer.bypass_change_detection().next.take() {
+            *state = next;
+        }
+    }
+}
+
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::too_many_arguments,
+    clippy::type_complexity
+)]
+pub fn cache_neighbor_entities<C>(
+    mut commands: Commands,
+    query: Query<(Entity, &C, Option<&GridRoot>)>,
+    changed: Query<(), Or<(Changed<C>, Changed<GridRoot>)>>,
+    mut removed: RemovedComponents<C>,
+    map: Res<CellMap<C>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    quarantine: Option<Res<Quarantine<C>>>,
+    mut quarantined: Local<bool>,
+) where
     C: Cell,
 {
-    for (entity, new_cell) in query.iter() {
-        let old_entity = map.insert_cell(new_cell.coords().clone(), entity);
+    let boundary_changed = boundary.as_ref().is_some_and(DetectChanges::is_changed)
+        || map_size.as_ref().is_some_and(DetectChanges::is_changed);
+    // A removed quarantine must restore the cut links
+    let quarantine_changed = quarantine
+        .as_ref()
+        .map_or(*quarantined, DetectChanges::is_changed);
+    *quarantined = quarantine.is_some();
+    // `read` must be called to consume the events
+    let removed = removed.read().count() > 0;
+    if changed.is_empty() && !removed && !boundary_changed && !quarantine_changed {
+        return;
+    }
+    log::trace!("Rebuilding neighbor entities cache");
+    let lookup = NeighborLookup {
+        boundary: boundary.zip(map_size).map(|(b, s)| (*b, *s)),
+        quarantine: quarantine.as_deref(),
+        grid: None,
+    };
+    for (entity, cell, root) in &query {
+        let grid = root.map(|r| r.0);
+        let neighbors = cell
+            .neighbor_coordinates()
+            .into_iter()
+            .filter_map(|c| lookup.neighbor(cell.coords(), c))
+            .filter_map(|c| map.get_grid_cell(grid, &c))
+            // The map may still contain the entities removed this frame
+            .filter(|e| query.contains(*e))
+            .collect();
+        commands.entity(entity).insert(NeighborEntities(neighbors));
+    }
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::type_complexity)]
+pub fn handle_changed_cells<C>(
+    query: Query<(Entity, &C, Option<&GridRoot>), Or<(Changed<C>, Changed<GridRoot>)>>,
+    mut map: ResMut<CellMap<C>>,
+) where
+    C: Cell,
+{
+    for (entity, new_cell, root) in query.iter() {
+        let grid = root.map(|r| r.0);
+        let old_entity = map.insert_grid_cell(grid, new_cell.coords().clone(), entity);
         if let Some(e) = old_entity {
             if e != entity {
                 log::warn!(
@@ -85,3 +1166,268 @@ where
     log::trace!("Removing {} cells from cell map", removed_cells.len());
     map.remove_entities(removed_cells.read());
 }
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use crate::{CellState, CoupledCellState, CoupledCellularAutomatonPlugin, MooreCell2d};
+    use bevy::prelude::*;
+
+    /// Infected hosts, infected by the vector at their coordinates
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+    struct Host(bool);
+
+    /// Infected vectors, infected by their neighbors or by the host at their
+    /// coordinates
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+    struct Vector(bool);
+
+    impl CellState for Host {
+        fn new_cell_state<'a>(&self, _: impl Iterator<Item = &'a Self>) -> Self {
+            *self
+        }
+
+        #[cfg(feature = "auto-coloring")]
+        fn color(&self) -> Option<Color> {
+            None
+        }
+    }
+
+    impl CellState for Vector {
+        fn new_cell_state<'a>(&self, _: impl Iterator<Item = &'a Self>) -> Self {
+            *self
+        }
+
+        #[cfg(feature = "auto-coloring")]
+        fn color(&self) -> Option<Color> {
+            None
+        }
+    }
+
+    impl CoupledCellState<Vector> for Host {
+        fn new_cell_state_coupled<'a>(
+            &self,
+            _: impl Iterator<Item = &'a Self>,
+            vector: Option<&Vector>,
+        ) -> Self {
+            Self(self.0 || vector.is_some_and(|v| v.0))
+        }
+    }
+
+    impl CoupledCellState<Host> for Vector {
+        fn new_cell_state_coupled<'a>(
+            &self,
+            mut neighbor_cells: impl Iterator<Item = &'a Self>,
+            host: Option<&Host>,
+        ) -> Self {
+            Self(self.0 || host.is_some_and(|h| h.0) || neighbor_cells.any(|v| v.0))
+        }
+    }
+
+    #[test]
+    fn coupled_layers() {
+        let mut app = App::new();
+        app.add_plugins((
+            CoupledCellularAutomatonPlugin::<MooreCell2d, Host, Vector>::new(),
+            CoupledCellularAutomatonPlugin::<MooreCell2d, Vector, Host>::new(),
+        ));
+        // The first cell holds both layers, the other cells hold them on
+        // distinct entities
+        let first = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), Host(true), Vector(false)))
+            .id();
+        let hosts: Vec<_> = (1..3)
+            .map(|x| {
+                let coords = IVec2::new(x, 0);
+                app.world.spawn((MooreCell2d::new(coords), Vector(false)));
+                app.world
+                    .spawn((MooreCell2d::new(coords), Host(false)))
+                    .id()
+            })
+            .collect();
+        let infected = |app: &App| -> Vec<bool> {
+            hosts
+                .iter()
+                .map(|e| app.world.get::<Host>(*e).unwrap().0)
+                .collect()
+        };
+        // Both layers are updated from the previous generation
+        app.update();
+        assert_eq!(app.world.get::<Vector>(first), Some(&Vector(true)));
+        assert_eq!(infected(&app), vec![false, false]);
+        app.update();
+        assert_eq!(infected(&app), vec![false, false]);
+        app.update();
+        assert_eq!(infected(&app), vec![true, false]);
+        app.update();
+        assert_eq!(infected(&app), vec![true, true]);
+    }
+
+    #[test]
+    fn pipeline_sets() {
+        use crate::{
+            CellularAutomatonSet, ConwayCellState, GameOfLife2dPlugin, SimulationSet,
+            SimulationTick, StateBuffer,
+        };
+
+        #[derive(Debug, Default, Resource)]
+        struct Log(Vec<(&'static str, bool)>);
+
+        let log = |name| {
+            move |states: Query<&ConwayCellState>, mut log: ResMut<Log>| {
+                log.0.extend(states.iter().map(|s| (name, s.0)));
+            }
+        };
+        let mut app = App::new();
+        app.add_plugins(GameOfLife2dPlugin::new())
+            .init_resource::<Log>()
+            .add_systems(
+                SimulationTick,
+                (
+                    log("before")
+                        .in_set(SimulationSet::Tick)
+                        .before(CellularAutomatonSet::ComputeNewStates),
+                    log("after")
+                        .in_set(SimulationSet::Tick)
+                        .after(CellularAutomatonSet::ApplyNewStates),
+                    // Both states are visible between the computation and the
+                    // application of the new states
+                    (|cells: Query<(&ConwayCellState, &StateBuffer<ConwayCellState>)>,
+                      mut log: ResMut<Log>| {
+                        for (state, buffer) in &cells {
+                            if let Some(next) = &buffer.next {
+                                log.0.extend([("current", state.0), ("next", next.0)]);
+                            }
+                        }
+                    })
+                    .in_set(SimulationSet::Tick)
+                    .after(CellularAutomatonSet::ComputeNewStates)
+                    .before(CellularAutomatonSet::ApplyNewStates),
+                ),
+            );
+        // A lonely cell dies
+        app.world
+            .spawn((MooreCell2d::new(IVec2::ZERO), ConwayCellState(true)));
+        app.update();
+        assert_eq!(
+            app.world.resource::<Log>().0,
+            [
+                ("before", true),
+                ("current", true),
+                ("next", false),
+                ("after", false)
+            ]
+        );
+        let mut buffers = app.world.query::<&StateBuffer<ConwayCellState>>();
+        assert!(buffers.iter(&app.world).all(|buffer| buffer.next.is_none()));
+    }
+
+    #[test]
+    fn rebuilt_state_maps() {
+        use super::{DenseStateMap, StateMap};
+        use crate::{ConwayCellState, MapSize};
+        use bevy::{ecs::system::RunSystemOnce, utils::HashMap};
+
+        type Sparse = HashMap<IVec2, ConwayCellState>;
+
+        let mut world = World::new();
+        let cells: Vec<_> = (0..4)
+            .map(|x| {
+                let coords = IVec2::new(x, 0);
+                world
+                    .spawn((MooreCell2d::new(coords), ConwayCellState(x % 2 == 0)))
+                    .id()
+            })
+            .collect();
+        let size = Some(MapSize::new(4, 1));
+        let build = move |query: Query<(&MooreCell2d, &ConwayCellState)>| {
+            (
+                <Sparse as StateMap<MooreCell2d, _>>::build(query.iter(), size),
+                <DenseStateMap<_> as StateMap<MooreCell2d, _>>::build(query.iter(), size),
+            )
+        };
+        let (mut sparse, mut dense) = world.run_system_once(build);
+        world.entity_mut(cells[1]).insert(ConwayCellState(true));
+        world.despawn(cells[2]);
+        let (sparse, dense) =
+            world.run_system_once(move |query: Query<(&MooreCell2d, &ConwayCellState)>| {
+                StateMap::<MooreCell2d, _>::rebuild(&mut sparse, query.iter(), size);
+                StateMap::<MooreCell2d, _>::rebuild(&mut dense, query.iter(), size);
+                (sparse.clone(), dense.states.clone())
+            });
+        let (built_sparse, built_dense) = world.run_system_once(build);
+        assert_eq!(sparse, built_sparse);
+        assert_eq!(dense, built_dense.states);
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(
+            dense,
+            vec![
+                Some(ConwayCellState(true)),
+                Some(ConwayCellState(true)),
+                None,
+                Some(ConwayCellState(false))
+            ]
+        );
+    }
+
+    #[test]
+    fn reused_snapshots() {
+        use super::{GridCells, SnapshotBuffers};
+        use crate::{ConwayCellState, GridRoot};
+        use bevy::{ecs::system::RunSystemOnce, utils::HashMap};
+
+        type Sparse = HashMap<IVec2, ConwayCellState>;
+
+        #[derive(Default, Resource)]
+        struct Snapshots(SnapshotBuffers<Sparse>);
+
+        let mut world = World::new();
+        world.init_resource::<Snapshots>();
+        let root = world.spawn_empty().id();
+        let cells: Vec<_> = (0..64)
+            .map(|x| {
+                let cell = (MooreCell2d::new(IVec2::new(x, 0)), ConwayCellState(false));
+                match x % 2 {
+                    0 => world.spawn(cell).id(),
+                    _ => world.spawn((cell, GridRoot(root))).id(),
+                }
+            })
+            .collect();
+        // Capacities of the snapshots and addresses of the buckets of both
+        // grids
+        let tick = move |query: GridCells<MooreCell2d, ConwayCellState>,
+                         mut snapshots: ResMut<Snapshots>| {
+            let maps = snapshots.0.snapshot(&query, None);
+            let grids = [None, Some(root)].map(|grid| {
+                let map = maps.grid(grid).unwrap();
+                assert_eq!(map.len(), 32);
+                (map.capacity(), snapshots.0.buckets[&grid].as_ptr())
+            });
+            snapshots.0.recycle(maps);
+            grids
+        };
+        let first = world.run_system_once(tick);
+        for generation in 0..4 {
+            for (x, cell) in cells.iter().enumerate() {
+                world
+                    .entity_mut(*cell)
+                    .insert(ConwayCellState((x + generation) % 3 == 0));
+            }
+            assert_eq!(world.run_system_once(tick), first);
+        }
+        // Without any root, the default grid is rebuilt in place
+        for cell in &cells {
+            world.entity_mut(*cell).remove::<GridRoot>();
+        }
+        world.run_system_once(
+            move |query: GridCells<MooreCell2d, ConwayCellState>,
+                  mut snapshots: ResMut<Snapshots>| {
+                let maps = snapshots.0.snapshot(&query, None);
+                assert_eq!(maps.grid(None).unwrap().len(), 64);
+                assert!(maps.grid(Some(root)).is_none());
+                assert!(snapshots.0.buckets.is_empty());
+                snapshots.0.recycle(maps);
+            },
+        );
+    }
+}
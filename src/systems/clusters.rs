@@ -0,0 +1,60 @@
+use crate::{
+    components::{Cell, CellCoordinates, CellState},
+    resources::{BoundaryCondition, ClusterDetection, MapSize},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+
+/// Detects the clusters of the [`ClusterDetection`] resource, if any, every
+/// `interval` ticks.
+///
+/// The clusters are found with a flood fill through the neighbors of every
+/// matching cell.
+#[allow(clippy::needless_pass_by_value)]
+pub fn detect_clusters<C, S>(
+    cells: Query<(&C, &S)>,
+    detection: Option<ResMut<ClusterDetection<S>>>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+) where
+    C: Cell,
+    S: CellState,
+{
+    let Some(mut detection) = detection else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let tick = detection.tick;
+    detection.tick += 1;
+    if tick % u64::from(detection.interval.max(1)) != 0 {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let mut remaining: HashMap<C::Coordinates, &C> = cells
+        .iter()
+        .filter(|(_, state)| (detection.predicate)(state))
+        .map(|(cell, _)| (cell.coords().clone(), cell))
+        .collect();
+    let mut sizes = Vec::new();
+    while let Some(origin) = remaining.keys().next().cloned() {
+        let mut stack: Vec<_> = remaining.remove(&origin).into_iter().collect();
+        let mut size = 0;
+        while let Some(cell) = stack.pop() {
+            size += 1;
+            for neighbor in cell.neighbor_coordinates() {
+                let neighbor = match boundary {
+                    Some((condition, size)) => neighbor.apply_boundary(condition, size),
+                    None => Some(neighbor),
+                };
+                if let Some(neighbor) = neighbor.and_then(|n| remaining.remove(&n)) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+        sizes.push(size);
+    }
+    detection.set_clusters(tick, sizes);
+}
@@ -24,9 +24,10 @@ pub fn color_sprites<S>(
 ) where
     S: CellState,
 {
-    if batch.is_some() {
+    if let Some(batch) = batch {
         query
             .par_iter_mut()
+            .batching_strategy(batch.batching_strategy())
             .for_each(|(state, mut visible, mut sprite)| {
                 apply_color(state, &mut visible, &mut sprite);
             });
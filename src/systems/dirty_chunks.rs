@@ -0,0 +1,42 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::{BoundaryCondition, DirtyChunks, MapSize},
+    systems::{cells::bounded_neighbors, generation::PendingGenerations},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Marks the [`DirtyChunks`] of the cells whose state changed since the last
+/// tick, and of their neighbors, before the rules.
+///
+/// The dirty chunks of a generation computed across several ticks under a
+/// [`TickBudget`] accumulate until it is complete.
+///
+/// [`TickBudget`]: crate::TickBudget
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn track_dirty_chunks<C: Cell, S: CellState>(
+    changed: Query<&C, Or<(Changed<C>, Changed<S>)>>,
+    dirty: Option<ResMut<DirtyChunks<S>>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    pending: Option<Res<PendingGenerations>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    let Some(mut dirty) = dirty else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    // Every chunk stays dirty on the tick the tracking starts
+    if !dirty.is_added() && pending.is_none_or(|p| p.0 == 0) {
+        dirty.clear();
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    for cell in &changed {
+        dirty.mark(cell.coords());
+        for coords in bounded_neighbors(cell, boundary) {
+            dirty.mark(&coords);
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::{TimeSeriesExport, TimeSeriesRecorder},
+};
+use bevy::{app::AppExit, log, prelude::*};
+
+/// Writes the files of the [`TimeSeriesExport`] resource, if any, every
+/// `interval` ticks of the [`TimeSeriesRecorder`] and when the app exits.
+#[allow(clippy::needless_pass_by_value)]
+pub fn export_files<C, S>(
+    cells: Query<(&C, &S)>,
+    export: Option<Res<TimeSeriesExport<C, S>>>,
+    recorder: Option<Res<TimeSeriesRecorder<S>>>,
+    mut exit: EventReader<AppExit>,
+    mut exported_tick: Local<Option<u64>>,
+) where
+    C: Cell,
+    S: CellState,
+{
+    let exiting = exit.read().count() > 0;
+    let (Some(export), Some(recorder)) = (export, recorder) else {
+        return;
+    };
+    let Some((tick, _)) = recorder.last() else {
+        return;
+    };
+    let due = export.is_due(tick) && *exported_tick != Some(tick);
+    if !exiting && !due {
+        return;
+    }
+    *exported_tick = Some(tick);
+    let result = export
+        .write_time_series(&recorder)
+        .and_then(|_| export.write_snapshot(tick, &cells));
+    if let Err(error) = result {
+        log::error!("Failed to export the time series: {error}");
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{events::NewGenerationEvent, resources::Generation, SimulationPause};
+use bevy::prelude::*;
+
+/// Increments the [`Generation`] after the rules of a tick and sends the
+/// matching [`NewGenerationEvent`]
+#[allow(clippy::needless_pass_by_value)]
+pub fn advance_generation(
+    mut generation: ResMut<Generation>,
+    mut events: EventWriter<NewGenerationEvent>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    generation.0 += 1;
+    events.send(NewGenerationEvent {
+        generation: generation.0,
+    });
+}
+
+/// Number of generations computed across several ticks under a [`TickBudget`]
+/// and not completed yet
+///
+/// [`TickBudget`]: crate::TickBudget
+#[derive(Debug, Default, Resource)]
+pub struct PendingGenerations(pub usize);
+
+/// Run condition of the ticks completing a generation, outside of the
+/// generations computed across several ticks under a [`TickBudget`]
+///
+/// [`TickBudget`]: crate::TickBudget
+#[must_use]
+#[allow(clippy::needless_pass_by_value)]
+pub fn generation_complete(pending: Option<Res<PendingGenerations>>) -> bool {
+    pending.is_none_or(|p| p.0 == 0)
+}
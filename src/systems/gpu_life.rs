@@ -0,0 +1,259 @@
+use crate::{
+    resources::{BoundaryCondition, GpuLifeGrid},
+    SimulationPause,
+};
+use bevy::{
+    asset::load_internal_asset,
+    log,
+    prelude::*,
+    render::{
+        extract_resource::{ExtractResource, ExtractResourcePlugin},
+        graph::CameraDriverLabel,
+        render_asset::RenderAssets,
+        render_graph::{self, RenderGraph, RenderLabel},
+        render_resource::{
+            binding_types::{texture_2d, texture_storage_2d, uniform_buffer},
+            BindGroup, BindGroupEntries, BindGroupLayout, BindGroupLayoutEntries,
+            CachedComputePipelineId, ComputePassDescriptor, ComputePipelineDescriptor, Extent3d,
+            PipelineCache, ShaderStages, StorageTextureAccess, TextureFormat, TextureSampleType,
+            UniformBuffer,
+        },
+        renderer::{RenderContext, RenderDevice, RenderQueue},
+        Render, RenderApp, RenderSet,
+    },
+};
+use std::borrow::Cow;
+
+/// Compute shader applying the rule of the [`GpuLifeGrid`]
+const SHADER_HANDLE: Handle<Shader> =
+    Handle::weak_from_u128(0x2f1c_5b6e_9d84_4a37_b0e2_71c8_d5a9_3e60);
+/// Workgroup size of the compute shader, along both axes
+const WORKGROUP_SIZE: u32 = 8;
+
+/// Number of ticks of the [`GpuLifeGrid`] to run on the current frame
+#[derive(Debug, Default, Clone, Copy, Resource, ExtractResource)]
+pub struct GpuTicks(pub u32);
+
+/// Counts a tick of the [`GpuLifeGrid`], run by the render world on the
+/// current frame
+#[allow(clippy::needless_pass_by_value)]
+pub fn count_gpu_ticks(mut ticks: ResMut<GpuTicks>, pause: Option<Res<SimulationPause>>) {
+    if pause.is_some() {
+        return;
+    }
+    ticks.0 += 1;
+}
+
+/// Resets the [`GpuTicks`] of the previous frame, already extracted to the
+/// render world
+pub fn reset_gpu_ticks(mut ticks: ResMut<GpuTicks>) {
+    ticks.0 = 0;
+}
+
+pub use params::GpuLifeParams;
+
+mod params {
+    // The field checks generated by `ShaderType` are never called
+    #![allow(dead_code)]
+    use bevy::{math::UVec2, render::render_resource::ShaderType};
+
+    /// Uniform parameters of the compute shader
+    #[derive(Debug, Default, Clone, Copy, ShaderType)]
+    pub struct GpuLifeParams {
+        /// Size of the grid, in cells
+        pub size: UVec2,
+        /// Bitmask of the live neighbor counts giving birth to a dead cell
+        pub birth: u32,
+        /// Bitmask of the live neighbor counts keeping a live cell alive
+        pub survival: u32,
+        /// Condition applied on the grid edges: dead (0), wrap (1) or mirror
+        /// (2)
+        pub boundary: u32,
+    }
+}
+
+impl From<&GpuLifeGrid> for GpuLifeParams {
+    fn from(grid: &GpuLifeGrid) -> Self {
+        Self {
+            size: grid.size(),
+            birth: grid.rule.birth,
+            survival: grid.rule.survival,
+            boundary: match grid.boundary {
+                BoundaryCondition::FixedDead => 0,
+                BoundaryCondition::Wrap => 1,
+                BoundaryCondition::Mirror => 2,
+            },
+        }
+    }
+}
+
+/// Render world resource holding the compute pipeline
+#[derive(Debug, Resource)]
+pub struct GpuLifePipeline {
+    layout: BindGroupLayout,
+    pipeline: CachedComputePipelineId,
+}
+
+impl FromWorld for GpuLifePipeline {
+    fn from_world(world: &mut World) -> Self {
+        let layout = world.resource::<RenderDevice>().create_bind_group_layout(
+            "gpu_life_bind_group_layout",
+            &BindGroupLayoutEntries::sequential(
+                ShaderStages::COMPUTE,
+                (
+                    texture_2d(TextureSampleType::Float { filterable: false }),
+                    texture_storage_2d(TextureFormat::Rgba8Unorm, StorageTextureAccess::WriteOnly),
+                    uniform_buffer::<GpuLifeParams>(false),
+                ),
+            ),
+        );
+        let pipeline =
+            world
+                .resource::<PipelineCache>()
+                .queue_compute_pipeline(ComputePipelineDescriptor {
+                    label: Some("gpu_life_pipeline".into()),
+                    layout: vec![layout.clone()],
+                    push_constant_ranges: Vec::new(),
+                    shader: SHADER_HANDLE,
+                    shader_defs: Vec::new(),
+                    entry_point: Cow::from("update"),
+                });
+        Self { layout, pipeline }
+    }
+}
+
+/// Render world resource binding the textures and parameters of the
+/// [`GpuLifeGrid`]
+#[derive(Debug, Resource)]
+pub struct GpuLifeBindGroup(BindGroup);
+
+/// Writes the parameters of the [`GpuLifeGrid`] and binds its textures, once
+/// uploaded
+#[allow(clippy::needless_pass_by_value)]
+pub fn prepare_gpu_life_bind_group(
+    mut commands: Commands,
+    grid: Option<Res<GpuLifeGrid>>,
+    pipeline: Res<GpuLifePipeline>,
+    images: Res<RenderAssets<Image>>,
+    device: Res<RenderDevice>,
+    queue: Res<RenderQueue>,
+    mut params: Local<UniformBuffer<GpuLifeParams>>,
+) {
+    let Some(grid) = grid else {
+        commands.remove_resource::<GpuLifeBindGroup>();
+        return;
+    };
+    let (Some(image), Some(output)) = (images.get(grid.image()), images.get(grid.output())) else {
+        return;
+    };
+    params.set(GpuLifeParams::from(&*grid));
+    params.write_buffer(&device, &queue);
+    let Some(uniform) = params.binding() else {
+        return;
+    };
+    let bind_group = device.create_bind_group(
+        "gpu_life_bind_group",
+        &pipeline.layout,
+        &BindGroupEntries::sequential((&image.texture_view, &output.texture_view, uniform)),
+    );
+    commands.insert_resource(GpuLifeBindGroup(bind_group));
+}
+
+/// Render graph label of the [`GpuLifeNode`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, RenderLabel)]
+pub struct GpuLifeLabel;
+
+/// Render graph node running the [`GpuTicks`] of the frame, each tick writing
+/// the next generation to the output texture before copying it back to the
+/// displayed image
+#[derive(Debug, Default)]
+pub struct GpuLifeNode;
+
+impl render_graph::Node for GpuLifeNode {
+    fn run(
+        &self,
+        _graph: &mut render_graph::RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), render_graph::NodeRunError> {
+        let ticks = world.get_resource::<GpuTicks>().map_or(0, |ticks| ticks.0);
+        if ticks == 0 {
+            return Ok(());
+        }
+        let (Some(grid), Some(bind_group), Some(pipeline)) = (
+            world.get_resource::<GpuLifeGrid>(),
+            world.get_resource::<GpuLifeBindGroup>(),
+            world.get_resource::<GpuLifePipeline>(),
+        ) else {
+            return Ok(());
+        };
+        // Ticks queued while the shader is compiling are dropped
+        let Some(compute) = world
+            .resource::<PipelineCache>()
+            .get_compute_pipeline(pipeline.pipeline)
+        else {
+            return Ok(());
+        };
+        let images = world.resource::<RenderAssets<Image>>();
+        let (Some(image), Some(output)) = (images.get(grid.image()), images.get(grid.output()))
+        else {
+            return Ok(());
+        };
+        let size = grid.size();
+        let extent = Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        };
+        let encoder = render_context.command_encoder();
+        for _ in 0..ticks {
+            {
+                let mut pass = encoder.begin_compute_pass(&ComputePassDescriptor::default());
+                pass.set_bind_group(0, &bind_group.0, &[]);
+                pass.set_pipeline(compute);
+                pass.dispatch_workgroups(
+                    size.x.div_ceil(WORKGROUP_SIZE),
+                    size.y.div_ceil(WORKGROUP_SIZE),
+                    1,
+                );
+            }
+            encoder.copy_texture_to_texture(
+                output.texture.as_image_copy(),
+                image.texture.as_image_copy(),
+                extent,
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Registers the compute shader, the extraction of the [`GpuLifeGrid`] and
+/// the render graph node running its ticks, if the app renders
+pub fn build_gpu_life_render_app(app: &mut App) {
+    if !app.world.contains_resource::<Assets<Shader>>() {
+        log::warn!("No render plugin, the GPU life grid will not be simulated");
+        return;
+    }
+    load_internal_asset!(app, SHADER_HANDLE, "gpu_life.wgsl", Shader::from_wgsl);
+    app.add_plugins((
+        ExtractResourcePlugin::<GpuLifeGrid>::default(),
+        ExtractResourcePlugin::<GpuTicks>::default(),
+    ));
+    let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+        return;
+    };
+    render_app.add_systems(
+        Render,
+        prepare_gpu_life_bind_group.in_set(RenderSet::PrepareBindGroups),
+    );
+    let mut graph = render_app.world.resource_mut::<RenderGraph>();
+    graph.add_node(GpuLifeLabel, GpuLifeNode);
+    graph.add_node_edge(GpuLifeLabel, CameraDriverLabel);
+}
+
+/// Initializes the compute pipeline, once the render device exists
+pub fn finish_gpu_life_render_app(app: &mut App) {
+    if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+        render_app.init_resource::<GpuLifePipeline>();
+    }
+}
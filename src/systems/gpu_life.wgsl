@@ -0,0 +1,52 @@
+// Life-like rule of the `GpuLifePlugin`: reads the current grid from `input`
+// and writes the next generation to `output`, one invocation per cell.
+
+struct LifeParams {
+    size: vec2<u32>,
+    // Bit `n` is set if a count of `n` live neighbors gives birth or survives
+    birth: u32,
+    survival: u32,
+    // 0: fixed dead, 1: wrap, 2: mirror
+    boundary: u32,
+}
+
+@group(0) @binding(0) var input: texture_2d<f32>;
+@group(0) @binding(1) var output: texture_storage_2d<rgba8unorm, write>;
+@group(0) @binding(2) var<uniform> params: LifeParams;
+
+fn mirror_axis(value: i32, size: i32) -> i32 {
+    let period = ((value % (2 * size)) + 2 * size) % (2 * size);
+    return select(period, 2 * size - 1 - period, period >= size);
+}
+
+fn is_alive(location: vec2<i32>) -> u32 {
+    let size = vec2<i32>(params.size);
+    var coords = location;
+    if params.boundary == 1u {
+        coords = ((coords % size) + size) % size;
+    } else if params.boundary == 2u {
+        coords = vec2<i32>(mirror_axis(coords.x, size.x), mirror_axis(coords.y, size.y));
+    } else if any(coords < vec2<i32>(0)) || any(coords >= size) {
+        return 0u;
+    }
+    return u32(textureLoad(input, coords, 0).r > 0.5);
+}
+
+@compute @workgroup_size(8, 8, 1)
+fn update(@builtin(global_invocation_id) id: vec3<u32>) {
+    if any(id.xy >= params.size) {
+        return;
+    }
+    let location = vec2<i32>(id.xy);
+    var count = 0u;
+    for (var dy = -1; dy <= 1; dy += 1) {
+        for (var dx = -1; dx <= 1; dx += 1) {
+            if dx != 0 || dy != 0 {
+                count += is_alive(location + vec2<i32>(dx, dy));
+            }
+        }
+    }
+    let mask = select(params.birth, params.survival, is_alive(location) == 1u);
+    let alive = f32((mask >> count) & 1u);
+    textureStore(output, location, vec4<f32>(alive, alive, alive, 1.0));
+}
@@ -0,0 +1,49 @@
+use crate::{
+    components::{Cell, ConwayCellState},
+    resources::HashLife,
+    SimulationPause,
+};
+use bevy::{log, prelude::*, utils::HashSet};
+
+/// Fast-forwards the [`HashLife`] by `2^step` generations, clearing its cache
+/// once its nodes exceed the node budget
+#[allow(clippy::needless_pass_by_value)]
+pub fn step_hash_life(mut life: ResMut<HashLife>, pause: Option<Res<SimulationPause>>) {
+    if pause.is_some() {
+        return;
+    }
+    let step = life.step;
+    let result = life.fast_forward(step);
+    if let Err(error) = result {
+        log::error!("Failed to fast-forward the HashLife: {error}");
+    }
+    if result.is_err() || life.node_count() > life.node_budget {
+        log::debug!("Clearing the cache of {} HashLife nodes", life.node_count());
+        if let Err(error) = life.clear_cache() {
+            log::error!("Failed to clear the HashLife cache: {error}");
+        }
+    }
+}
+
+/// Re-materializes the live cells of the [`HashLife`] into the cell entities,
+/// spawning the missing live cells
+#[allow(clippy::needless_pass_by_value)]
+pub fn materialize_hash_life<C: Cell<Coordinates = IVec2> + From<IVec2>>(
+    mut commands: Commands,
+    mut cells: Query<(&C, &mut ConwayCellState)>,
+    life: Res<HashLife>,
+) {
+    if !life.is_changed() {
+        return;
+    }
+    let mut alive: HashSet<IVec2> = life.alive().into_iter().collect();
+    for (cell, mut state) in &mut cells {
+        let is_alive = alive.remove(cell.coords());
+        if state.0 != is_alive {
+            state.0 = is_alive;
+        }
+    }
+    for coords in alive {
+        commands.spawn((C::from(coords), ConwayCellState(true)));
+    }
+}
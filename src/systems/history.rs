@@ -0,0 +1,49 @@
+use crate::{
+    components::CellState,
+    resources::{Generation, SimulationHistory},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Rewinding functions of the recorded histories, called by the
+/// [`Rewind`](crate::Rewind) command
+#[derive(Debug, Default, Resource)]
+pub struct HistoryRewinds(pub Vec<fn(&mut World, u64)>);
+
+/// Records the cell states of the current [`Generation`] in the
+/// [`SimulationHistory`] resource, if any.
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_history<S: CellState>(
+    states: Query<(Entity, &S)>,
+    generation: Res<Generation>,
+    history: Option<ResMut<SimulationHistory<S>>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    let Some(mut history) = history else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let states = states.iter().map(|(entity, state)| (entity, state.clone()));
+    history.record(generation.0, states);
+}
+
+/// Rewinds the [`SimulationHistory`] of the `S` states, if any, by
+/// `generations`
+pub fn rewind_history<S: CellState>(world: &mut World, generations: u64) {
+    let current = world.get_resource::<Generation>().map_or(0, |g| g.0);
+    let Some(mut history) = world.get_resource_mut::<SimulationHistory<S>>() else {
+        return;
+    };
+    let Some((generation, states)) = history.rewind(current.saturating_sub(generations)) else {
+        return;
+    };
+    let states = states.to_vec();
+    for (entity, state) in states {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.insert(state);
+        }
+    }
+    world.insert_resource(Generation(generation));
+}
@@ -0,0 +1,32 @@
+use crate::{
+    components::SeverityCellState,
+    resources::{HospitalCapacity, SeverityParams},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Counts the occupied beds and the severe cells waiting for one in the
+/// [`HospitalCapacity`], then writes the resulting admission probability to
+/// the [`SeverityParams`].
+#[allow(clippy::needless_pass_by_value)]
+pub fn manage_hospitals(
+    states: Query<&SeverityCellState>,
+    capacity: Option<ResMut<HospitalCapacity>>,
+    params: Option<ResMut<SeverityParams>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let (Some(mut capacity), Some(mut params)) = (capacity, params) else {
+        return;
+    };
+    let (mut occupied, mut waiting) = (0, 0);
+    for state in &states {
+        occupied += u32::from(state.keeps_bed(&params));
+        waiting += u32::from(state.needs_bed(&params));
+    }
+    capacity.occupied = occupied;
+    capacity.waiting = waiting;
+    params.admission_probability = capacity.admission_probability();
+}
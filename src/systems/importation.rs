@@ -0,0 +1,89 @@
+use crate::{
+    components::{Cell, CellCoordinates, InfectableCellState},
+    resources::{
+        BoundaryCondition, CellRng, Importation, ImportationSchedule, ImportationTarget, MapSize,
+        SimulationRng,
+    },
+    SimulationPause,
+};
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use rand::seq::SliceRandom;
+use rand_distr::{Distribution, Poisson};
+
+/// Seeds the infections of the [`Importation`] resource, if any.
+///
+/// Border cells are the cells missing some of their neighbors, there are none
+/// on wrapped grids.
+#[allow(
+    clippy::needless_pass_by_value,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn import_infections<C, S>(
+    mut cells: Query<(&C, &mut S)>,
+    importation: Option<ResMut<Importation<C>>>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) where
+    C: Cell,
+    S: InfectableCellState,
+{
+    let Some(mut importation) = importation else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let mut rng = CellRng;
+    let tick = importation.tick;
+    importation.tick += 1;
+    let importations = match importation.schedule {
+        ImportationSchedule::Rate(rate) => {
+            Poisson::new(rate).map_or(0, |p| p.sample(&mut rng) as u32)
+        }
+        ImportationSchedule::Every(ticks) => u32::from(tick % u64::from(ticks.max(1)) == 0),
+    };
+    if importations == 0 {
+        return;
+    }
+    let candidates: Vec<C::Coordinates> = match &importation.target {
+        ImportationTarget::Anywhere => cells.iter().map(|(c, _)| c.coords().clone()).collect(),
+        ImportationTarget::Cells(coords) => coords.clone(),
+        ImportationTarget::Border => {
+            let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+            let coords: HashSet<_> = cells.iter().map(|(c, _)| c.coords().clone()).collect();
+            cells
+                .iter()
+                .filter(|(cell, _)| {
+                    cell.neighbor_coordinates().into_iter().any(|c| {
+                        match boundary {
+                            Some((condition, size)) => c.apply_boundary(condition, size),
+                            None => Some(c),
+                        }
+                        .is_none_or(|c| !coords.contains(&c))
+                    })
+                })
+                .map(|(c, _)| c.coords().clone())
+                .collect()
+        }
+    };
+    let mut cases: HashMap<C::Coordinates, u32> = HashMap::new();
+    for _ in 0..importations {
+        if let Some(coords) = candidates.choose(&mut rng) {
+            let count = cases.entry(coords.clone()).or_default();
+            *count = count.saturating_add(importation.cases);
+        }
+    }
+    for (cell, mut state) in &mut cells {
+        if let Some(count) = cases.get(cell.coords()) {
+            let infected = state.import_infection(*count);
+            state.set_if_neq(infected);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{resources::InterventionTimeline, SimulationPause};
+use bevy::prelude::*;
+
+/// Runs the interventions of the current [`InterventionTimeline`] tick, then
+/// moves on to the next tick.
+pub fn run_interventions(world: &mut World) {
+    if world.contains_resource::<SimulationPause>() {
+        return;
+    }
+    let Some(mut timeline) = world.get_resource_mut::<InterventionTimeline>() else {
+        return;
+    };
+    for intervention in timeline.take_due() {
+        intervention(world);
+    }
+    if let Some(mut timeline) = world.get_resource_mut::<InterventionTimeline>() {
+        timeline.tick += 1;
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    components::{Cell, CoarseCellState, GridRoot},
+    resources::{LevelOfDetail, SimulationRng},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+
+/// Entities, coordinates and states of the cells of a region to coarsen
+type CoarseCells<'a, S> = (Vec<Entity>, Vec<IVec2>, Vec<&'a S>);
+
+/// Moves the focus of the [`LevelOfDetail`] with the cameras, if enabled,
+/// coarsens the cells of the regions far from the focus and refines the
+/// super-cells near the focus back into cells of the same grid
+#[allow(clippy::needless_pass_by_value)]
+pub fn update_level_of_detail<C, S>(
+    mut commands: Commands,
+    cells: Query<(Entity, &C, &S, Option<&GridRoot>)>,
+    cameras: Query<&GlobalTransform, With<Camera>>,
+    mut lod: ResMut<LevelOfDetail<S>>,
+) where
+    C: Cell<Coordinates = IVec2> + From<IVec2>,
+    S: CoarseCellState,
+{
+    if let Some(cell_size) = lod.camera_cell_size {
+        lod.focus = cameras
+            .iter()
+            .map(|transform| (transform.translation().truncate() / cell_size).round())
+            .map(|focus| focus.as_ivec2())
+            .collect();
+    }
+    let mut coarse: HashMap<(Option<Entity>, IVec2), CoarseCells<S>> = HashMap::default();
+    for (entity, cell, state, root) in &cells {
+        let region = lod.region(*cell.coords());
+        if !lod.is_detailed(region) {
+            let grid = root.map(|r| r.0);
+            let (entities, coords, states) = coarse.entry((grid, region)).or_default();
+            entities.push(entity);
+            coords.push(*cell.coords());
+            states.push(state);
+        }
+    }
+    for ((grid, region), (entities, coords, states)) in coarse {
+        lod.coarsen_grid(grid, region, coords, &states);
+        for entity in entities {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+    let refined: Vec<_> = lod
+        .grid_super_cells()
+        .map(|(grid, region, _)| (grid, region))
+        .filter(|(_, region)| lod.is_detailed(*region))
+        .collect();
+    for (grid, region) in refined {
+        for (coords, state) in lod.refine_grid(grid, region).unwrap_or_default() {
+            let mut cell = commands.spawn((C::from(coords), state));
+            if let Some(root) = grid {
+                cell.insert(GridRoot(root));
+            }
+            if let Some(on_spawn) = lod.on_spawn {
+                on_spawn(coords, &mut cell);
+            }
+        }
+    }
+}
+
+/// Computes the next state of every super-cell of the [`LevelOfDetail`]
+#[allow(clippy::needless_pass_by_value)]
+pub fn step_super_cells<S: CoarseCellState>(
+    mut lod: ResMut<LevelOfDetail<S>>,
+    pause: Option<Res<SimulationPause>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    lod.step();
+}
@@ -1,3 +1,41 @@
+#[cfg(feature = "2D")]
+pub mod ants;
+pub mod bitboard;
 pub mod cells;
+pub mod clusters;
 #[cfg(feature = "auto-coloring")]
 pub mod coloring;
+pub mod dirty_chunks;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+pub mod export;
+pub mod generation;
+#[cfg(feature = "gpu")]
+pub mod gpu_life;
+pub mod hash_life;
+pub mod history;
+pub mod hospitals;
+pub mod importation;
+pub mod interventions;
+pub mod level_of_detail;
+pub mod moving;
+pub mod pausing;
+#[cfg(feature = "egui-plot")]
+pub mod plotting;
+pub mod recording;
+pub mod region_of_interest;
+pub mod reiter;
+pub mod relocation;
+pub mod sandpile;
+#[cfg(all(feature = "1D", feature = "auto-coloring"))]
+pub mod scrolling;
+pub mod seasons;
+pub mod sleeping;
+pub mod sparse;
+pub mod speed;
+pub mod state_events;
+pub mod state_hash;
+pub mod termination;
+pub mod time_series;
+#[cfg(feature = "1D")]
+pub mod traffic;
+pub mod vaccination;
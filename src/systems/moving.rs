@@ -0,0 +1,80 @@
+use crate::{
+    components::{Cell, CellCoordinates, CellMove, GridRoot, MovingCellState},
+    resources::{BoundaryCondition, CellMap, CellRng, MapSize, SimulationRng},
+    systems::cells::NeighborBuffer,
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashSet};
+use rand::seq::SliceRandom;
+
+/// Applies the [`MovingCellState`] rules to every cell, one after the other in
+/// a random order. With `SWEEP`, the cells are swept row by row from the
+/// bottom up, in a random order within each row.
+///
+/// Every rule sees the states already updated during the tick, and the cells
+/// claimed by a move are skipped.
+#[allow(clippy::needless_pass_by_value)]
+pub fn handle_moving_cells<C, S, const SWEEP: bool>(
+    cells: Query<(Entity, &C, Option<&GridRoot>)>,
+    mut states: Query<&mut S>,
+    map: Res<CellMap<C>>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) where
+    C: Cell,
+    S: MovingCellState,
+{
+    if pause.is_some() {
+        return;
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let mut order: Vec<_> = cells.iter().collect();
+    order.shuffle(&mut CellRng);
+    if SWEEP {
+        // The stable sort keeps the random order within rows
+        order.sort_by_key(|(_, cell, _)| cell.coords().sweep_row());
+    }
+    let mut claimed = HashSet::new();
+    for (entity, cell, root) in order {
+        if claimed.contains(&entity) {
+            continue;
+        }
+        let Ok(state) = states.get(entity) else {
+            continue;
+        };
+        // The neighbors borrow the states until the move is known
+        let (origin, target) = {
+            let neighbors: NeighborBuffer<_> = cell
+                .neighbor_coordinates()
+                .into_iter()
+                .map(|c| {
+                    let c = match boundary {
+                        Some((condition, size)) => c.apply_boundary(condition, size)?,
+                        None => c,
+                    };
+                    let entity = map.get_grid_cell(root.map(|r| r.0), &c)?;
+                    Some((entity, states.get(entity).ok()?))
+                })
+                .collect();
+            let neighbor_states: NeighborBuffer<_> =
+                neighbors.iter().map(|n| n.map(|(_, s)| s)).collect();
+            let CellMove { origin, target } = state.move_cell_rng(&neighbor_states, &mut CellRng);
+            let target = target.and_then(|(i, target)| Some(((*neighbors.get(i)?)?.0, target)));
+            (origin, target)
+        };
+        if let Ok(mut state) = states.get_mut(entity) {
+            if *state != origin {
+                *state = origin;
+            }
+        }
+        if let Some((target_entity, target)) = target {
+            if let Ok(mut state) = states.get_mut(target_entity) {
+                *state = target;
+            }
+            claimed.insert(target_entity);
+        }
+    }
+}
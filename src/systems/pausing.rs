@@ -0,0 +1,19 @@
+use crate::resources::SimulationState;
+use bevy::prelude::*;
+
+/// Builds a system toggling the [`SimulationState`] when `key` is pressed, if
+/// the keyboard input is available
+#[allow(clippy::type_complexity)]
+pub fn toggle_simulation_state(
+    key: KeyCode,
+) -> impl FnMut(
+    Option<Res<ButtonInput<KeyCode>>>,
+    Res<State<SimulationState>>,
+    ResMut<NextState<SimulationState>>,
+) {
+    move |keys, state, mut next_state| {
+        if keys.is_some_and(|keys| keys.just_pressed(key)) {
+            next_state.set(state.get().toggled());
+        }
+    }
+}
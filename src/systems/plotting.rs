@@ -0,0 +1,36 @@
+use crate::{
+    components::CellState,
+    resources::{TimeSeriesPlot, TimeSeriesRecorder},
+};
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+use egui_plot::{Legend, Line, Plot, PlotPoints};
+
+/// Draws the [`TimeSeriesPlot`] overlay of the [`TimeSeriesRecorder`]
+/// resource, if any.
+#[allow(clippy::needless_pass_by_value)]
+pub fn plot_time_series<S>(
+    mut contexts: EguiContexts,
+    plot: Res<TimeSeriesPlot<S>>,
+    recorder: Option<Res<TimeSeriesRecorder<S>>>,
+) where
+    S: CellState,
+{
+    let Some(recorder) = recorder else {
+        return;
+    };
+    if !plot.visible {
+        return;
+    }
+    let lines = plot.lines(&recorder);
+    egui::Window::new(&plot.title).show(contexts.ctx_mut(), |ui| {
+        Plot::new(&plot.title)
+            .legend(Legend::default())
+            .view_aspect(2.0)
+            .show(ui, |plot_ui| {
+                for (name, points) in lines {
+                    plot_ui.line(Line::new(PlotPoints::from(points)).name(name));
+                }
+            });
+    });
+}
@@ -0,0 +1,147 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::{Generation, Playback, Recording},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Records the changed cell states of the current [`Generation`] in the
+/// [`Recording`] resource, if any.
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn record_cells<C: Cell, S: CellState>(
+    cells: Query<(&C, &S), Or<(Changed<C>, Changed<S>)>>,
+    generation: Res<Generation>,
+    recording: Option<ResMut<Recording<C, S>>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    let Some(mut recording) = recording else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let changes = cells
+        .iter()
+        .map(|(cell, state)| (cell.coords().clone(), state.clone()));
+    recording.record(generation.0, changes);
+}
+
+/// Advances the [`Playback`] resource, if any, while playing
+pub fn advance_playback(playback: Option<ResMut<Playback>>) {
+    if let Some(mut playback) = playback.filter(|p| p.playing) {
+        playback.generation += 1;
+    }
+}
+
+/// Applies the states of the [`Playback`] generation from the [`Recording`]
+/// resource to the cells, stopping the playback at the last recorded
+/// generation.
+#[allow(clippy::needless_pass_by_value)]
+pub fn apply_playback<C: Cell, S: CellState>(
+    mut cells: Query<(&C, &mut S)>,
+    recording: Option<Res<Recording<C, S>>>,
+    playback: Option<ResMut<Playback>>,
+    generation: Option<ResMut<Generation>>,
+    mut shown: Local<Option<u64>>,
+) {
+    let (Some(recording), Some(mut playback)) = (recording, playback) else {
+        return;
+    };
+    let Some(last) = recording.last_generation() else {
+        return;
+    };
+    if recording.is_changed() {
+        *shown = None;
+    }
+    if playback.generation > last {
+        playback.generation = last;
+        playback.playing = false;
+    }
+    let target = playback.generation;
+    if *shown == Some(target) {
+        return;
+    }
+    // Moving forward only replays the changes since the shown generation
+    let states = match *shown {
+        Some(shown) if shown < target => recording.changes_between(Some(shown), target),
+        _ => recording.states_at(target),
+    };
+    for (cell, mut state) in &mut cells {
+        if let Some(new_state) = states.get(cell.coords()) {
+            if *state != *new_state {
+                *state = new_state.clone();
+            }
+        }
+    }
+    *shown = Some(target);
+    if let Some(mut generation) = generation {
+        generation.0 = target;
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use crate::*;
+    use bevy::prelude::*;
+
+    fn spawn_cells(app: &mut App, alive: impl Fn(IVec2) -> bool) -> Vec<Entity> {
+        (-1..=1)
+            .flat_map(|y| (-1..=1).map(move |x| IVec2::new(x, y)))
+            .map(|pos| {
+                let state = ConwayCellState(alive(pos));
+                app.world.spawn((MooreCell2d::new(pos), state)).id()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn replayed_blinker() {
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::new(),
+            RecordingPlugin::<MooreCell2d, ConwayCellState>::new(),
+        ))
+        .init_resource::<Recording<MooreCell2d, ConwayCellState>>();
+        spawn_cells(&mut app, |pos| pos.y == 0);
+        for _ in 0..3 {
+            app.update();
+        }
+        let recording = app
+            .world
+            .remove_resource::<Recording<MooreCell2d, ConwayCellState>>()
+            .unwrap();
+        assert_eq!(recording.last_generation(), Some(3));
+        assert_eq!(recording.changes(0).map(<[_]>::len), Some(9));
+        // Two cells die and two are born every generation
+        assert_eq!(recording.changes(2).map(<[_]>::len), Some(4));
+
+        let mut app = App::new();
+        app.add_plugins(PlaybackPlugin::<MooreCell2d, ConwayCellState>::new())
+            .insert_resource(recording);
+        let cells = spawn_cells(&mut app, |_| false);
+        let alive = |app: &App| -> Vec<bool> {
+            cells
+                .iter()
+                .map(|e| app.world.get::<ConwayCellState>(*e).unwrap().0)
+                .collect()
+        };
+        let horizontal: Vec<_> = (0..9).map(|i| i / 3 == 1).collect();
+        let vertical: Vec<_> = (0..9).map(|i| i % 3 == 1).collect();
+        app.update();
+        assert_eq!(alive(&app), vertical);
+        app.update();
+        assert_eq!(alive(&app), horizontal);
+        app.world.resource_mut::<Playback>().step_backward(1);
+        app.update();
+        assert_eq!(alive(&app), vertical);
+        assert_eq!(app.world.resource::<Generation>().0, 1);
+        app.world.resource_mut::<Playback>().seek(0);
+        app.update();
+        assert_eq!(alive(&app), horizontal);
+        // Scrubbing past the end stops at the last generation
+        app.world.resource_mut::<Playback>().seek(10);
+        app.update();
+        assert_eq!(alive(&app), vertical);
+        assert_eq!(app.world.resource::<Playback>().generation, 3);
+    }
+}
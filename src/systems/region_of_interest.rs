@@ -0,0 +1,27 @@
+use crate::resources::RegionOfInterest;
+use bevy::prelude::*;
+
+/// Moves the rect of the [`RegionOfInterest`], if it follows the cameras, to
+/// the cells drawn by the active orthographic cameras
+#[allow(clippy::needless_pass_by_value)]
+pub fn follow_region_of_interest(
+    cameras: Query<(&Camera, &GlobalTransform, &OrthographicProjection)>,
+    mut interest: ResMut<RegionOfInterest>,
+) {
+    let Some(cell_size) = interest.camera_cell_size else {
+        return;
+    };
+    let rect = cameras
+        .iter()
+        .filter(|(camera, _, _)| camera.is_active)
+        .map(|(_, transform, projection)| {
+            let center = transform.translation().truncate();
+            let min = ((projection.area.min + center) / cell_size).floor();
+            let max = ((projection.area.max + center) / cell_size).ceil();
+            IRect::from_corners(min.as_ivec2(), max.as_ivec2())
+        })
+        .reduce(|a, b| a.union(b));
+    if let Some(rect) = rect {
+        interest.rect = rect;
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    components::{Cell, CellCoordinates, ReiterState},
+    resources::{BoundaryCondition, MapSize, ReiterParams},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+
+/// Applies the [`ReiterState`] snowflake growth rules to every cell, missing
+/// neighbors having the background vapor level.
+#[allow(clippy::needless_pass_by_value, clippy::cast_precision_loss)]
+pub fn handle_reiter<C: Cell>(
+    mut cells: Query<(&C, &mut ReiterState)>,
+    params: Res<ReiterParams>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let states: HashMap<C::Coordinates, ReiterState> = cells
+        .iter()
+        .map(|(cell, state)| (cell.coords().clone(), *state))
+        .collect();
+    let receptive: HashMap<C::Coordinates, bool> = cells
+        .iter()
+        .map(|(cell, state)| {
+            let receptive = state.is_frozen()
+                || neighbors(cell, boundary)
+                    .flatten()
+                    .any(|c| states.get(&c).is_some_and(|s| s.is_frozen()));
+            (cell.coords().clone(), receptive)
+        })
+        .collect();
+    // Diffusing vapor of a cell, missing cells being in the background
+    let vapor = |coords: Option<&C::Coordinates>| match coords {
+        Some(c) if receptive.get(c).copied().unwrap_or(false) => 0.0,
+        Some(c) => states.get(c).map_or(params.beta, |s| s.0),
+        None => params.beta,
+    };
+    for (cell, mut state) in &mut cells {
+        let coords = cell.coords();
+        let (vapor_sum, count) = neighbors(cell, boundary)
+            .fold((0.0, 0_usize), |(sum, count), c| {
+                (sum + vapor(c.as_ref()), count + 1)
+            });
+        let average = vapor_sum / count.max(1) as f32;
+        let (diffusing, static_water) = if receptive[coords] {
+            (0.0, state.0 + params.gamma)
+        } else {
+            (state.0, 0.0)
+        };
+        let diffused = (params.alpha / 2.0).mul_add(average - diffusing, diffusing);
+        let new_state = ReiterState(diffused + static_water);
+        if *state != new_state {
+            *state = new_state;
+        }
+    }
+}
+
+/// Retrieves the neighbor coordinates of `cell`, the neighbors outside of a
+/// bounded grid being `None`
+fn neighbors<C: Cell>(
+    cell: &C,
+    boundary: Option<(BoundaryCondition, MapSize)>,
+) -> impl Iterator<Item = Option<C::Coordinates>> + '_ {
+    cell.neighbor_coordinates()
+        .into_iter()
+        .map(move |c| match boundary {
+            Some((condition, size)) => c.apply_boundary(condition, size),
+            None => Some(c),
+        })
+}
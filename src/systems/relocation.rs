@@ -0,0 +1,62 @@
+use crate::{
+    components::{Cell, CellCoordinates, RelocatingCellState},
+    resources::{BoundaryCondition, CellRng, MapSize, SimulationRng},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+use rand::seq::SliceRandom;
+
+/// Swaps every unsatisfied [`RelocatingCellState`] with a random vacant cell.
+///
+/// Satisfaction is evaluated on the states before any relocation. When there
+/// are more unsatisfied cells than vacant ones, a random subset of them
+/// relocates.
+#[allow(clippy::needless_pass_by_value)]
+pub fn relocate_cells<C, S>(
+    mut cells: Query<(Entity, &C, &mut S)>,
+    params: Res<S::Params>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) where
+    C: Cell,
+    S: RelocatingCellState,
+{
+    if pause.is_some() {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let states: HashMap<C::Coordinates, S> = cells
+        .iter()
+        .map(|(_, cell, state)| (cell.coords().clone(), state.clone()))
+        .collect();
+    let mut vacant = Vec::new();
+    let mut unsatisfied = Vec::new();
+    for (entity, cell, state) in &cells {
+        if state.is_vacant() {
+            vacant.push(entity);
+            continue;
+        }
+        let neighbors = cell
+            .neighbor_coordinates()
+            .into_iter()
+            .filter_map(|c| match boundary {
+                Some((condition, size)) => c.apply_boundary(condition, size),
+                None => Some(c),
+            })
+            .filter_map(|c| states.get(&c));
+        if !state.is_satisfied(neighbors, &params) {
+            unsatisfied.push(entity);
+        }
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let mut rng = CellRng;
+    vacant.shuffle(&mut rng);
+    unsatisfied.shuffle(&mut rng);
+    for pair in unsatisfied.into_iter().zip(vacant) {
+        if let Ok([(_, _, mut from), (_, _, mut to)]) = cells.get_many_mut(pair.into()) {
+            std::mem::swap(&mut *from, &mut *to);
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    components::{Cell, SandpileState},
+    resources::{BoundaryCondition, CellRng, MapSize, SandpileDrop, SimulationRng},
+    systems::cells::bounded_neighbors,
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+use rand::seq::IteratorRandom;
+
+/// Drops the grains of the [`SandpileDrop`] resource, if any
+#[allow(clippy::needless_pass_by_value)]
+pub fn drop_grains<C: Cell>(
+    mut cells: Query<(&C, &mut SandpileState)>,
+    drop: Option<Res<SandpileDrop<C>>>,
+    pause: Option<Res<SimulationPause>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) {
+    let Some(drop) = drop else {
+        return;
+    };
+    if pause.is_some() || drop.grains == 0 {
+        return;
+    }
+    if let Some(coords) = &drop.coords {
+        if let Some((_, mut state)) = cells.iter_mut().find(|(c, _)| c.coords() == coords) {
+            state.0 += drop.grains;
+        }
+        return;
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let mut rng = CellRng;
+    for _ in 0..drop.grains {
+        if let Some((_, mut state)) = cells.iter_mut().choose(&mut rng) {
+            state.0 += 1;
+        }
+    }
+}
+
+/// Topples every unstable [`SandpileState`] until the whole pile is stable,
+/// resolving complete avalanches within a single tick.
+///
+/// Since the sandpile is abelian, the toppling order doesn't change the
+/// resulting stable pile.
+#[allow(clippy::needless_pass_by_value)]
+pub fn topple_sandpile<C: Cell>(
+    mut cells: Query<(&C, &mut SandpileState)>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let mut grains: HashMap<C::Coordinates, u32> = cells
+        .iter()
+        .map(|(cell, state)| (cell.coords().clone(), state.0))
+        .collect();
+    let mut stack: Vec<_> = cells
+        .iter()
+        .filter(|(_, state)| !state.is_stable())
+        .map(|(cell, _)| cell.coords().clone())
+        .collect();
+    if stack.is_empty() {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    {
+        let cells_at: HashMap<C::Coordinates, &C> = cells
+            .iter()
+            .map(|(cell, _)| (cell.coords().clone(), cell))
+            .collect();
+        while let Some(coords) = stack.pop() {
+            let Some(amount) = grains.get_mut(&coords) else {
+                continue;
+            };
+            let topples = *amount / SandpileState::THRESHOLD;
+            if topples == 0 {
+                continue;
+            }
+            *amount %= SandpileState::THRESHOLD;
+            for neighbor in bounded_neighbors(cells_at[&coords], boundary) {
+                if let Some(amount) = grains.get_mut(&neighbor) {
+                    *amount += topples;
+                    if *amount >= SandpileState::THRESHOLD {
+                        stack.push(neighbor);
+                    }
+                }
+            }
+        }
+    }
+    for (cell, mut state) in &mut cells {
+        let amount = grains[cell.coords()];
+        if state.0 != amount {
+            state.0 = amount;
+        }
+    }
+}
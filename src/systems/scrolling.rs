@@ -0,0 +1,49 @@
+use crate::{
+    components::{Cell, CellState, GenerationTrail},
+    resources::GenerationScroll,
+};
+use bevy::prelude::*;
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn scroll_generations<C, S>(
+    mut commands: Commands,
+    scroll: Res<GenerationScroll>,
+    changed: Query<(), (With<C>, Changed<S>)>,
+    cells: Query<(&S, &Sprite, &Transform, Option<&Parent>), With<C>>,
+    mut trails: Query<(Entity, &mut GenerationTrail, &mut Transform), Without<C>>,
+) where
+    C: Cell,
+    S: CellState,
+{
+    if changed.is_empty() {
+        return;
+    }
+    for (entity, mut trail, mut transform) in &mut trails {
+        trail.age += 1;
+        if trail.age > scroll.max_rows {
+            commands.entity(entity).despawn();
+        } else {
+            transform.translation.y -= scroll.row_height;
+        }
+    }
+    for (state, sprite, transform, parent) in &cells {
+        let Some(color) = state.color() else {
+            continue;
+        };
+        let mut cmd = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: sprite.custom_size,
+                    ..default()
+                },
+                transform: transform.with_translation(transform.translation - Vec3::Z),
+                ..default()
+            },
+            GenerationTrail { age: 0 },
+        ));
+        if let Some(parent) = parent {
+            cmd.set_parent(parent.get());
+        }
+    }
+}
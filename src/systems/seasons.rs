@@ -0,0 +1,23 @@
+use crate::{
+    resources::{EpidemicParams, SeasonalForcing},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Writes the current [`SeasonalForcing`] multiplier to the
+/// [`EpidemicParams`] seasonality, then moves on to the next generation.
+#[allow(clippy::needless_pass_by_value)]
+pub fn force_seasons(
+    forcing: Option<ResMut<SeasonalForcing>>,
+    params: Option<ResMut<EpidemicParams>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let (Some(mut forcing), Some(mut params)) = (forcing, params) else {
+        return;
+    };
+    params.seasonality = forcing.multiplier(forcing.generation);
+    forcing.generation += 1;
+}
@@ -0,0 +1,48 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::{BoundaryCondition, MapSize, SleepingCells},
+    systems::{cells::bounded_neighbors, generation::PendingGenerations},
+    SimulationPause,
+};
+use bevy::prelude::*;
+
+/// Wakes the [`SleepingCells`] whose state changed since the last tick, or
+/// matches the `keep_awake` predicate, and their neighbors, before the rules.
+///
+/// The awake cells of a generation computed across several ticks under a
+/// [`TickBudget`] accumulate until it is complete.
+///
+/// [`TickBudget`]: crate::TickBudget
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+pub fn track_sleeping_cells<C: Cell, S: CellState>(
+    changed: Query<&C, Or<(Changed<C>, Changed<S>)>>,
+    cells: Query<(&C, &S)>,
+    sleeping: Option<ResMut<SleepingCells<C, S>>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    pending: Option<Res<PendingGenerations>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    let Some(mut sleeping) = sleeping else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    // Every cell stays awake on the tick the tracking starts
+    if !sleeping.is_added() && pending.is_none_or(|p| p.0 == 0) {
+        sleeping.clear();
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let keep_awake = sleeping.keep_awake;
+    let restless = cells
+        .iter()
+        .filter(|(_, state)| keep_awake.is_some_and(|predicate| predicate(state)))
+        .map(|(cell, _)| cell);
+    for cell in changed.iter().chain(restless) {
+        sleeping.wake(cell.coords().clone());
+        for coords in bounded_neighbors(cell, boundary) {
+            sleeping.wake(coords);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::components::{Cell, CellState, GridRoot};
+use bevy::{
+    log,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+/// Spawns the missing neighbors of every live cell, in its grid, and despawns
+/// the dead cells without any live neighbor. A cell is dead when its state is
+/// the default state.
+#[allow(clippy::needless_pass_by_value)]
+pub fn expand_sparse_cells<C, S>(
+    mut commands: Commands,
+    query: Query<(Entity, &C, &S, Option<&GridRoot>)>,
+    changed: Query<(), Changed<S>>,
+) where
+    C: Cell + From<C::Coordinates>,
+    S: CellState + Default,
+{
+    if changed.is_empty() {
+        return;
+    }
+    let dead = S::default();
+    let states: HashMap<_, _> = query
+        .iter()
+        .map(|(_entity, cell, state, root)| ((root.map(|r| r.0), cell.coords().clone()), state))
+        .collect();
+    let mut spawned = HashSet::new();
+    for (entity, cell, state, root) in &query {
+        let grid = root.map(|r| r.0);
+        if *state == dead {
+            let isolated = cell
+                .neighbor_coordinates()
+                .into_iter()
+                .all(|c| states.get(&(grid, c)).is_none_or(|s| **s == dead));
+            if isolated {
+                commands.entity(entity).despawn_recursive();
+            }
+            continue;
+        }
+        for coords in cell.neighbor_coordinates() {
+            let key = (grid, coords);
+            if !states.contains_key(&key) && spawned.insert(key.clone()) {
+                let mut spawned_cell = commands.spawn((C::from(key.1), dead.clone()));
+                if let Some(root) = root {
+                    spawned_cell.insert(*root);
+                }
+            }
+        }
+    }
+    log::trace!("Spawned {} sparse cells", spawned.len());
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use crate::{ConwayCellState, GameOfLife2dPlugin, GridRoot, InfiniteGridPlugin, MooreCell2d};
+    use bevy::{prelude::*, utils::HashSet};
+
+    fn alive_cells(app: &mut App) -> HashSet<IVec2> {
+        app.world
+            .query::<(&MooreCell2d, &ConwayCellState)>()
+            .iter(&app.world)
+            .filter(|(_, state)| state.0)
+            .map(|(cell, _)| cell.coords)
+            .collect()
+    }
+
+    #[test]
+    fn traveling_glider() {
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::default(),
+            InfiniteGridPlugin::<MooreCell2d, ConwayCellState>::default(),
+        ));
+        let glider: HashSet<_> = [(1, 2), (2, 1), (0, 0), (1, 0), (2, 0)]
+            .into_iter()
+            .map(IVec2::from)
+            .collect();
+        for coords in &glider {
+            app.world
+                .spawn((MooreCell2d::new(*coords), ConwayCellState(true)));
+        }
+        for generation in 1..=40 {
+            app.update();
+            if generation % 4 == 0 {
+                let offset = IVec2::new(1, -1) * (generation / 4);
+                let expected: HashSet<_> = glider.iter().map(|c| *c + offset).collect();
+                assert_eq!(alive_cells(&mut app), expected);
+            }
+        }
+        // Only the glider and its surroundings exist
+        let cells = app.world.query::<&MooreCell2d>().iter(&app.world).count();
+        assert!(cells <= 5 * 9, "{cells}");
+    }
+
+    #[test]
+    fn sparse_grids() {
+        let mut app = App::new();
+        app.add_plugins((
+            GameOfLife2dPlugin::default(),
+            InfiniteGridPlugin::<MooreCell2d, ConwayCellState>::default(),
+        ));
+        let root = app.world.spawn_empty().id();
+        app.world
+            .spawn((MooreCell2d::new(IVec2::ZERO), ConwayCellState(true)));
+        app.world.spawn((
+            MooreCell2d::new(IVec2::ZERO),
+            ConwayCellState(true),
+            GridRoot(root),
+        ));
+        app.update();
+        // Both grids spawned the neighbors of their live cell
+        let mut cells = app.world.query::<(&MooreCell2d, Option<&GridRoot>)>();
+        let grid_cells: HashSet<_> = cells
+            .iter(&app.world)
+            .map(|(cell, root)| (root.copied(), cell.coords))
+            .collect();
+        for grid in [None, Some(GridRoot(root))] {
+            assert!(grid_cells.contains(&(grid, IVec2::X)));
+            assert!(grid_cells.contains(&(grid, IVec2::NEG_ONE)));
+        }
+    }
+}
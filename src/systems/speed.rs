@@ -0,0 +1,48 @@
+use crate::{resources::SimulationSpeed, SimulationTick};
+use bevy::prelude::*;
+
+/// Runs the [`SimulationTick`] schedule as many times as the
+/// [`SimulationSpeed`] allows for the current frame, once without it.
+pub fn run_simulation_ticks(world: &mut World) {
+    let delta = world
+        .get_resource::<Time>()
+        .map_or_else(default, Time::delta);
+    let ticks = world
+        .get_resource_mut::<SimulationSpeed>()
+        .map_or(1, |mut speed| speed.advance(delta));
+    for _ in 0..ticks {
+        world.run_schedule(SimulationTick);
+    }
+}
+
+#[cfg(all(test, feature = "2D"))]
+mod tests {
+    use crate::{CellularAutomatonPlugin, ConwayCellState, MooreCell2d};
+    use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash, ScheduleLabel)]
+    struct Step;
+
+    #[test]
+    fn custom_schedule() {
+        let mut app = App::new();
+        app.add_plugins(
+            CellularAutomatonPlugin::<MooreCell2d, ConwayCellState>::new().with_schedule(Step),
+        );
+        let cell = app
+            .world
+            .spawn((MooreCell2d::new(IVec2::ZERO), ConwayCellState(true)))
+            .id();
+        app.update();
+        assert_eq!(
+            app.world.get::<ConwayCellState>(cell),
+            Some(&ConwayCellState(true))
+        );
+        // The lonely cell dies when the schedule runs
+        app.world.run_schedule(Step);
+        assert_eq!(
+            app.world.get::<ConwayCellState>(cell),
+            Some(&ConwayCellState(false))
+        );
+    }
+}
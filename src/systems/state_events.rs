@@ -0,0 +1,38 @@
+use crate::{components::CellState, events::CellStateChangedEvent};
+use bevy::{prelude::*, utils::HashMap};
+
+/// Last known `S` state of every cell
+#[derive(Resource)]
+pub struct KnownStates<S>(HashMap<Entity, S>);
+
+impl<S> Default for KnownStates<S> {
+    fn default() -> Self {
+        Self(HashMap::default())
+    }
+}
+
+/// Sends a [`CellStateChangedEvent`] for every cell whose `S` state changed
+/// since the last known state. New cells are only recorded.
+#[allow(clippy::needless_pass_by_value)]
+pub fn send_state_changed_events<S: CellState>(
+    cells: Query<(Entity, &S), Changed<S>>,
+    mut removed: RemovedComponents<S>,
+    mut known: ResMut<KnownStates<S>>,
+    mut events: EventWriter<CellStateChangedEvent<S>>,
+) {
+    for entity in removed.read() {
+        known.0.remove(&entity);
+    }
+    for (entity, state) in &cells {
+        match known.0.insert(entity, state.clone()) {
+            Some(old) if &old != state => {
+                events.send(CellStateChangedEvent {
+                    entity,
+                    old,
+                    new: state.clone(),
+                });
+            }
+            _ => (),
+        }
+    }
+}
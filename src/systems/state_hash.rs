@@ -0,0 +1,23 @@
+use crate::{
+    components::{Cell, CellState},
+    resources::{Generation, StateHash},
+    SimulationPause,
+};
+use bevy::prelude::*;
+use std::hash::Hash;
+
+/// Computes the [`StateHash`] of the cell states of the current
+/// [`Generation`]
+#[allow(clippy::needless_pass_by_value)]
+pub fn hash_states<C: Cell, S: CellState + Hash>(
+    cells: Query<(&C, &S)>,
+    generation: Res<Generation>,
+    mut hash: ResMut<StateHash<S>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    hash.generation = generation.0;
+    hash.hash = StateHash::compute(cells.iter().map(|(cell, state)| (cell.coords(), state)));
+}
@@ -0,0 +1,40 @@
+use crate::{
+    components::CellState,
+    events::SimulationFinishedEvent,
+    resources::{Generation, SimulationState, Termination},
+    SimulationPause,
+};
+use bevy::{app::AppExit, prelude::*};
+
+/// Checks the conditions of the [`Termination`] resource, if any, after a
+/// tick, sending a [`SimulationFinishedEvent`] and pausing the simulation
+/// when one is met.
+#[allow(clippy::needless_pass_by_value, clippy::too_many_arguments)]
+pub fn detect_termination<S: CellState>(
+    states: Query<&S>,
+    changed: Query<(), Changed<S>>,
+    generation: Res<Generation>,
+    termination: Option<ResMut<Termination<S>>>,
+    mut events: EventWriter<SimulationFinishedEvent>,
+    mut exit: EventWriter<AppExit>,
+    mut next_state: ResMut<NextState<SimulationState>>,
+    pause: Option<Res<SimulationPause>>,
+) {
+    let Some(mut termination) = termination else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let Some(reason) = termination.check(generation.0, !changed.is_empty(), states.iter()) else {
+        return;
+    };
+    events.send(SimulationFinishedEvent {
+        generation: generation.0,
+        reason,
+    });
+    next_state.set(SimulationState::Paused);
+    if termination.exit {
+        exit.send(AppExit);
+    }
+}
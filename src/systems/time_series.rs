@@ -0,0 +1,37 @@
+use crate::{components::CellState, resources::TimeSeriesRecorder, SimulationPause};
+use bevy::{app::AppExit, prelude::*};
+
+/// Appends a row of aggregates of every cell state to the
+/// [`TimeSeriesRecorder`] resource, if any.
+#[allow(clippy::needless_pass_by_value)]
+pub fn record_time_series<S>(
+    states: Query<&S>,
+    recorder: Option<ResMut<TimeSeriesRecorder<S>>>,
+    pause: Option<Res<SimulationPause>>,
+) where
+    S: CellState,
+{
+    let Some(mut recorder) = recorder else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let states: Vec<&S> = states.iter().collect();
+    recorder.record(&states);
+}
+
+/// Exports the [`TimeSeriesRecorder`] resource, if any, when the app exits.
+pub fn export_time_series<S>(
+    mut exit: EventReader<AppExit>,
+    recorder: Option<Res<TimeSeriesRecorder<S>>>,
+) where
+    S: CellState,
+{
+    if exit.read().count() == 0 {
+        return;
+    }
+    if let Some(recorder) = recorder {
+        recorder.export();
+    }
+}
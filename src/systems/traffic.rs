@@ -0,0 +1,76 @@
+use crate::{
+    components::{Cell1d, CellCoordinates, TrafficState},
+    resources::{BoundaryCondition, CellRng, MapSize, SimulationRng, TrafficParams, TrafficStats},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashMap};
+use rand::Rng;
+
+/// Applies the Nagel–Schreckenberg steps to every car and moves them all at
+/// once, then updates the [`TrafficStats`].
+///
+/// Cars driving out of a bounded road leave the simulation.
+#[allow(clippy::needless_pass_by_value)]
+pub fn handle_traffic(
+    mut cells: Query<(&Cell1d, &mut TrafficState)>,
+    params: Res<TrafficParams>,
+    mut stats: ResMut<TrafficStats>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) {
+    if pause.is_some() {
+        return;
+    }
+    let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+    let road = |coords: i32| match boundary {
+        Some((condition, size)) => coords.apply_boundary(condition, size),
+        None => Some(coords),
+    };
+    let road_states: HashMap<i32, TrafficState> = cells
+        .iter()
+        .map(|(cell, state)| (cell.coords, *state))
+        .collect();
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let mut rng = CellRng;
+    let mut cars = HashMap::new();
+    for (&coords, state) in &road_states {
+        let TrafficState::Car(velocity) = *state else {
+            continue;
+        };
+        // Acceleration
+        let mut velocity = velocity.saturating_add(1).min(params.max_velocity);
+        // Braking
+        if let Some(gap) = (1..=velocity).find(|d| {
+            road(coords + i32::from(*d))
+                .and_then(|c| road_states.get(&c))
+                .is_some_and(|s| *s != TrafficState::Empty)
+        }) {
+            velocity = gap - 1;
+        }
+        // Randomization
+        if velocity > 0 && rng.gen_bool(params.slowdown_probability) {
+            velocity -= 1;
+        }
+        // Motion
+        if let Some(target) =
+            road(coords + i32::from(velocity)).filter(|c| road_states.contains_key(c))
+        {
+            cars.insert(target, velocity);
+        }
+    }
+    for (cell, mut state) in &mut cells {
+        let new_state = cars
+            .get(&cell.coords)
+            .map_or(TrafficState::Empty, |v| TrafficState::Car(*v));
+        if *state != new_state {
+            *state = new_state;
+        }
+    }
+    *stats = TrafficStats {
+        cells: road_states.len(),
+        cars: cars.len(),
+        total_velocity: cars.values().map(|v| u32::from(*v)).sum(),
+    };
+}
@@ -0,0 +1,55 @@
+use crate::{
+    components::{Cell, CellCoordinates, VaccinableCellState},
+    resources::{BoundaryCondition, MapSize, SimulationRng, Vaccination, VaccinationTarget},
+    SimulationPause,
+};
+use bevy::{prelude::*, utils::HashSet};
+
+/// Vaccinates the cells targeted by the [`Vaccination`] resource, if any.
+///
+/// Ring vaccination targets the neighbors of the cells infected before any
+/// vaccination.
+#[allow(clippy::needless_pass_by_value)]
+pub fn vaccinate_cells<C, S>(
+    mut cells: Query<(&C, &mut S)>,
+    vaccination: Option<Res<Vaccination<C>>>,
+    pause: Option<Res<SimulationPause>>,
+    boundary: Option<Res<BoundaryCondition>>,
+    map_size: Option<Res<MapSize>>,
+    mut simulation_rng: Option<ResMut<SimulationRng>>,
+) where
+    C: Cell,
+    S: VaccinableCellState,
+{
+    let Some(vaccination) = vaccination else {
+        return;
+    };
+    if pause.is_some() {
+        return;
+    }
+    let _scope = SimulationRng::scope(simulation_rng.as_deref_mut());
+    let targets: Option<HashSet<C::Coordinates>> = match &vaccination.target {
+        VaccinationTarget::Everywhere => None,
+        VaccinationTarget::Region(coords) => Some(coords.iter().cloned().collect()),
+        VaccinationTarget::Ring => {
+            let boundary = boundary.zip(map_size).map(|(b, s)| (*b, *s));
+            Some(
+                cells
+                    .iter()
+                    .filter(|(_, state)| state.is_infected())
+                    .flat_map(|(cell, _)| cell.neighbor_coordinates())
+                    .filter_map(|c| match boundary {
+                        Some((condition, size)) => c.apply_boundary(condition, size),
+                        None => Some(c),
+                    })
+                    .collect(),
+            )
+        }
+    };
+    for (cell, mut state) in &mut cells {
+        if targets.as_ref().is_none_or(|t| t.contains(cell.coords())) {
+            let vaccinated = state.vaccinate(vaccination.coverage);
+            state.set_if_neq(vaccinated);
+        }
+    }
+}